@@ -0,0 +1,36 @@
+use std::process::Command;
+
+use chrono::{DateTime, Utc};
+
+/// `/version` で返すビルド情報を環境変数としてクレートに渡す
+fn main() {
+    let git_commit = git(&["rev-parse", "--short=12", "HEAD"]).unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=TODO_API_GIT_COMMIT={}", git_commit);
+
+    // 再現可能なビルドのため SOURCE_DATE_EPOCH があればその時刻を使う
+    let build_time = match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(epoch) => epoch
+            .parse()
+            .ok()
+            .and_then(|secs| DateTime::<Utc>::from_timestamp(secs, 0))
+            .map(|time| time.to_rfc3339())
+            .unwrap_or_else(|| "unknown".to_string()),
+        Err(_) => Utc::now().to_rfc3339(),
+    };
+    println!("cargo:rustc-env=TODO_API_BUILD_TIME={}", build_time);
+
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    if let Some(head_ref) = git(&["symbolic-ref", "-q", "HEAD"]) {
+        println!("cargo:rerun-if-changed=.git/{}", head_ref);
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let value = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!value.is_empty()).then_some(value)
+}
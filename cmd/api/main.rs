@@ -1,7 +1,38 @@
-use todo_api::infrastructure::server;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use clap::Parser;
+use todo_api::infrastructure::config::{Config, ConfigOverrides};
+use todo_api::infrastructure::{logging, server};
 
-#[tokio::main]
-async fn main() {
-    server::start_server().await;
+/// Todo APIサーバー
+#[derive(Parser)]
+#[command(name = "api", version)]
+struct Cli {
+    /// 設定ファイル（TOML）の場所（環境変数 TODO_API_CONFIG でも指定できる）
+    #[arg(short, long, value_name = "FILE")]
+    config: Option<PathBuf>,
+
+    #[command(flatten)]
+    overrides: ConfigOverrides,
 }
 
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let config = match Config::load(cli.config.as_deref(), &cli.overrides) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("configuration error: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    logging::init(&config.log);
+
+    if let Err(e) = server::start_server(config).await {
+        eprintln!("error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Host};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ApiKeysGetResponse {
+    /// List of API keys
+    Status200_ListOfAPIKeys
+    (Vec<models::ApiKey>)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope
+    Status403_TheCredentialsDoNotGrantTheRequiredScope
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ApiKeysIdDeleteResponse {
+    /// API key revoked
+    Status204_APIKeyRevoked
+    ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope
+    Status403_TheCredentialsDoNotGrantTheRequiredScope
+    (models::Problem)
+    ,
+    /// API key not found
+    Status404_APIKeyNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ApiKeysPostResponse {
+    /// API key created
+    Status201_APIKeyCreated
+    (models::CreatedApiKey)
+    ,
+    /// Validation error
+    Status400_ValidationError
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope
+    Status403_TheCredentialsDoNotGrantTheRequiredScope
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+
+/// ApiKeys
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait ApiKeys<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    /// List API keys.
+    ///
+    /// ApiKeysGet - GET /api-keys
+    async fn api_keys_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<ApiKeysGetResponse, E>;
+
+    /// Revoke an API key.
+    ///
+    /// ApiKeysIdDelete - DELETE /api-keys/{id}
+    async fn api_keys_id_delete(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::ApiKeysIdDeletePathParams,
+    ) -> Result<ApiKeysIdDeleteResponse, E>;
+
+    /// Create an API key.
+    ///
+    /// ApiKeysPost - POST /api-keys
+    async fn api_keys_post(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+            body: &models::CreateApiKey,
+    ) -> Result<ApiKeysPostResponse, E>;
+}
@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Host};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthLoginPostResponse {
+    /// Session started
+    Status200_SessionStarted
+    {
+        body: models::Session,
+        set_cookie:
+        String
+
+    }
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the CSRF token is missing or wrong
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheCSRFTokenIsMissingOrWrong
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthLogoutPostResponse {
+    /// Session ended
+    Status204_SessionEnded
+    {
+        set_cookie:
+        String
+
+    }
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the CSRF token is missing or wrong
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheCSRFTokenIsMissingOrWrong
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthSessionGetResponse {
+    /// Current session
+    Status200_CurrentSession
+    (models::Session)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// No session cookie was sent
+    Status404_NoSessionCookieWasSent
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+
+/// Auth
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Auth<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    /// Start a cookie session.
+    ///
+    /// AuthLoginPost - POST /auth/login
+    async fn auth_login_post(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<AuthLoginPostResponse, E>;
+
+    /// End the cookie session.
+    ///
+    /// AuthLogoutPost - POST /auth/logout
+    async fn auth_logout_post(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<AuthLogoutPostResponse, E>;
+
+    /// Get the cookie session.
+    ///
+    /// AuthSessionGet - GET /auth/session
+    async fn auth_session_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<AuthSessionGetResponse, E>;
+}
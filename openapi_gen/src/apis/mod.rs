@@ -1,4 +1,9 @@
+pub mod api_keys;
+pub mod auth;
+pub mod projects;
+pub mod system;
 pub mod tasks;
+pub mod users;
 
 
 // Error handler for unhandled errors.
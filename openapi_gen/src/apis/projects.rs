@@ -0,0 +1,366 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Host};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ProjectsGetResponse {
+    /// List of projects
+    Status200_ListOfProjects
+    (Vec<models::Project>)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ProjectsIdDeleteResponse {
+    /// Project deleted
+    Status204_ProjectDeleted
+    ,
+    /// The inbox cannot be deleted, or a negative ID in the path
+    Status400_TheInboxCannotBeDeletedOrANegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the project belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheProjectBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Project not found
+    Status404_ProjectNotFound
+    (models::Problem)
+    ,
+    /// The project still has tasks
+    Status409_TheProjectStillHasTasks
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ProjectsIdGetResponse {
+    /// Project found
+    Status200_ProjectFound
+    (models::Project)
+    ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The project belongs to another user
+    Status403_TheProjectBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Project not found
+    Status404_ProjectNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ProjectsIdPutResponse {
+    /// Project updated
+    Status200_ProjectUpdated
+    (models::Project)
+    ,
+    /// Validation error, or a negative ID in the path
+    Status400_ValidationErrorOrANegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the project belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheProjectBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Project not found
+    Status404_ProjectNotFound
+    (models::Problem)
+    ,
+    /// A project with this name already exists
+    Status409_AProjectWithThisNameAlreadyExists
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ProjectsIdTasksGetResponse {
+    /// List of project tasks
+    Status200_ListOfProjectTasks
+    {
+        body: Vec<models::Task>,
+        link:
+        Option<String>
+        ,
+        x_next_cursor:
+        Option<String>
+
+    }
+    ,
+    /// Invalid query parameter, or a negative ID in the path
+    Status400_InvalidQueryParameterOrANegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The project belongs to another user
+    Status403_TheProjectBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Project not found
+    Status404_ProjectNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ProjectsPostResponse {
+    /// Project created
+    Status201_ProjectCreated
+    (models::Project)
+    ,
+    /// Validation error
+    Status400_ValidationError
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope
+    Status403_TheCredentialsDoNotGrantTheRequiredScope
+    (models::Problem)
+    ,
+    /// A project with this name already exists
+    Status409_AProjectWithThisNameAlreadyExists
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+
+/// Projects
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Projects<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    /// List projects.
+    ///
+    /// ProjectsGet - GET /projects
+    async fn projects_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<ProjectsGetResponse, E>;
+
+    /// Delete a project.
+    ///
+    /// ProjectsIdDelete - DELETE /projects/{id}
+    async fn projects_id_delete(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::ProjectsIdDeletePathParams,
+    ) -> Result<ProjectsIdDeleteResponse, E>;
+
+    /// Get a project by ID.
+    ///
+    /// ProjectsIdGet - GET /projects/{id}
+    async fn projects_id_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::ProjectsIdGetPathParams,
+    ) -> Result<ProjectsIdGetResponse, E>;
+
+    /// Update a project.
+    ///
+    /// ProjectsIdPut - PUT /projects/{id}
+    async fn projects_id_put(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::ProjectsIdPutPathParams,
+            body: &models::UpdateProject,
+    ) -> Result<ProjectsIdPutResponse, E>;
+
+    /// List the tasks of a project.
+    ///
+    /// ProjectsIdTasksGet - GET /projects/{id}/tasks
+    async fn projects_id_tasks_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::ProjectsIdTasksGetPathParams,
+      query_params: &models::ProjectsIdTasksGetQueryParams,
+    ) -> Result<ProjectsIdTasksGetResponse, E>;
+
+    /// Create a project.
+    ///
+    /// ProjectsPost - POST /projects
+    async fn projects_post(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+            body: &models::CreateProject,
+    ) -> Result<ProjectsPostResponse, E>;
+}
@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Host};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum HealthzGetResponse {
+    /// The process is alive
+    Status200_TheProcessIsAlive
+    (models::HealthStatus)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum ReadyzGetResponse {
+    /// The service is ready to accept traffic
+    Status200_TheServiceIsReadyToAcceptTraffic
+    (models::HealthStatus)
+    ,
+    /// The task repository is unavailable
+    Status503_TheTaskRepositoryIsUnavailable
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum VersionGetResponse {
+    /// Build information
+    Status200_BuildInformation
+    (models::VersionInfo)
+}
+
+
+/// System
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait System<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    /// Liveness probe.
+    ///
+    /// HealthzGet - GET /healthz
+    async fn healthz_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<HealthzGetResponse, E>;
+
+    /// Readiness probe.
+    ///
+    /// ReadyzGet - GET /readyz
+    async fn readyz_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<ReadyzGetResponse, E>;
+
+    /// Build information.
+    ///
+    /// VersionGet - GET /version
+    async fn version_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<VersionGetResponse, E>;
+}
@@ -7,6 +7,84 @@ use serde::{Deserialize, Serialize};
 
 use crate::{models, types::*};
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TagsGetResponse {
+    /// Tags with task counts, ordered by tag
+    Status200_TagsWithTaskCountsOrderedByTag
+    (Vec<models::TagCount>)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksBulkPostResponse {
+    /// Batch processed
+    Status200_BatchProcessed
+    (models::BulkResult)
+    ,
+    /// Malformed batch
+    Status400_MalformedBatch
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or an operation addresses a task that belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrAnOperationAddressesATaskThatBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
@@ -15,8 +93,70 @@ pub enum TasksCompletedGetResponse {
     Status200_ListOfCompletedTasks
     (Vec<models::Task>)
     ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksDueSoonGetResponse {
+    /// List of tasks due soon
+    Status200_ListOfTasksDueSoon
+    (Vec<models::Task>)
+    ,
+    /// Invalid query parameter
+    Status400_InvalidQueryParameter
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -25,10 +165,92 @@ pub enum TasksCompletedGetResponse {
 pub enum TasksGetResponse {
     /// List of all tasks
     Status200_ListOfAllTasks
+    {
+        body: Vec<models::Task>,
+        link:
+        Option<String>
+        ,
+        x_next_cursor:
+        Option<String>
+
+    }
+    ,
+    /// Invalid query parameter
+    Status400_InvalidQueryParameter
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksIdChildrenGetResponse {
+    /// List of subtasks
+    Status200_ListOfSubtasks
     (Vec<models::Task>)
     ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The task belongs to another user
+    Status403_TheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Task not found
+    Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -37,13 +259,58 @@ pub enum TasksGetResponse {
 pub enum TasksIdCompletePutResponse {
     /// Task marked as completed
     Status200_TaskMarkedAsCompleted
-    (models::Task)
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
     ,
     /// Task not found
     Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Task is blocked by incomplete dependencies
+    Status409_TaskIsBlockedByIncompleteDependencies
+    (models::Problem)
+    ,
+    /// Task was modified since the given ETag
+    Status412_TaskWasModifiedSinceTheGivenETag
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
     ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -53,11 +320,155 @@ pub enum TasksIdDeleteResponse {
     /// Task deleted successfully
     Status204_TaskDeletedSuccessfully
     ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Task not found
+    Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Task has subtasks and children is reject
+    Status409_TaskHasSubtasksAndChildrenIsReject
+    (models::Problem)
+    ,
+    /// Task was modified since the given ETag
+    Status412_TaskWasModifiedSinceTheGivenETag
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksIdDependenciesDependencyIdDeleteResponse {
+    /// Dependency removed successfully
+    Status200_DependencyRemovedSuccessfully
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Task not found
+    Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksIdDependenciesPostResponse {
+    /// Dependencies added successfully
+    Status200_DependenciesAddedSuccessfully
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Unknown dependency or dependency cycle, or a negative task or dependency ID
+    Status400_UnknownDependencyOrDependencyCycleOrANegativeTaskOrDependencyID
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
     /// Task not found
     Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
     ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -66,13 +477,152 @@ pub enum TasksIdDeleteResponse {
 pub enum TasksIdGetResponse {
     /// Task found
     Status200_TaskFound
-    (models::Task)
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Task has not been modified
+    Status304_TaskHasNotBeenModified
+    {
+        etag:
+        String
+
+    }
+    ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The task belongs to another user
+    Status403_TheTaskBelongsToAnotherUser
+    (models::Problem)
     ,
     /// Task not found
     Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
     ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksIdHistoryGetResponse {
+    /// Task history
+    Status200_TaskHistory
+    (Vec<models::TaskHistoryEntry>)
+    ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The task belongs to another user
+    Status403_TheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Task not found
+    Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksIdOccurrencesGetResponse {
+    /// Upcoming occurrences
+    Status200_UpcomingOccurrences
+    (Vec<chrono::DateTime::<chrono::Utc>>)
+    ,
+    /// Task does not recur, or a negative ID in the path
+    Status400_TaskDoesNotRecurOrANegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The task belongs to another user
+    Status403_TheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Task not found
+    Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -81,16 +631,266 @@ pub enum TasksIdGetResponse {
 pub enum TasksIdPutResponse {
     /// Task updated successfully
     Status200_TaskUpdatedSuccessfully
-    (models::Task)
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Validation error or invalid operation, or a negative ID in the path
+    Status400_ValidationErrorOrInvalidOperationOrANegativeIDInThePath
+    (models::Problem)
     ,
-    /// Validation error or invalid operation
-    Status400_ValidationErrorOrInvalidOperation
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
     ,
     /// Task not found
     Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Task is blocked by incomplete dependencies
+    Status409_TaskIsBlockedByIncompleteDependencies
+    (models::Problem)
+    ,
+    /// Task was modified since the given ETag
+    Status412_TaskWasModifiedSinceTheGivenETag
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksIdRecurrenceDeleteResponse {
+    /// Recurrence stopped
+    Status200_RecurrenceStopped
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Task not found
+    Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksIdRestorePostResponse {
+    /// Task restored
+    Status200_TaskRestored
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Task not found in the trash
+    Status404_TaskNotFoundInTheTrash
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
     ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksIdTagsPostResponse {
+    /// Tags added successfully
+    Status200_TagsAddedSuccessfully
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Invalid tag or too many tags, or a negative ID in the path
+    Status400_InvalidTagOrTooManyTagsOrANegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Task not found
+    Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksIdTagsTagDeleteResponse {
+    /// Tag removed successfully
+    Status200_TagRemovedSuccessfully
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Invalid tag, or a negative ID in the path
+    Status400_InvalidTagOrANegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
+    ,
+    /// Task not found
+    Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -99,13 +899,89 @@ pub enum TasksIdPutResponse {
 pub enum TasksIdUncompletePutResponse {
     /// Task marked as uncompleted
     Status200_TaskMarkedAsUncompleted
-    (models::Task)
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
+    ,
+    /// Negative ID in the path
+    Status400_NegativeIDInThePath
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope, or the task belongs to another user
+    Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+    (models::Problem)
     ,
     /// Task not found
     Status404_TaskNotFound
+    (models::Problem)
+    ,
+    /// Task was modified since the given ETag
+    Status412_TaskWasModifiedSinceTheGivenETag
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
     ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksOverdueGetResponse {
+    /// List of overdue tasks
+    Status200_ListOfOverdueTasks
+    (Vec<models::Task>)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -116,8 +992,31 @@ pub enum TasksPendingGetResponse {
     Status200_ListOfPendingTasks
     (Vec<models::Task>)
     ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -126,13 +1025,81 @@ pub enum TasksPendingGetResponse {
 pub enum TasksPostResponse {
     /// Task created successfully
     Status201_TaskCreatedSuccessfully
-    (models::Task)
+    {
+        body: models::Task,
+        etag:
+        String
+
+    }
     ,
     /// Validation error
     Status400_ValidationError
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope
+    Status403_TheCredentialsDoNotGrantTheRequiredScope
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
     ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksReadyGetResponse {
+    /// List of ready tasks
+    Status200_ListOfReadyTasks
+    (Vec<models::Task>)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -143,8 +1110,144 @@ pub enum TasksSearchGetResponse {
     Status200_SearchResults
     (Vec<models::Task>)
     ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksTrashDeleteResponse {
+    /// Trash purged
+    Status200_TrashPurged
+    (models::PurgeResult)
+    ,
+    /// Invalid query parameter
+    Status400_InvalidQueryParameter
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope
+    Status403_TheCredentialsDoNotGrantTheRequiredScope
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksTrashGetResponse {
+    /// List of deleted tasks
+    Status200_ListOfDeletedTasks
+    (Vec<models::Task>)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
     /// Internal server error
     Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum TasksTreeGetResponse {
+    /// Task tree
+    Status200_TaskTree
+    (Vec<models::TaskTreeNode>)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
 }
 
 
@@ -152,6 +1255,27 @@ pub enum TasksSearchGetResponse {
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    /// List tags.
+    ///
+    /// TagsGet - GET /tags
+    async fn tags_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<TagsGetResponse, E>;
+
+    /// Apply several task operations at once.
+    ///
+    /// TasksBulkPost - POST /tasks/bulk
+    async fn tasks_bulk_post(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+            body: &models::BulkRequest,
+    ) -> Result<TasksBulkPostResponse, E>;
+
     /// Get all completed tasks.
     ///
     /// TasksCompletedGet - GET /tasks/completed
@@ -162,6 +1286,17 @@ pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
     cookies: &CookieJar,
     ) -> Result<TasksCompletedGetResponse, E>;
 
+    /// Get tasks due soon.
+    ///
+    /// TasksDueSoonGet - GET /tasks/due-soon
+    async fn tasks_due_soon_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      query_params: &models::TasksDueSoonGetQueryParams,
+    ) -> Result<TasksDueSoonGetResponse, E>;
+
     /// Get all tasks.
     ///
     /// TasksGet - GET /tasks
@@ -170,8 +1305,20 @@ pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
     method: &Method,
     host: &Host,
     cookies: &CookieJar,
+      query_params: &models::TasksGetQueryParams,
     ) -> Result<TasksGetResponse, E>;
 
+    /// Get the subtasks of a task.
+    ///
+    /// TasksIdChildrenGet - GET /tasks/{id}/children
+    async fn tasks_id_children_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::TasksIdChildrenGetPathParams,
+    ) -> Result<TasksIdChildrenGetResponse, E>;
+
     /// Mark a task as completed.
     ///
     /// TasksIdCompletePut - PUT /tasks/{id}/complete
@@ -180,7 +1327,9 @@ pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
     method: &Method,
     host: &Host,
     cookies: &CookieJar,
+      header_params: &models::TasksIdCompletePutHeaderParams,
       path_params: &models::TasksIdCompletePutPathParams,
+      query_params: &models::TasksIdCompletePutQueryParams,
     ) -> Result<TasksIdCompletePutResponse, E>;
 
     /// Delete a task.
@@ -191,9 +1340,34 @@ pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
     method: &Method,
     host: &Host,
     cookies: &CookieJar,
+      header_params: &models::TasksIdDeleteHeaderParams,
       path_params: &models::TasksIdDeletePathParams,
+      query_params: &models::TasksIdDeleteQueryParams,
     ) -> Result<TasksIdDeleteResponse, E>;
 
+    /// Remove a dependency from a task.
+    ///
+    /// TasksIdDependenciesDependencyIdDelete - DELETE /tasks/{id}/dependencies/{dependency_id}
+    async fn tasks_id_dependencies_dependency_id_delete(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::TasksIdDependenciesDependencyIdDeletePathParams,
+    ) -> Result<TasksIdDependenciesDependencyIdDeleteResponse, E>;
+
+    /// Add dependencies to a task.
+    ///
+    /// TasksIdDependenciesPost - POST /tasks/{id}/dependencies
+    async fn tasks_id_dependencies_post(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::TasksIdDependenciesPostPathParams,
+            body: &models::AddDependencies,
+    ) -> Result<TasksIdDependenciesPostResponse, E>;
+
     /// Get a task by ID.
     ///
     /// TasksIdGet - GET /tasks/{id}
@@ -202,9 +1376,33 @@ pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
     method: &Method,
     host: &Host,
     cookies: &CookieJar,
+      header_params: &models::TasksIdGetHeaderParams,
       path_params: &models::TasksIdGetPathParams,
     ) -> Result<TasksIdGetResponse, E>;
 
+    /// Get the change history of a task.
+    ///
+    /// TasksIdHistoryGet - GET /tasks/{id}/history
+    async fn tasks_id_history_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::TasksIdHistoryGetPathParams,
+    ) -> Result<TasksIdHistoryGetResponse, E>;
+
+    /// Preview upcoming occurrences of a recurring task.
+    ///
+    /// TasksIdOccurrencesGet - GET /tasks/{id}/occurrences
+    async fn tasks_id_occurrences_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::TasksIdOccurrencesGetPathParams,
+      query_params: &models::TasksIdOccurrencesGetQueryParams,
+    ) -> Result<TasksIdOccurrencesGetResponse, E>;
+
     /// Update a task.
     ///
     /// TasksIdPut - PUT /tasks/{id}
@@ -213,10 +1411,56 @@ pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
     method: &Method,
     host: &Host,
     cookies: &CookieJar,
+      header_params: &models::TasksIdPutHeaderParams,
       path_params: &models::TasksIdPutPathParams,
             body: &models::UpdateTask,
     ) -> Result<TasksIdPutResponse, E>;
 
+    /// Stop a recurring task.
+    ///
+    /// TasksIdRecurrenceDelete - DELETE /tasks/{id}/recurrence
+    async fn tasks_id_recurrence_delete(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::TasksIdRecurrenceDeletePathParams,
+    ) -> Result<TasksIdRecurrenceDeleteResponse, E>;
+
+    /// Restore a task from the trash.
+    ///
+    /// TasksIdRestorePost - POST /tasks/{id}/restore
+    async fn tasks_id_restore_post(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::TasksIdRestorePostPathParams,
+    ) -> Result<TasksIdRestorePostResponse, E>;
+
+    /// Add tags to a task.
+    ///
+    /// TasksIdTagsPost - POST /tasks/{id}/tags
+    async fn tasks_id_tags_post(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::TasksIdTagsPostPathParams,
+            body: &models::AddTags,
+    ) -> Result<TasksIdTagsPostResponse, E>;
+
+    /// Remove a tag from a task.
+    ///
+    /// TasksIdTagsTagDelete - DELETE /tasks/{id}/tags/{tag}
+    async fn tasks_id_tags_tag_delete(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      path_params: &models::TasksIdTagsTagDeletePathParams,
+    ) -> Result<TasksIdTagsTagDeleteResponse, E>;
+
     /// Mark a task as uncompleted.
     ///
     /// TasksIdUncompletePut - PUT /tasks/{id}/uncomplete
@@ -225,9 +1469,20 @@ pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
     method: &Method,
     host: &Host,
     cookies: &CookieJar,
+      header_params: &models::TasksIdUncompletePutHeaderParams,
       path_params: &models::TasksIdUncompletePutPathParams,
     ) -> Result<TasksIdUncompletePutResponse, E>;
 
+    /// Get overdue tasks.
+    ///
+    /// TasksOverdueGet - GET /tasks/overdue
+    async fn tasks_overdue_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<TasksOverdueGetResponse, E>;
+
     /// Get all pending tasks.
     ///
     /// TasksPendingGet - GET /tasks/pending
@@ -249,6 +1504,16 @@ pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
             body: &models::CreateTask,
     ) -> Result<TasksPostResponse, E>;
 
+    /// Get tasks that are ready to work on.
+    ///
+    /// TasksReadyGet - GET /tasks/ready
+    async fn tasks_ready_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<TasksReadyGetResponse, E>;
+
     /// Search tasks by description.
     ///
     /// TasksSearchGet - GET /tasks/search
@@ -259,4 +1524,35 @@ pub trait Tasks<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHa
     cookies: &CookieJar,
       query_params: &models::TasksSearchGetQueryParams,
     ) -> Result<TasksSearchGetResponse, E>;
+
+    /// Empty the trash.
+    ///
+    /// TasksTrashDelete - DELETE /tasks/trash
+    async fn tasks_trash_delete(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+      query_params: &models::TasksTrashDeleteQueryParams,
+    ) -> Result<TasksTrashDeleteResponse, E>;
+
+    /// List tasks in the trash.
+    ///
+    /// TasksTrashGet - GET /tasks/trash
+    async fn tasks_trash_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<TasksTrashGetResponse, E>;
+
+    /// Get all tasks as a tree.
+    ///
+    /// TasksTreeGet - GET /tasks/tree
+    async fn tasks_tree_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<TasksTreeGetResponse, E>;
 }
@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Host};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum UsersGetResponse {
+    /// List of users
+    Status200_ListOfUsers
+    (Vec<models::User>)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum UsersMeGetResponse {
+    /// Current user
+    Status200_CurrentUser
+    (models::User)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum UsersPostResponse {
+    /// User created
+    Status201_UserCreated
+    (models::User)
+    ,
+    /// Validation error
+    Status400_ValidationError
+    (models::Problem)
+    ,
+    /// Missing or invalid credentials, or unknown user
+    Status401_MissingOrInvalidCredentialsOrUnknownUser
+    (models::Problem)
+    ,
+    /// The credentials do not grant the required scope
+    Status403_TheCredentialsDoNotGrantTheRequiredScope
+    (models::Problem)
+    ,
+    /// A user with this name already exists
+    Status409_AUserWithThisNameAlreadyExists
+    (models::Problem)
+    ,
+    /// Too many requests
+    Status429_TooManyRequests
+    {
+        body: models::Problem,
+        retry_after:
+        i64
+        ,
+        rate_limit_limit:
+        i64
+        ,
+        rate_limit_remaining:
+        i64
+        ,
+        rate_limit_reset:
+        i64
+
+    }
+    ,
+    /// Internal server error
+    Status500_InternalServerError
+    (models::Problem)
+}
+
+
+/// Users
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Users<E: std::fmt::Debug + Send + Sync + 'static = ()>: super::ErrorHandler<E> {
+    /// List users.
+    ///
+    /// UsersGet - GET /users
+    async fn users_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<UsersGetResponse, E>;
+
+    /// Get the current user.
+    ///
+    /// UsersMeGet - GET /users/me
+    async fn users_me_get(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+    ) -> Result<UsersMeGetResponse, E>;
+
+    /// Create a user.
+    ///
+    /// UsersPost - POST /users
+    async fn users_post(
+    &self,
+    method: &Method,
+    host: &Host,
+    cookies: &CookieJar,
+            body: &models::CreateUser,
+    ) -> Result<UsersPostResponse, E>;
+}
@@ -9,7 +9,170 @@ use crate::{models, types::*};
 
       
       
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct ApiKeysIdDeletePathParams {
+            /// API key ID
+                pub id: i64,
+    }
+
+
+      
+      
+      
+      
+      
+      
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct ProjectsIdDeletePathParams {
+            /// Project ID
+                pub id: i64,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct ProjectsIdGetPathParams {
+            /// Project ID
+                pub id: i64,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct ProjectsIdPutPathParams {
+            /// Project ID
+                pub id: i64,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct ProjectsIdTasksGetPathParams {
+            /// Project ID
+                pub id: i64,
+    }
+
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct ProjectsIdTasksGetQueryParams {
+            /// Maximum number of tasks to return (default 100)
+                #[serde(rename = "limit")]
+                #[validate(
+                        range(min = 1i32, max = 1000i32),
+                    )]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+            /// Opaque cursor returned in X-Next-Cursor by the previous page
+                #[serde(rename = "cursor")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub cursor: Option<String>,
+            /// Field to sort by (default id)
+                #[serde(rename = "sort")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub sort: Option<models::TaskSortField>,
+            /// Sort direction (default asc)
+                #[serde(rename = "order")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub order: Option<models::SortOrder>,
+            /// Only return tasks with this completion state
+                #[serde(rename = "completed")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub completed: Option<bool>,
+            /// Only return tasks with these tags (repeat the parameter for several tags)
+                #[serde(rename = "tag")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub tag: Option<Vec<String>>,
+            /// Whether tasks must have all of the given tags or any of them (default all)
+                #[serde(rename = "tag_mode")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub tag_mode: Option<models::TagMatchMode>,
+    }
+
+
+      
+      
+      
+      
+      
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksDueSoonGetQueryParams {
+            /// Size of the window in hours (default 24)
+                #[serde(rename = "hours")]
+                #[validate(
+                        range(min = 1i32, max = 8760i32),
+                    )]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub hours: Option<i32>,
+    }
+
+
       
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksGetQueryParams {
+            /// Maximum number of tasks to return (default 100)
+                #[serde(rename = "limit")]
+                #[validate(
+                        range(min = 1i32, max = 1000i32),
+                    )]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+            /// Opaque cursor returned in X-Next-Cursor by the previous page
+                #[serde(rename = "cursor")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub cursor: Option<String>,
+            /// Field to sort by (default id)
+                #[serde(rename = "sort")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub sort: Option<models::TaskSortField>,
+            /// Sort direction (default asc)
+                #[serde(rename = "order")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub order: Option<models::SortOrder>,
+            /// Only return tasks with this completion state
+                #[serde(rename = "completed")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub completed: Option<bool>,
+            /// Only return tasks with these tags (repeat the parameter for several tags)
+                #[serde(rename = "tag")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub tag: Option<Vec<String>>,
+            /// Whether tasks must have all of the given tags or any of them (default all)
+                #[serde(rename = "tag_mode")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub tag_mode: Option<models::TagMatchMode>,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdChildrenGetPathParams {
+            /// Task ID
+                pub id: i64,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdCompletePutHeaderParams {
+            /// Only apply the change if the task's current ETag matches one of these (or * for any)
+                #[serde(rename = "If-Match")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub if_match: Option<String>,
+    }
+
+
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
     pub struct TasksIdCompletePutPathParams {
@@ -18,7 +181,27 @@ use crate::{models, types::*};
     }
 
 
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdCompletePutQueryParams {
+            /// Complete the task even if tasks it depends on are still pending
+                #[serde(rename = "force")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub force: Option<bool>,
+    }
+
+
       
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdDeleteHeaderParams {
+            /// Only apply the change if the task's current ETag matches one of these (or * for any)
+                #[serde(rename = "If-Match")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub if_match: Option<String>,
+    }
+
+
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
     pub struct TasksIdDeletePathParams {
@@ -27,7 +210,47 @@ use crate::{models, types::*};
     }
 
 
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdDeleteQueryParams {
+            /// What to do with the task's subtasks (default reject)
+                #[serde(rename = "children")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub children: Option<models::ChildDeletePolicy>,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdDependenciesDependencyIdDeletePathParams {
+            /// Task ID
+                pub id: i64,
+            /// ID of the task to stop depending on
+                pub dependency_id: i64,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdDependenciesPostPathParams {
+            /// Task ID
+                pub id: i64,
+    }
+
+
       
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdGetHeaderParams {
+            /// Return 304 Not Modified if the task's current ETag matches one of these
+                #[serde(rename = "If-None-Match")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub if_none_match: Option<String>,
+    }
+
+
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
     pub struct TasksIdGetPathParams {
@@ -37,6 +260,47 @@ use crate::{models, types::*};
 
 
       
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdHistoryGetPathParams {
+            /// Task ID
+                pub id: i64,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdOccurrencesGetPathParams {
+            /// Task ID
+                pub id: i64,
+    }
+
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdOccurrencesGetQueryParams {
+            /// Number of occurrences to list (default 5)
+                #[serde(rename = "count")]
+                #[validate(
+                        range(min = 1i32, max = 100i32),
+                    )]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub count: Option<i32>,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdPutHeaderParams {
+            /// Only apply the change if the task's current ETag matches one of these (or * for any)
+                #[serde(rename = "If-Match")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub if_match: Option<String>,
+    }
+
+
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
     pub struct TasksIdPutPathParams {
@@ -46,6 +310,54 @@ use crate::{models, types::*};
 
 
       
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdRecurrenceDeletePathParams {
+            /// Task ID
+                pub id: i64,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdRestorePostPathParams {
+            /// Task ID
+                pub id: i64,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdTagsPostPathParams {
+            /// Task ID
+                pub id: i64,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdTagsTagDeletePathParams {
+            /// Task ID
+                pub id: i64,
+            /// Tag to remove
+                pub tag: String,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksIdUncompletePutHeaderParams {
+            /// Only apply the change if the task's current ETag matches one of these (or * for any)
+                #[serde(rename = "If-Match")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub if_match: Option<String>,
+    }
+
+
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
     pub struct TasksIdUncompletePutPathParams {
@@ -57,6 +369,8 @@ use crate::{models, types::*};
       
       
       
+      
+      
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
     pub struct TasksSearchGetQueryParams {
@@ -67,16 +381,4936 @@ use crate::{models, types::*};
     }
 
 
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct TasksTrashDeleteQueryParams {
+            /// Only purge tasks that have been in the trash for at least this many days
+                #[serde(rename = "older_than_days")]
+                #[validate(
+                        range(min = 0i32),
+                    )]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub older_than_days: Option<i32>,
+    }
+
+
+      
+      
+      
+      
+      
+      
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AddDependencies {
+    /// IDs of the tasks to depend on
+    #[serde(rename = "depends_on")]
+    #[validate(
+            length(min = 1, max = 100),
+        )]
+    pub depends_on: Vec<i64>,
+
+}
+
+
+
+
+
+impl AddDependencies {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(depends_on: Vec<i64>, ) -> AddDependencies {
+        AddDependencies {
+            depends_on,
+        }
+    }
+}
+
+/// Converts the AddDependencies value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for AddDependencies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("depends_on".to_string()),
+            Some(self.depends_on.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",")),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AddDependencies value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AddDependencies {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub depends_on: Vec<Vec<i64>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AddDependencies".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "depends_on" => return std::result::Result::Err("Parsing a container in this style is not supported in AddDependencies".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AddDependencies".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AddDependencies {
+            depends_on: intermediate_rep.depends_on.into_iter().next().ok_or_else(|| "depends_on missing in AddDependencies".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AddDependencies> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<AddDependencies>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AddDependencies>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AddDependencies - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AddDependencies> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AddDependencies as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AddDependencies - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AddTags {
+    /// Tags to add (lowercase letters, digits, '-', '_' and '.'; up to 32 characters)
+    #[serde(rename = "tags")]
+    #[validate(
+            length(min = 1, max = 20),
+        )]
+    pub tags: Vec<String>,
+
+}
+
+
+
+
+
+impl AddTags {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(tags: Vec<String>, ) -> AddTags {
+        AddTags {
+            tags,
+        }
+    }
+}
+
+/// Converts the AddTags value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for AddTags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("tags".to_string()),
+            Some(self.tags.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",")),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AddTags value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AddTags {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub tags: Vec<Vec<String>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AddTags".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "tags" => return std::result::Result::Err("Parsing a container in this style is not supported in AddTags".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AddTags".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AddTags {
+            tags: intermediate_rep.tags.into_iter().next().ok_or_else(|| "tags missing in AddTags".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AddTags> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<AddTags>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AddTags>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AddTags - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AddTags> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AddTags as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AddTags - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ApiKey {
+    /// Unique identifier for the API key
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    /// Name to tell the key apart
+    #[serde(rename = "name")]
+    #[validate(
+            length(max = 50),
+        )]
+    pub name: String,
+
+    #[serde(rename = "scope")]
+    pub scope: models::ApiKeyScope,
+
+    /// User that requests made with this key act as
+    #[serde(rename = "user_id")]
+    pub user_id: i64,
+
+    /// First characters of the key, to recognise it
+    #[serde(rename = "prefix")]
+    pub prefix: String,
+
+    /// When the key was issued
+    #[serde(rename = "created_at")]
+    pub created_at: chrono::DateTime::<chrono::Utc>,
+
+    /// When the key was revoked, or null while it is active
+    #[serde(rename = "revoked_at")]
+    #[serde(deserialize_with = "deserialize_optional_nullable")]
+    #[serde(default = "default_optional_nullable")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub revoked_at: Option<Nullable<chrono::DateTime::<chrono::Utc>>>,
+
+}
+
+
+
+
+
+impl ApiKey {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(id: i64, name: String, scope: models::ApiKeyScope, user_id: i64, prefix: String, created_at: chrono::DateTime::<chrono::Utc>, ) -> ApiKey {
+        ApiKey {
+            id,
+            name,
+            scope,
+            user_id,
+            prefix,
+            created_at,
+            revoked_at: None,
+        }
+    }
+}
+
+/// Converts the ApiKey value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+
+
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+
+            // Skipping scope in query parameter serialization
+
+
+            Some("user_id".to_string()),
+            Some(self.user_id.to_string()),
+
+
+            Some("prefix".to_string()),
+            Some(self.prefix.to_string()),
+
+            // Skipping created_at in query parameter serialization
+
+            // Skipping revoked_at in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ApiKey value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ApiKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i64>,
+            pub name: Vec<String>,
+            pub scope: Vec<models::ApiKeyScope>,
+            pub user_id: Vec<i64>,
+            pub prefix: Vec<String>,
+            pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub revoked_at: Vec<chrono::DateTime::<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ApiKey".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "scope" => intermediate_rep.scope.push(<models::ApiKeyScope as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "user_id" => intermediate_rep.user_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "prefix" => intermediate_rep.prefix.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "created_at" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "revoked_at" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in ApiKey".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ApiKey".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ApiKey {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in ApiKey".to_string())?,
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in ApiKey".to_string())?,
+            scope: intermediate_rep.scope.into_iter().next().ok_or_else(|| "scope missing in ApiKey".to_string())?,
+            user_id: intermediate_rep.user_id.into_iter().next().ok_or_else(|| "user_id missing in ApiKey".to_string())?,
+            prefix: intermediate_rep.prefix.into_iter().next().ok_or_else(|| "prefix missing in ApiKey".to_string())?,
+            created_at: intermediate_rep.created_at.into_iter().next().ok_or_else(|| "created_at missing in ApiKey".to_string())?,
+            revoked_at: std::result::Result::Err("Nullable types not supported in ApiKey".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ApiKey> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ApiKey>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ApiKey>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ApiKey - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ApiKey> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ApiKey as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ApiKey - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum ApiKeyScope {
+    #[serde(rename = "read")]
+    Read,
+    #[serde(rename = "write")]
+    Write,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl validator::Validate for ApiKeyScope
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ApiKeyScope::Read => write!(f, "read"),
+            ApiKeyScope::Write => write!(f, "write"),
+            ApiKeyScope::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl std::str::FromStr for ApiKeyScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "read" => std::result::Result::Ok(ApiKeyScope::Read),
+            "write" => std::result::Result::Ok(ApiKeyScope::Write),
+            "admin" => std::result::Result::Ok(ApiKeyScope::Admin),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ApiKeyScope> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ApiKeyScope>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ApiKeyScope>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ApiKeyScope - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ApiKeyScope> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ApiKeyScope as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ApiKeyScope - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct BulkItemResult {
+    /// Position of the operation in the request
+    #[serde(rename = "index")]
+    pub index: i32,
+
+    #[serde(rename = "op")]
+    pub op: models::BulkOperationType,
+
+    #[serde(rename = "status")]
+    pub status: models::BulkItemStatus,
+
+    #[serde(rename = "task")]
+    #[validate(
+            nested,
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub task: Option<models::Task>,
+
+    #[serde(rename = "error")]
+    #[validate(
+            nested,
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub error: Option<models::Problem>,
+
+}
+
+
+
+
+
+impl BulkItemResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(index: i32, op: models::BulkOperationType, status: models::BulkItemStatus, ) -> BulkItemResult {
+        BulkItemResult {
+            index,
+            op,
+            status,
+            task: None,
+            error: None,
+        }
+    }
+}
+
+/// Converts the BulkItemResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for BulkItemResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("index".to_string()),
+            Some(self.index.to_string()),
+
+            // Skipping op in query parameter serialization
+
+            // Skipping status in query parameter serialization
+
+            // Skipping task in query parameter serialization
+
+            // Skipping error in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a BulkItemResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for BulkItemResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub index: Vec<i32>,
+            pub op: Vec<models::BulkOperationType>,
+            pub status: Vec<models::BulkItemStatus>,
+            pub task: Vec<models::Task>,
+            pub error: Vec<models::Problem>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing BulkItemResult".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "index" => intermediate_rep.index.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "op" => intermediate_rep.op.push(<models::BulkOperationType as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(<models::BulkItemStatus as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "task" => intermediate_rep.task.push(<models::Task as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "error" => intermediate_rep.error.push(<models::Problem as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing BulkItemResult".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(BulkItemResult {
+            index: intermediate_rep.index.into_iter().next().ok_or_else(|| "index missing in BulkItemResult".to_string())?,
+            op: intermediate_rep.op.into_iter().next().ok_or_else(|| "op missing in BulkItemResult".to_string())?,
+            status: intermediate_rep.status.into_iter().next().ok_or_else(|| "status missing in BulkItemResult".to_string())?,
+            task: intermediate_rep.task.into_iter().next(),
+            error: intermediate_rep.error.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<BulkItemResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<BulkItemResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<BulkItemResult>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for BulkItemResult - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BulkItemResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <BulkItemResult as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into BulkItemResult - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum BulkItemStatus {
+    #[serde(rename = "succeeded")]
+    Succeeded,
+    #[serde(rename = "failed")]
+    Failed,
+    #[serde(rename = "rolled_back")]
+    RolledBack,
+    #[serde(rename = "skipped")]
+    Skipped,
+}
+
+impl validator::Validate for BulkItemStatus
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for BulkItemStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            BulkItemStatus::Succeeded => write!(f, "succeeded"),
+            BulkItemStatus::Failed => write!(f, "failed"),
+            BulkItemStatus::RolledBack => write!(f, "rolled_back"),
+            BulkItemStatus::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+impl std::str::FromStr for BulkItemStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "succeeded" => std::result::Result::Ok(BulkItemStatus::Succeeded),
+            "failed" => std::result::Result::Ok(BulkItemStatus::Failed),
+            "rolled_back" => std::result::Result::Ok(BulkItemStatus::RolledBack),
+            "skipped" => std::result::Result::Ok(BulkItemStatus::Skipped),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<BulkItemStatus> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<BulkItemStatus>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<BulkItemStatus>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for BulkItemStatus - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BulkItemStatus> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <BulkItemStatus as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into BulkItemStatus - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct BulkOperation {
+    #[serde(rename = "op")]
+    pub op: models::BulkOperationType,
+
+    /// Task ID
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<i64>,
+
+    #[serde(rename = "task")]
+    #[validate(
+            nested,
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub task: Option<models::CreateTask>,
+
+    #[serde(rename = "changes")]
+    #[validate(
+            nested,
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub changes: Option<models::UpdateTask>,
+
+}
+
+
+
+
+
+impl BulkOperation {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(op: models::BulkOperationType, ) -> BulkOperation {
+        BulkOperation {
+            op,
+            id: None,
+            task: None,
+            changes: None,
+        }
+    }
+}
+
+/// Converts the BulkOperation value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for BulkOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping op in query parameter serialization
+
+
+            self.id.as_ref().map(|id| {
+                [
+                    "id".to_string(),
+                    id.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping task in query parameter serialization
+
+            // Skipping changes in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a BulkOperation value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for BulkOperation {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub op: Vec<models::BulkOperationType>,
+            pub id: Vec<i64>,
+            pub task: Vec<models::CreateTask>,
+            pub changes: Vec<models::UpdateTask>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing BulkOperation".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "op" => intermediate_rep.op.push(<models::BulkOperationType as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "task" => intermediate_rep.task.push(<models::CreateTask as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "changes" => intermediate_rep.changes.push(<models::UpdateTask as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing BulkOperation".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(BulkOperation {
+            op: intermediate_rep.op.into_iter().next().ok_or_else(|| "op missing in BulkOperation".to_string())?,
+            id: intermediate_rep.id.into_iter().next(),
+            task: intermediate_rep.task.into_iter().next(),
+            changes: intermediate_rep.changes.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<BulkOperation> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<BulkOperation>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<BulkOperation>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for BulkOperation - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BulkOperation> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <BulkOperation as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into BulkOperation - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum BulkOperationType {
+    #[serde(rename = "create")]
+    Create,
+    #[serde(rename = "update")]
+    Update,
+    #[serde(rename = "complete")]
+    Complete,
+    #[serde(rename = "uncomplete")]
+    Uncomplete,
+    #[serde(rename = "delete")]
+    Delete,
+}
+
+impl validator::Validate for BulkOperationType
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for BulkOperationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            BulkOperationType::Create => write!(f, "create"),
+            BulkOperationType::Update => write!(f, "update"),
+            BulkOperationType::Complete => write!(f, "complete"),
+            BulkOperationType::Uncomplete => write!(f, "uncomplete"),
+            BulkOperationType::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+impl std::str::FromStr for BulkOperationType {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "create" => std::result::Result::Ok(BulkOperationType::Create),
+            "update" => std::result::Result::Ok(BulkOperationType::Update),
+            "complete" => std::result::Result::Ok(BulkOperationType::Complete),
+            "uncomplete" => std::result::Result::Ok(BulkOperationType::Uncomplete),
+            "delete" => std::result::Result::Ok(BulkOperationType::Delete),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<BulkOperationType> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<BulkOperationType>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<BulkOperationType>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for BulkOperationType - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BulkOperationType> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <BulkOperationType as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into BulkOperationType - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct BulkRequest {
+    /// Roll back every operation if any of them fails (default true)
+    #[serde(rename = "atomic")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub atomic: Option<bool>,
+
+    /// Operations to apply, in order
+    #[serde(rename = "operations")]
+    #[validate(
+            length(min = 1, max = 1000),
+            nested,
+        )]
+    pub operations: Vec<models::BulkOperation>,
+
+}
+
+
+
+
+
+impl BulkRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(operations: Vec<models::BulkOperation>, ) -> BulkRequest {
+        BulkRequest {
+            atomic: None,
+            operations,
+        }
+    }
+}
+
+/// Converts the BulkRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for BulkRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            self.atomic.as_ref().map(|atomic| {
+                [
+                    "atomic".to_string(),
+                    atomic.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping operations in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a BulkRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for BulkRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub atomic: Vec<bool>,
+            pub operations: Vec<Vec<models::BulkOperation>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing BulkRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "atomic" => intermediate_rep.atomic.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "operations" => return std::result::Result::Err("Parsing a container in this style is not supported in BulkRequest".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing BulkRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(BulkRequest {
+            atomic: intermediate_rep.atomic.into_iter().next(),
+            operations: intermediate_rep.operations.into_iter().next().ok_or_else(|| "operations missing in BulkRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<BulkRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<BulkRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<BulkRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for BulkRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BulkRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <BulkRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into BulkRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct BulkResult {
+    /// Whether any changes were saved; false when an atomic batch was rolled back
+    #[serde(rename = "committed")]
+    pub committed: bool,
+
+    /// Outcome of every operation, in request order
+    #[serde(rename = "results")]
+    #[validate(
+            nested,
+        )]
+    pub results: Vec<models::BulkItemResult>,
+
+}
+
+
+
+
+
+impl BulkResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(committed: bool, results: Vec<models::BulkItemResult>, ) -> BulkResult {
+        BulkResult {
+            committed,
+            results,
+        }
+    }
+}
+
+/// Converts the BulkResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for BulkResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("committed".to_string()),
+            Some(self.committed.to_string()),
+
+            // Skipping results in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a BulkResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for BulkResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub committed: Vec<bool>,
+            pub results: Vec<Vec<models::BulkItemResult>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing BulkResult".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "committed" => intermediate_rep.committed.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "results" => return std::result::Result::Err("Parsing a container in this style is not supported in BulkResult".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing BulkResult".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(BulkResult {
+            committed: intermediate_rep.committed.into_iter().next().ok_or_else(|| "committed missing in BulkResult".to_string())?,
+            results: intermediate_rep.results.into_iter().next().ok_or_else(|| "results missing in BulkResult".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<BulkResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<BulkResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<BulkResult>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for BulkResult - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BulkResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <BulkResult as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into BulkResult - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum ChildDeletePolicy {
+    #[serde(rename = "reject")]
+    Reject,
+    #[serde(rename = "cascade")]
+    Cascade,
+    #[serde(rename = "orphan")]
+    Orphan,
+}
+
+impl validator::Validate for ChildDeletePolicy
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for ChildDeletePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ChildDeletePolicy::Reject => write!(f, "reject"),
+            ChildDeletePolicy::Cascade => write!(f, "cascade"),
+            ChildDeletePolicy::Orphan => write!(f, "orphan"),
+        }
+    }
+}
+
+impl std::str::FromStr for ChildDeletePolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "reject" => std::result::Result::Ok(ChildDeletePolicy::Reject),
+            "cascade" => std::result::Result::Ok(ChildDeletePolicy::Cascade),
+            "orphan" => std::result::Result::Ok(ChildDeletePolicy::Orphan),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ChildDeletePolicy> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ChildDeletePolicy>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ChildDeletePolicy>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ChildDeletePolicy - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ChildDeletePolicy> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ChildDeletePolicy as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ChildDeletePolicy - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CreateApiKey {
+    /// Name to tell the key apart
+    #[serde(rename = "name")]
+    #[validate(
+            length(max = 50),
+        )]
+    pub name: String,
+
+    #[serde(rename = "scope")]
+    pub scope: models::ApiKeyScope,
+
+    /// User the key acts as; defaults to the calling user
+    #[serde(rename = "user_id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user_id: Option<i64>,
+
+}
+
+
+
+
+
+impl CreateApiKey {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(name: String, scope: models::ApiKeyScope, ) -> CreateApiKey {
+        CreateApiKey {
+            name,
+            scope,
+            user_id: None,
+        }
+    }
+}
+
+/// Converts the CreateApiKey value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CreateApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+
+            // Skipping scope in query parameter serialization
+
+
+            self.user_id.as_ref().map(|user_id| {
+                [
+                    "user_id".to_string(),
+                    user_id.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CreateApiKey value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CreateApiKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub scope: Vec<models::ApiKeyScope>,
+            pub user_id: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing CreateApiKey".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "scope" => intermediate_rep.scope.push(<models::ApiKeyScope as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "user_id" => intermediate_rep.user_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CreateApiKey".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CreateApiKey {
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in CreateApiKey".to_string())?,
+            scope: intermediate_rep.scope.into_iter().next().ok_or_else(|| "scope missing in CreateApiKey".to_string())?,
+            user_id: intermediate_rep.user_id.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CreateApiKey> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CreateApiKey>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<CreateApiKey>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for CreateApiKey - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CreateApiKey> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <CreateApiKey as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into CreateApiKey - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CreatedApiKey {
+    /// The API key itself; it cannot be retrieved again
+    #[serde(rename = "key")]
+    pub key: String,
+
+    #[serde(rename = "api_key")]
+    #[validate(
+            nested,
+        )]
+    pub api_key: models::ApiKey,
+
+}
+
+
+
+
+
+impl CreatedApiKey {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(key: String, api_key: models::ApiKey, ) -> CreatedApiKey {
+        CreatedApiKey {
+            key,
+            api_key,
+        }
+    }
+}
+
+/// Converts the CreatedApiKey value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CreatedApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("key".to_string()),
+            Some(self.key.to_string()),
+
+            // Skipping api_key in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CreatedApiKey value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CreatedApiKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub key: Vec<String>,
+            pub api_key: Vec<models::ApiKey>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing CreatedApiKey".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "key" => intermediate_rep.key.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "api_key" => intermediate_rep.api_key.push(<models::ApiKey as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CreatedApiKey".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CreatedApiKey {
+            key: intermediate_rep.key.into_iter().next().ok_or_else(|| "key missing in CreatedApiKey".to_string())?,
+            api_key: intermediate_rep.api_key.into_iter().next().ok_or_else(|| "api_key missing in CreatedApiKey".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CreatedApiKey> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CreatedApiKey>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<CreatedApiKey>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for CreatedApiKey - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CreatedApiKey> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <CreatedApiKey as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into CreatedApiKey - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CreateProject {
+    /// Project name
+    #[serde(rename = "name")]
+    #[validate(
+            length(max = 100),
+        )]
+    pub name: String,
+
+}
+
+
+
+
+
+impl CreateProject {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(name: String, ) -> CreateProject {
+        CreateProject {
+            name,
+        }
+    }
+}
+
+/// Converts the CreateProject value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CreateProject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CreateProject value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CreateProject {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing CreateProject".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CreateProject".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CreateProject {
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in CreateProject".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CreateProject> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CreateProject>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<CreateProject>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for CreateProject - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CreateProject> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <CreateProject as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into CreateProject - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CreateTask {
+    /// Task description
+    #[serde(rename = "description")]
+    #[validate(
+            length(max = 1000),
+        )]
+    pub description: String,
+
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub priority: Option<models::TaskPriority>,
+
+    /// When the task is due (must not be in the past)
+    #[serde(rename = "due_at")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub due_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+    /// ID of the project to file the task under (default the inbox)
+    #[serde(rename = "project_id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub project_id: Option<i64>,
+
+    /// ID of an existing task to create this task under
+    #[serde(rename = "parent_id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub parent_id: Option<i64>,
+
+    #[serde(rename = "recurrence")]
+    #[validate(
+            nested,
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub recurrence: Option<models::RecurrenceRule>,
+
+}
+
+
+
+
+
+impl CreateTask {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(description: String, ) -> CreateTask {
+        CreateTask {
+            description,
+            priority: None,
+            due_at: None,
+            project_id: None,
+            parent_id: None,
+            recurrence: None,
+        }
+    }
+}
+
+/// Converts the CreateTask value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CreateTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("description".to_string()),
+            Some(self.description.to_string()),
+
+            // Skipping priority in query parameter serialization
+
+            // Skipping due_at in query parameter serialization
+
+
+            self.project_id.as_ref().map(|project_id| {
+                [
+                    "project_id".to_string(),
+                    project_id.to_string(),
+                ].join(",")
+            }),
+
+
+            self.parent_id.as_ref().map(|parent_id| {
+                [
+                    "parent_id".to_string(),
+                    parent_id.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping recurrence in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CreateTask value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CreateTask {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub description: Vec<String>,
+            pub priority: Vec<models::TaskPriority>,
+            pub due_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub project_id: Vec<i64>,
+            pub parent_id: Vec<i64>,
+            pub recurrence: Vec<models::RecurrenceRule>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing CreateTask".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "description" => intermediate_rep.description.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "priority" => intermediate_rep.priority.push(<models::TaskPriority as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "due_at" => intermediate_rep.due_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "project_id" => intermediate_rep.project_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "parent_id" => intermediate_rep.parent_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "recurrence" => intermediate_rep.recurrence.push(<models::RecurrenceRule as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CreateTask".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CreateTask {
+            description: intermediate_rep.description.into_iter().next().ok_or_else(|| "description missing in CreateTask".to_string())?,
+            priority: intermediate_rep.priority.into_iter().next(),
+            due_at: intermediate_rep.due_at.into_iter().next(),
+            project_id: intermediate_rep.project_id.into_iter().next(),
+            parent_id: intermediate_rep.parent_id.into_iter().next(),
+            recurrence: intermediate_rep.recurrence.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CreateTask> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CreateTask>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<CreateTask>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for CreateTask - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CreateTask> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <CreateTask as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into CreateTask - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct CreateUser {
+    /// User name (ASCII letters, digits, '-', '_' and '.')
+    #[serde(rename = "name")]
+    #[validate(
+            length(max = 50),
+        )]
+    pub name: String,
+
+}
+
+
+
+
+
+impl CreateUser {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(name: String, ) -> CreateUser {
+        CreateUser {
+            name,
+        }
+    }
+}
+
+/// Converts the CreateUser value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for CreateUser {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a CreateUser value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for CreateUser {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing CreateUser".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing CreateUser".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(CreateUser {
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in CreateUser".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<CreateUser> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<CreateUser>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<CreateUser>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for CreateUser - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CreateUser> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <CreateUser as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into CreateUser - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct FieldChange {
+    /// Task field name, as in the Task schema
+    #[serde(rename = "field")]
+    pub field: String,
+
+    /// Value before the change; absent if the field had no value
+    #[serde(rename = "before")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub before: Option<crate::types::Object>,
+
+    /// Value after the change; absent if the field has no value
+    #[serde(rename = "after")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub after: Option<crate::types::Object>,
+
+}
+
+
+
+
+
+impl FieldChange {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(field: String, ) -> FieldChange {
+        FieldChange {
+            field,
+            before: None,
+            after: None,
+        }
+    }
+}
+
+/// Converts the FieldChange value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("field".to_string()),
+            Some(self.field.to_string()),
+
+            // Skipping before in query parameter serialization
+
+            // Skipping after in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a FieldChange value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for FieldChange {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub field: Vec<String>,
+            pub before: Vec<crate::types::Object>,
+            pub after: Vec<crate::types::Object>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing FieldChange".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "field" => intermediate_rep.field.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "before" => intermediate_rep.before.push(<crate::types::Object as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "after" => intermediate_rep.after.push(<crate::types::Object as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing FieldChange".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(FieldChange {
+            field: intermediate_rep.field.into_iter().next().ok_or_else(|| "field missing in FieldChange".to_string())?,
+            before: intermediate_rep.before.into_iter().next(),
+            after: intermediate_rep.after.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<FieldChange> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<FieldChange>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<FieldChange>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for FieldChange - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<FieldChange> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <FieldChange as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into FieldChange - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct HealthStatus {
+    /// Probe result, "ok" for liveness and "ready" for readiness
+    #[serde(rename = "status")]
+    pub status: String,
+
+}
+
+
+
+
+
+impl HealthStatus {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(status: String, ) -> HealthStatus {
+        HealthStatus {
+            status,
+        }
+    }
+}
+
+/// Converts the HealthStatus value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a HealthStatus value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for HealthStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub status: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing HealthStatus".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing HealthStatus".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(HealthStatus {
+            status: intermediate_rep.status.into_iter().next().ok_or_else(|| "status missing in HealthStatus".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<HealthStatus> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<HealthStatus>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<HealthStatus>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for HealthStatus - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<HealthStatus> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <HealthStatus as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into HealthStatus - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Problem {
+    /// URI reference identifying the problem type
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+    /// Short, human-readable summary of the problem type
+    #[serde(rename = "title")]
+    pub title: String,
+
+    /// HTTP status code of the response
+    #[serde(rename = "status")]
+    pub status: i32,
+
+    /// Human-readable explanation specific to this occurrence
+    #[serde(rename = "detail")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub detail: Option<String>,
+
+    /// Stable machine-readable error code (e.g. DESCRIPTION_TOO_LONG)
+    #[serde(rename = "code")]
+    pub code: String,
+
+    /// Request field that caused the error, if any
+    #[serde(rename = "field")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub field: Option<String>,
+
+    /// Identifier of the request that produced this problem
+    #[serde(rename = "request_id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub request_id: Option<String>,
+
+}
+
+
+
+
+
+impl Problem {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(r#type: String, title: String, status: i32, code: String, ) -> Problem {
+        Problem {
+            r#type,
+            title,
+            status,
+            detail: None,
+            code,
+            field: None,
+            request_id: None,
+        }
+    }
+}
+
+/// Converts the Problem value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+
+
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+
+
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+
+
+            self.detail.as_ref().map(|detail| {
+                [
+                    "detail".to_string(),
+                    detail.to_string(),
+                ].join(",")
+            }),
+
+
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+
+
+            self.field.as_ref().map(|field| {
+                [
+                    "field".to_string(),
+                    field.to_string(),
+                ].join(",")
+            }),
+
+
+            self.request_id.as_ref().map(|request_id| {
+                [
+                    "request_id".to_string(),
+                    request_id.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Problem value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Problem {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub r#type: Vec<String>,
+            pub title: Vec<String>,
+            pub status: Vec<i32>,
+            pub detail: Vec<String>,
+            pub code: Vec<String>,
+            pub field: Vec<String>,
+            pub request_id: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Problem".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "detail" => intermediate_rep.detail.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "field" => intermediate_rep.field.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "request_id" => intermediate_rep.request_id.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Problem".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Problem {
+            r#type: intermediate_rep.r#type.into_iter().next().ok_or_else(|| "type missing in Problem".to_string())?,
+            title: intermediate_rep.title.into_iter().next().ok_or_else(|| "title missing in Problem".to_string())?,
+            status: intermediate_rep.status.into_iter().next().ok_or_else(|| "status missing in Problem".to_string())?,
+            detail: intermediate_rep.detail.into_iter().next(),
+            code: intermediate_rep.code.into_iter().next().ok_or_else(|| "code missing in Problem".to_string())?,
+            field: intermediate_rep.field.into_iter().next(),
+            request_id: intermediate_rep.request_id.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Problem> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Problem>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Problem>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Problem - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Problem> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Problem as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Problem - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Project {
+    /// Unique identifier for the project; the inbox is always 1
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    /// Project name, unique regardless of case among the projects the owner can see
+    #[serde(rename = "name")]
+    #[validate(
+            length(max = 100),
+        )]
+    pub name: String,
+
+    /// ID of the user who owns the project; the inbox is shared by all users and owned by the default user
+    #[serde(rename = "owner_id")]
+    pub owner_id: i64,
+
+    /// Whether this is the inbox, which holds tasks created without a project and cannot be deleted
+    #[serde(rename = "inbox")]
+    pub inbox: bool,
+
+    /// Number of pending tasks in the project (tasks in the trash are not counted)
+    #[serde(rename = "pending_count")]
+    pub pending_count: i64,
+
+    /// Number of completed tasks in the project (tasks in the trash are not counted)
+    #[serde(rename = "completed_count")]
+    pub completed_count: i64,
+
+    /// When the project was created
+    #[serde(rename = "created_at")]
+    pub created_at: chrono::DateTime::<chrono::Utc>,
+
+    /// When the project was last renamed
+    #[serde(rename = "updated_at")]
+    pub updated_at: chrono::DateTime::<chrono::Utc>,
+
+}
+
+
+
+
+
+impl Project {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(id: i64, name: String, owner_id: i64, inbox: bool, pending_count: i64, completed_count: i64, created_at: chrono::DateTime::<chrono::Utc>, updated_at: chrono::DateTime::<chrono::Utc>, ) -> Project {
+        Project {
+            id,
+            name,
+            owner_id,
+            inbox,
+            pending_count,
+            completed_count,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+/// Converts the Project value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Project {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+
+
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+
+
+            Some("owner_id".to_string()),
+            Some(self.owner_id.to_string()),
+
+
+            Some("inbox".to_string()),
+            Some(self.inbox.to_string()),
+
+
+            Some("pending_count".to_string()),
+            Some(self.pending_count.to_string()),
+
+
+            Some("completed_count".to_string()),
+            Some(self.completed_count.to_string()),
+
+            // Skipping created_at in query parameter serialization
+
+            // Skipping updated_at in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Project value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Project {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i64>,
+            pub name: Vec<String>,
+            pub owner_id: Vec<i64>,
+            pub inbox: Vec<bool>,
+            pub pending_count: Vec<i64>,
+            pub completed_count: Vec<i64>,
+            pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub updated_at: Vec<chrono::DateTime::<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Project".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "owner_id" => intermediate_rep.owner_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "inbox" => intermediate_rep.inbox.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "pending_count" => intermediate_rep.pending_count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "completed_count" => intermediate_rep.completed_count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "created_at" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "updated_at" => intermediate_rep.updated_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Project".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Project {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in Project".to_string())?,
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in Project".to_string())?,
+            owner_id: intermediate_rep.owner_id.into_iter().next().ok_or_else(|| "owner_id missing in Project".to_string())?,
+            inbox: intermediate_rep.inbox.into_iter().next().ok_or_else(|| "inbox missing in Project".to_string())?,
+            pending_count: intermediate_rep.pending_count.into_iter().next().ok_or_else(|| "pending_count missing in Project".to_string())?,
+            completed_count: intermediate_rep.completed_count.into_iter().next().ok_or_else(|| "completed_count missing in Project".to_string())?,
+            created_at: intermediate_rep.created_at.into_iter().next().ok_or_else(|| "created_at missing in Project".to_string())?,
+            updated_at: intermediate_rep.updated_at.into_iter().next().ok_or_else(|| "updated_at missing in Project".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Project> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Project>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Project>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Project - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Project> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Project as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Project - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PurgeResult {
+    /// Number of tasks permanently removed
+    #[serde(rename = "purged")]
+    pub purged: i64,
+
+}
+
+
+
+
+
+impl PurgeResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(purged: i64, ) -> PurgeResult {
+        PurgeResult {
+            purged,
+        }
+    }
+}
+
+/// Converts the PurgeResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PurgeResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("purged".to_string()),
+            Some(self.purged.to_string()),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PurgeResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PurgeResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub purged: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing PurgeResult".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "purged" => intermediate_rep.purged.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PurgeResult".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PurgeResult {
+            purged: intermediate_rep.purged.into_iter().next().ok_or_else(|| "purged missing in PurgeResult".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PurgeResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PurgeResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<PurgeResult>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PurgeResult - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PurgeResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PurgeResult as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PurgeResult - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum RecurrenceFrequency {
+    #[serde(rename = "daily")]
+    Daily,
+    #[serde(rename = "weekly")]
+    Weekly,
+    #[serde(rename = "monthly")]
+    Monthly,
+}
+
+impl validator::Validate for RecurrenceFrequency
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for RecurrenceFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            RecurrenceFrequency::Daily => write!(f, "daily"),
+            RecurrenceFrequency::Weekly => write!(f, "weekly"),
+            RecurrenceFrequency::Monthly => write!(f, "monthly"),
+        }
+    }
+}
+
+impl std::str::FromStr for RecurrenceFrequency {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "daily" => std::result::Result::Ok(RecurrenceFrequency::Daily),
+            "weekly" => std::result::Result::Ok(RecurrenceFrequency::Weekly),
+            "monthly" => std::result::Result::Ok(RecurrenceFrequency::Monthly),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RecurrenceFrequency> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<RecurrenceFrequency>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<RecurrenceFrequency>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for RecurrenceFrequency - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecurrenceFrequency> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <RecurrenceFrequency as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into RecurrenceFrequency - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RecurrenceRule {
+    #[serde(rename = "frequency")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub frequency: Option<models::RecurrenceFrequency>,
+
+    /// Repeat every this many days, weeks or months (default 1)
+    #[serde(rename = "interval")]
+    #[validate(
+            range(min = 1i32, max = 999i32),
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub interval: Option<i32>,
+
+    /// Days of the week for weekly recurrence; defaults to the weekday of the due date
+    #[serde(rename = "weekdays")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub weekdays: Option<Vec<models::Weekday>>,
+
+    /// Day of the month for monthly recurrence, defaulting to the day of the due date; months without that day use their last day
+    #[serde(rename = "day_of_month")]
+    #[validate(
+            range(min = 1i32, max = 31i32),
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub day_of_month: Option<i32>,
+
+    /// No occurrences after this time
+    #[serde(rename = "until")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub until: Option<chrono::DateTime::<chrono::Utc>>,
+
+    /// RFC 5545 RRULE using FREQ (DAILY, WEEKLY, MONTHLY), INTERVAL, BYDAY, BYMONTHDAY and UNTIL; always present in responses
+    #[serde(rename = "rrule")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub rrule: Option<String>,
+
+}
+
+
+
+
+
+impl RecurrenceRule {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> RecurrenceRule {
+        RecurrenceRule {
+            frequency: None,
+            interval: None,
+            weekdays: None,
+            day_of_month: None,
+            until: None,
+            rrule: None,
+        }
+    }
+}
+
+/// Converts the RecurrenceRule value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping frequency in query parameter serialization
+
+
+            self.interval.as_ref().map(|interval| {
+                [
+                    "interval".to_string(),
+                    interval.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping weekdays in query parameter serialization
+
+
+            self.day_of_month.as_ref().map(|day_of_month| {
+                [
+                    "day_of_month".to_string(),
+                    day_of_month.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping until in query parameter serialization
+
+
+            self.rrule.as_ref().map(|rrule| {
+                [
+                    "rrule".to_string(),
+                    rrule.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RecurrenceRule value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RecurrenceRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub frequency: Vec<models::RecurrenceFrequency>,
+            pub interval: Vec<i32>,
+            pub weekdays: Vec<Vec<models::Weekday>>,
+            pub day_of_month: Vec<i32>,
+            pub until: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub rrule: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing RecurrenceRule".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "frequency" => intermediate_rep.frequency.push(<models::RecurrenceFrequency as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "interval" => intermediate_rep.interval.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "weekdays" => return std::result::Result::Err("Parsing a container in this style is not supported in RecurrenceRule".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "day_of_month" => intermediate_rep.day_of_month.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "until" => intermediate_rep.until.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "rrule" => intermediate_rep.rrule.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RecurrenceRule".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RecurrenceRule {
+            frequency: intermediate_rep.frequency.into_iter().next(),
+            interval: intermediate_rep.interval.into_iter().next(),
+            weekdays: intermediate_rep.weekdays.into_iter().next(),
+            day_of_month: intermediate_rep.day_of_month.into_iter().next(),
+            until: intermediate_rep.until.into_iter().next(),
+            rrule: intermediate_rep.rrule.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RecurrenceRule> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<RecurrenceRule>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<RecurrenceRule>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for RecurrenceRule - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RecurrenceRule> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <RecurrenceRule as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into RecurrenceRule - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Session {
+    /// User that requests made with the session act as
+    #[serde(rename = "user_id")]
+    pub user_id: i64,
+
+    #[serde(rename = "scope")]
+    pub scope: models::ApiKeyScope,
+
+    /// Token to send as X-CSRF-Token with every non-GET request made with the session
+    #[serde(rename = "csrf_token")]
+    pub csrf_token: String,
+
+    /// When the session ends
+    #[serde(rename = "expires_at")]
+    pub expires_at: chrono::DateTime::<chrono::Utc>,
+
+}
+
+
+
+
+
+impl Session {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(user_id: i64, scope: models::ApiKeyScope, csrf_token: String, expires_at: chrono::DateTime::<chrono::Utc>, ) -> Session {
+        Session {
+            user_id,
+            scope,
+            csrf_token,
+            expires_at,
+        }
+    }
+}
+
+/// Converts the Session value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("user_id".to_string()),
+            Some(self.user_id.to_string()),
+
+            // Skipping scope in query parameter serialization
+
+
+            Some("csrf_token".to_string()),
+            Some(self.csrf_token.to_string()),
+
+            // Skipping expires_at in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Session value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Session {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub user_id: Vec<i64>,
+            pub scope: Vec<models::ApiKeyScope>,
+            pub csrf_token: Vec<String>,
+            pub expires_at: Vec<chrono::DateTime::<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Session".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "user_id" => intermediate_rep.user_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "scope" => intermediate_rep.scope.push(<models::ApiKeyScope as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "csrf_token" => intermediate_rep.csrf_token.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "expires_at" => intermediate_rep.expires_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Session".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Session {
+            user_id: intermediate_rep.user_id.into_iter().next().ok_or_else(|| "user_id missing in Session".to_string())?,
+            scope: intermediate_rep.scope.into_iter().next().ok_or_else(|| "scope missing in Session".to_string())?,
+            csrf_token: intermediate_rep.csrf_token.into_iter().next().ok_or_else(|| "csrf_token missing in Session".to_string())?,
+            expires_at: intermediate_rep.expires_at.into_iter().next().ok_or_else(|| "expires_at missing in Session".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Session> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Session>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Session>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Session - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Session> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Session as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Session - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum SortOrder {
+    #[serde(rename = "asc")]
+    Asc,
+    #[serde(rename = "desc")]
+    Desc,
+}
+
+impl validator::Validate for SortOrder
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            SortOrder::Asc => write!(f, "asc"),
+            SortOrder::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+impl std::str::FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "asc" => std::result::Result::Ok(SortOrder::Asc),
+            "desc" => std::result::Result::Ok(SortOrder::Desc),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<SortOrder> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<SortOrder>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<SortOrder>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for SortOrder - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<SortOrder> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <SortOrder as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into SortOrder - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TagCount {
+    /// Tag name
+    #[serde(rename = "tag")]
+    pub tag: String,
+
+    /// Number of tasks with this tag
+    #[serde(rename = "count")]
+    pub count: i64,
+
+}
+
+
+
+
+
+impl TagCount {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(tag: String, count: i64, ) -> TagCount {
+        TagCount {
+            tag,
+            count,
+        }
+    }
+}
+
+/// Converts the TagCount value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for TagCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("tag".to_string()),
+            Some(self.tag.to_string()),
+
+
+            Some("count".to_string()),
+            Some(self.count.to_string()),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TagCount value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TagCount {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub tag: Vec<String>,
+            pub count: Vec<i64>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing TagCount".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "tag" => intermediate_rep.tag.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "count" => intermediate_rep.count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing TagCount".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TagCount {
+            tag: intermediate_rep.tag.into_iter().next().ok_or_else(|| "tag missing in TagCount".to_string())?,
+            count: intermediate_rep.count.into_iter().next().ok_or_else(|| "count missing in TagCount".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TagCount> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<TagCount>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<TagCount>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for TagCount - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TagCount> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <TagCount as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into TagCount - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum TagMatchMode {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "any")]
+    Any,
+}
+
+impl validator::Validate for TagMatchMode
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for TagMatchMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            TagMatchMode::All => write!(f, "all"),
+            TagMatchMode::Any => write!(f, "any"),
+        }
+    }
+}
+
+impl std::str::FromStr for TagMatchMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "all" => std::result::Result::Ok(TagMatchMode::All),
+            "any" => std::result::Result::Ok(TagMatchMode::Any),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TagMatchMode> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<TagMatchMode>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<TagMatchMode>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for TagMatchMode - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TagMatchMode> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <TagMatchMode as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into TagMatchMode - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Task {
+    /// Unique identifier for the task
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    /// Task description
+    #[serde(rename = "description")]
+    #[validate(
+            length(max = 1000),
+        )]
+    pub description: String,
+
+    /// Whether the task is completed
+    #[serde(rename = "completed")]
+    pub completed: bool,
+
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub priority: Option<models::TaskPriority>,
+
+    /// When the task is due
+    #[serde(rename = "due_at")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub due_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+    /// Tags attached to the task, sorted
+    #[serde(rename = "tags")]
+    pub tags: Vec<String>,
+
+    /// Incremented every time the task changes; the ETag is derived from it
+    #[serde(rename = "version")]
+    pub version: i64,
+
+    /// When the task was created
+    #[serde(rename = "created_at")]
+    pub created_at: chrono::DateTime::<chrono::Utc>,
+
+    /// When the task was last updated
+    #[serde(rename = "updated_at")]
+    pub updated_at: chrono::DateTime::<chrono::Utc>,
+
+    /// When the task was moved to the trash; absent for tasks that are not deleted
+    #[serde(rename = "deleted_at")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub deleted_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+    /// ID of the user that owns the task
+    #[serde(rename = "owner_id")]
+    pub owner_id: i64,
+
+    /// ID of the project the task belongs to
+    #[serde(rename = "project_id")]
+    pub project_id: i64,
+
+    /// ID of the parent task; absent for top-level tasks
+    #[serde(rename = "parent_id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub parent_id: Option<i64>,
+
+    /// Share of direct subtasks that are completed; only present on single-task, children and tree responses for tasks with subtasks
+    #[serde(rename = "completion_percentage")]
+    #[validate(
+            range(min = 0i32, max = 100i32),
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub completion_percentage: Option<i32>,
+
+    /// IDs of the tasks that must be completed before this one, sorted
+    #[serde(rename = "depends_on")]
+    pub depends_on: Vec<i64>,
+
+    /// Whether the task is pending and a task it depends on is still pending; absent in change events
+    #[serde(rename = "blocked")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub blocked: Option<bool>,
+
+    #[serde(rename = "recurrence")]
+    #[validate(
+            nested,
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub recurrence: Option<models::RecurrenceRule>,
+
+}
+
+
+
+
+
+impl Task {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(id: i64, description: String, completed: bool, tags: Vec<String>, version: i64, created_at: chrono::DateTime::<chrono::Utc>, updated_at: chrono::DateTime::<chrono::Utc>, owner_id: i64, project_id: i64, depends_on: Vec<i64>, ) -> Task {
+        Task {
+            id,
+            description,
+            completed,
+            priority: None,
+            due_at: None,
+            tags,
+            version,
+            created_at,
+            updated_at,
+            deleted_at: None,
+            owner_id,
+            project_id,
+            parent_id: None,
+            completion_percentage: None,
+            depends_on,
+            blocked: None,
+            recurrence: None,
+        }
+    }
+}
+
+/// Converts the Task value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Task {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+
+
+            Some("description".to_string()),
+            Some(self.description.to_string()),
+
+
+            Some("completed".to_string()),
+            Some(self.completed.to_string()),
+
+            // Skipping priority in query parameter serialization
+
+            // Skipping due_at in query parameter serialization
+
+
+            Some("tags".to_string()),
+            Some(self.tags.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",")),
+
+
+            Some("version".to_string()),
+            Some(self.version.to_string()),
+
+            // Skipping created_at in query parameter serialization
+
+            // Skipping updated_at in query parameter serialization
+
+            // Skipping deleted_at in query parameter serialization
+
+
+            Some("owner_id".to_string()),
+            Some(self.owner_id.to_string()),
+
+
+            Some("project_id".to_string()),
+            Some(self.project_id.to_string()),
+
+
+            self.parent_id.as_ref().map(|parent_id| {
+                [
+                    "parent_id".to_string(),
+                    parent_id.to_string(),
+                ].join(",")
+            }),
+
+
+            self.completion_percentage.as_ref().map(|completion_percentage| {
+                [
+                    "completion_percentage".to_string(),
+                    completion_percentage.to_string(),
+                ].join(",")
+            }),
+
+
+            Some("depends_on".to_string()),
+            Some(self.depends_on.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",")),
+
+
+            self.blocked.as_ref().map(|blocked| {
+                [
+                    "blocked".to_string(),
+                    blocked.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping recurrence in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Task value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Task {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i64>,
+            pub description: Vec<String>,
+            pub completed: Vec<bool>,
+            pub priority: Vec<models::TaskPriority>,
+            pub due_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub tags: Vec<Vec<String>>,
+            pub version: Vec<i64>,
+            pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub updated_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub deleted_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub owner_id: Vec<i64>,
+            pub project_id: Vec<i64>,
+            pub parent_id: Vec<i64>,
+            pub completion_percentage: Vec<i32>,
+            pub depends_on: Vec<Vec<i64>>,
+            pub blocked: Vec<bool>,
+            pub recurrence: Vec<models::RecurrenceRule>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Task".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "description" => intermediate_rep.description.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "completed" => intermediate_rep.completed.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "priority" => intermediate_rep.priority.push(<models::TaskPriority as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "due_at" => intermediate_rep.due_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "tags" => return std::result::Result::Err("Parsing a container in this style is not supported in Task".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "created_at" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "updated_at" => intermediate_rep.updated_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "deleted_at" => intermediate_rep.deleted_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "owner_id" => intermediate_rep.owner_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "project_id" => intermediate_rep.project_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "parent_id" => intermediate_rep.parent_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "completion_percentage" => intermediate_rep.completion_percentage.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "depends_on" => return std::result::Result::Err("Parsing a container in this style is not supported in Task".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "blocked" => intermediate_rep.blocked.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "recurrence" => intermediate_rep.recurrence.push(<models::RecurrenceRule as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Task".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Task {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in Task".to_string())?,
+            description: intermediate_rep.description.into_iter().next().ok_or_else(|| "description missing in Task".to_string())?,
+            completed: intermediate_rep.completed.into_iter().next().ok_or_else(|| "completed missing in Task".to_string())?,
+            priority: intermediate_rep.priority.into_iter().next(),
+            due_at: intermediate_rep.due_at.into_iter().next(),
+            tags: intermediate_rep.tags.into_iter().next().ok_or_else(|| "tags missing in Task".to_string())?,
+            version: intermediate_rep.version.into_iter().next().ok_or_else(|| "version missing in Task".to_string())?,
+            created_at: intermediate_rep.created_at.into_iter().next().ok_or_else(|| "created_at missing in Task".to_string())?,
+            updated_at: intermediate_rep.updated_at.into_iter().next().ok_or_else(|| "updated_at missing in Task".to_string())?,
+            deleted_at: intermediate_rep.deleted_at.into_iter().next(),
+            owner_id: intermediate_rep.owner_id.into_iter().next().ok_or_else(|| "owner_id missing in Task".to_string())?,
+            project_id: intermediate_rep.project_id.into_iter().next().ok_or_else(|| "project_id missing in Task".to_string())?,
+            parent_id: intermediate_rep.parent_id.into_iter().next(),
+            completion_percentage: intermediate_rep.completion_percentage.into_iter().next(),
+            depends_on: intermediate_rep.depends_on.into_iter().next().ok_or_else(|| "depends_on missing in Task".to_string())?,
+            blocked: intermediate_rep.blocked.into_iter().next(),
+            recurrence: intermediate_rep.recurrence.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Task> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Task>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Task>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Task - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Task> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Task as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Task - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TaskHistoryEntry {
+    /// Sequence number, increasing in the order entries were recorded
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    /// Task the entry belongs to
+    #[serde(rename = "task_id")]
+    pub task_id: i64,
+
+    #[serde(rename = "operation")]
+    pub operation: models::TaskHistoryOperation,
+
+    /// Fields whose values changed, sorted by field name
+    #[serde(rename = "changes")]
+    #[validate(
+            nested,
+        )]
+    pub changes: Vec<models::FieldChange>,
+
+    /// Who made the change, if known
+    #[serde(rename = "actor")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub actor: Option<String>,
+
+    /// When the change was recorded
+    #[serde(rename = "recorded_at")]
+    pub recorded_at: chrono::DateTime::<chrono::Utc>,
+
+}
+
+
+
+
+
+impl TaskHistoryEntry {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(id: i64, task_id: i64, operation: models::TaskHistoryOperation, changes: Vec<models::FieldChange>, recorded_at: chrono::DateTime::<chrono::Utc>, ) -> TaskHistoryEntry {
+        TaskHistoryEntry {
+            id,
+            task_id,
+            operation,
+            changes,
+            actor: None,
+            recorded_at,
+        }
+    }
+}
+
+/// Converts the TaskHistoryEntry value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for TaskHistoryEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+
+
+            Some("task_id".to_string()),
+            Some(self.task_id.to_string()),
+
+            // Skipping operation in query parameter serialization
+
+            // Skipping changes in query parameter serialization
+
+
+            self.actor.as_ref().map(|actor| {
+                [
+                    "actor".to_string(),
+                    actor.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping recorded_at in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TaskHistoryEntry value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TaskHistoryEntry {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i64>,
+            pub task_id: Vec<i64>,
+            pub operation: Vec<models::TaskHistoryOperation>,
+            pub changes: Vec<Vec<models::FieldChange>>,
+            pub actor: Vec<String>,
+            pub recorded_at: Vec<chrono::DateTime::<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing TaskHistoryEntry".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "task_id" => intermediate_rep.task_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "operation" => intermediate_rep.operation.push(<models::TaskHistoryOperation as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "changes" => return std::result::Result::Err("Parsing a container in this style is not supported in TaskHistoryEntry".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "actor" => intermediate_rep.actor.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "recorded_at" => intermediate_rep.recorded_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing TaskHistoryEntry".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TaskHistoryEntry {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in TaskHistoryEntry".to_string())?,
+            task_id: intermediate_rep.task_id.into_iter().next().ok_or_else(|| "task_id missing in TaskHistoryEntry".to_string())?,
+            operation: intermediate_rep.operation.into_iter().next().ok_or_else(|| "operation missing in TaskHistoryEntry".to_string())?,
+            changes: intermediate_rep.changes.into_iter().next().ok_or_else(|| "changes missing in TaskHistoryEntry".to_string())?,
+            actor: intermediate_rep.actor.into_iter().next(),
+            recorded_at: intermediate_rep.recorded_at.into_iter().next().ok_or_else(|| "recorded_at missing in TaskHistoryEntry".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TaskHistoryEntry> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<TaskHistoryEntry>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<TaskHistoryEntry>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for TaskHistoryEntry - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TaskHistoryEntry> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <TaskHistoryEntry as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into TaskHistoryEntry - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum TaskHistoryOperation {
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "updated")]
+    Updated,
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "uncompleted")]
+    Uncompleted,
+    #[serde(rename = "deleted")]
+    Deleted,
+    #[serde(rename = "restored")]
+    Restored,
+    #[serde(rename = "purged")]
+    Purged,
+}
+
+impl validator::Validate for TaskHistoryOperation
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for TaskHistoryOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            TaskHistoryOperation::Created => write!(f, "created"),
+            TaskHistoryOperation::Updated => write!(f, "updated"),
+            TaskHistoryOperation::Completed => write!(f, "completed"),
+            TaskHistoryOperation::Uncompleted => write!(f, "uncompleted"),
+            TaskHistoryOperation::Deleted => write!(f, "deleted"),
+            TaskHistoryOperation::Restored => write!(f, "restored"),
+            TaskHistoryOperation::Purged => write!(f, "purged"),
+        }
+    }
+}
+
+impl std::str::FromStr for TaskHistoryOperation {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "created" => std::result::Result::Ok(TaskHistoryOperation::Created),
+            "updated" => std::result::Result::Ok(TaskHistoryOperation::Updated),
+            "completed" => std::result::Result::Ok(TaskHistoryOperation::Completed),
+            "uncompleted" => std::result::Result::Ok(TaskHistoryOperation::Uncompleted),
+            "deleted" => std::result::Result::Ok(TaskHistoryOperation::Deleted),
+            "restored" => std::result::Result::Ok(TaskHistoryOperation::Restored),
+            "purged" => std::result::Result::Ok(TaskHistoryOperation::Purged),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TaskHistoryOperation> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<TaskHistoryOperation>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<TaskHistoryOperation>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for TaskHistoryOperation - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TaskHistoryOperation> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <TaskHistoryOperation as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into TaskHistoryOperation - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum TaskPriority {
+    #[serde(rename = "low")]
+    Low,
+    #[serde(rename = "normal")]
+    Normal,
+    #[serde(rename = "high")]
+    High,
+    #[serde(rename = "urgent")]
+    Urgent,
+}
+
+impl validator::Validate for TaskPriority
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for TaskPriority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            TaskPriority::Low => write!(f, "low"),
+            TaskPriority::Normal => write!(f, "normal"),
+            TaskPriority::High => write!(f, "high"),
+            TaskPriority::Urgent => write!(f, "urgent"),
+        }
+    }
+}
+
+impl std::str::FromStr for TaskPriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "low" => std::result::Result::Ok(TaskPriority::Low),
+            "normal" => std::result::Result::Ok(TaskPriority::Normal),
+            "high" => std::result::Result::Ok(TaskPriority::High),
+            "urgent" => std::result::Result::Ok(TaskPriority::Urgent),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TaskPriority> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<TaskPriority>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<TaskPriority>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for TaskPriority - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TaskPriority> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <TaskPriority as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into TaskPriority - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum TaskSortField {
+    #[serde(rename = "id")]
+    Id,
+    #[serde(rename = "created_at")]
+    CreatedAt,
+    #[serde(rename = "updated_at")]
+    UpdatedAt,
+}
+
+impl validator::Validate for TaskSortField
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for TaskSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            TaskSortField::Id => write!(f, "id"),
+            TaskSortField::CreatedAt => write!(f, "created_at"),
+            TaskSortField::UpdatedAt => write!(f, "updated_at"),
+        }
+    }
+}
+
+impl std::str::FromStr for TaskSortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "id" => std::result::Result::Ok(TaskSortField::Id),
+            "created_at" => std::result::Result::Ok(TaskSortField::CreatedAt),
+            "updated_at" => std::result::Result::Ok(TaskSortField::UpdatedAt),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TaskSortField> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<TaskSortField>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<TaskSortField>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for TaskSortField - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TaskSortField> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <TaskSortField as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into TaskSortField - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TaskTreeNode {
+    #[serde(rename = "task")]
+    #[validate(
+            nested,
+        )]
+    pub task: models::Task,
+
+    /// Direct subtasks, ordered by ID
+    #[serde(rename = "children")]
+    #[validate(
+            nested,
+        )]
+    pub children: Vec<models::TaskTreeNode>,
+
+}
+
+
+
+
+
+impl TaskTreeNode {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(task: models::Task, children: Vec<models::TaskTreeNode>, ) -> TaskTreeNode {
+        TaskTreeNode {
+            task,
+            children,
+        }
+    }
+}
+
+/// Converts the TaskTreeNode value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for TaskTreeNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping task in query parameter serialization
+
+            // Skipping children in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TaskTreeNode value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TaskTreeNode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub task: Vec<models::Task>,
+            pub children: Vec<Vec<models::TaskTreeNode>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing TaskTreeNode".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "task" => intermediate_rep.task.push(<models::Task as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "children" => return std::result::Result::Err("Parsing a container in this style is not supported in TaskTreeNode".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing TaskTreeNode".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TaskTreeNode {
+            task: intermediate_rep.task.into_iter().next().ok_or_else(|| "task missing in TaskTreeNode".to_string())?,
+            children: intermediate_rep.children.into_iter().next().ok_or_else(|| "children missing in TaskTreeNode".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TaskTreeNode> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<TaskTreeNode>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<TaskTreeNode>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for TaskTreeNode - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TaskTreeNode> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <TaskTreeNode as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into TaskTreeNode - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct UpdateProject {
+    /// New project name
+    #[serde(rename = "name")]
+    #[validate(
+            length(max = 100),
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub name: Option<String>,
+
+}
+
+
+
+
+
+impl UpdateProject {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> UpdateProject {
+        UpdateProject {
+            name: None,
+        }
+    }
+}
+
+/// Converts the UpdateProject value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for UpdateProject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            self.name.as_ref().map(|name| {
+                [
+                    "name".to_string(),
+                    name.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a UpdateProject value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for UpdateProject {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub name: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing UpdateProject".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing UpdateProject".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(UpdateProject {
+            name: intermediate_rep.name.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<UpdateProject> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<UpdateProject>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<UpdateProject>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for UpdateProject - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<UpdateProject> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <UpdateProject as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into UpdateProject - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct CreateTask {
+pub struct UpdateTask {
     /// Task description
     #[serde(rename = "description")]
     #[validate(
             length(max = 1000),
         )]
-    pub description: String,
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+
+    /// Whether the task is completed
+    #[serde(rename = "completed")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub completed: Option<bool>,
+
+    #[serde(rename = "priority")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub priority: Option<models::TaskPriority>,
+
+    /// New due date, or null to clear it
+    #[serde(rename = "due_at")]
+    #[serde(deserialize_with = "deserialize_optional_nullable")]
+    #[serde(default = "default_optional_nullable")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub due_at: Option<Nullable<chrono::DateTime::<chrono::Utc>>>,
+
+    /// Move the task to this project
+    #[serde(rename = "project_id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub project_id: Option<i64>,
+
+    /// New parent task, or null to make the task top-level
+    #[serde(rename = "parent_id")]
+    #[serde(deserialize_with = "deserialize_optional_nullable")]
+    #[serde(default = "default_optional_nullable")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub parent_id: Option<Nullable<i64>>,
+
+    #[serde(rename = "recurrence")]
+    #[validate(
+            nested,
+        )]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub recurrence: Option<models::RecurrenceRule>,
 
 }
 
@@ -84,24 +5318,58 @@ pub struct CreateTask {
 
 
 
-impl CreateTask {
+impl UpdateTask {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(description: String, ) -> CreateTask {
-        CreateTask {
-            description,
+    pub fn new() -> UpdateTask {
+        UpdateTask {
+            description: None,
+            completed: None,
+            priority: None,
+            due_at: None,
+            project_id: None,
+            parent_id: None,
+            recurrence: None,
         }
     }
 }
 
-/// Converts the CreateTask value to the Query Parameters representation (style=form, explode=false)
+/// Converts the UpdateTask value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for CreateTask {
+impl std::fmt::Display for UpdateTask {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
 
-            Some("description".to_string()),
-            Some(self.description.to_string()),
+            self.description.as_ref().map(|description| {
+                [
+                    "description".to_string(),
+                    description.to_string(),
+                ].join(",")
+            }),
+
+
+            self.completed.as_ref().map(|completed| {
+                [
+                    "completed".to_string(),
+                    completed.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping priority in query parameter serialization
+
+            // Skipping due_at in query parameter serialization
+
+
+            self.project_id.as_ref().map(|project_id| {
+                [
+                    "project_id".to_string(),
+                    project_id.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping parent_id in query parameter serialization
+
+            // Skipping recurrence in query parameter serialization
 
         ];
 
@@ -109,10 +5377,10 @@ impl std::fmt::Display for CreateTask {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a CreateTask value
+/// Converts Query Parameters representation (style=form, explode=false) to a UpdateTask value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for CreateTask {
+impl std::str::FromStr for UpdateTask {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -121,6 +5389,12 @@ impl std::str::FromStr for CreateTask {
         #[allow(dead_code)]
         struct IntermediateRep {
             pub description: Vec<String>,
+            pub completed: Vec<bool>,
+            pub priority: Vec<models::TaskPriority>,
+            pub due_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub project_id: Vec<i64>,
+            pub parent_id: Vec<i64>,
+            pub recurrence: Vec<models::RecurrenceRule>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -132,7 +5406,7 @@ impl std::str::FromStr for CreateTask {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing CreateTask".to_string())
+                None => return std::result::Result::Err("Missing value while parsing UpdateTask".to_string())
             };
 
             if let Some(key) = key_result {
@@ -140,7 +5414,17 @@ impl std::str::FromStr for CreateTask {
                 match key {
                     #[allow(clippy::redundant_clone)]
                     "description" => intermediate_rep.description.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing CreateTask".to_string())
+                    #[allow(clippy::redundant_clone)]
+                    "completed" => intermediate_rep.completed.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "priority" => intermediate_rep.priority.push(<models::TaskPriority as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "due_at" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in UpdateTask".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "project_id" => intermediate_rep.project_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "parent_id" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in UpdateTask".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "recurrence" => intermediate_rep.recurrence.push(<models::RecurrenceRule as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing UpdateTask".to_string())
                 }
             }
 
@@ -149,40 +5433,46 @@ impl std::str::FromStr for CreateTask {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(CreateTask {
-            description: intermediate_rep.description.into_iter().next().ok_or_else(|| "description missing in CreateTask".to_string())?,
+        std::result::Result::Ok(UpdateTask {
+            description: intermediate_rep.description.into_iter().next(),
+            completed: intermediate_rep.completed.into_iter().next(),
+            priority: intermediate_rep.priority.into_iter().next(),
+            due_at: std::result::Result::Err("Nullable types not supported in UpdateTask".to_string())?,
+            project_id: intermediate_rep.project_id.into_iter().next(),
+            parent_id: std::result::Result::Err("Nullable types not supported in UpdateTask".to_string())?,
+            recurrence: intermediate_rep.recurrence.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<CreateTask> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<UpdateTask> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<CreateTask>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<UpdateTask>> for HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<CreateTask>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<UpdateTask>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for CreateTask - value: {} is invalid {}",
+                 format!("Invalid header value for UpdateTask - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CreateTask> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<UpdateTask> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <CreateTask as std::str::FromStr>::from_str(value) {
+                    match <UpdateTask as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into CreateTask - {}",
+                            format!("Unable to convert header value '{}' into UpdateTask - {}",
                                 value, err))
                     }
              },
@@ -198,53 +5488,43 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<CreateTask>
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Task {
-    /// Unique identifier for the task
+pub struct User {
+    /// Unique identifier for the user; the default user is always 1
     #[serde(rename = "id")]
     pub id: i64,
 
-    /// Task description
-    #[serde(rename = "description")]
+    /// User name, unique regardless of case
+    #[serde(rename = "name")]
     #[validate(
-            length(max = 1000),
+            length(max = 50),
         )]
-    pub description: String,
-
-    /// Whether the task is completed
-    #[serde(rename = "completed")]
-    pub completed: bool,
+    pub name: String,
 
-    /// When the task was created
+    /// When the user was created
     #[serde(rename = "created_at")]
     pub created_at: chrono::DateTime::<chrono::Utc>,
 
-    /// When the task was last updated
-    #[serde(rename = "updated_at")]
-    pub updated_at: chrono::DateTime::<chrono::Utc>,
-
 }
 
 
 
 
 
-impl Task {
+impl User {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(id: i64, description: String, completed: bool, created_at: chrono::DateTime::<chrono::Utc>, updated_at: chrono::DateTime::<chrono::Utc>, ) -> Task {
-        Task {
+    pub fn new(id: i64, name: String, created_at: chrono::DateTime::<chrono::Utc>, ) -> User {
+        User {
             id,
-            description,
-            completed,
+            name,
             created_at,
-            updated_at,
         }
     }
 }
 
-/// Converts the Task value to the Query Parameters representation (style=form, explode=false)
+/// Converts the User value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for Task {
+impl std::fmt::Display for User {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
 
@@ -252,27 +5532,21 @@ impl std::fmt::Display for Task {
             Some(self.id.to_string()),
 
 
-            Some("description".to_string()),
-            Some(self.description.to_string()),
-
-
-            Some("completed".to_string()),
-            Some(self.completed.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
 
             // Skipping created_at in query parameter serialization
 
-            // Skipping updated_at in query parameter serialization
-
         ];
 
         write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Task value
+/// Converts Query Parameters representation (style=form, explode=false) to a User value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Task {
+impl std::str::FromStr for User {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -281,10 +5555,8 @@ impl std::str::FromStr for Task {
         #[allow(dead_code)]
         struct IntermediateRep {
             pub id: Vec<i64>,
-            pub description: Vec<String>,
-            pub completed: Vec<bool>,
+            pub name: Vec<String>,
             pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
-            pub updated_at: Vec<chrono::DateTime::<chrono::Utc>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -296,7 +5568,7 @@ impl std::str::FromStr for Task {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing Task".to_string())
+                None => return std::result::Result::Err("Missing value while parsing User".to_string())
             };
 
             if let Some(key) = key_result {
@@ -305,14 +5577,10 @@ impl std::str::FromStr for Task {
                     #[allow(clippy::redundant_clone)]
                     "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "description" => intermediate_rep.description.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "completed" => intermediate_rep.completed.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
                     "created_at" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    #[allow(clippy::redundant_clone)]
-                    "updated_at" => intermediate_rep.updated_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing Task".to_string())
+                    _ => return std::result::Result::Err("Unexpected key while parsing User".to_string())
                 }
             }
 
@@ -321,44 +5589,42 @@ impl std::str::FromStr for Task {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Task {
-            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in Task".to_string())?,
-            description: intermediate_rep.description.into_iter().next().ok_or_else(|| "description missing in Task".to_string())?,
-            completed: intermediate_rep.completed.into_iter().next().ok_or_else(|| "completed missing in Task".to_string())?,
-            created_at: intermediate_rep.created_at.into_iter().next().ok_or_else(|| "created_at missing in Task".to_string())?,
-            updated_at: intermediate_rep.updated_at.into_iter().next().ok_or_else(|| "updated_at missing in Task".to_string())?,
+        std::result::Result::Ok(User {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in User".to_string())?,
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in User".to_string())?,
+            created_at: intermediate_rep.created_at.into_iter().next().ok_or_else(|| "created_at missing in User".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Task> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<User> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<Task>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<User>> for HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<Task>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<User>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for Task - value: {} is invalid {}",
+                 format!("Invalid header value for User - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Task> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<User> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <Task as std::str::FromStr>::from_str(value) {
+                    match <User as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into Task - {}",
+                            format!("Unable to convert header value '{}' into User - {}",
                                 value, err))
                     }
              },
@@ -374,19 +5640,22 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Task> {
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct UpdateTask {
-    /// Task description
-    #[serde(rename = "description")]
-    #[validate(
-            length(max = 1000),
-        )]
-    #[serde(skip_serializing_if="Option::is_none")]
-    pub description: Option<String>,
+pub struct VersionInfo {
+    /// Crate version
+    #[serde(rename = "version")]
+    pub version: String,
 
-    /// Whether the task is completed
-    #[serde(rename = "completed")]
-    #[serde(skip_serializing_if="Option::is_none")]
-    pub completed: Option<bool>,
+    /// Git commit the binary was built from, or "unknown"
+    #[serde(rename = "git_commit")]
+    pub git_commit: String,
+
+    /// When the binary was built (RFC 3339), or "unknown"
+    #[serde(rename = "build_time")]
+    pub build_time: String,
+
+    /// Storage backend in use (memory or sqlite)
+    #[serde(rename = "storage")]
+    pub storage: String,
 
 }
 
@@ -394,37 +5663,39 @@ pub struct UpdateTask {
 
 
 
-impl UpdateTask {
+impl VersionInfo {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> UpdateTask {
-        UpdateTask {
-            description: None,
-            completed: None,
+    pub fn new(version: String, git_commit: String, build_time: String, storage: String, ) -> VersionInfo {
+        VersionInfo {
+            version,
+            git_commit,
+            build_time,
+            storage,
         }
     }
 }
 
-/// Converts the UpdateTask value to the Query Parameters representation (style=form, explode=false)
+/// Converts the VersionInfo value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for UpdateTask {
+impl std::fmt::Display for VersionInfo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
 
-            self.description.as_ref().map(|description| {
-                [
-                    "description".to_string(),
-                    description.to_string(),
-                ].join(",")
-            }),
+            Some("version".to_string()),
+            Some(self.version.to_string()),
 
 
-            self.completed.as_ref().map(|completed| {
-                [
-                    "completed".to_string(),
-                    completed.to_string(),
-                ].join(",")
-            }),
+            Some("git_commit".to_string()),
+            Some(self.git_commit.to_string()),
+
+
+            Some("build_time".to_string()),
+            Some(self.build_time.to_string()),
+
+
+            Some("storage".to_string()),
+            Some(self.storage.to_string()),
 
         ];
 
@@ -432,10 +5703,10 @@ impl std::fmt::Display for UpdateTask {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a UpdateTask value
+/// Converts Query Parameters representation (style=form, explode=false) to a VersionInfo value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for UpdateTask {
+impl std::str::FromStr for VersionInfo {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -443,8 +5714,10 @@ impl std::str::FromStr for UpdateTask {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub description: Vec<String>,
-            pub completed: Vec<bool>,
+            pub version: Vec<String>,
+            pub git_commit: Vec<String>,
+            pub build_time: Vec<String>,
+            pub storage: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -456,17 +5729,21 @@ impl std::str::FromStr for UpdateTask {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing UpdateTask".to_string())
+                None => return std::result::Result::Err("Missing value while parsing VersionInfo".to_string())
             };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "description" => intermediate_rep.description.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "version" => intermediate_rep.version.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "completed" => intermediate_rep.completed.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing UpdateTask".to_string())
+                    "git_commit" => intermediate_rep.git_commit.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "build_time" => intermediate_rep.build_time.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "storage" => intermediate_rep.storage.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing VersionInfo".to_string())
                 }
             }
 
@@ -475,41 +5752,146 @@ impl std::str::FromStr for UpdateTask {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(UpdateTask {
-            description: intermediate_rep.description.into_iter().next(),
-            completed: intermediate_rep.completed.into_iter().next(),
+        std::result::Result::Ok(VersionInfo {
+            version: intermediate_rep.version.into_iter().next().ok_or_else(|| "version missing in VersionInfo".to_string())?,
+            git_commit: intermediate_rep.git_commit.into_iter().next().ok_or_else(|| "git_commit missing in VersionInfo".to_string())?,
+            build_time: intermediate_rep.build_time.into_iter().next().ok_or_else(|| "build_time missing in VersionInfo".to_string())?,
+            storage: intermediate_rep.storage.into_iter().next().ok_or_else(|| "storage missing in VersionInfo".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<UpdateTask> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<VersionInfo> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<UpdateTask>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<VersionInfo>> for HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<UpdateTask>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<VersionInfo>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for UpdateTask - value: {} is invalid {}",
+                 format!("Invalid header value for VersionInfo - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<UpdateTask> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<VersionInfo> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <UpdateTask as std::str::FromStr>::from_str(value) {
+                    match <VersionInfo as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into UpdateTask - {}",
+                            format!("Unable to convert header value '{}' into VersionInfo - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum Weekday {
+    #[serde(rename = "monday")]
+    Monday,
+    #[serde(rename = "tuesday")]
+    Tuesday,
+    #[serde(rename = "wednesday")]
+    Wednesday,
+    #[serde(rename = "thursday")]
+    Thursday,
+    #[serde(rename = "friday")]
+    Friday,
+    #[serde(rename = "saturday")]
+    Saturday,
+    #[serde(rename = "sunday")]
+    Sunday,
+}
+
+impl validator::Validate for Weekday
+{
+    fn validate(&self) -> std::result::Result<(), validator::ValidationErrors> {
+        std::result::Result::Ok(())
+    }
+}
+
+impl std::fmt::Display for Weekday {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Weekday::Monday => write!(f, "monday"),
+            Weekday::Tuesday => write!(f, "tuesday"),
+            Weekday::Wednesday => write!(f, "wednesday"),
+            Weekday::Thursday => write!(f, "thursday"),
+            Weekday::Friday => write!(f, "friday"),
+            Weekday::Saturday => write!(f, "saturday"),
+            Weekday::Sunday => write!(f, "sunday"),
+        }
+    }
+}
+
+impl std::str::FromStr for Weekday {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "monday" => std::result::Result::Ok(Weekday::Monday),
+            "tuesday" => std::result::Result::Ok(Weekday::Tuesday),
+            "wednesday" => std::result::Result::Ok(Weekday::Wednesday),
+            "thursday" => std::result::Result::Ok(Weekday::Thursday),
+            "friday" => std::result::Result::Ok(Weekday::Friday),
+            "saturday" => std::result::Result::Ok(Weekday::Saturday),
+            "sunday" => std::result::Result::Ok(Weekday::Sunday),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Weekday> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Weekday>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Weekday>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Weekday - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Weekday> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Weekday as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Weekday - {}",
                                 value, err))
                     }
              },
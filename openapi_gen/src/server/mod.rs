@@ -17,39 +17,126 @@ use crate::{apis, models};
 pub fn new<I, A, E>(api_impl: I) -> Router
 where
     I: AsRef<A> + Clone + Send + Sync + 'static,
-    A: apis::tasks::Tasks<E> + Send + Sync + 'static,
+    A: apis::api_keys::ApiKeys<E> + apis::auth::Auth<E> + apis::projects::Projects<E> + apis::system::System<E> + apis::tasks::Tasks<E> + apis::users::Users<E> + Send + Sync + 'static,
     E: std::fmt::Debug + Send + Sync + 'static,
     
 {
     // build our application with a route
     Router::new()
+        .route("/api-keys",
+            get(api_keys_get::<I, A, E>).post(api_keys_post::<I, A, E>)
+        )
+        .route("/api-keys/{id}",
+            delete(api_keys_id_delete::<I, A, E>)
+        )
+        .route("/auth/login",
+            post(auth_login_post::<I, A, E>)
+        )
+        .route("/auth/logout",
+            post(auth_logout_post::<I, A, E>)
+        )
+        .route("/auth/session",
+            get(auth_session_get::<I, A, E>)
+        )
+        .route("/healthz",
+            get(healthz_get::<I, A, E>)
+        )
+        .route("/projects",
+            get(projects_get::<I, A, E>).post(projects_post::<I, A, E>)
+        )
+        .route("/projects/{id}",
+            delete(projects_id_delete::<I, A, E>).get(projects_id_get::<I, A, E>).put(projects_id_put::<I, A, E>)
+        )
+        .route("/projects/{id}/tasks",
+            get(projects_id_tasks_get::<I, A, E>)
+        )
+        .route("/readyz",
+            get(readyz_get::<I, A, E>)
+        )
+        .route("/tags",
+            get(tags_get::<I, A, E>)
+        )
         .route("/tasks",
             get(tasks_get::<I, A, E>).post(tasks_post::<I, A, E>)
         )
+        .route("/tasks/bulk",
+            post(tasks_bulk_post::<I, A, E>)
+        )
         .route("/tasks/completed",
             get(tasks_completed_get::<I, A, E>)
         )
+        .route("/tasks/due-soon",
+            get(tasks_due_soon_get::<I, A, E>)
+        )
+        .route("/tasks/overdue",
+            get(tasks_overdue_get::<I, A, E>)
+        )
         .route("/tasks/pending",
             get(tasks_pending_get::<I, A, E>)
         )
+        .route("/tasks/ready",
+            get(tasks_ready_get::<I, A, E>)
+        )
         .route("/tasks/search",
             get(tasks_search_get::<I, A, E>)
         )
+        .route("/tasks/trash",
+            delete(tasks_trash_delete::<I, A, E>).get(tasks_trash_get::<I, A, E>)
+        )
+        .route("/tasks/tree",
+            get(tasks_tree_get::<I, A, E>)
+        )
         .route("/tasks/{id}",
             delete(tasks_id_delete::<I, A, E>).get(tasks_id_get::<I, A, E>).put(tasks_id_put::<I, A, E>)
         )
+        .route("/tasks/{id}/children",
+            get(tasks_id_children_get::<I, A, E>)
+        )
         .route("/tasks/{id}/complete",
             put(tasks_id_complete_put::<I, A, E>)
         )
+        .route("/tasks/{id}/dependencies",
+            post(tasks_id_dependencies_post::<I, A, E>)
+        )
+        .route("/tasks/{id}/dependencies/{dependency_id}",
+            delete(tasks_id_dependencies_dependency_id_delete::<I, A, E>)
+        )
+        .route("/tasks/{id}/history",
+            get(tasks_id_history_get::<I, A, E>)
+        )
+        .route("/tasks/{id}/occurrences",
+            get(tasks_id_occurrences_get::<I, A, E>)
+        )
+        .route("/tasks/{id}/recurrence",
+            delete(tasks_id_recurrence_delete::<I, A, E>)
+        )
+        .route("/tasks/{id}/restore",
+            post(tasks_id_restore_post::<I, A, E>)
+        )
+        .route("/tasks/{id}/tags",
+            post(tasks_id_tags_post::<I, A, E>)
+        )
+        .route("/tasks/{id}/tags/{tag}",
+            delete(tasks_id_tags_tag_delete::<I, A, E>)
+        )
         .route("/tasks/{id}/uncomplete",
             put(tasks_id_uncomplete_put::<I, A, E>)
         )
+        .route("/users",
+            get(users_get::<I, A, E>).post(users_post::<I, A, E>)
+        )
+        .route("/users/me",
+            get(users_me_get::<I, A, E>)
+        )
+        .route("/version",
+            get(version_get::<I, A, E>)
+        )
         .with_state(api_impl)
 }
 
 
 #[tracing::instrument(skip_all)]
-fn tasks_completed_get_validation(
+fn api_keys_get_validation(
 ) -> std::result::Result<(
 ), ValidationErrors>
 {
@@ -57,9 +144,9 @@ fn tasks_completed_get_validation(
 Ok((
 ))
 }
-/// TasksCompletedGet - GET /tasks/completed
+/// ApiKeysGet - GET /api-keys
 #[tracing::instrument(skip_all)]
-async fn tasks_completed_get<I, A, E>(
+async fn api_keys_get<I, A, E>(
   method: Method,
   host: Host,
   cookies: CookieJar,
@@ -67,14 +154,14 @@ async fn tasks_completed_get<I, A, E>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::tasks::Tasks<E> + Send + Sync,
+    A: apis::api_keys::ApiKeys<E> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
         {
 
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    tasks_completed_get_validation(
+    api_keys_get_validation(
     )
   ).await.unwrap();
 
@@ -86,7 +173,7 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().tasks_completed_get(
+  let result = api_impl.as_ref().api_keys_get(
       &method,
       &host,
       &cookies,
@@ -96,7 +183,7 @@ where
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::tasks::TasksCompletedGetResponse::Status200_ListOfCompletedTasks
+                                                apis::api_keys::ApiKeysGetResponse::Status200_ListOfAPIKeys
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -114,10 +201,147 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksCompletedGetResponse::Status500_InternalServerError
+                                                apis::api_keys::ApiKeysGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysGetResponse::Status403_TheCredentialsDoNotGrantTheRequiredScope
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysGetResponse::Status500_InternalServerError
+                                                    (body)
                                                 => {
                                                   let mut response = response.status(500);
-                                                  response.body(Body::empty())
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
                                             },
                                             Err(why) => {
@@ -132,36 +356,43 @@ where
 
 
 #[tracing::instrument(skip_all)]
-fn tasks_get_validation(
+fn api_keys_id_delete_validation(
+  path_params: models::ApiKeysIdDeletePathParams,
 ) -> std::result::Result<(
+  models::ApiKeysIdDeletePathParams,
 ), ValidationErrors>
 {
+  path_params.validate()?;
 
 Ok((
+  path_params,
 ))
 }
-/// TasksGet - GET /tasks
+/// ApiKeysIdDelete - DELETE /api-keys/{id}
 #[tracing::instrument(skip_all)]
-async fn tasks_get<I, A, E>(
+async fn api_keys_id_delete<I, A, E>(
   method: Method,
   host: Host,
   cookies: CookieJar,
+  Path(path_params): Path<models::ApiKeysIdDeletePathParams>,
  State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::tasks::Tasks<E> + Send + Sync,
+    A: apis::api_keys::ApiKeys<E> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
         {
 
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    tasks_get_validation(
+    api_keys_id_delete_validation(
+        path_params,
     )
   ).await.unwrap();
 
   let Ok((
+    path_params,
   )) = validation else {
     return Response::builder()
             .status(StatusCode::BAD_REQUEST)
@@ -169,25 +400,31 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().tasks_get(
+  let result = api_impl.as_ref().api_keys_id_delete(
       &method,
       &host,
       &cookies,
+        &path_params,
   ).await;
 
   let mut response = Response::builder();
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::tasks::TasksGetResponse::Status200_ListOfAllTasks
+                                                apis::api_keys::ApiKeysIdDeleteResponse::Status204_APIKeyRevoked
+                                                => {
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::api_keys::ApiKeysIdDeleteResponse::Status400_NegativeIDInThePath
                                                     (body)
                                                 => {
-                                                  let mut response = response.status(200);
+                                                  let mut response = response.status(400);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
                                                   }
 
                                                   let body_content =  tokio::task::spawn_blocking(move ||
@@ -197,10 +434,165 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksGetResponse::Status500_InternalServerError
+                                                apis::api_keys::ApiKeysIdDeleteResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysIdDeleteResponse::Status403_TheCredentialsDoNotGrantTheRequiredScope
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysIdDeleteResponse::Status404_APIKeyNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysIdDeleteResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysIdDeleteResponse::Status500_InternalServerError
+                                                    (body)
                                                 => {
                                                   let mut response = response.status(500);
-                                                  response.body(Body::empty())
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
                                             },
                                             Err(why) => {
@@ -213,45 +605,53 @@ where
                                         resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
 }
 
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct ApiKeysPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::CreateApiKey,
+    }
+
 
 #[tracing::instrument(skip_all)]
-fn tasks_id_complete_put_validation(
-  path_params: models::TasksIdCompletePutPathParams,
+fn api_keys_post_validation(
+        body: models::CreateApiKey,
 ) -> std::result::Result<(
-  models::TasksIdCompletePutPathParams,
+        models::CreateApiKey,
 ), ValidationErrors>
 {
-  path_params.validate()?;
+              let b = ApiKeysPostBodyValidator { body: &body };
+              b.validate()?;
 
 Ok((
-  path_params,
+    body,
 ))
 }
-/// TasksIdCompletePut - PUT /tasks/{id}/complete
+/// ApiKeysPost - POST /api-keys
 #[tracing::instrument(skip_all)]
-async fn tasks_id_complete_put<I, A, E>(
+async fn api_keys_post<I, A, E>(
   method: Method,
   host: Host,
   cookies: CookieJar,
-  Path(path_params): Path<models::TasksIdCompletePutPathParams>,
  State(api_impl): State<I>,
+          Json(body): Json<models::CreateApiKey>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::tasks::Tasks<E> + Send + Sync,
+    A: apis::api_keys::ApiKeys<E> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
         {
 
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    tasks_id_complete_put_validation(
-        path_params,
+    api_keys_post_validation(
+          body,
     )
   ).await.unwrap();
 
   let Ok((
-    path_params,
+      body,
   )) = validation else {
     return Response::builder()
             .status(StatusCode::BAD_REQUEST)
@@ -259,21 +659,21 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().tasks_id_complete_put(
+  let result = api_impl.as_ref().api_keys_post(
       &method,
       &host,
       &cookies,
-        &path_params,
+              &body,
   ).await;
 
   let mut response = Response::builder();
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::tasks::TasksIdCompletePutResponse::Status200_TaskMarkedAsCompleted
+                                                apis::api_keys::ApiKeysPostResponse::Status201_APIKeyCreated
                                                     (body)
                                                 => {
-                                                  let mut response = response.status(200);
+                                                  let mut response = response.status(201);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
@@ -288,18 +688,9015 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdCompletePutResponse::Status404_TaskNotFound
+                                                apis::api_keys::ApiKeysPostResponse::Status400_ValidationError
+                                                    (body)
                                                 => {
-                                                  let mut response = response.status(404);
-                                                  response.body(Body::empty())
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdCompletePutResponse::Status500_InternalServerError
+                                                apis::api_keys::ApiKeysPostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
                                                 => {
-                                                  let mut response = response.status(500);
-                                                  response.body(Body::empty())
-                                                },
-                                            },
-                                            Err(why) => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysPostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScope
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysPostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::api_keys::ApiKeysPostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn auth_login_post_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// AuthLoginPost - POST /auth/login
+#[tracing::instrument(skip_all)]
+async fn auth_login_post<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::auth::Auth<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    auth_login_post_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().auth_login_post(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::auth::AuthLoginPostResponse::Status200_SessionStarted
+                                                    {
+                                                        body,
+                                                        set_cookie,
+                                                    }
+                                                => {
+                                                      let set_cookie = match header::IntoHeaderValue(set_cookie).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling set_cookie header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("set-cookie"),
+                                                            set_cookie);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthLoginPostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthLoginPostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheCSRFTokenIsMissingOrWrong
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthLoginPostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthLoginPostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn auth_logout_post_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// AuthLogoutPost - POST /auth/logout
+#[tracing::instrument(skip_all)]
+async fn auth_logout_post<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::auth::Auth<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    auth_logout_post_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().auth_logout_post(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::auth::AuthLogoutPostResponse::Status204_SessionEnded
+                                                    {
+                                                        set_cookie,
+                                                    }
+                                                => {
+                                                      let set_cookie = match header::IntoHeaderValue(set_cookie).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling set_cookie header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("set-cookie"),
+                                                            set_cookie);
+                                                      }
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::auth::AuthLogoutPostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthLogoutPostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheCSRFTokenIsMissingOrWrong
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthLogoutPostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthLogoutPostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn auth_session_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// AuthSessionGet - GET /auth/session
+#[tracing::instrument(skip_all)]
+async fn auth_session_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::auth::Auth<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    auth_session_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().auth_session_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::auth::AuthSessionGetResponse::Status200_CurrentSession
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthSessionGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthSessionGetResponse::Status404_NoSessionCookieWasSent
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthSessionGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthSessionGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn projects_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// ProjectsGet - GET /projects
+#[tracing::instrument(skip_all)]
+async fn projects_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::projects::Projects<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    projects_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().projects_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::projects::ProjectsGetResponse::Status200_ListOfProjects
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn projects_id_delete_validation(
+  path_params: models::ProjectsIdDeletePathParams,
+) -> std::result::Result<(
+  models::ProjectsIdDeletePathParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+
+Ok((
+  path_params,
+))
+}
+/// ProjectsIdDelete - DELETE /projects/{id}
+#[tracing::instrument(skip_all)]
+async fn projects_id_delete<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::ProjectsIdDeletePathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::projects::Projects<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    projects_id_delete_validation(
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().projects_id_delete(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::projects::ProjectsIdDeleteResponse::Status204_ProjectDeleted
+                                                => {
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::projects::ProjectsIdDeleteResponse::Status400_TheInboxCannotBeDeletedOrANegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdDeleteResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdDeleteResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheProjectBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdDeleteResponse::Status404_ProjectNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdDeleteResponse::Status409_TheProjectStillHasTasks
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdDeleteResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdDeleteResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn projects_id_get_validation(
+  path_params: models::ProjectsIdGetPathParams,
+) -> std::result::Result<(
+  models::ProjectsIdGetPathParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+
+Ok((
+  path_params,
+))
+}
+/// ProjectsIdGet - GET /projects/{id}
+#[tracing::instrument(skip_all)]
+async fn projects_id_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::ProjectsIdGetPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::projects::Projects<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    projects_id_get_validation(
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().projects_id_get(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::projects::ProjectsIdGetResponse::Status200_ProjectFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdGetResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdGetResponse::Status403_TheProjectBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdGetResponse::Status404_ProjectNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct ProjectsIdPutBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::UpdateProject,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn projects_id_put_validation(
+  path_params: models::ProjectsIdPutPathParams,
+        body: models::UpdateProject,
+) -> std::result::Result<(
+  models::ProjectsIdPutPathParams,
+        models::UpdateProject,
+), ValidationErrors>
+{
+  path_params.validate()?;
+              let b = ProjectsIdPutBodyValidator { body: &body };
+              b.validate()?;
+
+Ok((
+  path_params,
+    body,
+))
+}
+/// ProjectsIdPut - PUT /projects/{id}
+#[tracing::instrument(skip_all)]
+async fn projects_id_put<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::ProjectsIdPutPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<models::UpdateProject>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::projects::Projects<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    projects_id_put_validation(
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+      body,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().projects_id_put(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+              &body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::projects::ProjectsIdPutResponse::Status200_ProjectUpdated
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdPutResponse::Status400_ValidationErrorOrANegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdPutResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdPutResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheProjectBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdPutResponse::Status404_ProjectNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdPutResponse::Status409_AProjectWithThisNameAlreadyExists
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdPutResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdPutResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn projects_id_tasks_get_validation(
+  path_params: models::ProjectsIdTasksGetPathParams,
+  query_params: models::ProjectsIdTasksGetQueryParams,
+) -> std::result::Result<(
+  models::ProjectsIdTasksGetPathParams,
+  models::ProjectsIdTasksGetQueryParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  path_params,
+  query_params,
+))
+}
+/// ProjectsIdTasksGet - GET /projects/{id}/tasks
+#[tracing::instrument(skip_all)]
+async fn projects_id_tasks_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::ProjectsIdTasksGetPathParams>,
+  QueryExtra(query_params): QueryExtra<models::ProjectsIdTasksGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::projects::Projects<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    projects_id_tasks_get_validation(
+        path_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+    query_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().projects_id_tasks_get(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+        &query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::projects::ProjectsIdTasksGetResponse::Status200_ListOfProjectTasks
+                                                    {
+                                                        body,
+                                                        link,
+                                                        x_next_cursor,
+                                                    }
+                                                => {
+                                                      if let Some(link) = link {
+                                                      let link = match header::IntoHeaderValue(link).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling link header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("link"),
+                                                            link);
+                                                      }
+                                                      }
+                                                      if let Some(x_next_cursor) = x_next_cursor {
+                                                      let x_next_cursor = match header::IntoHeaderValue(x_next_cursor).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling x_next_cursor header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("x-next-cursor"),
+                                                            x_next_cursor);
+                                                      }
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdTasksGetResponse::Status400_InvalidQueryParameterOrANegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdTasksGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdTasksGetResponse::Status403_TheProjectBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdTasksGetResponse::Status404_ProjectNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdTasksGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsIdTasksGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct ProjectsPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::CreateProject,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn projects_post_validation(
+        body: models::CreateProject,
+) -> std::result::Result<(
+        models::CreateProject,
+), ValidationErrors>
+{
+              let b = ProjectsPostBodyValidator { body: &body };
+              b.validate()?;
+
+Ok((
+    body,
+))
+}
+/// ProjectsPost - POST /projects
+#[tracing::instrument(skip_all)]
+async fn projects_post<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+          Json(body): Json<models::CreateProject>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::projects::Projects<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    projects_post_validation(
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+      body,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().projects_post(
+      &method,
+      &host,
+      &cookies,
+              &body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::projects::ProjectsPostResponse::Status201_ProjectCreated
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(201);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsPostResponse::Status400_ValidationError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsPostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsPostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScope
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsPostResponse::Status409_AProjectWithThisNameAlreadyExists
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsPostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::projects::ProjectsPostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn healthz_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// HealthzGet - GET /healthz
+#[tracing::instrument(skip_all)]
+async fn healthz_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::system::System<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    healthz_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().healthz_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::system::HealthzGetResponse::Status200_TheProcessIsAlive
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn readyz_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// ReadyzGet - GET /readyz
+#[tracing::instrument(skip_all)]
+async fn readyz_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::system::System<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    readyz_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().readyz_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::system::ReadyzGetResponse::Status200_TheServiceIsReadyToAcceptTraffic
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::system::ReadyzGetResponse::Status503_TheTaskRepositoryIsUnavailable
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(503);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn version_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// VersionGet - GET /version
+#[tracing::instrument(skip_all)]
+async fn version_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::system::System<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    version_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().version_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::system::VersionGetResponse::Status200_BuildInformation
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tags_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// TagsGet - GET /tags
+#[tracing::instrument(skip_all)]
+async fn tags_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tags_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tags_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TagsGetResponse::Status200_TagsWithTaskCountsOrderedByTag
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TagsGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TagsGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TagsGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct TasksBulkPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::BulkRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_bulk_post_validation(
+        body: models::BulkRequest,
+) -> std::result::Result<(
+        models::BulkRequest,
+), ValidationErrors>
+{
+              let b = TasksBulkPostBodyValidator { body: &body };
+              b.validate()?;
+
+Ok((
+    body,
+))
+}
+/// TasksBulkPost - POST /tasks/bulk
+#[tracing::instrument(skip_all)]
+async fn tasks_bulk_post<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+          Json(body): Json<models::BulkRequest>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_bulk_post_validation(
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+      body,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_bulk_post(
+      &method,
+      &host,
+      &cookies,
+              &body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksBulkPostResponse::Status200_BatchProcessed
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksBulkPostResponse::Status400_MalformedBatch
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksBulkPostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksBulkPostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrAnOperationAddressesATaskThatBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksBulkPostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksBulkPostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_completed_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// TasksCompletedGet - GET /tasks/completed
+#[tracing::instrument(skip_all)]
+async fn tasks_completed_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_completed_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_completed_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksCompletedGetResponse::Status200_ListOfCompletedTasks
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksCompletedGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksCompletedGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksCompletedGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_due_soon_get_validation(
+  query_params: models::TasksDueSoonGetQueryParams,
+) -> std::result::Result<(
+  models::TasksDueSoonGetQueryParams,
+), ValidationErrors>
+{
+  query_params.validate()?;
+
+Ok((
+  query_params,
+))
+}
+/// TasksDueSoonGet - GET /tasks/due-soon
+#[tracing::instrument(skip_all)]
+async fn tasks_due_soon_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  QueryExtra(query_params): QueryExtra<models::TasksDueSoonGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_due_soon_get_validation(
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    query_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_due_soon_get(
+      &method,
+      &host,
+      &cookies,
+        &query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksDueSoonGetResponse::Status200_ListOfTasksDueSoon
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksDueSoonGetResponse::Status400_InvalidQueryParameter
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksDueSoonGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksDueSoonGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksDueSoonGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_get_validation(
+  query_params: models::TasksGetQueryParams,
+) -> std::result::Result<(
+  models::TasksGetQueryParams,
+), ValidationErrors>
+{
+  query_params.validate()?;
+
+Ok((
+  query_params,
+))
+}
+/// TasksGet - GET /tasks
+#[tracing::instrument(skip_all)]
+async fn tasks_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  QueryExtra(query_params): QueryExtra<models::TasksGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_get_validation(
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    query_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_get(
+      &method,
+      &host,
+      &cookies,
+        &query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksGetResponse::Status200_ListOfAllTasks
+                                                    {
+                                                        body,
+                                                        link,
+                                                        x_next_cursor,
+                                                    }
+                                                => {
+                                                      if let Some(link) = link {
+                                                      let link = match header::IntoHeaderValue(link).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling link header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("link"),
+                                                            link);
+                                                      }
+                                                      }
+                                                      if let Some(x_next_cursor) = x_next_cursor {
+                                                      let x_next_cursor = match header::IntoHeaderValue(x_next_cursor).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling x_next_cursor header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("x-next-cursor"),
+                                                            x_next_cursor);
+                                                      }
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksGetResponse::Status400_InvalidQueryParameter
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_children_get_validation(
+  path_params: models::TasksIdChildrenGetPathParams,
+) -> std::result::Result<(
+  models::TasksIdChildrenGetPathParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+
+Ok((
+  path_params,
+))
+}
+/// TasksIdChildrenGet - GET /tasks/{id}/children
+#[tracing::instrument(skip_all)]
+async fn tasks_id_children_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::TasksIdChildrenGetPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_children_get_validation(
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_children_get(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdChildrenGetResponse::Status200_ListOfSubtasks
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdChildrenGetResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdChildrenGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdChildrenGetResponse::Status403_TheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdChildrenGetResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdChildrenGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdChildrenGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_complete_put_validation(
+  header_params: models::TasksIdCompletePutHeaderParams,
+  path_params: models::TasksIdCompletePutPathParams,
+  query_params: models::TasksIdCompletePutQueryParams,
+) -> std::result::Result<(
+  models::TasksIdCompletePutHeaderParams,
+  models::TasksIdCompletePutPathParams,
+  models::TasksIdCompletePutQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+  query_params,
+))
+}
+/// TasksIdCompletePut - PUT /tasks/{id}/complete
+#[tracing::instrument(skip_all)]
+async fn tasks_id_complete_put<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::TasksIdCompletePutPathParams>,
+  QueryExtra(query_params): QueryExtra<models::TasksIdCompletePutQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+    // Header parameters
+    let header_params = {
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Invalid header If-Match - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::TasksIdCompletePutHeaderParams {
+          if_match: header_if_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_complete_put_validation(
+        header_params,
+        path_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+    query_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_complete_put(
+      &method,
+      &host,
+      &cookies,
+        &header_params,
+        &path_params,
+        &query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdCompletePutResponse::Status200_TaskMarkedAsCompleted
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdCompletePutResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdCompletePutResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdCompletePutResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdCompletePutResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdCompletePutResponse::Status409_TaskIsBlockedByIncompleteDependencies
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdCompletePutResponse::Status412_TaskWasModifiedSinceTheGivenETag
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdCompletePutResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdCompletePutResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_delete_validation(
+  header_params: models::TasksIdDeleteHeaderParams,
+  path_params: models::TasksIdDeletePathParams,
+  query_params: models::TasksIdDeleteQueryParams,
+) -> std::result::Result<(
+  models::TasksIdDeleteHeaderParams,
+  models::TasksIdDeletePathParams,
+  models::TasksIdDeleteQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+  query_params,
+))
+}
+/// TasksIdDelete - DELETE /tasks/{id}
+#[tracing::instrument(skip_all)]
+async fn tasks_id_delete<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::TasksIdDeletePathParams>,
+  QueryExtra(query_params): QueryExtra<models::TasksIdDeleteQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+    // Header parameters
+    let header_params = {
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Invalid header If-Match - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::TasksIdDeleteHeaderParams {
+          if_match: header_if_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_delete_validation(
+        header_params,
+        path_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+    query_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_delete(
+      &method,
+      &host,
+      &cookies,
+        &header_params,
+        &path_params,
+        &query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdDeleteResponse::Status204_TaskDeletedSuccessfully
+                                                => {
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::tasks::TasksIdDeleteResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDeleteResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDeleteResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDeleteResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDeleteResponse::Status409_TaskHasSubtasksAndChildrenIsReject
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDeleteResponse::Status412_TaskWasModifiedSinceTheGivenETag
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDeleteResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDeleteResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_dependencies_dependency_id_delete_validation(
+  path_params: models::TasksIdDependenciesDependencyIdDeletePathParams,
+) -> std::result::Result<(
+  models::TasksIdDependenciesDependencyIdDeletePathParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+
+Ok((
+  path_params,
+))
+}
+/// TasksIdDependenciesDependencyIdDelete - DELETE /tasks/{id}/dependencies/{dependency_id}
+#[tracing::instrument(skip_all)]
+async fn tasks_id_dependencies_dependency_id_delete<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::TasksIdDependenciesDependencyIdDeletePathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_dependencies_dependency_id_delete_validation(
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_dependencies_dependency_id_delete(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdDependenciesDependencyIdDeleteResponse::Status200_DependencyRemovedSuccessfully
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesDependencyIdDeleteResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesDependencyIdDeleteResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesDependencyIdDeleteResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesDependencyIdDeleteResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesDependencyIdDeleteResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesDependencyIdDeleteResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct TasksIdDependenciesPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::AddDependencies,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_dependencies_post_validation(
+  path_params: models::TasksIdDependenciesPostPathParams,
+        body: models::AddDependencies,
+) -> std::result::Result<(
+  models::TasksIdDependenciesPostPathParams,
+        models::AddDependencies,
+), ValidationErrors>
+{
+  path_params.validate()?;
+              let b = TasksIdDependenciesPostBodyValidator { body: &body };
+              b.validate()?;
+
+Ok((
+  path_params,
+    body,
+))
+}
+/// TasksIdDependenciesPost - POST /tasks/{id}/dependencies
+#[tracing::instrument(skip_all)]
+async fn tasks_id_dependencies_post<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::TasksIdDependenciesPostPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<models::AddDependencies>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_dependencies_post_validation(
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+      body,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_dependencies_post(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+              &body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdDependenciesPostResponse::Status200_DependenciesAddedSuccessfully
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesPostResponse::Status400_UnknownDependencyOrDependencyCycleOrANegativeTaskOrDependencyID
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesPostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesPostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesPostResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesPostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdDependenciesPostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_get_validation(
+  header_params: models::TasksIdGetHeaderParams,
+  path_params: models::TasksIdGetPathParams,
+) -> std::result::Result<(
+  models::TasksIdGetHeaderParams,
+  models::TasksIdGetPathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// TasksIdGet - GET /tasks/{id}
+#[tracing::instrument(skip_all)]
+async fn tasks_id_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::TasksIdGetPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+    // Header parameters
+    let header_params = {
+                let header_if_none_match = headers.get(HeaderName::from_static("if-none-match"));
+
+                let header_if_none_match = match header_if_none_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Invalid header If-None-Match - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::TasksIdGetHeaderParams {
+          if_none_match: header_if_none_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_get_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_get(
+      &method,
+      &host,
+      &cookies,
+        &header_params,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdGetResponse::Status200_TaskFound
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdGetResponse::Status304_TaskHasNotBeenModified
+                                                    {
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(304);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::tasks::TasksIdGetResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdGetResponse::Status403_TheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdGetResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_history_get_validation(
+  path_params: models::TasksIdHistoryGetPathParams,
+) -> std::result::Result<(
+  models::TasksIdHistoryGetPathParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+
+Ok((
+  path_params,
+))
+}
+/// TasksIdHistoryGet - GET /tasks/{id}/history
+#[tracing::instrument(skip_all)]
+async fn tasks_id_history_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::TasksIdHistoryGetPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_history_get_validation(
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_history_get(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdHistoryGetResponse::Status200_TaskHistory
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdHistoryGetResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdHistoryGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdHistoryGetResponse::Status403_TheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdHistoryGetResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdHistoryGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdHistoryGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_occurrences_get_validation(
+  path_params: models::TasksIdOccurrencesGetPathParams,
+  query_params: models::TasksIdOccurrencesGetQueryParams,
+) -> std::result::Result<(
+  models::TasksIdOccurrencesGetPathParams,
+  models::TasksIdOccurrencesGetQueryParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  path_params,
+  query_params,
+))
+}
+/// TasksIdOccurrencesGet - GET /tasks/{id}/occurrences
+#[tracing::instrument(skip_all)]
+async fn tasks_id_occurrences_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::TasksIdOccurrencesGetPathParams>,
+  QueryExtra(query_params): QueryExtra<models::TasksIdOccurrencesGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_occurrences_get_validation(
+        path_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+    query_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_occurrences_get(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+        &query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdOccurrencesGetResponse::Status200_UpcomingOccurrences
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdOccurrencesGetResponse::Status400_TaskDoesNotRecurOrANegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdOccurrencesGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdOccurrencesGetResponse::Status403_TheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdOccurrencesGetResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdOccurrencesGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdOccurrencesGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct TasksIdPutBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::UpdateTask,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_put_validation(
+  header_params: models::TasksIdPutHeaderParams,
+  path_params: models::TasksIdPutPathParams,
+        body: models::UpdateTask,
+) -> std::result::Result<(
+  models::TasksIdPutHeaderParams,
+  models::TasksIdPutPathParams,
+        models::UpdateTask,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+              let b = TasksIdPutBodyValidator { body: &body };
+              b.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+    body,
+))
+}
+/// TasksIdPut - PUT /tasks/{id}
+#[tracing::instrument(skip_all)]
+async fn tasks_id_put<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::TasksIdPutPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<models::UpdateTask>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+    // Header parameters
+    let header_params = {
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Invalid header If-Match - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::TasksIdPutHeaderParams {
+          if_match: header_if_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_put_validation(
+        header_params,
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+      body,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_put(
+      &method,
+      &host,
+      &cookies,
+        &header_params,
+        &path_params,
+              &body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdPutResponse::Status200_TaskUpdatedSuccessfully
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdPutResponse::Status400_ValidationErrorOrInvalidOperationOrANegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdPutResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdPutResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdPutResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdPutResponse::Status409_TaskIsBlockedByIncompleteDependencies
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdPutResponse::Status412_TaskWasModifiedSinceTheGivenETag
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdPutResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdPutResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_recurrence_delete_validation(
+  path_params: models::TasksIdRecurrenceDeletePathParams,
+) -> std::result::Result<(
+  models::TasksIdRecurrenceDeletePathParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+
+Ok((
+  path_params,
+))
+}
+/// TasksIdRecurrenceDelete - DELETE /tasks/{id}/recurrence
+#[tracing::instrument(skip_all)]
+async fn tasks_id_recurrence_delete<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::TasksIdRecurrenceDeletePathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_recurrence_delete_validation(
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_recurrence_delete(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdRecurrenceDeleteResponse::Status200_RecurrenceStopped
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRecurrenceDeleteResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRecurrenceDeleteResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRecurrenceDeleteResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRecurrenceDeleteResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRecurrenceDeleteResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRecurrenceDeleteResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_restore_post_validation(
+  path_params: models::TasksIdRestorePostPathParams,
+) -> std::result::Result<(
+  models::TasksIdRestorePostPathParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+
+Ok((
+  path_params,
+))
+}
+/// TasksIdRestorePost - POST /tasks/{id}/restore
+#[tracing::instrument(skip_all)]
+async fn tasks_id_restore_post<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::TasksIdRestorePostPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_restore_post_validation(
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_restore_post(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdRestorePostResponse::Status200_TaskRestored
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRestorePostResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRestorePostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRestorePostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRestorePostResponse::Status404_TaskNotFoundInTheTrash
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRestorePostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdRestorePostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct TasksIdTagsPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::AddTags,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_tags_post_validation(
+  path_params: models::TasksIdTagsPostPathParams,
+        body: models::AddTags,
+) -> std::result::Result<(
+  models::TasksIdTagsPostPathParams,
+        models::AddTags,
+), ValidationErrors>
+{
+  path_params.validate()?;
+              let b = TasksIdTagsPostBodyValidator { body: &body };
+              b.validate()?;
+
+Ok((
+  path_params,
+    body,
+))
+}
+/// TasksIdTagsPost - POST /tasks/{id}/tags
+#[tracing::instrument(skip_all)]
+async fn tasks_id_tags_post<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::TasksIdTagsPostPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<models::AddTags>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_tags_post_validation(
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+      body,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_tags_post(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+              &body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdTagsPostResponse::Status200_TagsAddedSuccessfully
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsPostResponse::Status400_InvalidTagOrTooManyTagsOrANegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsPostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsPostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsPostResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsPostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsPostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_tags_tag_delete_validation(
+  path_params: models::TasksIdTagsTagDeletePathParams,
+) -> std::result::Result<(
+  models::TasksIdTagsTagDeletePathParams,
+), ValidationErrors>
+{
+  path_params.validate()?;
+
+Ok((
+  path_params,
+))
+}
+/// TasksIdTagsTagDelete - DELETE /tasks/{id}/tags/{tag}
+#[tracing::instrument(skip_all)]
+async fn tasks_id_tags_tag_delete<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  Path(path_params): Path<models::TasksIdTagsTagDeletePathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_tags_tag_delete_validation(
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_tags_tag_delete(
+      &method,
+      &host,
+      &cookies,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdTagsTagDeleteResponse::Status200_TagRemovedSuccessfully
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsTagDeleteResponse::Status400_InvalidTagOrANegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsTagDeleteResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsTagDeleteResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsTagDeleteResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsTagDeleteResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdTagsTagDeleteResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_id_uncomplete_put_validation(
+  header_params: models::TasksIdUncompletePutHeaderParams,
+  path_params: models::TasksIdUncompletePutPathParams,
+) -> std::result::Result<(
+  models::TasksIdUncompletePutHeaderParams,
+  models::TasksIdUncompletePutPathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// TasksIdUncompletePut - PUT /tasks/{id}/uncomplete
+#[tracing::instrument(skip_all)]
+async fn tasks_id_uncomplete_put<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::TasksIdUncompletePutPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+    // Header parameters
+    let header_params = {
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return Response::builder()
+                                    .status(StatusCode::BAD_REQUEST)
+                                    .body(Body::from(format!("Invalid header If-Match - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::TasksIdUncompletePutHeaderParams {
+          if_match: header_if_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_id_uncomplete_put_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_id_uncomplete_put(
+      &method,
+      &host,
+      &cookies,
+        &header_params,
+        &path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksIdUncompletePutResponse::Status200_TaskMarkedAsUncompleted
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdUncompletePutResponse::Status400_NegativeIDInThePath
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdUncompletePutResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdUncompletePutResponse::Status403_TheCredentialsDoNotGrantTheRequiredScopeOrTheTaskBelongsToAnotherUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdUncompletePutResponse::Status404_TaskNotFound
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdUncompletePutResponse::Status412_TaskWasModifiedSinceTheGivenETag
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdUncompletePutResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksIdUncompletePutResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_overdue_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// TasksOverdueGet - GET /tasks/overdue
+#[tracing::instrument(skip_all)]
+async fn tasks_overdue_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_overdue_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_overdue_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksOverdueGetResponse::Status200_ListOfOverdueTasks
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksOverdueGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksOverdueGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksOverdueGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_pending_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// TasksPendingGet - GET /tasks/pending
+#[tracing::instrument(skip_all)]
+async fn tasks_pending_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_pending_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_pending_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksPendingGetResponse::Status200_ListOfPendingTasks
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksPendingGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksPendingGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksPendingGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct TasksPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::CreateTask,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_post_validation(
+        body: models::CreateTask,
+) -> std::result::Result<(
+        models::CreateTask,
+), ValidationErrors>
+{
+              let b = TasksPostBodyValidator { body: &body };
+              b.validate()?;
+
+Ok((
+    body,
+))
+}
+/// TasksPost - POST /tasks
+#[tracing::instrument(skip_all)]
+async fn tasks_post<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+          Json(body): Json<models::CreateTask>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_post_validation(
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+      body,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_post(
+      &method,
+      &host,
+      &cookies,
+              &body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksPostResponse::Status201_TaskCreatedSuccessfully
+                                                    {
+                                                        body,
+                                                        etag,
+                                                    }
+                                                => {
+                                                      let etag = match header::IntoHeaderValue(etag).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling etag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("etag"),
+                                                            etag);
+                                                      }
+                                                  let mut response = response.status(201);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksPostResponse::Status400_ValidationError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksPostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksPostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScope
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksPostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksPostResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_ready_get_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// TasksReadyGet - GET /tasks/ready
+#[tracing::instrument(skip_all)]
+async fn tasks_ready_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_ready_get_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_ready_get(
+      &method,
+      &host,
+      &cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksReadyGetResponse::Status200_ListOfReadyTasks
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksReadyGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksReadyGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksReadyGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
                                                 // Application code returned an error. This should not happen, as the implementation should
                                                 // return a valid response.
                                                 return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
@@ -311,75 +9708,448 @@ where
 
 
 #[tracing::instrument(skip_all)]
-fn tasks_id_delete_validation(
-  path_params: models::TasksIdDeletePathParams,
+fn tasks_search_get_validation(
+  query_params: models::TasksSearchGetQueryParams,
 ) -> std::result::Result<(
-  models::TasksIdDeletePathParams,
+  models::TasksSearchGetQueryParams,
 ), ValidationErrors>
 {
-  path_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  query_params,
+))
+}
+/// TasksSearchGet - GET /tasks/search
+#[tracing::instrument(skip_all)]
+async fn tasks_search_get<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  QueryExtra(query_params): QueryExtra<models::TasksSearchGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_search_get_validation(
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    query_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_search_get(
+      &method,
+      &host,
+      &cookies,
+        &query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksSearchGetResponse::Status200_SearchResults
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksSearchGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksSearchGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksSearchGetResponse::Status500_InternalServerError
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(500);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(why) => {
+                                                // Application code returned an error. This should not happen, as the implementation should
+                                                // return a valid response.
+                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn tasks_trash_delete_validation(
+  query_params: models::TasksTrashDeleteQueryParams,
+) -> std::result::Result<(
+  models::TasksTrashDeleteQueryParams,
+), ValidationErrors>
+{
+  query_params.validate()?;
+
+Ok((
+  query_params,
+))
+}
+/// TasksTrashDelete - DELETE /tasks/trash
+#[tracing::instrument(skip_all)]
+async fn tasks_trash_delete<I, A, E>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  QueryExtra(query_params): QueryExtra<models::TasksTrashDeleteQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::tasks::Tasks<E> + Send + Sync,
+    E: std::fmt::Debug + Send + Sync + 'static,
+        {
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    tasks_trash_delete_validation(
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    query_params,
+  )) = validation else {
+    return Response::builder()
+            .status(StatusCode::BAD_REQUEST)
+            .body(Body::from(validation.unwrap_err().to_string()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().tasks_trash_delete(
+      &method,
+      &host,
+      &cookies,
+        &query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::tasks::TasksTrashDeleteResponse::Status200_TrashPurged
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksTrashDeleteResponse::Status400_InvalidQueryParameter
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(400);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksTrashDeleteResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksTrashDeleteResponse::Status403_TheCredentialsDoNotGrantTheRequiredScope
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksTrashDeleteResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
 
-Ok((
-  path_params,
-))
-}
-/// TasksIdDelete - DELETE /tasks/{id}
-#[tracing::instrument(skip_all)]
-async fn tasks_id_delete<I, A, E>(
-  method: Method,
-  host: Host,
-  cookies: CookieJar,
-  Path(path_params): Path<models::TasksIdDeletePathParams>,
- State(api_impl): State<I>,
-) -> Result<Response, StatusCode>
-where
-    I: AsRef<A> + Send + Sync,
-    A: apis::tasks::Tasks<E> + Send + Sync,
-    E: std::fmt::Debug + Send + Sync + 'static,
-        {
 
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
 
-      #[allow(clippy::redundant_closure)]
-      let validation = tokio::task::spawn_blocking(move ||
-    tasks_id_delete_validation(
-        path_params,
-    )
-  ).await.unwrap();
 
-  let Ok((
-    path_params,
-  )) = validation else {
-    return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
-            .map_err(|_| StatusCode::BAD_REQUEST);
-  };
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
 
-  let result = api_impl.as_ref().tasks_id_delete(
-      &method,
-      &host,
-      &cookies,
-        &path_params,
-  ).await;
 
-  let mut response = Response::builder();
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
 
-  let resp = match result {
-                                            Ok(rsp) => match rsp {
-                                                apis::tasks::TasksIdDeleteResponse::Status204_TaskDeletedSuccessfully
-                                                => {
-                                                  let mut response = response.status(204);
-                                                  response.body(Body::empty())
-                                                },
-                                                apis::tasks::TasksIdDeleteResponse::Status404_TaskNotFound
-                                                => {
-                                                  let mut response = response.status(404);
-                                                  response.body(Body::empty())
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdDeleteResponse::Status500_InternalServerError
+                                                apis::tasks::TasksTrashDeleteResponse::Status500_InternalServerError
+                                                    (body)
                                                 => {
                                                   let mut response = response.status(500);
-                                                  response.body(Body::empty())
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
                                             },
                                             Err(why) => {
@@ -394,25 +10164,20 @@ where
 
 
 #[tracing::instrument(skip_all)]
-fn tasks_id_get_validation(
-  path_params: models::TasksIdGetPathParams,
+fn tasks_trash_get_validation(
 ) -> std::result::Result<(
-  models::TasksIdGetPathParams,
 ), ValidationErrors>
 {
-  path_params.validate()?;
 
 Ok((
-  path_params,
 ))
 }
-/// TasksIdGet - GET /tasks/{id}
+/// TasksTrashGet - GET /tasks/trash
 #[tracing::instrument(skip_all)]
-async fn tasks_id_get<I, A, E>(
+async fn tasks_trash_get<I, A, E>(
   method: Method,
   host: Host,
   cookies: CookieJar,
-  Path(path_params): Path<models::TasksIdGetPathParams>,
  State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
@@ -424,13 +10189,11 @@ where
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    tasks_id_get_validation(
-        path_params,
+    tasks_trash_get_validation(
     )
   ).await.unwrap();
 
   let Ok((
-    path_params,
   )) = validation else {
     return Response::builder()
             .status(StatusCode::BAD_REQUEST)
@@ -438,18 +10201,17 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().tasks_id_get(
+  let result = api_impl.as_ref().tasks_trash_get(
       &method,
       &host,
       &cookies,
-        &path_params,
   ).await;
 
   let mut response = Response::builder();
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::tasks::TasksIdGetResponse::Status200_TaskFound
+                                                apis::tasks::TasksTrashGetResponse::Status200_ListOfDeletedTasks
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -467,15 +10229,129 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdGetResponse::Status404_TaskNotFound
+                                                apis::tasks::TasksTrashGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
                                                 => {
-                                                  let mut response = response.status(404);
-                                                  response.body(Body::empty())
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdGetResponse::Status500_InternalServerError
+                                                apis::tasks::TasksTrashGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::tasks::TasksTrashGetResponse::Status500_InternalServerError
+                                                    (body)
                                                 => {
                                                   let mut response = response.status(500);
-                                                  response.body(Body::empty())
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
                                             },
                                             Err(why) => {
@@ -488,41 +10364,23 @@ where
                                         resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
 }
 
-    #[derive(validator::Validate)]
-    #[allow(dead_code)]
-    struct TasksIdPutBodyValidator<'a> {
-            #[validate(nested)]
-          body: &'a models::UpdateTask,
-    }
-
 
 #[tracing::instrument(skip_all)]
-fn tasks_id_put_validation(
-  path_params: models::TasksIdPutPathParams,
-        body: models::UpdateTask,
+fn tasks_tree_get_validation(
 ) -> std::result::Result<(
-  models::TasksIdPutPathParams,
-        models::UpdateTask,
 ), ValidationErrors>
 {
-  path_params.validate()?;
-              let b = TasksIdPutBodyValidator { body: &body };
-              b.validate()?;
 
 Ok((
-  path_params,
-    body,
 ))
 }
-/// TasksIdPut - PUT /tasks/{id}
+/// TasksTreeGet - GET /tasks/tree
 #[tracing::instrument(skip_all)]
-async fn tasks_id_put<I, A, E>(
+async fn tasks_tree_get<I, A, E>(
   method: Method,
   host: Host,
   cookies: CookieJar,
-  Path(path_params): Path<models::TasksIdPutPathParams>,
  State(api_impl): State<I>,
-          Json(body): Json<models::UpdateTask>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
@@ -533,15 +10391,11 @@ where
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    tasks_id_put_validation(
-        path_params,
-          body,
+    tasks_tree_get_validation(
     )
   ).await.unwrap();
 
   let Ok((
-    path_params,
-      body,
   )) = validation else {
     return Response::builder()
             .status(StatusCode::BAD_REQUEST)
@@ -549,19 +10403,17 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().tasks_id_put(
+  let result = api_impl.as_ref().tasks_tree_get(
       &method,
       &host,
       &cookies,
-        &path_params,
-              &body,
   ).await;
 
   let mut response = Response::builder();
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::tasks::TasksIdPutResponse::Status200_TaskUpdatedSuccessfully
+                                                apis::tasks::TasksTreeGetResponse::Status200_TaskTree
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -579,20 +10431,129 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdPutResponse::Status400_ValidationErrorOrInvalidOperation
+                                                apis::tasks::TasksTreeGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
                                                 => {
-                                                  let mut response = response.status(400);
-                                                  response.body(Body::empty())
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdPutResponse::Status404_TaskNotFound
+                                                apis::tasks::TasksTreeGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
                                                 => {
-                                                  let mut response = response.status(404);
-                                                  response.body(Body::empty())
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdPutResponse::Status500_InternalServerError
+                                                apis::tasks::TasksTreeGetResponse::Status500_InternalServerError
+                                                    (body)
                                                 => {
                                                   let mut response = response.status(500);
-                                                  response.body(Body::empty())
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
                                             },
                                             Err(why) => {
@@ -607,43 +10568,36 @@ where
 
 
 #[tracing::instrument(skip_all)]
-fn tasks_id_uncomplete_put_validation(
-  path_params: models::TasksIdUncompletePutPathParams,
+fn users_get_validation(
 ) -> std::result::Result<(
-  models::TasksIdUncompletePutPathParams,
 ), ValidationErrors>
 {
-  path_params.validate()?;
 
 Ok((
-  path_params,
 ))
 }
-/// TasksIdUncompletePut - PUT /tasks/{id}/uncomplete
+/// UsersGet - GET /users
 #[tracing::instrument(skip_all)]
-async fn tasks_id_uncomplete_put<I, A, E>(
+async fn users_get<I, A, E>(
   method: Method,
   host: Host,
   cookies: CookieJar,
-  Path(path_params): Path<models::TasksIdUncompletePutPathParams>,
  State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::tasks::Tasks<E> + Send + Sync,
+    A: apis::users::Users<E> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
         {
 
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    tasks_id_uncomplete_put_validation(
-        path_params,
+    users_get_validation(
     )
   ).await.unwrap();
 
   let Ok((
-    path_params,
   )) = validation else {
     return Response::builder()
             .status(StatusCode::BAD_REQUEST)
@@ -651,26 +10605,43 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().tasks_id_uncomplete_put(
+  let result = api_impl.as_ref().users_get(
       &method,
       &host,
       &cookies,
-        &path_params,
   ).await;
 
   let mut response = Response::builder();
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::tasks::TasksIdUncompletePutResponse::Status200_TaskMarkedAsUncompleted
+                                                apis::users::UsersGetResponse::Status200_ListOfUsers
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::users::UsersGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
                                                     (body)
                                                 => {
-                                                  let mut response = response.status(200);
+                                                  let mut response = response.status(401);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
                                                   }
 
                                                   let body_content =  tokio::task::spawn_blocking(move ||
@@ -680,15 +10651,111 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdUncompletePutResponse::Status404_TaskNotFound
+                                                apis::users::UsersGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
                                                 => {
-                                                  let mut response = response.status(404);
-                                                  response.body(Body::empty())
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksIdUncompletePutResponse::Status500_InternalServerError
+                                                apis::users::UsersGetResponse::Status500_InternalServerError
+                                                    (body)
                                                 => {
                                                   let mut response = response.status(500);
-                                                  response.body(Body::empty())
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
                                             },
                                             Err(why) => {
@@ -703,7 +10770,7 @@ where
 
 
 #[tracing::instrument(skip_all)]
-fn tasks_pending_get_validation(
+fn users_me_get_validation(
 ) -> std::result::Result<(
 ), ValidationErrors>
 {
@@ -711,9 +10778,9 @@ fn tasks_pending_get_validation(
 Ok((
 ))
 }
-/// TasksPendingGet - GET /tasks/pending
+/// UsersMeGet - GET /users/me
 #[tracing::instrument(skip_all)]
-async fn tasks_pending_get<I, A, E>(
+async fn users_me_get<I, A, E>(
   method: Method,
   host: Host,
   cookies: CookieJar,
@@ -721,14 +10788,14 @@ async fn tasks_pending_get<I, A, E>(
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::tasks::Tasks<E> + Send + Sync,
+    A: apis::users::Users<E> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
         {
 
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    tasks_pending_get_validation(
+    users_me_get_validation(
     )
   ).await.unwrap();
 
@@ -740,7 +10807,7 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().tasks_pending_get(
+  let result = api_impl.as_ref().users_me_get(
       &method,
       &host,
       &cookies,
@@ -750,7 +10817,7 @@ where
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::tasks::TasksPendingGetResponse::Status200_ListOfPendingTasks
+                                                apis::users::UsersMeGetResponse::Status200_CurrentUser
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -768,10 +10835,129 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksPendingGetResponse::Status500_InternalServerError
+                                                apis::users::UsersMeGetResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::users::UsersMeGetResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
+
+
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::users::UsersMeGetResponse::Status500_InternalServerError
+                                                    (body)
                                                 => {
                                                   let mut response = response.status(500);
-                                                  response.body(Body::empty())
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
                                             },
                                             Err(why) => {
@@ -786,45 +10972,45 @@ where
 
     #[derive(validator::Validate)]
     #[allow(dead_code)]
-    struct TasksPostBodyValidator<'a> {
+    struct UsersPostBodyValidator<'a> {
             #[validate(nested)]
-          body: &'a models::CreateTask,
+          body: &'a models::CreateUser,
     }
 
 
 #[tracing::instrument(skip_all)]
-fn tasks_post_validation(
-        body: models::CreateTask,
+fn users_post_validation(
+        body: models::CreateUser,
 ) -> std::result::Result<(
-        models::CreateTask,
+        models::CreateUser,
 ), ValidationErrors>
 {
-              let b = TasksPostBodyValidator { body: &body };
+              let b = UsersPostBodyValidator { body: &body };
               b.validate()?;
 
 Ok((
     body,
 ))
 }
-/// TasksPost - POST /tasks
+/// UsersPost - POST /users
 #[tracing::instrument(skip_all)]
-async fn tasks_post<I, A, E>(
+async fn users_post<I, A, E>(
   method: Method,
   host: Host,
   cookies: CookieJar,
  State(api_impl): State<I>,
-          Json(body): Json<models::CreateTask>,
+          Json(body): Json<models::CreateUser>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::tasks::Tasks<E> + Send + Sync,
+    A: apis::users::Users<E> + Send + Sync,
     E: std::fmt::Debug + Send + Sync + 'static,
         {
 
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    tasks_post_validation(
+    users_post_validation(
           body,
     )
   ).await.unwrap();
@@ -838,7 +11024,7 @@ where
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().tasks_post(
+  let result = api_impl.as_ref().users_post(
       &method,
       &host,
       &cookies,
@@ -849,7 +11035,7 @@ where
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::tasks::TasksPostResponse::Status201_TaskCreatedSuccessfully
+                                                apis::users::UsersPostResponse::Status201_UserCreated
                                                     (body)
                                                 => {
                                                   let mut response = response.status(201);
@@ -867,93 +11053,157 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksPostResponse::Status400_ValidationError
+                                                apis::users::UsersPostResponse::Status400_ValidationError
+                                                    (body)
                                                 => {
                                                   let mut response = response.status(400);
-                                                  response.body(Body::empty())
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksPostResponse::Status500_InternalServerError
+                                                apis::users::UsersPostResponse::Status401_MissingOrInvalidCredentialsOrUnknownUser
+                                                    (body)
                                                 => {
-                                                  let mut response = response.status(500);
-                                                  response.body(Body::empty())
+                                                  let mut response = response.status(401);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
-                                            },
-                                            Err(why) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                return api_impl.as_ref().handle_error(&method, &host, &cookies, why).await;
-                                            },
-                                        };
+                                                apis::users::UsersPostResponse::Status403_TheCredentialsDoNotGrantTheRequiredScope
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
 
-                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
-}
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::users::UsersPostResponse::Status409_AUserWithThisNameAlreadyExists
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
 
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::users::UsersPostResponse::Status429_TooManyRequests
+                                                    {
+                                                        body,
+                                                        retry_after,
+                                                        rate_limit_limit,
+                                                        rate_limit_remaining,
+                                                        rate_limit_reset,
+                                                    }
+                                                => {
+                                                      let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
 
-#[tracing::instrument(skip_all)]
-fn tasks_search_get_validation(
-  query_params: models::TasksSearchGetQueryParams,
-) -> std::result::Result<(
-  models::TasksSearchGetQueryParams,
-), ValidationErrors>
-{
-  query_params.validate()?;
 
-Ok((
-  query_params,
-))
-}
-/// TasksSearchGet - GET /tasks/search
-#[tracing::instrument(skip_all)]
-async fn tasks_search_get<I, A, E>(
-  method: Method,
-  host: Host,
-  cookies: CookieJar,
-  QueryExtra(query_params): QueryExtra<models::TasksSearchGetQueryParams>,
- State(api_impl): State<I>,
-) -> Result<Response, StatusCode>
-where
-    I: AsRef<A> + Send + Sync,
-    A: apis::tasks::Tasks<E> + Send + Sync,
-    E: std::fmt::Debug + Send + Sync + 'static,
-        {
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("retry-after"),
+                                                            retry_after);
+                                                      }
+                                                      let rate_limit_limit = match header::IntoHeaderValue(rate_limit_limit).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_limit header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
 
 
-      #[allow(clippy::redundant_closure)]
-      let validation = tokio::task::spawn_blocking(move ||
-    tasks_search_get_validation(
-        query_params,
-    )
-  ).await.unwrap();
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-limit"),
+                                                            rate_limit_limit);
+                                                      }
+                                                      let rate_limit_remaining = match header::IntoHeaderValue(rate_limit_remaining).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_remaining header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
 
-  let Ok((
-    query_params,
-  )) = validation else {
-    return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
-            .map_err(|_| StatusCode::BAD_REQUEST);
-  };
 
-  let result = api_impl.as_ref().tasks_search_get(
-      &method,
-      &host,
-      &cookies,
-        &query_params,
-  ).await;
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-remaining"),
+                                                            rate_limit_remaining);
+                                                      }
+                                                      let rate_limit_reset = match header::IntoHeaderValue(rate_limit_reset).try_into() {
+                                                          Ok(val) => val,
+                                                          Err(e) => {
+                                                              return Response::builder()
+                                                                                      .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                                      .body(Body::from(format!("An internal server error occurred handling rate_limit_reset header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                          }
+                                                      };
 
-  let mut response = Response::builder();
 
-  let resp = match result {
-                                            Ok(rsp) => match rsp {
-                                                apis::tasks::TasksSearchGetResponse::Status200_SearchResults
-                                                    (body)
-                                                => {
-                                                  let mut response = response.status(200);
+                                                      {
+                                                        let mut response_headers = response.headers_mut().unwrap();
+                                                        response_headers.insert(
+                                                            HeaderName::from_static("ratelimit-reset"),
+                                                            rate_limit_reset);
+                                                      }
+                                                  let mut response = response.status(429);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
                                                         CONTENT_TYPE,
-                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
                                                   }
 
                                                   let body_content =  tokio::task::spawn_blocking(move ||
@@ -963,10 +11213,23 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::tasks::TasksSearchGetResponse::Status500_InternalServerError
+                                                apis::users::UsersPostResponse::Status500_InternalServerError
+                                                    (body)
                                                 => {
                                                   let mut response = response.status(500);
-                                                  response.body(Body::empty())
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/problem+json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
                                             },
                                             Err(why) => {
@@ -1,5 +1,15 @@
 pub mod model;
 pub mod repository;
 
+pub use model::api_key::*;
+pub use model::event::*;
+pub use model::history::*;
+pub use model::project::*;
 pub use model::task::*;
-pub use repository::task::*; 
\ No newline at end of file
+pub use model::user::*;
+pub use repository::api_key::*;
+pub use repository::event::*;
+pub use repository::history::*;
+pub use repository::project::*;
+pub use repository::task::*;
+pub use repository::user::*; 
\ No newline at end of file
@@ -0,0 +1,128 @@
+use std::fmt;
+use std::str::FromStr;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// 発行するAPIキーの先頭に付ける目印（ログや設定に紛れ込んだときに見分けるため）
+pub const API_KEY_PREFIX: &str = "todo_";
+/// 一覧で鍵を見分けるために残す先頭の文字数
+pub const API_KEY_DISPLAY_LENGTH: usize = 12;
+/// APIキーの名前の最大文字数
+pub const MAX_API_KEY_NAME_LENGTH: usize = 50;
+
+/// APIキーで許す操作の範囲（上位の範囲は下位の範囲を含む）
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiKeyScope {
+    /// 参照だけ
+    Read,
+    /// 参照と変更
+    Write,
+    /// 参照と変更に加えて、APIキーの管理
+    Admin,
+}
+
+impl ApiKeyScope {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ApiKeyScope::Read => "read",
+            ApiKeyScope::Write => "write",
+            ApiKeyScope::Admin => "admin",
+        }
+    }
+
+    /// `required` の範囲の操作を許すか
+    pub fn allows(self, required: ApiKeyScope) -> bool {
+        self >= required
+    }
+}
+
+impl fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ApiKeyScope {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "read" => Ok(ApiKeyScope::Read),
+            "write" => Ok(ApiKeyScope::Write),
+            "admin" => Ok(ApiKeyScope::Admin),
+            _ => Err("expected one of: read, write, admin".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ApiKeyValidationError {
+    #[error("API key name cannot be empty")]
+    EmptyName,
+    #[error("API key name cannot exceed {0} characters")]
+    NameTooLong(usize),
+    #[error("User with id {0} does not exist")]
+    UserNotFound(u64),
+}
+
+/// 鍵そのものは保存せず、照合に使うハッシュ値だけを持つ
+pub fn hash_secret(secret: &str) -> String {
+    Sha256::digest(secret.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// 利用者の代わりにAPIを呼ぶための鍵
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct ApiKey {
+    pub id: u64,
+    pub name: String,
+    pub scope: ApiKeyScope,
+    /// この鍵で呼び出したリクエストを処理する利用者
+    pub user_id: u64,
+    /// 鍵の先頭部分（一覧で鍵を見分けるためのもの）
+    pub prefix: String,
+    #[serde(skip)]
+    pub secret_hash: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    /// 失効していないか
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none()
+    }
+
+    /// 失効させる（失効済みなら何もしない）
+    pub fn revoke(&mut self) {
+        if self.revoked_at.is_none() {
+            self.revoked_at = Some(Utc::now());
+        }
+    }
+}
+
+/// APIキーの発行内容（`user_id` を省略するとリクエストの利用者の鍵になる）
+#[derive(Deserialize, Debug, ToSchema)]
+pub struct CreateApiKey {
+    pub name: String,
+    pub scope: ApiKeyScope,
+    pub user_id: Option<u64>,
+}
+
+impl CreateApiKey {
+    pub fn validate(&self) -> Result<(), ApiKeyValidationError> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(ApiKeyValidationError::EmptyName);
+        }
+        if name.chars().count() > MAX_API_KEY_NAME_LENGTH {
+            return Err(ApiKeyValidationError::NameTooLong(MAX_API_KEY_NAME_LENGTH));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::domain::model::task::Task;
+
+/// タスクに起きた変更の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskChangeKind {
+    Created,
+    Updated,
+    Completed,
+    Uncompleted,
+    Deleted,
+    /// ゴミ箱から元に戻された
+    Restored,
+}
+
+impl TaskChangeKind {
+    /// イベント名として使う文字列
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaskChangeKind::Created => "created",
+            TaskChangeKind::Updated => "updated",
+            TaskChangeKind::Completed => "completed",
+            TaskChangeKind::Uncompleted => "uncompleted",
+            TaskChangeKind::Deleted => "deleted",
+            TaskChangeKind::Restored => "restored",
+        }
+    }
+}
+
+/// タスクの変更（削除の場合はゴミ箱に移す直前のタスク）
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskChange {
+    pub kind: TaskChangeKind,
+    pub task: Task,
+}
+
+impl TaskChange {
+    pub fn new(kind: TaskChangeKind, task: Task) -> Self {
+        Self { kind, task }
+    }
+
+    /// 更新前後のタスクから変更の種類を決める（版数が進んでいなければ None）
+    ///
+    /// 完了状態だけが変わった場合は `Completed` / `Uncompleted`、それ以外は `Updated` とする。
+    pub fn between(previous: &Task, current: &Task) -> Option<Self> {
+        if previous.version == current.version {
+            return None;
+        }
+        let only_completion_changed = previous.completed != current.completed
+            && previous.description == current.description
+            && previous.priority == current.priority
+            && previous.due_at == current.due_at
+            && previous.tags == current.tags
+            && previous.project_id == current.project_id
+            && previous.parent_id == current.parent_id
+            && previous.depends_on == current.depends_on
+            && previous.recurrence == current.recurrence;
+        let kind = match (only_completion_changed, current.completed) {
+            (true, true) => TaskChangeKind::Completed,
+            (true, false) => TaskChangeKind::Uncompleted,
+            (false, _) => TaskChangeKind::Updated,
+        };
+        Some(Self::new(kind, current.clone()))
+    }
+}
@@ -0,0 +1,141 @@
+use std::str::FromStr;
+use chrono::{DateTime, Utc};
+use crate::domain::model::event::TaskChangeKind;
+use crate::domain::model::task::Task;
+
+/// 履歴に記録する操作の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskHistoryOperation {
+    Created,
+    Updated,
+    Completed,
+    Uncompleted,
+    Deleted,
+    Restored,
+    /// ゴミ箱から完全に削除された
+    Purged,
+}
+
+impl TaskHistoryOperation {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TaskHistoryOperation::Created => "created",
+            TaskHistoryOperation::Updated => "updated",
+            TaskHistoryOperation::Completed => "completed",
+            TaskHistoryOperation::Uncompleted => "uncompleted",
+            TaskHistoryOperation::Deleted => "deleted",
+            TaskHistoryOperation::Restored => "restored",
+            TaskHistoryOperation::Purged => "purged",
+        }
+    }
+}
+
+impl FromStr for TaskHistoryOperation {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "created" => Ok(TaskHistoryOperation::Created),
+            "updated" => Ok(TaskHistoryOperation::Updated),
+            "completed" => Ok(TaskHistoryOperation::Completed),
+            "uncompleted" => Ok(TaskHistoryOperation::Uncompleted),
+            "deleted" => Ok(TaskHistoryOperation::Deleted),
+            "restored" => Ok(TaskHistoryOperation::Restored),
+            "purged" => Ok(TaskHistoryOperation::Purged),
+            _ => Err(format!("unknown history operation: {}", value)),
+        }
+    }
+}
+
+impl From<TaskChangeKind> for TaskHistoryOperation {
+    fn from(kind: TaskChangeKind) -> Self {
+        match kind {
+            TaskChangeKind::Created => TaskHistoryOperation::Created,
+            TaskChangeKind::Updated => TaskHistoryOperation::Updated,
+            TaskChangeKind::Completed => TaskHistoryOperation::Completed,
+            TaskChangeKind::Uncompleted => TaskHistoryOperation::Uncompleted,
+            TaskChangeKind::Deleted => TaskHistoryOperation::Deleted,
+            TaskChangeKind::Restored => TaskHistoryOperation::Restored,
+        }
+    }
+}
+
+/// 1つの項目の変更前後の値（値がない場合は None）
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+}
+
+/// 差分に含めない項目（変更のたびに必ず変わるか、変わることがない）
+const UNTRACKED_FIELDS: &[&str] = &["id", "version", "created_at", "updated_at"];
+
+impl FieldChange {
+    /// 変更前後のタスクで値が異なる項目を、項目名の順に並べる
+    ///
+    /// 作成では `before`、完全な削除では `after` を None にすると、すべての項目が差分になる。
+    pub fn diff(before: Option<&Task>, after: Option<&Task>) -> Vec<FieldChange> {
+        let before = fields(before);
+        let after = fields(after);
+        let mut names: Vec<&String> = before.keys().chain(after.keys()).collect();
+        names.sort();
+        names.dedup();
+        names
+            .into_iter()
+            .filter(|name| !UNTRACKED_FIELDS.contains(&name.as_str()))
+            .filter_map(|name| {
+                let old = before.get(name).filter(|value| !value.is_null());
+                let new = after.get(name).filter(|value| !value.is_null());
+                (old != new).then(|| FieldChange {
+                    field: name.clone(),
+                    before: old.cloned(),
+                    after: new.cloned(),
+                })
+            })
+            .collect()
+    }
+}
+
+fn fields(task: Option<&Task>) -> serde_json::Map<String, serde_json::Value> {
+    match task.map(serde_json::to_value) {
+        Some(Ok(serde_json::Value::Object(map))) => map,
+        _ => serde_json::Map::new(),
+    }
+}
+
+/// これから記録する履歴（連番は記録するときに振られる）
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewTaskHistoryEntry {
+    pub task_id: u64,
+    pub operation: TaskHistoryOperation,
+    pub changes: Vec<FieldChange>,
+    /// 操作した利用者（分からない場合は None）
+    pub actor: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// 記録済みの履歴（一度記録したら変更しない）
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskHistoryEntry {
+    /// 履歴全体で一意な、記録した順に増える連番
+    pub id: u64,
+    pub task_id: u64,
+    pub operation: TaskHistoryOperation,
+    pub changes: Vec<FieldChange>,
+    pub actor: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl TaskHistoryEntry {
+    pub fn new(id: u64, entry: NewTaskHistoryEntry) -> Self {
+        Self {
+            id,
+            task_id: entry.task_id,
+            operation: entry.operation,
+            changes: entry.changes,
+            actor: entry.actor,
+            recorded_at: entry.recorded_at,
+        }
+    }
+}
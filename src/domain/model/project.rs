@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+use super::user::DEFAULT_USER_ID;
+
+/// 受信箱（所属先を指定しないタスクが入る、削除できないプロジェクト）のID
+pub const INBOX_PROJECT_ID: u64 = 1;
+/// 受信箱の最初の名前
+pub const INBOX_PROJECT_NAME: &str = "Inbox";
+/// プロジェクト名の最大文字数
+pub const MAX_PROJECT_NAME_LENGTH: usize = 100;
+
+#[derive(Debug, Error)]
+pub enum ProjectValidationError {
+    #[error("Project name cannot be empty")]
+    EmptyName,
+    #[error("Project name cannot exceed {0} characters")]
+    NameTooLong(usize),
+    #[error("A project named '{0}' already exists")]
+    DuplicateName(String),
+}
+
+/// 前後の空白を除いたプロジェクト名を検証する
+fn validate_name(name: &str) -> Result<String, ProjectValidationError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ProjectValidationError::EmptyName);
+    }
+    if name.chars().count() > MAX_PROJECT_NAME_LENGTH {
+        return Err(ProjectValidationError::NameTooLong(MAX_PROJECT_NAME_LENGTH));
+    }
+    Ok(name.to_string())
+}
+
+/// タスクをまとめるプロジェクト（リスト）
+///
+/// 受信箱は全員で共有し、それ以外のプロジェクトは持ち主だけが見られる。
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct Project {
+    pub id: u64,
+    pub name: String,
+    /// プロジェクトを持つ利用者のID（受信箱は既定の利用者）
+    pub owner_id: u64,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+    #[schema(value_type = String, format = DateTime)]
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Project {
+    pub fn new(id: u64, name: &str, owner_id: u64) -> Result<Self, ProjectValidationError> {
+        let now = Utc::now();
+        Ok(Self { id, name: validate_name(name)?, owner_id, created_at: now, updated_at: now })
+    }
+
+    /// 受信箱
+    pub fn inbox() -> Self {
+        let now = Utc::now();
+        Self {
+            id: INBOX_PROJECT_ID,
+            name: INBOX_PROJECT_NAME.to_string(),
+            owner_id: DEFAULT_USER_ID,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_inbox(&self) -> bool {
+        self.id == INBOX_PROJECT_ID
+    }
+
+    /// 利用者がこのプロジェクトを見られ、タスクを入れられるか（受信箱は誰でも使える）
+    pub fn is_visible_to(&self, user_id: u64) -> bool {
+        self.is_inbox() || self.owner_id == user_id
+    }
+
+    pub fn rename(&mut self, name: &str) -> Result<(), ProjectValidationError> {
+        let name = validate_name(name)?;
+        if self.name != name {
+            self.name = name;
+            self.updated_at = Utc::now();
+        }
+        Ok(())
+    }
+
+    /// 大文字・小文字を区別せずに同じ名前か
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name.to_lowercase() == name.trim().to_lowercase()
+    }
+}
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateProject {
+    pub name: String,
+    /// プロジェクトを持つ利用者のID（None なら既定の利用者）
+    pub owner_id: Option<u64>,
+}
+
+impl CreateProject {
+    pub fn validate(&self) -> Result<(), ProjectValidationError> {
+        validate_name(&self.name).map(|_| ())
+    }
+}
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct UpdateProject {
+    pub name: Option<String>,
+}
+
+impl UpdateProject {
+    pub fn validate(&self) -> Result<(), ProjectValidationError> {
+        match &self.name {
+            Some(name) => validate_name(name).map(|_| ()),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use std::fmt;
+use std::str::FromStr;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use super::task::TaskValidationError;
+
+/// 間隔に指定できる最大値
+pub const MAX_RECURRENCE_INTERVAL: u32 = 999;
+
+/// 繰り返しの単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl Frequency {
+    fn as_rrule(self) -> &'static str {
+        match self {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+        }
+    }
+}
+
+/// タスクの繰り返しの規則（RFC 5545 の RRULE のうち FREQ・INTERVAL・BYDAY・BYMONTHDAY・UNTIL に相当する）
+///
+/// 永続化や履歴では RRULE の文字列として扱う。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    /// 何日・何週・何か月ごとに繰り返すか
+    pub interval: u32,
+    /// 毎週の曜日（月曜始まりの順。空なら期限と同じ曜日）
+    pub weekdays: Vec<Weekday>,
+    /// 毎月の日（None なら起点と同じ日。その月にない日は月末に繰り上げる）
+    pub month_day: Option<u32>,
+    /// この日時より後には繰り返さない
+    pub until: Option<DateTime<Utc>>,
+}
+
+fn invalid(reason: impl Into<String>) -> TaskValidationError {
+    TaskValidationError::InvalidRecurrence(reason.into())
+}
+
+impl Recurrence {
+    /// 組み合わせを確かめて規則を作る（曜日は重複を除いて月曜始まりの順に並べる）
+    pub fn new(
+        frequency: Frequency,
+        interval: u32,
+        mut weekdays: Vec<Weekday>,
+        month_day: Option<u32>,
+        until: Option<DateTime<Utc>>,
+    ) -> Result<Self, TaskValidationError> {
+        if !(1..=MAX_RECURRENCE_INTERVAL).contains(&interval) {
+            return Err(invalid(format!("interval must be between 1 and {}", MAX_RECURRENCE_INTERVAL)));
+        }
+        if !weekdays.is_empty() && frequency != Frequency::Weekly {
+            return Err(invalid("weekdays can only be given for weekly recurrence"));
+        }
+        if let Some(day) = month_day {
+            if frequency != Frequency::Monthly {
+                return Err(invalid("a day of the month can only be given for monthly recurrence"));
+            }
+            if !(1..=31).contains(&day) {
+                return Err(invalid("the day of the month must be between 1 and 31"));
+            }
+        }
+        weekdays.sort_by_key(|weekday| weekday.num_days_from_monday());
+        weekdays.dedup();
+        Ok(Self { frequency, interval, weekdays, month_day, until })
+    }
+
+    /// 起点が `start` の繰り返しのうち、`after` より後の最初の回（`until` を過ぎる場合は None）
+    ///
+    /// 週や月の途中では同じ週・月の残りの曜日や日を先に使い、使い切ったら `interval` 分だけ先の週・月に進む。
+    /// 毎月の日を指定していなければ `start` の日を使うため、31日に始めた繰り返しは2月末の後も31日に戻る。
+    /// 時刻は `after` のものを引き継ぐ。
+    pub fn next_after(&self, start: DateTime<Utc>, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let next = match self.frequency {
+            Frequency::Daily => after + Duration::days(self.interval.into()),
+            Frequency::Weekly => {
+                let today = after.weekday().num_days_from_monday();
+                let days = match (self.weekdays.iter().map(Weekday::num_days_from_monday).find(|&day| day > today), self.weekdays.first()) {
+                    (Some(day), _) => day - today,
+                    (None, Some(first)) => 7 * self.interval - today + first.num_days_from_monday(),
+                    (None, None) => 7 * self.interval,
+                };
+                after + Duration::days(days.into())
+            }
+            Frequency::Monthly => {
+                let day = self.month_day.unwrap_or(start.day());
+                let this_month = clamp_to_month(after.year(), after.month(), day);
+                let date = match this_month {
+                    Some(date) if date.day() > after.day() => date,
+                    _ => {
+                        let months = after.month0() + self.interval;
+                        clamp_to_month(after.year() + (months / 12) as i32, months % 12 + 1, day)?
+                    }
+                };
+                Utc.from_utc_datetime(&date.and_time(after.time()))
+            }
+        };
+        match self.until {
+            Some(until) if next > until => None,
+            _ => Some(next),
+        }
+    }
+
+    /// 起点 `start` より後の繰り返しを最大 `count` 件
+    pub fn occurrences(&self, start: DateTime<Utc>, count: usize) -> Vec<DateTime<Utc>> {
+        std::iter::successors(self.next_after(start, start), |&previous| self.next_after(start, previous))
+            .take(count)
+            .collect()
+    }
+
+    /// 起点の日を毎月の日として書き込んだ規則（起点の分からない次の回にも同じ日を引き継ぐため）
+    pub fn anchored_at(&self, start: DateTime<Utc>) -> Self {
+        let month_day = match self.frequency {
+            Frequency::Monthly => self.month_day.or(Some(start.day())),
+            _ => self.month_day,
+        };
+        Self { month_day, ..self.clone() }
+    }
+}
+
+/// 指定した月の `day` 日（その月にない日は月末）
+fn clamp_to_month(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+    (1..=day).rev().find_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri, Weekday::Sat, Weekday::Sun]
+        .into_iter()
+        .find(|&weekday| weekday_code(weekday) == code)
+}
+
+/// UNTIL の値（`YYYYMMDD` または `YYYYMMDDTHHMMSSZ`。日付だけの場合はその日の終わりまで）
+fn parse_until(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+        let end_of_day = NaiveTime::from_hms_opt(23, 59, 59)?;
+        return Some(Utc.from_utc_datetime(&date.and_time(end_of_day)));
+    }
+    chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+        .ok()
+        .map(|datetime| Utc.from_utc_datetime(&datetime))
+}
+
+/// RRULE の文字列として書き出す（INTERVAL=1 は省く）
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.frequency.as_rrule())?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.weekdays.is_empty() {
+            let codes: Vec<&str> = self.weekdays.iter().copied().map(weekday_code).collect();
+            write!(f, ";BYDAY={}", codes.join(","))?;
+        }
+        if let Some(day) = self.month_day {
+            write!(f, ";BYMONTHDAY={}", day)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+        Ok(())
+    }
+}
+
+/// RRULE の文字列を読む（先頭の `RRULE:` は省略できる。対応していない項目はエラー）
+impl FromStr for Recurrence {
+    type Err = TaskValidationError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let rule = rule.trim();
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+        let mut frequency = None;
+        let mut interval = 1;
+        let mut weekdays = Vec::new();
+        let mut month_day = None;
+        let mut until = None;
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("'{}' is not a NAME=VALUE pair", part)))?;
+            match name.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        other => return Err(invalid(format!("unsupported FREQ '{}'", other))),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = value.parse().map_err(|_| invalid(format!("invalid INTERVAL '{}'", value)))?;
+                }
+                "BYDAY" => {
+                    weekdays = value
+                        .split(',')
+                        .map(|code| parse_weekday(&code.to_ascii_uppercase()).ok_or_else(|| invalid(format!("unsupported BYDAY '{}'", code))))
+                        .collect::<Result<_, _>>()?;
+                }
+                "BYMONTHDAY" => {
+                    month_day = Some(value.parse().map_err(|_| invalid(format!("unsupported BYMONTHDAY '{}'", value)))?);
+                }
+                "UNTIL" => {
+                    until = Some(parse_until(value).ok_or_else(|| invalid(format!("invalid UNTIL '{}'", value)))?);
+                }
+                other => return Err(invalid(format!("unsupported rule part '{}'", other))),
+            }
+        }
+        let frequency = frequency.ok_or_else(|| invalid("FREQ is required"))?;
+        Self::new(frequency, interval, weekdays, month_day, until)
+    }
+}
+
+impl Serialize for Recurrence {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Recurrence {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
@@ -1,13 +1,96 @@
+use std::collections::{BTreeSet, HashMap};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use super::project::INBOX_PROJECT_ID;
+use super::user::DEFAULT_USER_ID;
+use super::recurrence::Recurrence;
 use thiserror::Error;
 use utoipa::ToSchema;
 
+/// 説明文の最大文字数
+pub const MAX_DESCRIPTION_LENGTH: usize = 1000;
+/// タグ1つあたりの最大文字数
+pub const MAX_TAG_LENGTH: usize = 32;
+/// 1つのタスクに付けられるタグの最大数
+pub const MAX_TAGS_PER_TASK: usize = 20;
+
 #[derive(Debug, Error)]
 pub enum TaskValidationError {
     #[error("Description cannot be empty")]
     EmptyDescription,
     #[error("Description cannot exceed {0} characters")]
     DescriptionTooLong(usize),
+    #[error("Due date cannot be earlier than the task creation time")]
+    DueBeforeCreation,
+    #[error("Invalid tag '{0}': tags must be 1-32 characters of a-z, 0-9, '-', '_' or '.'")]
+    InvalidTag(String),
+    #[error("A task cannot have more than {0} tags")]
+    TooManyTags(usize),
+    #[error("Parent task {0} does not exist")]
+    ParentNotFound(u64),
+    #[error("Task {0} cannot be its own ancestor")]
+    ParentCycle(u64),
+    #[error("Dependency task {0} does not exist")]
+    DependencyNotFound(u64),
+    #[error("Depending on task {0} would create a dependency cycle")]
+    DependencyCycle(u64),
+    #[error("Invalid recurrence: {0}")]
+    InvalidRecurrence(String),
+    #[error("Project {0} does not exist")]
+    ProjectNotFound(u64),
+}
+
+/// 完了した子タスクの割合（0〜100、子タスクがなければ None）
+pub fn completion_percentage(completed: usize, total: usize) -> Option<u8> {
+    (total > 0).then(|| (completed * 100 / total) as u8)
+}
+
+/// タグを正規化する（前後の空白を除いて小文字にし、使用できる文字か検証する）
+pub fn normalize_tag(tag: &str) -> Result<String, TaskValidationError> {
+    let normalized = tag.trim().to_lowercase();
+    let valid_chars = normalized
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.'));
+    if normalized.is_empty() || normalized.len() > MAX_TAG_LENGTH || !valid_chars {
+        return Err(TaskValidationError::InvalidTag(tag.to_string()));
+    }
+    Ok(normalized)
+}
+
+/// タスクの優先度
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskPriority {
+    Low,
+    Normal,
+    High,
+    Urgent,
+}
+
+impl TaskPriority {
+    /// 保存や表示に使う文字列表現
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskPriority::Low => "low",
+            TaskPriority::Normal => "normal",
+            TaskPriority::High => "high",
+            TaskPriority::Urgent => "urgent",
+        }
+    }
+}
+
+impl std::str::FromStr for TaskPriority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "low" => Ok(TaskPriority::Low),
+            "normal" => Ok(TaskPriority::Normal),
+            "high" => Ok(TaskPriority::High),
+            "urgent" => Ok(TaskPriority::Urgent),
+            _ => Err(format!("Unknown priority: {}", s)),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
@@ -15,10 +98,31 @@ pub struct Task {
     pub id: u64,
     pub description: String,
     pub completed: bool,
+    pub priority: Option<TaskPriority>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub due_at: Option<DateTime<Utc>>,
+    /// 正規化済みのタグ
+    pub tags: BTreeSet<String>,
+    /// タスクを持つ利用者のID（作成後は変わらない）
+    pub owner_id: u64,
+    /// 所属するプロジェクトのID
+    pub project_id: u64,
+    /// 親タスクのID（最上位のタスクはNone）
+    pub parent_id: Option<u64>,
+    /// 先に完了している必要があるタスクのID
+    pub depends_on: BTreeSet<u64>,
+    /// 繰り返しの規則（完了すると次の回のタスクに引き継ぐ）
+    #[schema(value_type = Option<String>)]
+    pub recurrence: Option<Recurrence>,
+    /// 保存のたびに増える版数（楽観的排他制御に使う）
+    pub version: u64,
     #[schema(value_type = String, format = DateTime)]
     pub created_at: chrono::DateTime<chrono::Utc>,
     #[schema(value_type = String, format = DateTime)]
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// ゴミ箱に移した日時（ゴミ箱にないタスクはNone）
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl Task {
@@ -28,20 +132,91 @@ impl Task {
             id,
             description: description.clone(),
             completed: false,
+            priority: None,
+            due_at: None,
+            tags: BTreeSet::new(),
+            owner_id: DEFAULT_USER_ID,
+            project_id: INBOX_PROJECT_ID,
+            parent_id: None,
+            depends_on: BTreeSet::new(),
+            recurrence: None,
+            version: 1,
             created_at: now,
             updated_at: now,
+            deleted_at: None,
         };
         task.validate()?;
         Ok(task)
     }
 
+    /// 作成リクエストの内容で新しいタスクを組み立てる
+    pub fn from_create(id: u64, create_task: CreateTask) -> Result<Self, TaskValidationError> {
+        let mut task = Self::new(id, create_task.description)?;
+        task.priority = create_task.priority;
+        task.due_at = create_task.due_at;
+        task.owner_id = create_task.owner_id.unwrap_or(DEFAULT_USER_ID);
+        task.project_id = create_task.project_id.unwrap_or(INBOX_PROJECT_ID);
+        task.parent_id = create_task.parent_id;
+        task.recurrence = create_task.recurrence;
+        task.add_tags(&create_task.tags)?;
+        task.validate()?;
+        Ok(task)
+    }
+
+    /// 繰り返しの次の回を作るリクエスト（繰り返さないタスクや、繰り返しが終わった場合は None）
+    ///
+    /// 次の期限は現在の期限（なければ `now`）を起点に数え、`now` より後になるまで進める。
+    /// 毎月の繰り返しは起点の日を次の回の規則に書き込み、月末に繰り上げた後も元の日に戻れるようにする。
+    pub fn next_occurrence(&self, now: DateTime<Utc>) -> Option<CreateTask> {
+        let start = self.due_at.unwrap_or(now);
+        let recurrence = self.recurrence.as_ref()?.anchored_at(start);
+        let mut due_at = recurrence.next_after(start, start)?;
+        while due_at <= now {
+            due_at = recurrence.next_after(start, due_at)?;
+        }
+        Some(CreateTask {
+            description: self.description.clone(),
+            priority: self.priority,
+            due_at: Some(due_at),
+            owner_id: Some(self.owner_id),
+            project_id: Some(self.project_id),
+            parent_id: self.parent_id,
+            recurrence: Some(recurrence),
+            tags: self.tags.iter().cloned().collect(),
+        })
+    }
+
+    /// 繰り返しの規則を変える（`None` で繰り返しをやめる）
+    pub fn set_recurrence(&mut self, recurrence: Option<Recurrence>) {
+        if self.recurrence != recurrence {
+            self.recurrence = recurrence;
+            self.updated_at = chrono::Utc::now();
+        }
+    }
+
     pub fn validate(&self) -> Result<(), TaskValidationError> {
         if self.description.trim().is_empty() {
             return Err(TaskValidationError::EmptyDescription);
         }
         
-        if self.description.len() > 1000 {
-            return Err(TaskValidationError::DescriptionTooLong(1000));
+        if self.description.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(TaskValidationError::DescriptionTooLong(MAX_DESCRIPTION_LENGTH));
+        }
+
+        if self.due_at.is_some_and(|due_at| due_at < self.created_at) {
+            return Err(TaskValidationError::DueBeforeCreation);
+        }
+
+        if self.tags.len() > MAX_TAGS_PER_TASK {
+            return Err(TaskValidationError::TooManyTags(MAX_TAGS_PER_TASK));
+        }
+
+        if let Some(tag) = self.tags.iter().find(|tag| normalize_tag(tag).ok().as_deref() != Some(tag.as_str())) {
+            return Err(TaskValidationError::InvalidTag(tag.clone()));
+        }
+
+        if self.parent_id == Some(self.id) {
+            return Err(TaskValidationError::ParentCycle(self.id));
         }
         
         Ok(())
@@ -73,6 +248,136 @@ impl Task {
         }
     }
 
+    pub fn set_priority(&mut self, priority: TaskPriority) {
+        self.priority = Some(priority);
+        self.updated_at = chrono::Utc::now();
+    }
+
+    pub fn set_due_at(&mut self, due_at: Option<DateTime<Utc>>) -> Result<(), TaskValidationError> {
+        let original_due_at = self.due_at;
+        self.due_at = due_at;
+
+        match self.validate() {
+            Ok(()) => {
+                self.updated_at = chrono::Utc::now();
+                Ok(())
+            }
+            Err(e) => {
+                self.due_at = original_due_at;
+                Err(e)
+            }
+        }
+    }
+
+    /// タグを追加する（既に付いているタグは無視する）
+    pub fn add_tags(&mut self, tags: &[String]) -> Result<(), TaskValidationError> {
+        let original_tags = self.tags.clone();
+        for tag in tags {
+            self.tags.insert(normalize_tag(tag)?);
+        }
+
+        if self.tags == original_tags {
+            return Ok(());
+        }
+        match self.validate() {
+            Ok(()) => {
+                self.updated_at = chrono::Utc::now();
+                Ok(())
+            }
+            Err(e) => {
+                self.tags = original_tags;
+                Err(e)
+            }
+        }
+    }
+
+    /// タグを外す（付いていないタグは無視する）
+    pub fn remove_tags(&mut self, tags: &[String]) -> Result<(), TaskValidationError> {
+        let mut removed = false;
+        for tag in tags {
+            removed |= self.tags.remove(&normalize_tag(tag)?);
+        }
+        if removed {
+            self.updated_at = chrono::Utc::now();
+        }
+        Ok(())
+    }
+
+    /// 親タスクを付け替える（`None` で最上位のタスクにする）
+    ///
+    /// 自分自身以外の循環や親の存在は、他のタスクを参照できるリポジトリが確かめる。
+    pub fn set_parent(&mut self, parent_id: Option<u64>) -> Result<(), TaskValidationError> {
+        if parent_id == self.parent_id {
+            return Ok(());
+        }
+        if parent_id == Some(self.id) {
+            return Err(TaskValidationError::ParentCycle(self.id));
+        }
+        self.parent_id = parent_id;
+        self.updated_at = chrono::Utc::now();
+        Ok(())
+    }
+
+    /// 別のプロジェクトに移す
+    pub fn set_project(&mut self, project_id: u64) {
+        if self.project_id != project_id {
+            self.project_id = project_id;
+            self.updated_at = chrono::Utc::now();
+        }
+    }
+
+    /// 依存先を追加する（既に依存しているタスクは無視する）
+    ///
+    /// 依存先の存在や自分自身以外との循環は、他のタスクを参照できるリポジトリが確かめる。
+    pub fn add_dependencies(&mut self, ids: &[u64]) -> Result<(), TaskValidationError> {
+        if ids.contains(&self.id) {
+            return Err(TaskValidationError::DependencyCycle(self.id));
+        }
+        let original_len = self.depends_on.len();
+        self.depends_on.extend(ids);
+        if self.depends_on.len() != original_len {
+            self.updated_at = chrono::Utc::now();
+        }
+        Ok(())
+    }
+
+    /// 依存先を外す（依存していないタスクは無視する）
+    pub fn remove_dependencies(&mut self, ids: &[u64]) {
+        let mut removed = false;
+        for id in ids {
+            removed |= self.depends_on.remove(id);
+        }
+        if removed {
+            self.updated_at = chrono::Utc::now();
+        }
+    }
+
+    /// ゴミ箱に移す
+    pub fn trash(&mut self, at: DateTime<Utc>) {
+        self.deleted_at = Some(at);
+    }
+
+    /// ゴミ箱から元に戻す
+    pub fn restore(&mut self) {
+        self.deleted_at = None;
+    }
+
+    pub fn is_trashed(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// 保存前の状態から内容が変わっていれば版数を1つ進める
+    pub fn advance_version(&mut self, previous: &Task) {
+        if self != previous {
+            self.version = previous.version + 1;
+        }
+    }
+
+    /// 未完了のまま期限を過ぎているか
+    pub fn is_overdue(&self, now: DateTime<Utc>) -> bool {
+        !self.completed && self.due_at.is_some_and(|due_at| due_at < now)
+    }
+
     pub fn is_completed(&self) -> bool {
         self.completed
     }
@@ -82,14 +387,29 @@ impl Task {
     }
 }
 
-#[derive(Deserialize, Debug, ToSchema)]
+#[derive(Deserialize, Debug, Default, ToSchema)]
 pub struct CreateTask {
     pub description: String,
+    pub priority: Option<TaskPriority>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub due_at: Option<DateTime<Utc>>,
+    /// タスクを持つ利用者のID（None なら既定の利用者）
+    pub owner_id: Option<u64>,
+    /// 所属するプロジェクトのID（None なら受信箱）
+    pub project_id: Option<u64>,
+    /// 親タスクのID
+    pub parent_id: Option<u64>,
+    /// 繰り返しの規則
+    #[schema(value_type = Option<String>)]
+    pub recurrence: Option<Recurrence>,
+    /// 付けるタグ
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl CreateTask {
     pub fn new(description: String) -> Result<Self, TaskValidationError> {
-        let create_task = Self { description };
+        let create_task = Self { description, ..Self::default() };
         create_task.validate()?;
         Ok(create_task)
     }
@@ -99,23 +419,50 @@ impl CreateTask {
             return Err(TaskValidationError::EmptyDescription);
         }
         
-        if self.description.len() > 1000 {
-            return Err(TaskValidationError::DescriptionTooLong(1000));
+        if self.description.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(TaskValidationError::DescriptionTooLong(MAX_DESCRIPTION_LENGTH));
+        }
+
+        for tag in &self.tags {
+            normalize_tag(tag)?;
         }
         
         Ok(())
     }
 }
 
-#[derive(Deserialize, Debug, ToSchema)]
+#[derive(Deserialize, Debug, Default, ToSchema)]
 pub struct UpdateTask {
     pub description: Option<String>,
     pub completed: Option<bool>,
+    pub priority: Option<TaskPriority>,
+    /// 期限の変更（`Some(None)` で期限を解除する）
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub due_at: Option<Option<DateTime<Utc>>>,
+    /// 追加するタグ
+    #[serde(default)]
+    pub add_tags: Vec<String>,
+    /// 外すタグ
+    #[serde(default)]
+    pub remove_tags: Vec<String>,
+    /// 別のプロジェクトへの移動
+    pub project_id: Option<u64>,
+    /// 親タスクの変更（`Some(None)` で最上位のタスクにする）
+    pub parent_id: Option<Option<u64>>,
+    /// 追加する依存先
+    #[serde(default)]
+    pub add_dependencies: Vec<u64>,
+    /// 外す依存先
+    #[serde(default)]
+    pub remove_dependencies: Vec<u64>,
+    /// 繰り返しの規則の変更（`Some(None)` で繰り返しをやめる）
+    #[schema(value_type = Option<String>)]
+    pub recurrence: Option<Option<Recurrence>>,
 }
 
 impl UpdateTask {
     pub fn new(description: Option<String>, completed: Option<bool>) -> Result<Self, TaskValidationError> {
-        let update_task = Self { description, completed };
+        let update_task = Self { description, completed, ..Self::default() };
         update_task.validate()?;
         Ok(update_task)
     }
@@ -126,17 +473,124 @@ impl UpdateTask {
                 return Err(TaskValidationError::EmptyDescription);
             }
             
-            if description.len() > 1000 {
-                return Err(TaskValidationError::DescriptionTooLong(1000));
+            if description.len() > MAX_DESCRIPTION_LENGTH {
+                return Err(TaskValidationError::DescriptionTooLong(MAX_DESCRIPTION_LENGTH));
             }
         }
+
+        for tag in self.add_tags.iter().chain(&self.remove_tags) {
+            normalize_tag(tag)?;
+        }
         
         Ok(())
     }
 
     pub fn is_empty(&self) -> bool {
-        self.description.is_none() && self.completed.is_none()
+        self.description.is_none()
+            && self.completed.is_none()
+            && self.priority.is_none()
+            && self.due_at.is_none()
+            && self.add_tags.is_empty()
+            && self.remove_tags.is_empty()
+            && self.project_id.is_none()
+            && self.parent_id.is_none()
+            && self.add_dependencies.is_empty()
+            && self.remove_dependencies.is_empty()
+            && self.recurrence.is_none()
+    }
+}
+
+ 
+
+/// 子タスクを含めたタスクの階層
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskNode {
+    pub task: Task,
+    /// 直下の子タスク（ID順）
+    pub children: Vec<TaskNode>,
+}
+
+impl TaskNode {
+    /// タスクを親子関係に従って組み立て、最上位のタスクをID順に返す
+    ///
+    /// 親が `tasks` に含まれないタスクも最上位として扱う。
+    pub fn build_forest(mut tasks: Vec<Task>) -> Vec<TaskNode> {
+        tasks.sort_by_key(|task| task.id);
+        let ids: BTreeSet<u64> = tasks.iter().map(|task| task.id).collect();
+        let mut children_of: HashMap<u64, Vec<Task>> = HashMap::new();
+        let mut roots = Vec::new();
+        for task in tasks {
+            match task.parent_id.filter(|parent_id| ids.contains(parent_id)) {
+                Some(parent_id) => children_of.entry(parent_id).or_default().push(task),
+                None => roots.push(task),
+            }
+        }
+        roots.into_iter().map(|task| Self::attach(task, &mut children_of)).collect()
+    }
+
+    fn attach(task: Task, children_of: &mut HashMap<u64, Vec<Task>>) -> TaskNode {
+        let children = children_of
+            .remove(&task.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child| Self::attach(child, children_of))
+            .collect();
+        TaskNode { task, children }
     }
+
+    /// 直下の子タスクのうち完了したものの割合（子タスクがなければ None）
+    pub fn completion_percentage(&self) -> Option<u8> {
+        let completed = self.children.iter().filter(|child| child.task.completed).count();
+        completion_percentage(completed, self.children.len())
+    }
+}
+
+/// 未完了の依存先があるタスクのIDを求める（完了済みのタスクは待つ必要がないため含めない）
+///
+/// `completed` は依存先のタスクが完了しているかを返す。存在しないタスクへの依存は待たない。
+pub fn blocked_task_ids<'a>(
+    tasks: impl IntoIterator<Item = &'a Task>,
+    completed: impl Fn(u64) -> Option<bool>,
+) -> BTreeSet<u64> {
+    tasks
+        .into_iter()
+        .filter(|task| !task.completed && task.depends_on.iter().any(|&id| completed(id) == Some(false)))
+        .map(|task| task.id)
+        .collect()
 }
 
- 
\ No newline at end of file
+/// 依存先が先に来るように並べる（同じ順位のタスクはID順）
+///
+/// `tasks` に含まれないタスクへの依存は無視する。循環している場合、循環に含まれるタスクは末尾にID順で並べる。
+pub fn dependency_order(tasks: Vec<Task>) -> Vec<Task> {
+    let ids: BTreeSet<u64> = tasks.iter().map(|task| task.id).collect();
+    let mut waiting: HashMap<u64, usize> = HashMap::new();
+    let mut dependents: HashMap<u64, Vec<u64>> = HashMap::new();
+    for task in &tasks {
+        let dependencies: Vec<u64> = task.depends_on.iter().copied().filter(|id| ids.contains(id)).collect();
+        waiting.insert(task.id, dependencies.len());
+        for dependency in dependencies {
+            dependents.entry(dependency).or_default().push(task.id);
+        }
+    }
+
+    let mut ready: BTreeSet<u64> = waiting.iter().filter(|(_, &count)| count == 0).map(|(&id, _)| id).collect();
+    let mut order = Vec::with_capacity(tasks.len());
+    while let Some(id) = ready.pop_first() {
+        order.push(id);
+        for dependent in dependents.remove(&id).unwrap_or_default() {
+            if let Some(count) = waiting.get_mut(&dependent) {
+                *count -= 1;
+                if *count == 0 {
+                    ready.insert(dependent);
+                }
+            }
+        }
+    }
+    let mut by_id: HashMap<u64, Task> = tasks.into_iter().map(|task| (task.id, task)).collect();
+    let mut sorted: Vec<Task> = order.into_iter().filter_map(|id| by_id.remove(&id)).collect();
+    let mut cyclic: Vec<Task> = by_id.into_values().collect();
+    cyclic.sort_by_key(|task| task.id);
+    sorted.extend(cyclic);
+    sorted
+}
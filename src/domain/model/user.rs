@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use utoipa::ToSchema;
+
+/// 利用者を指定しないリクエストが使う既定の利用者のID（認証を導入する前のタスクもこの利用者が持つ）
+pub const DEFAULT_USER_ID: u64 = 1;
+/// 既定の利用者の名前
+pub const DEFAULT_USER_NAME: &str = "default";
+/// 利用者名の最大文字数
+pub const MAX_USER_NAME_LENGTH: usize = 50;
+
+#[derive(Debug, Error)]
+pub enum UserValidationError {
+    #[error("User name cannot be empty")]
+    EmptyName,
+    #[error("User name cannot exceed {0} characters")]
+    NameTooLong(usize),
+    #[error("User name '{0}' may only contain ASCII letters, digits, '-', '_' and '.'")]
+    InvalidName(String),
+    #[error("A user named '{0}' already exists")]
+    DuplicateName(String),
+}
+
+/// 利用者名を検証する（履歴やログにそのまま載せるため、使える文字を絞る）
+fn validate_name(name: &str) -> Result<String, UserValidationError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(UserValidationError::EmptyName);
+    }
+    if name.len() > MAX_USER_NAME_LENGTH {
+        return Err(UserValidationError::NameTooLong(MAX_USER_NAME_LENGTH));
+    }
+    if !name.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.')) {
+        return Err(UserValidationError::InvalidName(name.to_string()));
+    }
+    Ok(name.to_string())
+}
+
+/// タスクを持つ利用者
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct User {
+    pub id: u64,
+    pub name: String,
+    #[schema(value_type = String, format = DateTime)]
+    pub created_at: DateTime<Utc>,
+}
+
+impl User {
+    pub fn new(id: u64, name: &str) -> Result<Self, UserValidationError> {
+        Ok(Self { id, name: validate_name(name)?, created_at: Utc::now() })
+    }
+
+    /// 既定の利用者
+    pub fn default_user() -> Self {
+        Self { id: DEFAULT_USER_ID, name: DEFAULT_USER_NAME.to_string(), created_at: Utc::now() }
+    }
+
+    /// 大文字・小文字を区別せずに同じ名前か
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name.trim())
+    }
+}
+
+#[derive(Deserialize, Debug, Default, ToSchema)]
+pub struct CreateUser {
+    pub name: String,
+}
+
+impl CreateUser {
+    pub fn validate(&self) -> Result<(), UserValidationError> {
+        validate_name(&self.name).map(|_| ())
+    }
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use thiserror::Error;
+use crate::domain::model::api_key::{ApiKey, ApiKeyScope, ApiKeyValidationError};
+
+#[derive(Debug, Error)]
+pub enum ApiKeyError {
+    #[error("API key not found with id {0}")]
+    NotFound(u64),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] Box<dyn std::error::Error + Send + Sync>),
+    #[error("Validation error: {0}")]
+    ValidationError(#[from] ApiKeyValidationError),
+}
+
+/// 保存するAPIキー（鍵そのものではなくハッシュ値を渡す）
+#[derive(Debug, Clone)]
+pub struct NewApiKey {
+    pub name: String,
+    pub scope: ApiKeyScope,
+    pub user_id: u64,
+    pub prefix: String,
+    pub secret_hash: String,
+}
+
+/// APIキーの保存先
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    /// 失効したものも含めてID順に取得する
+    async fn get_all(&self) -> Result<Vec<ApiKey>, ApiKeyError>;
+    /// ハッシュ値が一致する鍵を探す（失効したものも返す）
+    async fn find_by_hash(&self, secret_hash: &str) -> Result<Option<ApiKey>, ApiKeyError>;
+    /// 保存する（存在しない利用者の鍵は作らない）
+    async fn create(&self, new_key: NewApiKey) -> Result<ApiKey, ApiKeyError>;
+    /// 失効させる（失効済みならそのまま返す）
+    async fn revoke(&self, id: u64) -> Result<ApiKey, ApiKeyError>;
+}
+
+/// APIキーも同じ保存先に持つタスクのリポジトリ
+pub trait ApiKeyStorage {
+    type ApiKeys: ApiKeyRepository + Clone + 'static;
+
+    /// 同じ保存先のAPIキーのリポジトリ
+    fn api_keys(&self) -> Self::ApiKeys;
+}
@@ -0,0 +1,7 @@
+use crate::domain::model::event::TaskChange;
+
+/// リポジトリでの変更を受け取る通知先
+pub trait TaskChangePublisher: Send + Sync {
+    /// 変更を通知する（書き込みは完了しており、失敗しても取り消さない）
+    fn publish(&self, change: TaskChange);
+}
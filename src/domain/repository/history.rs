@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use crate::domain::model::history::{NewTaskHistoryEntry, TaskHistoryEntry};
+use crate::domain::repository::task::TaskError;
+
+/// タスクの変更履歴の保存先
+///
+/// 履歴は追記するだけで、記録後に書き換えたり消したりしない。タスクを完全に削除しても残る。
+#[async_trait]
+pub trait TaskHistoryRepository: Send + Sync {
+    /// 履歴を追記し、連番を振った記録を返す
+    async fn append(&self, entry: NewTaskHistoryEntry) -> Result<TaskHistoryEntry, TaskError>;
+
+    /// タスクの履歴を記録した順に取得する（記録がなければ空）
+    async fn get_by_task(&self, task_id: u64) -> Result<Vec<TaskHistoryEntry>, TaskError>;
+}
+
+/// 変更履歴も同じ保存先に持つタスクのリポジトリ
+pub trait HistoryStorage {
+    type History: TaskHistoryRepository + Clone + 'static;
+
+    /// 同じ保存先の変更履歴のリポジトリ
+    fn history(&self) -> Self::History;
+}
@@ -1 +1,6 @@
-pub mod task; 
\ No newline at end of file
+pub mod api_key;
+pub mod event;
+pub mod history;
+pub mod project;
+pub mod task;
+pub mod user;
\ No newline at end of file
@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+use async_trait::async_trait;
+use thiserror::Error;
+use crate::domain::model::project::{CreateProject, Project, ProjectValidationError, UpdateProject};
+use crate::domain::repository::task::TaskCounts;
+
+#[derive(Debug, Error)]
+pub enum ProjectError {
+    #[error("Project not found with id {0}")]
+    NotFound(u64),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] Box<dyn std::error::Error + Send + Sync>),
+    #[error("Validation error: {0}")]
+    ValidationError(#[from] ProjectValidationError),
+    #[error("Invalid operation: {0}")]
+    InvalidOperation(String),
+    #[error("Project {0} still has tasks")]
+    NotEmpty(u64),
+}
+
+/// プロジェクトの保存先
+///
+/// タスクと同じ保存先に置き、タスクの所属先の確認やプロジェクトの削除をタスクの書き込みと同じ
+/// ロック・トランザクションの中で行う。受信箱は保存先を開いたときに作られる。
+#[async_trait]
+pub trait ProjectRepository: Send + Sync {
+    /// ID順に取得する
+    async fn get_all(&self) -> Result<Vec<Project>, ProjectError>;
+    async fn get_by_id(&self, id: u64) -> Result<Project, ProjectError>;
+    /// 作成する（同じ名前のプロジェクトがあれば作らない）
+    async fn create(&self, create_project: CreateProject) -> Result<Project, ProjectError>;
+    async fn update(&self, id: u64, update_project: UpdateProject) -> Result<Project, ProjectError>;
+
+    /// 削除する
+    ///
+    /// 受信箱と、ゴミ箱以外にタスクが残っているプロジェクトは削除できない。
+    /// ゴミ箱にあるタスクは受信箱に移す。
+    async fn delete(&self, id: u64) -> Result<(), ProjectError>;
+
+    /// プロジェクトごとのタスク数（ゴミ箱のタスクは数えない。タスクのないプロジェクトは含まない）
+    ///
+    /// `owner_id` を指定した場合は、その利用者のタスクだけを数える。
+    async fn count_tasks(&self, owner_id: Option<u64>) -> Result<BTreeMap<u64, TaskCounts>, ProjectError>;
+}
+
+/// プロジェクトも同じ保存先に持つタスクのリポジトリ
+pub trait ProjectStorage {
+    type Projects: ProjectRepository + Clone + 'static;
+
+    /// 同じ保存先のプロジェクトのリポジトリ
+    fn projects(&self) -> Self::Projects;
+}
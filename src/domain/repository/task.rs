@@ -1,6 +1,10 @@
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use thiserror::Error;
-use crate::domain::model::task::{Task, CreateTask, UpdateTask, TaskValidationError};
+use crate::domain::model::history::TaskHistoryEntry;
+use crate::domain::model::task::{completion_percentage, Task, CreateTask, UpdateTask, TaskValidationError};
 
 #[derive(Debug, Error)]
 pub enum TaskError {
@@ -12,6 +16,339 @@ pub enum TaskError {
     ValidationError(#[from] TaskValidationError),
     #[error("Invalid operation: {0}")]
     InvalidOperation(String),
+    #[error("Task {id} is at version {actual}, expected {expected}")]
+    VersionMismatch { id: u64, expected: u64, actual: u64 },
+    #[error("Task {0} has child tasks")]
+    HasChildren(u64),
+    #[error("Task {id} is blocked by incomplete tasks {blockers:?}")]
+    Blocked { id: u64, blockers: Vec<u64> },
+}
+
+/// タスクの現在の版数が期待する版数と一致するか確かめる
+pub fn ensure_version(task: &Task, expected_version: u64) -> Result<(), TaskError> {
+    if task.version == expected_version {
+        Ok(())
+    } else {
+        Err(TaskError::VersionMismatch { id: task.id, expected: expected_version, actual: task.version })
+    }
+}
+
+/// 親に指定したタスクが存在し、親子関係が循環しないか確かめる
+///
+/// `parent_of` は指定したIDのタスクの親を返す（タスクが存在しなければ `None`）。
+/// `id` は付け替えるタスクのIDで、新しく作るタスクでは `None` を渡す。
+pub fn ensure_parent(
+    id: Option<u64>,
+    parent_id: u64,
+    mut parent_of: impl FnMut(u64) -> Result<Option<Option<u64>>, TaskError>,
+) -> Result<(), TaskError> {
+    let mut ancestor = Some(parent_id);
+    while let Some(current) = ancestor {
+        if Some(current) == id {
+            return Err(TaskValidationError::ParentCycle(current).into());
+        }
+        ancestor = match parent_of(current)? {
+            Some(parent) => parent,
+            None if current == parent_id => return Err(TaskValidationError::ParentNotFound(parent_id).into()),
+            None => None,
+        };
+    }
+    Ok(())
+}
+
+/// 依存先に指定できるか確かめる（存在しないタスクや、`id` に依存しているタスクは指定できない）
+///
+/// `dependencies_of` は指定したIDのタスクの依存先を返す（タスクが存在しなければ `None`）。
+pub fn ensure_dependency(
+    id: u64,
+    dependency: u64,
+    mut dependencies_of: impl FnMut(u64) -> Result<Option<BTreeSet<u64>>, TaskError>,
+) -> Result<(), TaskError> {
+    let Some(first) = dependencies_of(dependency)? else {
+        return Err(TaskValidationError::DependencyNotFound(dependency).into());
+    };
+    let mut visited = BTreeSet::from([dependency]);
+    let mut pending: Vec<u64> = first.into_iter().collect();
+    while let Some(current) = pending.pop() {
+        if current == id {
+            return Err(TaskValidationError::DependencyCycle(dependency).into());
+        }
+        if visited.insert(current) {
+            pending.extend(dependencies_of(current)?.unwrap_or_default());
+        }
+    }
+    Ok(())
+}
+
+/// 未完了の依存先（`blockers`）が残っていれば完了にできない
+pub fn ensure_unblocked(id: u64, blockers: Vec<u64>) -> Result<(), TaskError> {
+    if blockers.is_empty() {
+        Ok(())
+    } else {
+        Err(TaskError::Blocked { id, blockers })
+    }
+}
+
+/// 子タスクを持つタスクを削除するときの扱い
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChildPolicy {
+    /// 子タスクがあれば削除しない
+    #[default]
+    Reject,
+    /// 子孫のタスクもまとめてゴミ箱に移す
+    Cascade,
+    /// 子タスクの親を外して最上位のタスクにする
+    Orphan,
+}
+
+/// 一覧の並び替えに使う項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskSortField {
+    #[default]
+    Id,
+    CreatedAt,
+    UpdatedAt,
+}
+
+/// 並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// 複数タグで絞り込む場合の条件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TagMatch {
+    /// すべてのタグが付いているタスク
+    #[default]
+    All,
+    /// いずれかのタグが付いているタスク
+    Any,
+}
+
+/// タグとそのタグが付いたタスクの数
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagCount {
+    pub tag: String,
+    pub count: usize,
+}
+
+impl TagCount {
+    /// タスクに付いたタグを数え、タグ名の順に並べる
+    pub fn count(tasks: impl IntoIterator<Item = Task>) -> Vec<TagCount> {
+        let mut counts = BTreeMap::new();
+        for task in tasks {
+            for tag in task.tags {
+                *counts.entry(tag).or_insert(0) += 1;
+            }
+        }
+        counts.into_iter().map(|(tag, count)| TagCount { tag, count }).collect()
+    }
+}
+
+/// 状態ごとのタスク数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TaskCounts {
+    pub total: usize,
+    pub completed: usize,
+}
+
+impl TaskCounts {
+    pub fn pending(&self) -> usize {
+        self.total - self.completed
+    }
+
+    /// 完了したタスクの割合（0〜100、タスクがなければ None）
+    pub fn completion_percentage(&self) -> Option<u8> {
+        completion_percentage(self.completed, self.total)
+    }
+}
+
+/// キーセットページングの再開位置（直前のページの最後のタスク）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TaskCursor {
+    pub id: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskCursor {
+    /// 並び替え項目の値とIDの組（IDで同値を解消する）
+    pub fn key(&self, sort: TaskSortField) -> (Option<DateTime<Utc>>, u64) {
+        match sort {
+            TaskSortField::Id => (None, self.id),
+            TaskSortField::CreatedAt => (Some(self.created_at), self.id),
+            TaskSortField::UpdatedAt => (Some(self.updated_at), self.id),
+        }
+    }
+}
+
+impl From<&Task> for TaskCursor {
+    fn from(task: &Task) -> Self {
+        Self {
+            id: task.id,
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+        }
+    }
+}
+
+/// タスク一覧の取得条件
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskQuery {
+    /// 完了状態で絞り込む（Noneなら絞り込まない）
+    pub completed: Option<bool>,
+    pub sort: TaskSortField,
+    pub direction: SortDirection,
+    /// 期限がこの日時以降のタスクだけを返す（期限のないタスクは除く）
+    pub due_from: Option<DateTime<Utc>>,
+    /// 期限がこの日時より前のタスクだけを返す（期限のないタスクは除く）
+    pub due_before: Option<DateTime<Utc>>,
+    /// 正規化済みのタグで絞り込む（空なら絞り込まない）
+    pub tags: Vec<String>,
+    pub tag_match: TagMatch,
+    /// このタスクの直下の子タスクだけを返す
+    pub parent_id: Option<u64>,
+    /// このプロジェクトのタスクだけを返す
+    pub project_id: Option<u64>,
+    /// この利用者のタスクだけを返す
+    pub owner_id: Option<u64>,
+    /// このカーソルより後ろのタスクだけを返す
+    pub after: Option<TaskCursor>,
+    /// 返す最大件数（Noneなら全件）
+    pub limit: Option<usize>,
+}
+
+impl TaskQuery {
+    /// 並び順に従って2つの位置を比較する
+    pub fn compare(&self, a: &TaskCursor, b: &TaskCursor) -> Ordering {
+        let ordering = a.key(self.sort).cmp(&b.key(self.sort));
+        match self.direction {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    }
+
+    /// タスクが絞り込み条件とカーソル位置を満たすか
+    pub fn matches(&self, task: &Task) -> bool {
+        if self.completed.is_some_and(|completed| task.completed != completed) {
+            return false;
+        }
+        if !self.matches_tags(task) {
+            return false;
+        }
+        if self.parent_id.is_some_and(|parent_id| task.parent_id != Some(parent_id)) {
+            return false;
+        }
+        if self.project_id.is_some_and(|project_id| task.project_id != project_id) {
+            return false;
+        }
+        if self.owner_id.is_some_and(|owner_id| task.owner_id != owner_id) {
+            return false;
+        }
+        if self.due_from.is_some() || self.due_before.is_some() {
+            let Some(due_at) = task.due_at else {
+                return false;
+            };
+            if self.due_from.is_some_and(|from| due_at < from)
+                || self.due_before.is_some_and(|before| due_at >= before)
+            {
+                return false;
+            }
+        }
+        match &self.after {
+            Some(after) => self.compare(&TaskCursor::from(task), after) == Ordering::Greater,
+            None => true,
+        }
+    }
+
+    /// タスクがタグの絞り込み条件を満たすか
+    pub fn matches_tags(&self, task: &Task) -> bool {
+        if self.tags.is_empty() {
+            return true;
+        }
+        match self.tag_match {
+            TagMatch::All => self.tags.iter().all(|tag| task.tags.contains(tag)),
+            TagMatch::Any => self.tags.iter().any(|tag| task.tags.contains(tag)),
+        }
+    }
+
+    /// メモリ上のタスクに条件を適用して1ページ分を取り出す
+    pub fn apply(&self, tasks: Vec<Task>) -> TaskPage {
+        let mut tasks: Vec<Task> = tasks.into_iter().filter(|task| self.matches(task)).collect();
+        tasks.sort_by(|a, b| self.compare(&TaskCursor::from(a), &TaskCursor::from(b)));
+        TaskPage::from_window(tasks, self.limit)
+    }
+}
+
+/// 一覧取得の結果
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskPage {
+    pub tasks: Vec<Task>,
+    /// 続きがある場合の次ページの開始位置
+    pub next: Option<TaskCursor>,
+}
+
+impl TaskPage {
+    /// 並び替え済みのタスク（続きの有無を判定するため最大 limit + 1 件）からページを作る
+    pub fn from_window(mut tasks: Vec<Task>, limit: Option<usize>) -> Self {
+        let next = match limit {
+            Some(limit) if tasks.len() > limit => {
+                tasks.truncate(limit);
+                tasks.last().map(TaskCursor::from)
+            }
+            _ => None,
+        };
+        Self { tasks, next }
+    }
+}
+
+/// 一括処理の1件分の操作
+#[derive(Debug)]
+pub enum TaskOperation {
+    Create(CreateTask),
+    Update { id: u64, update: UpdateTask },
+    Complete(u64),
+    Uncomplete(u64),
+    Delete(u64),
+}
+
+/// 一括処理の進め方
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchMode {
+    /// 1件でも失敗したらすべて取り消す
+    #[default]
+    Atomic,
+    /// 失敗した操作だけを飛ばして残りを続ける
+    BestEffort,
+}
+
+/// 1件の操作の前後のタスク（作成では `previous`、削除では `current` がない）
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskOperationOutcome {
+    pub previous: Option<Task>,
+    pub current: Option<Task>,
+}
+
+/// 一括処理の結果
+#[derive(Debug)]
+pub struct BatchOutcome<E = TaskError> {
+    /// 変更が保存されたか（`Atomic` で失敗した場合は false で、何も変わっていない）
+    pub committed: bool,
+    /// 実行した操作の結果（`Atomic` で失敗した場合は失敗した操作まで）
+    pub results: Vec<Result<TaskOperationOutcome, E>>,
+}
+
+impl<E> BatchOutcome<E> {
+    /// 各操作のエラーを変換する
+    pub fn map_err<F>(self, mut f: impl FnMut(E) -> F) -> BatchOutcome<F> {
+        BatchOutcome {
+            committed: self.committed,
+            results: self.results.into_iter().map(|result| result.map_err(&mut f)).collect(),
+        }
+    }
 }
 
 #[async_trait]
@@ -20,7 +357,193 @@ pub trait TaskRepository: Send + Sync {
     async fn get_by_id(&self, id: u64) -> Result<Task, TaskError>;
     async fn create(&self, task: CreateTask) -> Result<Task, TaskError>;
     async fn update(&self, id: u64, update_task: UpdateTask) -> Result<Task, TaskError>;
+    /// タスクを削除する（ゴミ箱を持つ実装はゴミ箱に移し、以降の取得や一覧には現れない）
     async fn delete(&self, id: u64) -> Result<(), TaskError>;
     async fn complete(&self, id: u64) -> Result<Task, TaskError>;
     async fn uncomplete(&self, id: u64) -> Result<Task, TaskError>;
+
+    /// 現在の版数が `expected_version` と一致する場合だけ更新する
+    ///
+    /// 既定の実装は確認と更新の間に他の更新が割り込みうる。排他制御できる実装は上書きする。
+    async fn update_if_version(&self, id: u64, update_task: UpdateTask, expected_version: u64) -> Result<Task, TaskError> {
+        ensure_version(&self.get_by_id(id).await?, expected_version)?;
+        self.update(id, update_task).await
+    }
+
+    /// 現在の版数が `expected_version` と一致する場合だけ削除する
+    async fn delete_if_version(&self, id: u64, expected_version: u64) -> Result<(), TaskError> {
+        ensure_version(&self.get_by_id(id).await?, expected_version)?;
+        self.delete(id).await
+    }
+
+    /// 未完了の依存先が残っていても完了にする（`expected_version` を指定すると版数を確かめる）
+    ///
+    /// 既定の実装は `complete` を呼ぶため、依存先を確かめる実装は上書きする。
+    async fn force_complete(&self, id: u64, expected_version: Option<u64>) -> Result<Task, TaskError> {
+        if let Some(expected_version) = expected_version {
+            ensure_version(&self.get_by_id(id).await?, expected_version)?;
+        }
+        self.complete(id).await
+    }
+
+    /// 子タスクの扱いを指定して削除し、変更したタスクの前後を返す（先頭は指定したタスク）
+    ///
+    /// `Cascade` では子孫もゴミ箱に移し、`Orphan` では子タスクの親を外す。`expected_version` は
+    /// 指定したタスクの版数だけを確かめる。既定の実装は既存のメソッドを順に呼ぶため、途中で
+    /// 失敗すると一部だけ反映される。まとめて取り消せる実装は上書きする。
+    async fn delete_with_children(&self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> Result<Vec<TaskOperationOutcome>, TaskError> {
+        let target = self.get_by_id(id).await?;
+        if let Some(expected_version) = expected_version {
+            ensure_version(&target, expected_version)?;
+        }
+        let tasks = self.get_all().await?;
+        let children: Vec<&Task> = tasks.iter().filter(|task| task.parent_id == Some(id)).collect();
+        if !children.is_empty() && policy == ChildPolicy::Reject {
+            return Err(TaskError::HasChildren(id));
+        }
+
+        let mut outcomes = vec![TaskOperationOutcome { previous: Some(target), current: None }];
+        match policy {
+            ChildPolicy::Reject => {}
+            ChildPolicy::Cascade => {
+                let mut pending: Vec<u64> = children.iter().map(|child| child.id).collect();
+                while let Some(parent_id) = pending.pop() {
+                    let descendant = tasks.iter().find(|task| task.id == parent_id).cloned();
+                    pending.extend(tasks.iter().filter(|task| task.parent_id == Some(parent_id)).map(|task| task.id));
+                    outcomes.push(TaskOperationOutcome { previous: descendant, current: None });
+                }
+            }
+            ChildPolicy::Orphan => {
+                for child in children {
+                    let update = UpdateTask { parent_id: Some(None), ..UpdateTask::default() };
+                    let orphaned = self.update(child.id, update).await?;
+                    outcomes.push(TaskOperationOutcome { previous: Some(child.clone()), current: Some(orphaned) });
+                }
+            }
+        }
+        // 子孫から順に削除し、子タスクを持つタスクの削除が拒否されないようにする
+        for outcome in outcomes.iter().rev().filter(|outcome| outcome.current.is_none()) {
+            if let Some(task) = &outcome.previous {
+                self.delete(task.id).await?;
+            }
+        }
+        Ok(outcomes)
+    }
+
+    /// 条件に合うタスクを1ページ分取得する
+    ///
+    /// 既定の実装は `get_all` の結果をメモリ上で絞り込む。ストレージ側で絞り込める実装は上書きする。
+    async fn query(&self, query: TaskQuery) -> Result<TaskPage, TaskError> {
+        let tasks = self.get_all().await?;
+        Ok(query.apply(tasks))
+    }
+
+    /// 全体・完了済みのタスク数を数える
+    ///
+    /// 既定の実装は `get_all` の結果から数える。ストレージ側で数えられる実装は上書きする。
+    async fn count_tasks(&self) -> Result<TaskCounts, TaskError> {
+        let tasks = self.get_all().await?;
+        Ok(TaskCounts {
+            total: tasks.len(),
+            completed: tasks.iter().filter(|task| task.completed).count(),
+        })
+    }
+
+    /// ストレージに到達でき、リクエストを処理できる状態か確かめる
+    ///
+    /// 既定の実装は1件だけ取得してみる。より軽い確認ができる実装は上書きする。
+    async fn ping(&self) -> Result<(), TaskError> {
+        self.query(TaskQuery { limit: Some(1), ..TaskQuery::default() }).await.map(|_| ())
+    }
+
+    /// 使用しているストレージの種類（`memory` や `sqlite`）
+    fn backend_name(&self) -> &'static str {
+        "custom"
+    }
+
+    /// サーバーの停止時に呼ばれ、書き込みを永続化してリソースを解放する
+    ///
+    /// 既定の実装は何もしない。停止後に他のメソッドが呼ばれることは想定しない。
+    async fn shutdown(&self) -> Result<(), TaskError> {
+        Ok(())
+    }
+
+    /// 複数の操作を順に実行する
+    ///
+    /// 既定の実装は1件ずつ既存のメソッドを呼ぶため `BatchMode::BestEffort` にしか対応しない。
+    /// 操作をまとめて取り消せる実装は上書きする。
+    async fn apply_batch(&self, operations: Vec<TaskOperation>, mode: BatchMode) -> Result<BatchOutcome, TaskError> {
+        if mode == BatchMode::Atomic {
+            return Err(TaskError::InvalidOperation("this repository does not support atomic batches".to_string()));
+        }
+        let mut results = Vec::with_capacity(operations.len());
+        for operation in operations {
+            let result = match operation {
+                TaskOperation::Create(create_task) => self
+                    .create(create_task)
+                    .await
+                    .map(|task| TaskOperationOutcome { previous: None, current: Some(task) }),
+                TaskOperation::Update { id, update } => match self.get_by_id(id).await {
+                    Ok(previous) => self
+                        .update(id, update)
+                        .await
+                        .map(|task| TaskOperationOutcome { previous: Some(previous), current: Some(task) }),
+                    Err(e) => Err(e),
+                },
+                TaskOperation::Complete(id) | TaskOperation::Uncomplete(id) => {
+                    let completed = matches!(operation, TaskOperation::Complete(_));
+                    match self.get_by_id(id).await {
+                        Ok(previous) => {
+                            let result = if completed { self.complete(id).await } else { self.uncomplete(id).await };
+                            result.map(|task| TaskOperationOutcome { previous: Some(previous), current: Some(task) })
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+                TaskOperation::Delete(id) => match self.get_by_id(id).await {
+                    Ok(previous) => self
+                        .delete(id)
+                        .await
+                        .map(|()| TaskOperationOutcome { previous: Some(previous), current: None }),
+                    Err(e) => Err(e),
+                },
+            };
+            results.push(result);
+        }
+        Ok(BatchOutcome { committed: true, results })
+    }
+
+    /// ゴミ箱にあるタスクを、ゴミ箱に移した日時の新しい順に取得する
+    ///
+    /// 既定の実装は空を返す。ゴミ箱を持たない実装では `delete` でタスクが完全に消える。
+    async fn get_trash(&self) -> Result<Vec<Task>, TaskError> {
+        Ok(Vec::new())
+    }
+
+    /// ゴミ箱にあるタスクを元に戻す（ゴミ箱になければ `NotFound`）
+    async fn restore(&self, id: u64) -> Result<Task, TaskError> {
+        Err(TaskError::NotFound(id))
+    }
+
+    /// ゴミ箱のタスクを完全に削除し、削除した件数を返す
+    ///
+    /// `deleted_before` を指定した場合は、その日時より前にゴミ箱へ移したタスクだけを削除する。
+    /// `owner_id` を指定した場合は、その利用者のタスクだけを削除する。
+    async fn purge_trash(&self, _deleted_before: Option<DateTime<Utc>>, _owner_id: Option<u64>) -> Result<usize, TaskError> {
+        Ok(0)
+    }
+
+    /// タスクの変更履歴を記録した順に取得する
+    ///
+    /// 既定の実装は履歴を持たず、常に空を返す。履歴を記録するラッパーが上書きする。
+    async fn get_history(&self, _id: u64) -> Result<Vec<TaskHistoryEntry>, TaskError> {
+        Ok(Vec::new())
+    }
+
+    /// 使われているタグとタスク数をタグ名の順に取得する
+    ///
+    /// 既定の実装は `get_all` の結果から数える。索引を持つ実装は上書きする。
+    async fn get_tag_counts(&self) -> Result<Vec<TagCount>, TaskError> {
+        Ok(TagCount::count(self.get_all().await?))
+    }
 } 
\ No newline at end of file
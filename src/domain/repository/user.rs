@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use thiserror::Error;
+use crate::domain::model::user::{CreateUser, User, UserValidationError};
+
+#[derive(Debug, Error)]
+pub enum UserError {
+    #[error("User not found with id {0}")]
+    NotFound(u64),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] Box<dyn std::error::Error + Send + Sync>),
+    #[error("Validation error: {0}")]
+    ValidationError(#[from] UserValidationError),
+}
+
+/// 利用者の保存先
+///
+/// 既定の利用者は保存先を開いたときに作られる。
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    /// ID順に取得する
+    async fn get_all(&self) -> Result<Vec<User>, UserError>;
+    async fn get_by_id(&self, id: u64) -> Result<User, UserError>;
+    /// 作成する（同じ名前の利用者がいれば作らない）
+    async fn create(&self, create_user: CreateUser) -> Result<User, UserError>;
+}
+
+/// 利用者も同じ保存先に持つタスクのリポジトリ
+pub trait UserStorage {
+    type Users: UserRepository + Clone + 'static;
+
+    /// 同じ保存先の利用者のリポジトリ
+    fn users(&self) -> Self::Users;
+}
@@ -0,0 +1,10 @@
+//! ビルド時に埋め込まれる情報（build.rs を参照）
+
+/// クレートのバージョン
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// ビルド元のgitコミット（gitがなければ `unknown`）
+pub const GIT_COMMIT: &str = env!("TODO_API_GIT_COMMIT");
+
+/// ビルドした時刻（RFC 3339）
+pub const BUILD_TIME: &str = env!("TODO_API_BUILD_TIME");
@@ -0,0 +1,662 @@
+use std::fmt::Display;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use serde::Deserialize;
+use thiserror::Error;
+use tracing::level_filters::LevelFilter;
+use crate::domain::model::api_key::ApiKeyScope;
+
+/// 設定ファイルの場所を指定する環境変数
+pub const CONFIG_PATH_ENV: &str = "TODO_API_CONFIG";
+/// 待ち受けアドレスを上書きする環境変数
+pub const BIND_ENV: &str = "TODO_API_BIND";
+/// ストレージの種類を上書きする環境変数
+pub const STORAGE_ENV: &str = "TODO_API_STORAGE";
+/// SQLiteファイルの場所を上書きする環境変数
+pub const DB_PATH_ENV: &str = "TODO_API_DB_PATH";
+/// ログレベルを上書きする環境変数
+pub const LOG_LEVEL_ENV: &str = "TODO_API_LOG_LEVEL";
+/// ログの出力形式を上書きする環境変数
+pub const LOG_FORMAT_ENV: &str = "TODO_API_LOG_FORMAT";
+/// CORSで許可するオリジン（カンマ区切り）を上書きする環境変数
+pub const CORS_ORIGINS_ENV: &str = "TODO_API_CORS_ORIGINS";
+/// リクエスト本文の上限バイト数を上書きする環境変数
+pub const MAX_BODY_BYTES_ENV: &str = "TODO_API_MAX_BODY_BYTES";
+/// 停止時に処理中のリクエストを待つ秒数を上書きする環境変数
+pub const SHUTDOWN_TIMEOUT_ENV: &str = "TODO_API_SHUTDOWN_TIMEOUT_SECS";
+/// APIキーを必須にするかを上書きする環境変数
+pub const REQUIRE_API_KEY_ENV: &str = "TODO_API_REQUIRE_API_KEY";
+/// 管理用のAPIキーを渡す環境変数
+pub const ADMIN_KEY_ENV: &str = "TODO_API_ADMIN_KEY";
+/// JWTの検証に使う鍵ファイル（HS256の共有鍵かRS256の公開鍵）を上書きする環境変数
+pub const JWT_KEY_FILE_ENV: &str = "TODO_API_JWT_KEY_FILE";
+/// JWTの検証に使うJWKSファイルを上書きする環境変数
+pub const JWT_JWKS_FILE_ENV: &str = "TODO_API_JWT_JWKS_FILE";
+/// JWTの署名アルゴリズムを上書きする環境変数
+pub const JWT_ALGORITHM_ENV: &str = "TODO_API_JWT_ALGORITHM";
+/// JWTの `iss` に求める発行者を上書きする環境変数
+pub const JWT_ISSUER_ENV: &str = "TODO_API_JWT_ISSUER";
+/// JWTの `aud` に求める受信者を上書きする環境変数
+pub const JWT_AUDIENCE_ENV: &str = "TODO_API_JWT_AUDIENCE";
+/// ログインしたセッションの有効秒数を上書きする環境変数
+pub const SESSION_TTL_ENV: &str = "TODO_API_SESSION_TTL_SECS";
+/// 流量制限を有効にするかを指定する環境変数
+pub const RATE_LIMIT_ENABLED_ENV: &str = "TODO_API_RATE_LIMIT_ENABLED";
+
+/// リクエスト本文の上限の既定値（axumの既定と同じ2MiB）
+pub const DEFAULT_MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+/// 停止時に処理中のリクエストを待つ秒数の既定値
+pub const DEFAULT_SHUTDOWN_TIMEOUT_SECS: u64 = 30;
+/// 管理用のAPIキーの最小文字数（推測されにくい長さを求める）
+pub const MIN_ADMIN_KEY_LENGTH: usize = 16;
+/// JWTの `exp` と `nbf` で許す時計のずれ（秒）の既定値
+pub const DEFAULT_JWT_LEEWAY_SECS: u64 = 30;
+/// JWTに与える最も広い範囲（APIキーの管理は管理用キーか admin のAPIキーでしか行えない）
+pub const MAX_JWT_SCOPE: ApiKeyScope = ApiKeyScope::Write;
+/// ログインしたセッションの有効秒数の既定値（8時間）
+pub const DEFAULT_SESSION_TTL_SECS: u64 = 8 * 60 * 60;
+/// セッションIDを新しくするまでの秒数の既定値
+pub const DEFAULT_SESSION_ROTATE_AFTER_SECS: u64 = 15 * 60;
+/// 参照の流量制限の既定値（1分あたりのリクエスト数と、まとめて送れる数）
+pub const DEFAULT_READ_RATE_LIMIT: RateLimitBudget = RateLimitBudget { requests_per_minute: 600, burst: 100 };
+/// 変更の流量制限の既定値（1分あたりのリクエスト数と、まとめて送れる数）
+pub const DEFAULT_WRITE_RATE_LIMIT: RateLimitBudget = RateLimitBudget { requests_per_minute: 60, burst: 20 };
+/// 接続元ごとの流量制限の既定値（参照と変更をまとめて数え、同じ接続元の複数の依頼元が収まるようにする）
+pub const DEFAULT_PER_ADDRESS_RATE_LIMIT: RateLimitBudget = RateLimitBudget { requests_per_minute: 1200, burst: 200 };
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read config file {path}: {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("failed to parse config file {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+    #[error("invalid value {value:?} for {name}: {reason}")]
+    InvalidValue { name: String, value: String, reason: String },
+}
+
+impl ConfigError {
+    fn invalid(name: &str, value: impl Display, reason: impl Display) -> Self {
+        ConfigError::InvalidValue {
+            name: name.to_string(),
+            value: value.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// タスクの保存先
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// プロセス内のメモリ（再起動で消える）
+    #[default]
+    Memory,
+    /// SQLiteファイル
+    Sqlite,
+}
+
+impl FromStr for StorageBackend {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "memory" => Ok(StorageBackend::Memory),
+            "sqlite" => Ok(StorageBackend::Sqlite),
+            _ => Err("expected one of: memory, sqlite".to_string()),
+        }
+    }
+}
+
+/// ログの出力レベル
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// tracing のレベルフィルタに変換
+    pub fn to_level_filter(self) -> LevelFilter {
+        match self {
+            LogLevel::Trace => LevelFilter::TRACE,
+            LogLevel::Debug => LevelFilter::DEBUG,
+            LogLevel::Info => LevelFilter::INFO,
+            LogLevel::Warn => LevelFilter::WARN,
+            LogLevel::Error => LevelFilter::ERROR,
+        }
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "trace" => Ok(LogLevel::Trace),
+            "debug" => Ok(LogLevel::Debug),
+            "info" => Ok(LogLevel::Info),
+            "warn" => Ok(LogLevel::Warn),
+            "error" => Ok(LogLevel::Error),
+            _ => Err("expected one of: trace, debug, info, warn, error".to_string()),
+        }
+    }
+}
+
+/// ログの出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// 人が読むための複数行の形式
+    #[default]
+    Pretty,
+    /// 1行1イベントのJSON（ログ収集基盤向け）
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "pretty" => Ok(LogFormat::Pretty),
+            "json" => Ok(LogFormat::Json),
+            _ => Err("expected one of: pretty, json".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    /// 待ち受けるアドレス
+    pub bind: SocketAddr,
+    /// 停止の合図を受けてから処理中のリクエストを待つ秒数
+    pub shutdown_timeout_secs: u64,
+}
+
+impl ServerConfig {
+    pub fn shutdown_timeout(&self) -> Duration {
+        Duration::from_secs(self.shutdown_timeout_secs)
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind: SocketAddr::from(([127, 0, 0, 1], 3000)),
+            shutdown_timeout_secs: DEFAULT_SHUTDOWN_TIMEOUT_SECS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    /// SQLiteファイルの場所（backend = "sqlite" のときだけ使う）
+    pub path: PathBuf,
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self {
+            backend: StorageBackend::default(),
+            path: PathBuf::from("todo.db"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    pub level: LogLevel,
+    pub format: LogFormat,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CorsConfig {
+    /// 許可するオリジン（空ならCORSを無効にする、"*" ならすべて許可する）
+    pub allowed_origins: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitsConfig {
+    /// リクエスト本文の上限バイト数
+    pub max_body_bytes: usize,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self { max_body_bytes: DEFAULT_MAX_BODY_BYTES }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuthConfig {
+    /// ヘルスチェックとバージョン以外のすべてのリクエストにAPIキーを求めるか
+    pub require_api_key: bool,
+    /// 管理用のAPIキー（既定の利用者として admin の範囲で扱う。ファイルより環境変数で渡す）
+    pub admin_key: Option<String>,
+    pub jwt: JwtConfig,
+    pub session: SessionConfig,
+}
+
+impl AuthConfig {
+    /// 認証を行うか（APIキーを必須にしたか、JWTの鍵を設定したとき）
+    pub fn is_enabled(&self) -> bool {
+        self.require_api_key || self.jwt.is_enabled()
+    }
+}
+
+/// JWTの署名アルゴリズム
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum JwtAlgorithm {
+    /// 共有鍵による HMAC-SHA256
+    HS256,
+    /// RSA公開鍵による RSASSA-PKCS1-v1_5 SHA-256
+    RS256,
+}
+
+impl FromStr for JwtAlgorithm {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_uppercase().as_str() {
+            "HS256" => Ok(JwtAlgorithm::HS256),
+            "RS256" => Ok(JwtAlgorithm::RS256),
+            _ => Err("expected one of: HS256, RS256".to_string()),
+        }
+    }
+}
+
+/// 他のサービスが発行したJWTの検証（鍵はローカルのファイルから読み、ネットワークからは取得しない）
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JwtConfig {
+    /// HS256の共有鍵か、RS256の公開鍵（PEM）のファイル
+    pub key_file: Option<PathBuf>,
+    /// 鍵の集合（JWKS）のファイル（`kid` で鍵を選ぶ）
+    pub jwks_file: Option<PathBuf>,
+    /// 署名アルゴリズム（key_file では必須、JWKS では `alg` のない鍵に使う）
+    pub algorithm: Option<JwtAlgorithm>,
+    /// `iss` に求める発行者
+    pub issuer: Option<String>,
+    /// `aud` に求める受信者
+    pub audience: Option<String>,
+    /// `scope` クレームがないトークンに与える範囲（`MAX_JWT_SCOPE` より広くはできない）
+    pub default_scope: ApiKeyScope,
+    /// `exp` と `nbf` で許す時計のずれ（秒）
+    pub leeway_secs: u64,
+}
+
+impl JwtConfig {
+    /// 鍵が設定され、JWTを受け付けるか
+    pub fn is_enabled(&self) -> bool {
+        self.key_file.is_some() || self.jwks_file.is_some()
+    }
+}
+
+impl Default for JwtConfig {
+    fn default() -> Self {
+        Self {
+            key_file: None,
+            jwks_file: None,
+            algorithm: None,
+            issuer: None,
+            audience: None,
+            default_scope: ApiKeyScope::Read,
+            leeway_secs: DEFAULT_JWT_LEEWAY_SECS,
+        }
+    }
+}
+
+/// `POST /auth/login` で始めるクッキーのセッション（セッションはメモリ上に持ち、再起動で消える）
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SessionConfig {
+    /// ログインしてからセッションが切れるまでの秒数
+    pub ttl_secs: u64,
+    /// セッションIDを新しくするまでの秒数（盗まれたIDを使える期間を短くする）
+    pub rotate_after_secs: u64,
+    /// クッキーに Secure を付けるか（HTTPSでない開発環境では false にする）
+    pub secure_cookie: bool,
+}
+
+impl SessionConfig {
+    pub fn ttl(&self) -> Duration {
+        Duration::from_secs(self.ttl_secs)
+    }
+
+    pub fn rotate_after(&self) -> Duration {
+        Duration::from_secs(self.rotate_after_secs)
+    }
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            ttl_secs: DEFAULT_SESSION_TTL_SECS,
+            rotate_after_secs: DEFAULT_SESSION_ROTATE_AFTER_SECS,
+            secure_cookie: true,
+        }
+    }
+}
+
+/// 流量制限の予算（トークンバケット）
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RateLimitBudget {
+    /// 1分あたりに補充するリクエスト数
+    pub requests_per_minute: u32,
+    /// 続けて送れるリクエスト数（バケットの容量）
+    pub burst: u32,
+}
+
+/// 依頼元ごとの流量制限（APIキー、認証した利用者、接続元のIPアドレスの順に依頼元を決める）
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    /// GET・HEAD・OPTIONS の予算
+    pub read: RateLimitBudget,
+    /// それ以外のメソッドの予算
+    pub write: RateLimitBudget,
+    /// 接続元のIPアドレスごとの予算（認証の前に数えるため、認証に失敗したリクエストも含む）
+    pub per_address: RateLimitBudget,
+    /// `X-Forwarded-For` の先頭を接続元とみなすか（信頼できるプロキシの後ろで動かすときだけ有効にする）
+    pub trust_forwarded_for: bool,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            read: DEFAULT_READ_RATE_LIMIT,
+            write: DEFAULT_WRITE_RATE_LIMIT,
+            per_address: DEFAULT_PER_ADDRESS_RATE_LIMIT,
+            trust_forwarded_for: false,
+        }
+    }
+}
+
+/// サーバーの設定
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub server: ServerConfig,
+    pub storage: StorageConfig,
+    pub log: LogConfig,
+    pub cors: CorsConfig,
+    pub limits: LimitsConfig,
+    pub auth: AuthConfig,
+    pub rate_limit: RateLimitConfig,
+}
+
+/// コマンドラインフラグによる上書き（指定されたものだけ反映する）
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct ConfigOverrides {
+    /// 待ち受けるアドレス（例: 0.0.0.0:8080）
+    #[arg(long, value_name = "ADDR")]
+    pub bind: Option<SocketAddr>,
+    /// 停止時に処理中のリクエストを待つ秒数
+    #[arg(long, value_name = "SECS")]
+    pub shutdown_timeout_secs: Option<u64>,
+    /// ストレージの種類（memory または sqlite）
+    #[arg(long, value_name = "BACKEND")]
+    pub storage: Option<StorageBackend>,
+    /// SQLiteファイルの場所
+    #[arg(long, value_name = "FILE")]
+    pub db_path: Option<PathBuf>,
+    /// ログレベル（trace, debug, info, warn, error）
+    #[arg(long, value_name = "LEVEL")]
+    pub log_level: Option<LogLevel>,
+    /// ログの出力形式（pretty または json）
+    #[arg(long, value_name = "FORMAT")]
+    pub log_format: Option<LogFormat>,
+    /// CORSで許可するオリジン（複数指定可）
+    #[arg(long = "cors-origin", value_name = "ORIGIN")]
+    pub cors_origins: Vec<String>,
+    /// リクエスト本文の上限バイト数
+    #[arg(long, value_name = "BYTES")]
+    pub max_body_bytes: Option<usize>,
+    /// ヘルスチェックとバージョン以外のすべてのリクエストにAPIキーを求める
+    #[arg(long)]
+    pub require_api_key: bool,
+}
+
+impl Config {
+    /// 既定値 → 設定ファイル → 環境変数 → コマンドラインの順に重ねて設定を組み立てる
+    pub fn load(config_path: Option<&Path>, overrides: &ConfigOverrides) -> Result<Self, ConfigError> {
+        Self::load_with_env(config_path, overrides, |name| std::env::var(name).ok())
+    }
+
+    /// 環境変数の読み出し方を指定して設定を組み立てる
+    pub fn load_with_env<F>(config_path: Option<&Path>, overrides: &ConfigOverrides, env: F) -> Result<Self, ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let config_path = config_path
+            .map(Path::to_path_buf)
+            .or_else(|| env(CONFIG_PATH_ENV).map(PathBuf::from));
+        let mut config = match config_path {
+            Some(path) => Self::from_file(&path)?,
+            None => Self::default(),
+        };
+        config.apply_env(&env)?;
+        config.apply_overrides(overrides);
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// TOMLファイルから設定を読み込む（書かれていない項目は既定値）
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|source| ConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        toml::from_str(&content).map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    fn apply_env<F>(&mut self, env: &F) -> Result<(), ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        if let Some(bind) = parse_env(env, BIND_ENV)? {
+            self.server.bind = bind;
+        }
+        if let Some(timeout) = parse_env(env, SHUTDOWN_TIMEOUT_ENV)? {
+            self.server.shutdown_timeout_secs = timeout;
+        }
+        if let Some(backend) = parse_env(env, STORAGE_ENV)? {
+            self.storage.backend = backend;
+        }
+        if let Some(path) = env(DB_PATH_ENV) {
+            self.storage.path = PathBuf::from(path);
+        }
+        if let Some(level) = parse_env(env, LOG_LEVEL_ENV)? {
+            self.log.level = level;
+        }
+        if let Some(format) = parse_env(env, LOG_FORMAT_ENV)? {
+            self.log.format = format;
+        }
+        if let Some(origins) = env(CORS_ORIGINS_ENV) {
+            self.cors.allowed_origins = origins
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+        if let Some(max_body_bytes) = parse_env(env, MAX_BODY_BYTES_ENV)? {
+            self.limits.max_body_bytes = max_body_bytes;
+        }
+        if let Some(require_api_key) = parse_env(env, REQUIRE_API_KEY_ENV)? {
+            self.auth.require_api_key = require_api_key;
+        }
+        if let Some(admin_key) = env(ADMIN_KEY_ENV) {
+            self.auth.admin_key = Some(admin_key);
+        }
+        if let Some(key_file) = env(JWT_KEY_FILE_ENV) {
+            self.auth.jwt.key_file = Some(PathBuf::from(key_file));
+        }
+        if let Some(jwks_file) = env(JWT_JWKS_FILE_ENV) {
+            self.auth.jwt.jwks_file = Some(PathBuf::from(jwks_file));
+        }
+        if let Some(algorithm) = parse_env(env, JWT_ALGORITHM_ENV)? {
+            self.auth.jwt.algorithm = Some(algorithm);
+        }
+        if let Some(issuer) = env(JWT_ISSUER_ENV) {
+            self.auth.jwt.issuer = Some(issuer);
+        }
+        if let Some(audience) = env(JWT_AUDIENCE_ENV) {
+            self.auth.jwt.audience = Some(audience);
+        }
+        if let Some(ttl_secs) = parse_env(env, SESSION_TTL_ENV)? {
+            self.auth.session.ttl_secs = ttl_secs;
+        }
+        if let Some(enabled) = parse_env(env, RATE_LIMIT_ENABLED_ENV)? {
+            self.rate_limit.enabled = enabled;
+        }
+        Ok(())
+    }
+
+    fn apply_overrides(&mut self, overrides: &ConfigOverrides) {
+        if let Some(bind) = overrides.bind {
+            self.server.bind = bind;
+        }
+        if let Some(timeout) = overrides.shutdown_timeout_secs {
+            self.server.shutdown_timeout_secs = timeout;
+        }
+        if let Some(backend) = overrides.storage {
+            self.storage.backend = backend;
+        }
+        if let Some(path) = &overrides.db_path {
+            self.storage.path = path.clone();
+        }
+        if let Some(level) = overrides.log_level {
+            self.log.level = level;
+        }
+        if let Some(format) = overrides.log_format {
+            self.log.format = format;
+        }
+        if !overrides.cors_origins.is_empty() {
+            self.cors.allowed_origins = overrides.cors_origins.clone();
+        }
+        if let Some(max_body_bytes) = overrides.max_body_bytes {
+            self.limits.max_body_bytes = max_body_bytes;
+        }
+        if overrides.require_api_key {
+            self.auth.require_api_key = true;
+        }
+    }
+
+    /// 組み合わせとして正しいか確かめる
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.storage.backend == StorageBackend::Sqlite && self.storage.path.as_os_str().is_empty() {
+            return Err(ConfigError::invalid("storage.path", "", "a file path is required for the sqlite backend"));
+        }
+
+        for origin in &self.cors.allowed_origins {
+            if origin == "*" {
+                continue;
+            }
+            let is_http = origin.starts_with("http://") || origin.starts_with("https://");
+            if !is_http || origin.ends_with('/') || http::HeaderValue::from_str(origin).is_err() {
+                return Err(ConfigError::invalid(
+                    "cors.allowed_origins",
+                    origin,
+                    "expected \"*\" or an origin such as https://example.com",
+                ));
+            }
+        }
+
+        if self.limits.max_body_bytes == 0 {
+            return Err(ConfigError::invalid("limits.max_body_bytes", 0, "must be greater than zero"));
+        }
+
+        if let Some(admin_key) = &self.auth.admin_key {
+            // 鍵そのものはエラーに載せない
+            if admin_key.trim().len() < MIN_ADMIN_KEY_LENGTH {
+                return Err(ConfigError::invalid(
+                    "auth.admin_key",
+                    "<redacted>",
+                    format!("must be at least {} characters", MIN_ADMIN_KEY_LENGTH),
+                ));
+            }
+        }
+
+        let jwt = &self.auth.jwt;
+        if let (Some(key_file), Some(_)) = (&jwt.key_file, &jwt.jwks_file) {
+            return Err(ConfigError::invalid("auth.jwt.key_file", key_file.display(), "cannot be combined with auth.jwt.jwks_file"));
+        }
+        if !MAX_JWT_SCOPE.allows(jwt.default_scope) {
+            return Err(ConfigError::invalid("auth.jwt.default_scope", jwt.default_scope, format!("cannot be wider than {}", MAX_JWT_SCOPE)));
+        }
+        if jwt.is_enabled() {
+            if jwt.key_file.is_some() && jwt.algorithm.is_none() {
+                return Err(ConfigError::invalid("auth.jwt.algorithm", "", "is required with auth.jwt.key_file"));
+            }
+            // 他のサービス向けのトークンを受け付けないよう、発行者と受信者は必ず確かめる
+            for (name, value) in [("auth.jwt.issuer", &jwt.issuer), ("auth.jwt.audience", &jwt.audience)] {
+                if value.as_deref().is_none_or(|value| value.trim().is_empty()) {
+                    return Err(ConfigError::invalid(name, "", "is required when JWT verification is enabled"));
+                }
+            }
+        }
+
+        let session = &self.auth.session;
+        if session.ttl_secs == 0 {
+            return Err(ConfigError::invalid("auth.session.ttl_secs", 0, "must be greater than zero"));
+        }
+        if session.rotate_after_secs == 0 {
+            return Err(ConfigError::invalid("auth.session.rotate_after_secs", 0, "must be greater than zero"));
+        }
+
+        let budgets = [
+            ("rate_limit.read", &self.rate_limit.read),
+            ("rate_limit.write", &self.rate_limit.write),
+            ("rate_limit.per_address", &self.rate_limit.per_address),
+        ];
+        for (name, budget) in budgets {
+            if budget.requests_per_minute == 0 {
+                return Err(ConfigError::invalid(&format!("{}.requests_per_minute", name), 0, "must be greater than zero"));
+            }
+            if budget.burst == 0 {
+                return Err(ConfigError::invalid(&format!("{}.burst", name), 0, "must be greater than zero"));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_env<F, T>(env: &F, name: &str) -> Result<Option<T>, ConfigError>
+where
+    F: Fn(&str) -> Option<String>,
+    T: FromStr,
+    T::Err: Display,
+{
+    env(name)
+        .map(|value| value.trim().parse().map_err(|e| ConfigError::invalid(name, &value, e)))
+        .transpose()
+}
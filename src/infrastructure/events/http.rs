@@ -0,0 +1,75 @@
+use std::convert::Infallible;
+use std::sync::Arc;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use futures_util::stream::{self, StreamExt};
+use tokio::sync::broadcast::error::RecvError;
+use super::{TaskEvent, TaskEventBroker};
+use crate::infrastructure::http::identity::current_user_id;
+use crate::infrastructure::http::problem;
+use crate::interface::presenter::task::TaskMapper;
+
+/// 再接続したクライアントが最後に受け取ったイベントのID
+const LAST_EVENT_ID: &str = "last-event-id";
+
+fn to_sse_event(event: TaskEvent) -> Event {
+    let task = TaskMapper::domain_to_api(event.task);
+    Event::default()
+        .id(event.id.to_string())
+        .event(event.kind.as_str())
+        .data(serde_json::to_string(&task).unwrap_or_default())
+}
+
+/// タスクの変更を Server-Sent Events で配信する
+///
+/// `Last-Event-ID` を付けて再接続すると、再送バッファに残っている続きのイベントから配信する。
+/// 配信に追いつけなくなった接続は切り、再接続で取りこぼしを再送させる。
+/// 配信するのはリクエストの利用者が持つタスクのイベントだけ。
+#[utoipa::path(
+    get,
+    path = "/tasks/events",
+    tag = "tasks",
+    params(
+        ("Last-Event-ID" = Option<String>, Header, description = "Resume after this event id")
+    ),
+    responses(
+        (status = 200, description = "Stream of task change events (text/event-stream)"),
+        (status = 503, description = "The server is shutting down")
+    )
+)]
+pub async fn task_events(State(broker): State<Arc<TaskEventBroker>>, headers: HeaderMap) -> Response {
+    // ストリームは利用者を決めるミドルウェアの外で流れるため、接続時に利用者を確定しておく
+    let owner_id = current_user_id();
+    let last_event_id = headers
+        .get(LAST_EVENT_ID)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok());
+
+    let Some(subscription) = broker.subscribe(last_event_id) else {
+        return problem::into_response(problem::problem(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "SHUTTING_DOWN",
+            Some("The server is shutting down".to_string()),
+            None,
+        ));
+    };
+
+    let live = stream::unfold(subscription.receiver, |mut receiver| async move {
+        match receiver.recv().await {
+            Ok(event) => Some((event, receiver)),
+            Err(RecvError::Lagged(skipped)) => {
+                tracing::warn!(skipped, "event stream fell behind; closing so the client resumes from Last-Event-ID");
+                None
+            }
+            Err(RecvError::Closed) => None,
+        }
+    });
+    let events = stream::iter(subscription.replay)
+        .chain(live)
+        .filter(move |event| std::future::ready(event.task.owner_id == owner_id))
+        .map(|event| Ok::<_, Infallible>(to_sse_event(event)));
+
+    Sse::new(events).keep_alive(KeepAlive::default()).into_response()
+}
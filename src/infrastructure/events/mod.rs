@@ -0,0 +1,113 @@
+pub mod http;
+
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+use tokio::sync::broadcast;
+use crate::domain::model::event::{TaskChange, TaskChangeKind};
+use crate::domain::model::task::Task;
+use crate::domain::repository::event::TaskChangePublisher;
+
+/// 再接続時に再送できるイベントの数
+pub const DEFAULT_REPLAY_CAPACITY: usize = 1024;
+
+/// 配信するイベント
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaskEvent {
+    /// 1から始まり、イベントごとに1つずつ増える
+    pub id: u64,
+    pub kind: TaskChangeKind,
+    pub task: Task,
+}
+
+/// 購読の開始時点で再送するイベントと、それ以降のイベントの受信口
+pub struct Subscription {
+    pub replay: Vec<TaskEvent>,
+    pub receiver: broadcast::Receiver<TaskEvent>,
+}
+
+struct BrokerState {
+    next_id: u64,
+    /// 直近のイベント（古いものから順に、最大 capacity 件）
+    recent: VecDeque<TaskEvent>,
+    /// 停止後は None（購読中のストリームは終わる）
+    sender: Option<broadcast::Sender<TaskEvent>>,
+}
+
+/// タスクの変更に連番を振り、購読者へ配信する
+///
+/// 採番・再送バッファへの追加・配信を同じロックの中で行うため、購読の開始時に
+/// 再送分と配信分の間でイベントが抜けたり重複したりしない。
+pub struct TaskEventBroker {
+    capacity: usize,
+    state: Mutex<BrokerState>,
+}
+
+impl TaskEventBroker {
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_REPLAY_CAPACITY)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self {
+            capacity,
+            state: Mutex::new(BrokerState {
+                next_id: 1,
+                recent: VecDeque::with_capacity(capacity),
+                sender: Some(sender),
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BrokerState> {
+        // 状態の更新中にパニックしても整合性は崩れないので、毒化したロックもそのまま使う
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// 購読を始める（停止後は None）
+    ///
+    /// `last_event_id` を指定すると、それより後のイベントのうち再送バッファに残っているものを返す。
+    /// 今より新しいID（再起動前のIDなど）が指定された場合はバッファの全件を返す。
+    pub fn subscribe(&self, last_event_id: Option<u64>) -> Option<Subscription> {
+        let state = self.lock();
+        let receiver = state.sender.as_ref()?.subscribe();
+        let replay = match last_event_id {
+            Some(last) if last < state.next_id => {
+                state.recent.iter().filter(|event| event.id > last).cloned().collect()
+            }
+            Some(_) => state.recent.iter().cloned().collect(),
+            None => Vec::new(),
+        };
+        Some(Subscription { replay, receiver })
+    }
+
+    /// 配信を止め、購読中のストリームを終わらせる
+    pub fn close(&self) {
+        self.lock().sender = None;
+    }
+}
+
+impl Default for TaskEventBroker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskChangePublisher for TaskEventBroker {
+    fn publish(&self, change: TaskChange) {
+        let mut state = self.lock();
+        let event = TaskEvent { id: state.next_id, kind: change.kind, task: change.task };
+        state.next_id += 1;
+
+        if self.capacity > 0 {
+            if state.recent.len() == self.capacity {
+                state.recent.pop_front();
+            }
+            state.recent.push_back(event.clone());
+        }
+        if let Some(sender) = &state.sender {
+            // 購読者がいない場合のエラーは無視する
+            let _ = sender.send(event);
+        }
+    }
+}
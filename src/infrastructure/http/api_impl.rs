@@ -1,85 +1,503 @@
 use async_trait::async_trait;
 use axum_extra::extract::{CookieJar, Host};
-use axum::http::Method;
+use axum::http::{Method, StatusCode};
+use std::collections::BTreeSet;
 use std::fmt::Debug;
 
-use crate::domain::model::task::{TaskValidationError};
+use crate::domain::model::api_key::ApiKeyValidationError;
+use crate::domain::model::project::ProjectValidationError;
+use crate::domain::model::task::{Task, TaskNode, TaskValidationError, UpdateTask};
+use crate::domain::model::user::UserValidationError;
+use crate::domain::repository::task::{BatchMode, BatchOutcome, TaskOperationOutcome};
+use crate::usecase::api_key::ApiKeyError;
+use crate::usecase::project::ProjectError;
 use crate::usecase::task::TaskError;
+use crate::usecase::user::UserError;
+use crate::interface::presenter::api_key::ApiKeyMapper;
+use crate::interface::presenter::project::ProjectMapper;
 use crate::interface::presenter::task::TaskMapper;
-use openapi::apis::tasks::{Tasks, TasksCompletedGetResponse, TasksGetResponse, TasksIdCompletePutResponse, TasksIdDeleteResponse, TasksIdGetResponse, TasksIdPutResponse, TasksIdUncompletePutResponse, TasksPendingGetResponse, TasksPostResponse, TasksSearchGetResponse};
-use openapi::models::{TasksIdCompletePutPathParams, TasksIdDeletePathParams, TasksIdGetPathParams, TasksIdPutPathParams, TasksIdUncompletePutPathParams, TasksSearchGetQueryParams};
+use crate::interface::presenter::user::UserMapper;
+use crate::infrastructure::build_info;
+use crate::infrastructure::http::auth::Credential;
+use crate::infrastructure::http::identity::{current_principal, current_user, current_user_id};
+use crate::infrastructure::http::session::{Session, SessionManager, SessionStoreError};
+use crate::infrastructure::http::{etag, problem};
+use openapi::apis::api_keys::{ApiKeys, ApiKeysGetResponse, ApiKeysIdDeleteResponse, ApiKeysPostResponse};
+use openapi::apis::auth::{Auth, AuthLoginPostResponse, AuthLogoutPostResponse, AuthSessionGetResponse};
+use openapi::apis::projects::{Projects, ProjectsGetResponse, ProjectsIdDeleteResponse, ProjectsIdGetResponse, ProjectsIdPutResponse, ProjectsIdTasksGetResponse, ProjectsPostResponse};
+use openapi::apis::tasks::{TagsGetResponse, Tasks, TasksBulkPostResponse, TasksCompletedGetResponse, TasksDueSoonGetResponse, TasksGetResponse, TasksIdChildrenGetResponse, TasksIdCompletePutResponse, TasksIdDeleteResponse, TasksIdDependenciesDependencyIdDeleteResponse, TasksIdDependenciesPostResponse, TasksIdGetResponse, TasksIdHistoryGetResponse, TasksIdOccurrencesGetResponse, TasksIdPutResponse, TasksIdRecurrenceDeleteResponse, TasksIdRestorePostResponse, TasksIdTagsPostResponse, TasksIdTagsTagDeleteResponse, TasksIdUncompletePutResponse, TasksOverdueGetResponse, TasksPendingGetResponse, TasksPostResponse, TasksReadyGetResponse, TasksSearchGetResponse, TasksTrashDeleteResponse, TasksTrashGetResponse, TasksTreeGetResponse};
+use openapi::apis::system::{HealthzGetResponse, ReadyzGetResponse, System, VersionGetResponse};
+use openapi::apis::users::{Users, UsersGetResponse, UsersMeGetResponse, UsersPostResponse};
+use openapi::models::{ApiKeysIdDeletePathParams, BulkItemResult, Task as ApiTask, BulkItemStatus, BulkOperationType, BulkResult, HealthStatus, Problem, ProjectsIdDeletePathParams, ProjectsIdGetPathParams, ProjectsIdPutPathParams, ProjectsIdTasksGetPathParams, ProjectsIdTasksGetQueryParams, TasksDueSoonGetQueryParams, TasksIdChildrenGetPathParams, TasksIdCompletePutHeaderParams, TasksIdCompletePutPathParams, TasksIdCompletePutQueryParams, TasksIdDeleteHeaderParams, TasksIdDeletePathParams, TasksIdDeleteQueryParams, TasksIdDependenciesDependencyIdDeletePathParams, TasksIdDependenciesPostPathParams, TasksIdGetHeaderParams, TasksIdGetPathParams, TasksIdHistoryGetPathParams, TasksIdOccurrencesGetPathParams, TasksIdOccurrencesGetQueryParams, TasksIdPutHeaderParams, TasksIdPutPathParams, TasksIdRecurrenceDeletePathParams, TasksIdRestorePostPathParams, TasksIdTagsPostPathParams, TasksIdTagsTagDeletePathParams, TasksIdUncompletePutHeaderParams, TasksIdUncompletePutPathParams, TasksGetQueryParams, TasksSearchGetQueryParams, TasksTrashDeleteQueryParams, PurgeResult, VersionInfo};
 use tracing;
 
+/// 期限が近いタスクの取得で期間の指定がない場合の時間数
+const DEFAULT_DUE_SOON_HOURS: i32 = 24;
+
 /// API実装のエラー型
 #[derive(Debug, thiserror::Error)]
 pub enum ApiError {
-    #[error("Task not found: {0}")]
-    TaskNotFound(String),
-    #[error("Validation error: {0}")]
-    ValidationError(String),
+    #[error("Task with id {0} not found")]
+    TaskNotFound(u64),
+    #[error("Task with id {0} belongs to another user")]
+    Forbidden(u64),
+    #[error("{0}")]
+    ValidationError(TaskValidationError),
+    #[error("{0}")]
+    InvalidOperation(String),
+    #[error("Invalid pagination cursor")]
+    InvalidCursor,
+    #[error("{field} must not be negative, got {value}")]
+    InvalidPathId { field: &'static str, value: i64 },
+    #[error("Task with id {0} has been modified since the given ETag")]
+    PreconditionFailed(u64),
+    #[error("Task with id {0} has subtasks; delete them with children=cascade or detach them with children=orphan")]
+    HasChildren(u64),
+    #[error("Task with id {id} depends on incomplete tasks {blockers:?}; complete them first or use force=true")]
+    Blocked { id: u64, blockers: Vec<u64> },
+    #[error("Project with id {0} not found")]
+    ProjectNotFound(u64),
+    #[error("Project with id {0} belongs to another user")]
+    ProjectForbidden(u64),
+    #[error("{0}")]
+    ProjectValidation(ProjectValidationError),
+    #[error("Project with id {0} still has tasks; move or delete them first")]
+    ProjectNotEmpty(u64),
+    #[error("{0}")]
+    UserValidation(UserValidationError),
+    #[error("The request is not made by a known user")]
+    UnknownUser,
+    #[error("API key with id {0} not found")]
+    ApiKeyNotFound(u64),
+    #[error("{0}")]
+    ApiKeyValidation(ApiKeyValidationError),
+    #[error("No session cookie was sent, or the session has ended")]
+    NoSession,
+    #[error("Logging in requires an API key, the admin key or a bearer token")]
+    CredentialsRequired,
     #[error("Internal server error: {0}")]
     InternalError(String),
 }
 
+impl ApiError {
+    /// レスポンスのHTTPステータス
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::TaskNotFound(_) | ApiError::ProjectNotFound(_) | ApiError::ApiKeyNotFound(_) | ApiError::NoSession => {
+                StatusCode::NOT_FOUND
+            }
+            ApiError::Forbidden(_) | ApiError::ProjectForbidden(_) => StatusCode::FORBIDDEN,
+            ApiError::UnknownUser | ApiError::CredentialsRequired => StatusCode::UNAUTHORIZED,
+            ApiError::ProjectValidation(ProjectValidationError::DuplicateName(_))
+            | ApiError::UserValidation(UserValidationError::DuplicateName(_)) => StatusCode::CONFLICT,
+            ApiError::ValidationError(_)
+            | ApiError::ProjectValidation(_)
+            | ApiError::UserValidation(_)
+            | ApiError::ApiKeyValidation(_)
+            | ApiError::InvalidOperation(_)
+            | ApiError::InvalidCursor
+            | ApiError::InvalidPathId { .. } => StatusCode::BAD_REQUEST,
+            ApiError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            ApiError::HasChildren(_) | ApiError::Blocked { .. } | ApiError::ProjectNotEmpty(_) => StatusCode::CONFLICT,
+            ApiError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// クライアントが分岐に使う安定したエラーコード
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::TaskNotFound(_) => "TASK_NOT_FOUND",
+            ApiError::Forbidden(_) => "TASK_FORBIDDEN",
+            ApiError::ValidationError(TaskValidationError::EmptyDescription) => "DESCRIPTION_EMPTY",
+            ApiError::ValidationError(TaskValidationError::DescriptionTooLong(_)) => "DESCRIPTION_TOO_LONG",
+            ApiError::ValidationError(TaskValidationError::DueBeforeCreation) => "DUE_AT_BEFORE_CREATION",
+            ApiError::ValidationError(TaskValidationError::InvalidTag(_)) => "INVALID_TAG",
+            ApiError::ValidationError(TaskValidationError::TooManyTags(_)) => "TOO_MANY_TAGS",
+            ApiError::ValidationError(TaskValidationError::ParentNotFound(_)) => "PARENT_NOT_FOUND",
+            ApiError::ValidationError(TaskValidationError::ParentCycle(_)) => "PARENT_CYCLE",
+            ApiError::ValidationError(TaskValidationError::DependencyNotFound(_)) => "DEPENDENCY_NOT_FOUND",
+            ApiError::ValidationError(TaskValidationError::DependencyCycle(_)) => "DEPENDENCY_CYCLE",
+            ApiError::ValidationError(TaskValidationError::InvalidRecurrence(_)) => "INVALID_RECURRENCE",
+            ApiError::ValidationError(TaskValidationError::ProjectNotFound(_)) => "PROJECT_NOT_FOUND",
+            ApiError::InvalidOperation(_) => "INVALID_OPERATION",
+            ApiError::InvalidCursor => "INVALID_CURSOR",
+            ApiError::InvalidPathId { .. } => "INVALID_PATH_ID",
+            ApiError::PreconditionFailed(_) => "PRECONDITION_FAILED",
+            ApiError::HasChildren(_) => "TASK_HAS_CHILDREN",
+            ApiError::Blocked { .. } => "TASK_BLOCKED",
+            ApiError::ProjectNotFound(_) => "PROJECT_NOT_FOUND",
+            ApiError::ProjectForbidden(_) => "PROJECT_FORBIDDEN",
+            ApiError::ProjectValidation(ProjectValidationError::EmptyName) => "PROJECT_NAME_EMPTY",
+            ApiError::ProjectValidation(ProjectValidationError::NameTooLong(_)) => "PROJECT_NAME_TOO_LONG",
+            ApiError::ProjectValidation(ProjectValidationError::DuplicateName(_)) => "PROJECT_NAME_TAKEN",
+            ApiError::ProjectNotEmpty(_) => "PROJECT_NOT_EMPTY",
+            ApiError::UserValidation(UserValidationError::EmptyName) => "USER_NAME_EMPTY",
+            ApiError::UserValidation(UserValidationError::NameTooLong(_)) => "USER_NAME_TOO_LONG",
+            ApiError::UserValidation(UserValidationError::InvalidName(_)) => "USER_NAME_INVALID",
+            ApiError::UserValidation(UserValidationError::DuplicateName(_)) => "USER_NAME_TAKEN",
+            ApiError::UnknownUser => "UNKNOWN_USER",
+            ApiError::ApiKeyNotFound(_) => "API_KEY_NOT_FOUND",
+            ApiError::ApiKeyValidation(ApiKeyValidationError::EmptyName) => "API_KEY_NAME_EMPTY",
+            ApiError::ApiKeyValidation(ApiKeyValidationError::NameTooLong(_)) => "API_KEY_NAME_TOO_LONG",
+            ApiError::ApiKeyValidation(ApiKeyValidationError::UserNotFound(_)) => "USER_NOT_FOUND",
+            ApiError::NoSession => "NO_SESSION",
+            ApiError::CredentialsRequired => "CREDENTIALS_REQUIRED",
+            ApiError::InternalError(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    /// エラーの原因となったリクエストのフィールド
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            ApiError::ValidationError(
+                TaskValidationError::EmptyDescription | TaskValidationError::DescriptionTooLong(_),
+            ) => Some("description"),
+            ApiError::ValidationError(TaskValidationError::DueBeforeCreation) => Some("due_at"),
+            ApiError::ValidationError(
+                TaskValidationError::InvalidTag(_) | TaskValidationError::TooManyTags(_),
+            ) => Some("tags"),
+            ApiError::ValidationError(
+                TaskValidationError::ParentNotFound(_) | TaskValidationError::ParentCycle(_),
+            ) => Some("parent_id"),
+            ApiError::ValidationError(
+                TaskValidationError::DependencyNotFound(_) | TaskValidationError::DependencyCycle(_),
+            ) => Some("depends_on"),
+            ApiError::ValidationError(TaskValidationError::InvalidRecurrence(_)) => Some("recurrence"),
+            ApiError::ValidationError(TaskValidationError::ProjectNotFound(_)) => Some("project_id"),
+            ApiError::ApiKeyValidation(ApiKeyValidationError::UserNotFound(_)) => Some("user_id"),
+            ApiError::ProjectValidation(_) | ApiError::UserValidation(_) | ApiError::ApiKeyValidation(_) => Some("name"),
+            ApiError::InvalidCursor => Some("cursor"),
+            ApiError::InvalidPathId { field, .. } => Some(field),
+            _ => None,
+        }
+    }
+
+    /// クライアントに返す説明文（内部エラーの詳細は返さない）
+    pub fn detail(&self) -> String {
+        match self {
+            ApiError::InternalError(_) => "An unexpected error occurred".to_string(),
+            other => other.to_string(),
+        }
+    }
+
+    /// RFC 7807 のProblemに変換
+    pub fn to_problem(&self) -> Problem {
+        problem::problem(self.status(), self.code(), Some(self.detail()), self.field())
+    }
+}
+
 impl From<TaskError> for ApiError {
     fn from(error: TaskError) -> Self {
         match error {
-            TaskError::NotFound(id) => ApiError::TaskNotFound(format!("Task with id {} not found", id)),
-            TaskError::Validation(msg) => ApiError::ValidationError(msg.to_string()),
+            TaskError::NotFound(id) => ApiError::TaskNotFound(id),
+            TaskError::Forbidden(id) => ApiError::Forbidden(id),
+            TaskError::Validation(e) => ApiError::ValidationError(e),
+            TaskError::InvalidOperation(msg) => ApiError::InvalidOperation(msg),
+            TaskError::VersionMismatch { id, .. } => ApiError::PreconditionFailed(id),
+            TaskError::HasChildren(id) => ApiError::HasChildren(id),
+            TaskError::Blocked { id, blockers } => ApiError::Blocked { id, blockers },
             TaskError::Repository(msg) => ApiError::InternalError(msg),
         }
     }
 }
 
+impl From<ProjectError> for ApiError {
+    fn from(error: ProjectError) -> Self {
+        match error {
+            ProjectError::NotFound(id) => ApiError::ProjectNotFound(id),
+            ProjectError::Forbidden(id) => ApiError::ProjectForbidden(id),
+            ProjectError::Validation(e) => ApiError::ProjectValidation(e),
+            ProjectError::InvalidOperation(msg) => ApiError::InvalidOperation(msg),
+            ProjectError::NotEmpty(id) => ApiError::ProjectNotEmpty(id),
+            ProjectError::Repository(msg) => ApiError::InternalError(msg),
+        }
+    }
+}
+
+impl From<UserError> for ApiError {
+    fn from(error: UserError) -> Self {
+        match error {
+            // 利用者を削除する手段はないため、処理中の利用者が見つからないのは認証の問題として扱う
+            UserError::NotFound(_) => ApiError::UnknownUser,
+            UserError::Validation(e) => ApiError::UserValidation(e),
+            UserError::Repository(msg) => ApiError::InternalError(msg),
+        }
+    }
+}
+
+impl From<ApiKeyError> for ApiError {
+    fn from(error: ApiKeyError) -> Self {
+        match error {
+            ApiKeyError::NotFound(id) => ApiError::ApiKeyNotFound(id),
+            ApiKeyError::Validation(e) => ApiError::ApiKeyValidation(e),
+            ApiKeyError::Repository(msg) => ApiError::InternalError(msg),
+        }
+    }
+}
+
 impl From<TaskValidationError> for ApiError {
     fn from(error: TaskValidationError) -> Self {
-        ApiError::ValidationError(error.to_string())
+        ApiError::ValidationError(error)
+    }
+}
+
+impl From<SessionStoreError> for ApiError {
+    fn from(error: SessionStoreError) -> Self {
+        ApiError::InternalError(error.to_string())
+    }
+}
+
+/// 一括処理の結果を操作ごとの結果に変換する
+///
+/// `Atomic` で巻き戻された場合、成功していた操作は `rolled_back`、失敗した操作より後ろは `skipped` になる。
+fn bulk_result(kinds: &[BulkOperationType], outcome: BatchOutcome<TaskError>, blocked: &BTreeSet<u64>) -> BulkResult {
+    let committed = outcome.committed;
+    let mut outcomes = outcome.results.into_iter();
+    let results = kinds
+        .iter()
+        .enumerate()
+        .map(|(index, &op)| {
+            let mut result = BulkItemResult::new(index as i32, op, BulkItemStatus::Skipped);
+            match outcomes.next() {
+                Some(Ok(TaskOperationOutcome { previous, current })) => {
+                    result.status = if committed { BulkItemStatus::Succeeded } else { BulkItemStatus::RolledBack };
+                    result.task = current.or(previous).map(|task| TaskMapper::domain_to_api_with_blocked(task, blocked));
+                }
+                Some(Err(e)) => {
+                    let error = ApiError::from(e);
+                    if let ApiError::InternalError(ref msg) = error {
+                        tracing::error!("bulk operation {} failed: {}", index, msg);
+                    }
+                    result.status = BulkItemStatus::Failed;
+                    result.error = Some(error.to_problem());
+                }
+                None => {}
+            }
+            result
+        })
+        .collect();
+    BulkResult::new(committed, results)
+}
+
+/// パスや本文のIDを変換する（負の値は別のIDに化けないよう400にする）
+fn path_id(field: &'static str, value: i64) -> Result<u64, ApiError> {
+    u64::try_from(value).map_err(|_| ApiError::InvalidPathId { field, value })
+}
+
+/// 階層に含まれるすべてのタスク
+fn tree_tasks(nodes: &[TaskNode]) -> Vec<Task> {
+    let mut tasks = Vec::new();
+    let mut pending: Vec<&TaskNode> = nodes.iter().collect();
+    while let Some(node) = pending.pop() {
+        tasks.push(node.task.clone());
+        pending.extend(&node.children);
+    }
+    tasks
+}
+
+/// 次ページを指すLinkヘッダーの値（RFC 8288）を組み立てる
+/// タグは正規化済みの値を使う（正規化後は URL エンコード不要な文字だけになる）
+fn next_page_link(path: &str, params: &TasksGetQueryParams, tags: &[String], cursor: &str) -> String {
+    let mut query = Vec::new();
+    if let Some(limit) = params.limit {
+        query.push(format!("limit={}", limit));
+    }
+    if let Some(sort) = params.sort {
+        query.push(format!("sort={}", sort));
+    }
+    if let Some(order) = params.order {
+        query.push(format!("order={}", order));
+    }
+    if let Some(completed) = params.completed {
+        query.push(format!("completed={}", completed));
+    }
+    for tag in tags {
+        query.push(format!("tag={}", tag));
     }
+    if let Some(tag_mode) = params.tag_mode {
+        query.push(format!("tag_mode={}", tag_mode));
+    }
+    query.push(format!("cursor={}", cursor));
+    format!("<{}?{}>; rel=\"next\"", path, query.join("&"))
 }
 
 /// APIトレイトの実装
 #[derive(Clone)]
-pub struct TaskApiImpl<T> {
+pub struct TaskApiImpl<T, P, U, K> {
     pub usecase: T,
+    pub project_usecase: P,
+    pub user_usecase: U,
+    pub api_key_usecase: K,
+    pub sessions: SessionManager,
 }
 
-impl<T> TaskApiImpl<T> {
-    pub fn new(task_usecase: T) -> Self {
-        Self { usecase: task_usecase }
+impl<T, P, U, K> TaskApiImpl<T, P, U, K> {
+    pub fn new(task_usecase: T, project_usecase: P, user_usecase: U, api_key_usecase: K, sessions: SessionManager) -> Self {
+        Self { usecase: task_usecase, project_usecase, user_usecase, api_key_usecase, sessions }
     }
 }
 
-impl<T> AsRef<TaskApiImpl<T>> for TaskApiImpl<T> {
-    fn as_ref(&self) -> &TaskApiImpl<T> {
+impl<T, P, U, K> AsRef<TaskApiImpl<T, P, U, K>> for TaskApiImpl<T, P, U, K> {
+    fn as_ref(&self) -> &TaskApiImpl<T, P, U, K> {
         self
     }
 }
 
+impl<T, P, U, K> TaskApiImpl<T, P, U, K>
+where
+    T: crate::usecase::task::TaskUsecase,
+{
+    /// リクエストの利用者のタスクだけを扱うユースケース
+    fn tasks(&self) -> T {
+        self.usecase.for_owner(current_user_id())
+    }
+
+    /// If-Match ヘッダーを現在のタスクと照合し、条件付きで書き込むときの版数を返す
+    ///
+    /// ヘッダーがなければ条件なし（None）。照合後に他の更新が割り込んだ場合は
+    /// 版数を指定した書き込みがリポジトリで失敗する。
+    async fn if_match_version(&self, id: u64, if_match: Option<&str>) -> Result<Option<u64>, ApiError> {
+        let Some(if_match) = if_match else {
+            return Ok(None);
+        };
+        let current = self.tasks().get_task_by_id(id).await?;
+        if !etag::if_match(if_match, current.version) {
+            return Err(ApiError::PreconditionFailed(id));
+        }
+        Ok(Some(current.version))
+    }
+
+    /// 未完了の依存先が残っているかを付けてAPIのTaskに変換する
+    async fn present(&self, tasks: Vec<Task>) -> Result<Vec<ApiTask>, ApiError> {
+        let blocked = self.tasks().get_blocked_task_ids(&tasks).await?;
+        Ok(tasks.into_iter().map(|task| TaskMapper::domain_to_api_with_blocked(task, &blocked)).collect())
+    }
+
+    async fn present_one(&self, task: Task) -> Result<ApiTask, ApiError> {
+        let blocked = self.tasks().get_blocked_task_ids(std::slice::from_ref(&task)).await?;
+        Ok(TaskMapper::domain_to_api_with_blocked(task, &blocked))
+    }
+
+    /// 完了状態を変更する（If-Match があれば版数を指定して更新する）
+    async fn set_completed(&self, id: u64, completed: bool, if_match: Option<&str>) -> Result<Task, ApiError> {
+        let task = match self.if_match_version(id, if_match).await? {
+            Some(version) => {
+                let update_task = UpdateTask { completed: Some(completed), ..UpdateTask::default() };
+                self.tasks().update_task_if_version(id, update_task, version).await?
+            }
+            None if completed => self.tasks().complete_task(id).await?,
+            None => self.tasks().uncomplete_task(id).await?,
+        };
+        Ok(task)
+    }
+}
+
 #[async_trait]
-impl<T> Tasks<ApiError> for TaskApiImpl<T>
+impl<T, P, U, K> Tasks<ApiError> for TaskApiImpl<T, P, U, K>
 where
     T: crate::usecase::task::TaskUsecase + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+    U: Send + Sync + 'static,
+    K: Send + Sync + 'static,
 {
+    async fn tags_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<TagsGetResponse, ApiError> {
+        let counts = self.tasks().get_tag_counts().await?;
+        Ok(TagsGetResponse::Status200_TagsWithTaskCountsOrderedByTag(TaskMapper::tag_counts_to_api(counts)))
+    }
+
+    async fn tasks_bulk_post(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        body: &openapi::models::BulkRequest,
+    ) -> Result<TasksBulkPostResponse, ApiError> {
+        let operations = body
+            .operations
+            .iter()
+            .enumerate()
+            .map(|(index, operation)| {
+                TaskMapper::api_bulk_operation_to_domain(operation.clone())
+                    .map_err(|reason| ApiError::InvalidOperation(format!("operations[{}]: {}", index, reason)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let kinds: Vec<BulkOperationType> = body.operations.iter().map(|operation| operation.op).collect();
+        let mode = if body.atomic.unwrap_or(true) { BatchMode::Atomic } else { BatchMode::BestEffort };
+
+        let outcome = self.tasks().apply_batch(operations, mode).await?;
+        let tasks: Vec<Task> = outcome
+            .results
+            .iter()
+            .flatten()
+            .filter_map(|result| result.current.clone())
+            .collect();
+        let blocked = self.tasks().get_blocked_task_ids(&tasks).await?;
+        Ok(TasksBulkPostResponse::Status200_BatchProcessed(bulk_result(&kinds, outcome, &blocked)))
+    }
+
     async fn tasks_completed_get(
         &self,
         _method: &Method,
         _host: &Host,
         _cookies: &CookieJar,
     ) -> Result<TasksCompletedGetResponse, ApiError> {
-        let domain_tasks = self.usecase.get_completed_tasks().await?;
-        let api_tasks = TaskMapper::domain_vec_to_api(domain_tasks);
+        let domain_tasks = self.tasks().get_completed_tasks().await?;
+        let api_tasks = self.present(domain_tasks).await?;
         Ok(TasksCompletedGetResponse::Status200_ListOfCompletedTasks(api_tasks))
     }
 
+    async fn tasks_due_soon_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        query_params: &TasksDueSoonGetQueryParams,
+    ) -> Result<TasksDueSoonGetResponse, ApiError> {
+        let hours = query_params.hours.unwrap_or(DEFAULT_DUE_SOON_HOURS);
+        let domain_tasks = self.tasks().get_tasks_due_within(chrono::Duration::hours(hours.into())).await?;
+        let api_tasks = self.present(domain_tasks).await?;
+        Ok(TasksDueSoonGetResponse::Status200_ListOfTasksDueSoon(api_tasks))
+    }
+
     async fn tasks_get(
         &self,
         _method: &Method,
         _host: &Host,
         _cookies: &CookieJar,
+        query_params: &TasksGetQueryParams,
     ) -> Result<TasksGetResponse, ApiError> {
-        let domain_tasks = self.usecase.get_all_tasks().await?;
-        let api_tasks = TaskMapper::domain_vec_to_api(domain_tasks);
-        Ok(TasksGetResponse::Status200_ListOfAllTasks(api_tasks))
+        let after = query_params
+            .cursor
+            .as_deref()
+            .map(|cursor| TaskMapper::decode_cursor(cursor).ok_or(ApiError::InvalidCursor))
+            .transpose()?;
+        let query = TaskMapper::api_query_to_domain(query_params, after)?;
+        let tags = query.tags.clone();
+        let page = self.tasks().list_tasks(query).await?;
+        let next_cursor = page.next.as_ref().map(TaskMapper::encode_cursor);
+        Ok(TasksGetResponse::Status200_ListOfAllTasks {
+            body: self.present(page.tasks).await?,
+            link: next_cursor.as_deref().map(|cursor| next_page_link("/tasks", query_params, &tags, cursor)),
+            x_next_cursor: next_cursor,
+        })
+    }
+
+    async fn tasks_id_children_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &TasksIdChildrenGetPathParams,
+    ) -> Result<TasksIdChildrenGetResponse, ApiError> {
+        let children = self.tasks().get_children(path_id("id", path_params.id)?).await?;
+        let blocked = self.tasks().get_blocked_task_ids(&tree_tasks(&children)).await?;
+        let api_tasks = children.into_iter().map(|node| TaskMapper::node_task_to_api(node, &blocked)).collect();
+        Ok(TasksIdChildrenGetResponse::Status200_ListOfSubtasks(api_tasks))
     }
 
     async fn tasks_id_complete_put(
@@ -87,12 +505,21 @@ where
         _method: &Method,
         _host: &Host,
         _cookies: &CookieJar,
+        header_params: &TasksIdCompletePutHeaderParams,
         path_params: &TasksIdCompletePutPathParams,
+        query_params: &TasksIdCompletePutQueryParams,
     ) -> Result<TasksIdCompletePutResponse, ApiError> {
-        let task_id = path_params.id as u64;
-        let domain_task = self.usecase.complete_task(task_id).await?;
-        let api_task = TaskMapper::domain_to_api(domain_task);
-        Ok(TasksIdCompletePutResponse::Status200_TaskMarkedAsCompleted(api_task))
+        let task_id = path_id("id", path_params.id)?;
+        let if_match = header_params.if_match.as_deref();
+        let domain_task = if query_params.force.unwrap_or(false) {
+            let version = self.if_match_version(task_id, if_match).await?;
+            self.tasks().force_complete_task(task_id, version).await?
+        } else {
+            self.set_completed(task_id, true, if_match).await?
+        };
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksIdCompletePutResponse::Status200_TaskMarkedAsCompleted { body: api_task, etag })
     }
 
     async fn tasks_id_delete(
@@ -100,24 +527,98 @@ where
         _method: &Method,
         _host: &Host,
         _cookies: &CookieJar,
+        header_params: &TasksIdDeleteHeaderParams,
         path_params: &TasksIdDeletePathParams,
+        query_params: &TasksIdDeleteQueryParams,
     ) -> Result<TasksIdDeleteResponse, ApiError> {
-        let task_id = path_params.id as u64;
-        self.usecase.delete_task(task_id).await?;
+        let task_id = path_id("id", path_params.id)?;
+        let policy = TaskMapper::child_policy_to_domain(query_params.children);
+        let version = self.if_match_version(task_id, header_params.if_match.as_deref()).await?;
+        self.tasks().delete_task_with_children(task_id, policy, version).await?;
         Ok(TasksIdDeleteResponse::Status204_TaskDeletedSuccessfully)
     }
 
+    async fn tasks_id_dependencies_dependency_id_delete(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &TasksIdDependenciesDependencyIdDeletePathParams,
+    ) -> Result<TasksIdDependenciesDependencyIdDeleteResponse, ApiError> {
+        let task_id = path_id("id", path_params.id)?;
+        let domain_task = self.tasks().remove_dependency(task_id, path_id("dependency_id", path_params.dependency_id)?).await?;
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksIdDependenciesDependencyIdDeleteResponse::Status200_DependencyRemovedSuccessfully { body: api_task, etag })
+    }
+
+    async fn tasks_id_dependencies_post(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &TasksIdDependenciesPostPathParams,
+        body: &openapi::models::AddDependencies,
+    ) -> Result<TasksIdDependenciesPostResponse, ApiError> {
+        let task_id = path_id("id", path_params.id)?;
+        let depends_on = body
+            .depends_on
+            .iter()
+            .map(|&id| path_id("depends_on", id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let domain_task = self.tasks().add_dependencies(task_id, depends_on).await?;
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksIdDependenciesPostResponse::Status200_DependenciesAddedSuccessfully { body: api_task, etag })
+    }
+
     async fn tasks_id_get(
         &self,
         _method: &Method,
         _host: &Host,
         _cookies: &CookieJar,
+        header_params: &TasksIdGetHeaderParams,
         path_params: &TasksIdGetPathParams,
     ) -> Result<TasksIdGetResponse, ApiError> {
-        let task_id = path_params.id as u64;
-        let domain_task = self.usecase.get_task_by_id(task_id).await?;
-        let api_task = TaskMapper::domain_to_api(domain_task);
-        Ok(TasksIdGetResponse::Status200_TaskFound(api_task))
+        let task_id = path_id("id", path_params.id)?;
+        let domain_task = self.tasks().get_task_by_id(task_id).await?;
+        let etag = etag::from_version(domain_task.version);
+        if header_params
+            .if_none_match
+            .as_deref()
+            .is_some_and(|if_none_match| etag::if_none_match(if_none_match, domain_task.version))
+        {
+            return Ok(TasksIdGetResponse::Status304_TaskHasNotBeenModified { etag });
+        }
+        let subtasks = self.tasks().get_subtask_counts(task_id).await?;
+        let mut api_task = self.present_one(domain_task).await?;
+        api_task.completion_percentage = subtasks.completion_percentage().map(i32::from);
+        Ok(TasksIdGetResponse::Status200_TaskFound { body: api_task, etag })
+    }
+
+    async fn tasks_id_history_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &TasksIdHistoryGetPathParams,
+    ) -> Result<TasksIdHistoryGetResponse, ApiError> {
+        let history = self.tasks().get_task_history(path_id("id", path_params.id)?).await?;
+        Ok(TasksIdHistoryGetResponse::Status200_TaskHistory(TaskMapper::history_to_api(history)))
+    }
+
+    async fn tasks_id_occurrences_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &TasksIdOccurrencesGetPathParams,
+        query_params: &TasksIdOccurrencesGetQueryParams,
+    ) -> Result<TasksIdOccurrencesGetResponse, ApiError> {
+        let task_id = path_id("id", path_params.id)?;
+        let count = query_params.count.map(|count| count.max(0) as usize);
+        let occurrences = self.tasks().preview_occurrences(task_id, count).await?;
+        Ok(TasksIdOccurrencesGetResponse::Status200_UpcomingOccurrences(occurrences))
     }
 
     async fn tasks_id_put(
@@ -125,14 +626,76 @@ where
         _method: &Method,
         _host: &Host,
         _cookies: &CookieJar,
+        header_params: &TasksIdPutHeaderParams,
         path_params: &TasksIdPutPathParams,
         body: &openapi::models::UpdateTask,
     ) -> Result<TasksIdPutResponse, ApiError> {
-        let task_id = path_params.id as u64;
+        let task_id = path_id("id", path_params.id)?;
         let domain_update = TaskMapper::api_update_to_domain(body.clone())?;
-        let domain_task = self.usecase.update_task(task_id, domain_update).await?;
-        let api_task = TaskMapper::domain_to_api(domain_task);
-        Ok(TasksIdPutResponse::Status200_TaskUpdatedSuccessfully(api_task))
+        let domain_task = match self.if_match_version(task_id, header_params.if_match.as_deref()).await? {
+            Some(version) => self.tasks().update_task_if_version(task_id, domain_update, version).await?,
+            None => self.tasks().update_task(task_id, domain_update).await?,
+        };
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksIdPutResponse::Status200_TaskUpdatedSuccessfully { body: api_task, etag })
+    }
+
+    async fn tasks_id_recurrence_delete(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &TasksIdRecurrenceDeletePathParams,
+    ) -> Result<TasksIdRecurrenceDeleteResponse, ApiError> {
+        let task_id = path_id("id", path_params.id)?;
+        let domain_task = self.tasks().stop_recurrence(task_id).await?;
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksIdRecurrenceDeleteResponse::Status200_RecurrenceStopped { body: api_task, etag })
+    }
+
+    async fn tasks_id_restore_post(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &TasksIdRestorePostPathParams,
+    ) -> Result<TasksIdRestorePostResponse, ApiError> {
+        let task_id = path_id("id", path_params.id)?;
+        let domain_task = self.tasks().restore_task(task_id).await?;
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksIdRestorePostResponse::Status200_TaskRestored { body: api_task, etag })
+    }
+
+    async fn tasks_id_tags_post(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &TasksIdTagsPostPathParams,
+        body: &openapi::models::AddTags,
+    ) -> Result<TasksIdTagsPostResponse, ApiError> {
+        let task_id = path_id("id", path_params.id)?;
+        let domain_task = self.tasks().add_tags(task_id, body.tags.clone()).await?;
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksIdTagsPostResponse::Status200_TagsAddedSuccessfully { body: api_task, etag })
+    }
+
+    async fn tasks_id_tags_tag_delete(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &TasksIdTagsTagDeletePathParams,
+    ) -> Result<TasksIdTagsTagDeleteResponse, ApiError> {
+        let task_id = path_id("id", path_params.id)?;
+        let domain_task = self.tasks().remove_tag(task_id, path_params.tag.clone()).await?;
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksIdTagsTagDeleteResponse::Status200_TagRemovedSuccessfully { body: api_task, etag })
     }
 
     async fn tasks_id_uncomplete_put(
@@ -140,12 +703,25 @@ where
         _method: &Method,
         _host: &Host,
         _cookies: &CookieJar,
+        header_params: &TasksIdUncompletePutHeaderParams,
         path_params: &TasksIdUncompletePutPathParams,
     ) -> Result<TasksIdUncompletePutResponse, ApiError> {
-        let task_id = path_params.id as u64;
-        let domain_task = self.usecase.uncomplete_task(task_id).await?;
-        let api_task = TaskMapper::domain_to_api(domain_task);
-        Ok(TasksIdUncompletePutResponse::Status200_TaskMarkedAsUncompleted(api_task))
+        let task_id = path_id("id", path_params.id)?;
+        let domain_task = self.set_completed(task_id, false, header_params.if_match.as_deref()).await?;
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksIdUncompletePutResponse::Status200_TaskMarkedAsUncompleted { body: api_task, etag })
+    }
+
+    async fn tasks_overdue_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<TasksOverdueGetResponse, ApiError> {
+        let domain_tasks = self.tasks().get_overdue_tasks().await?;
+        let api_tasks = self.present(domain_tasks).await?;
+        Ok(TasksOverdueGetResponse::Status200_ListOfOverdueTasks(api_tasks))
     }
 
     async fn tasks_pending_get(
@@ -154,11 +730,22 @@ where
         _host: &Host,
         _cookies: &CookieJar,
     ) -> Result<TasksPendingGetResponse, ApiError> {
-        let domain_tasks = self.usecase.get_pending_tasks().await?;
-        let api_tasks = TaskMapper::domain_vec_to_api(domain_tasks);
+        let domain_tasks = self.tasks().get_pending_tasks().await?;
+        let api_tasks = self.present(domain_tasks).await?;
         Ok(TasksPendingGetResponse::Status200_ListOfPendingTasks(api_tasks))
     }
 
+    async fn tasks_ready_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<TasksReadyGetResponse, ApiError> {
+        let domain_tasks = self.tasks().get_ready_tasks().await?;
+        let api_tasks = self.present(domain_tasks).await?;
+        Ok(TasksReadyGetResponse::Status200_ListOfReadyTasks(api_tasks))
+    }
+
     async fn tasks_post(
         &self,
         _method: &Method,
@@ -167,9 +754,44 @@ where
         body: &openapi::models::CreateTask,
     ) -> Result<TasksPostResponse, ApiError> {
         let domain_create = TaskMapper::api_create_to_domain(body.clone())?;
-        let domain_task = self.usecase.create_task(domain_create).await?;
-        let api_task = TaskMapper::domain_to_api(domain_task);
-        Ok(TasksPostResponse::Status201_TaskCreatedSuccessfully(api_task))
+        let domain_task = self.tasks().create_task(domain_create).await?;
+        let etag = etag::from_version(domain_task.version);
+        let api_task = self.present_one(domain_task).await?;
+        Ok(TasksPostResponse::Status201_TaskCreatedSuccessfully { body: api_task, etag })
+    }
+
+    async fn tasks_trash_delete(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        query_params: &TasksTrashDeleteQueryParams,
+    ) -> Result<TasksTrashDeleteResponse, ApiError> {
+        let older_than = query_params.older_than_days.map(|days| chrono::Duration::days(days.max(0) as i64));
+        let purged = self.tasks().purge_trash(older_than).await?;
+        Ok(TasksTrashDeleteResponse::Status200_TrashPurged(PurgeResult::new(purged as i64)))
+    }
+
+    async fn tasks_trash_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<TasksTrashGetResponse, ApiError> {
+        let domain_tasks = self.tasks().get_trash().await?;
+        let api_tasks = self.present(domain_tasks).await?;
+        Ok(TasksTrashGetResponse::Status200_ListOfDeletedTasks(api_tasks))
+    }
+
+    async fn tasks_tree_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<TasksTreeGetResponse, ApiError> {
+        let tree = self.tasks().get_task_tree().await?;
+        let blocked = self.tasks().get_blocked_task_ids(&tree_tasks(&tree)).await?;
+        Ok(TasksTreeGetResponse::Status200_TaskTree(tree.into_iter().map(|node| TaskMapper::node_to_api(node, &blocked)).collect()))
     }
 
     async fn tasks_search_get(
@@ -180,17 +802,329 @@ where
         query_params: &TasksSearchGetQueryParams,
     ) -> Result<TasksSearchGetResponse, ApiError> {
         let query = query_params.q.as_deref().unwrap_or("");
-        let domain_tasks = self.usecase.search_tasks(query).await?;
-        let api_tasks = TaskMapper::domain_vec_to_api(domain_tasks);
+        let domain_tasks = self.tasks().search_tasks(query).await?;
+        let api_tasks = self.present(domain_tasks).await?;
         Ok(TasksSearchGetResponse::Status200_SearchResults(api_tasks))
     }
 }
 
+impl<T, P, U, K> TaskApiImpl<T, P, U, K>
+where
+    P: crate::usecase::project::ProjectUsecase,
+{
+    /// タスク数をリクエストの利用者のタスクだけで数えるユースケース
+    fn projects(&self) -> P {
+        self.project_usecase.for_owner(current_user_id())
+    }
+}
+
+#[async_trait]
+impl<T, P, U, K> Projects<ApiError> for TaskApiImpl<T, P, U, K>
+where
+    T: crate::usecase::task::TaskUsecase + Send + Sync + 'static,
+    P: crate::usecase::project::ProjectUsecase + Send + Sync + 'static,
+    U: Send + Sync + 'static,
+    K: Send + Sync + 'static,
+{
+    async fn projects_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<ProjectsGetResponse, ApiError> {
+        let projects = self.projects().list_projects().await?;
+        Ok(ProjectsGetResponse::Status200_ListOfProjects(
+            projects.into_iter().map(ProjectMapper::summary_to_api).collect(),
+        ))
+    }
+
+    async fn projects_id_delete(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &ProjectsIdDeletePathParams,
+    ) -> Result<ProjectsIdDeleteResponse, ApiError> {
+        self.projects().delete_project(path_id("id", path_params.id)?).await?;
+        Ok(ProjectsIdDeleteResponse::Status204_ProjectDeleted)
+    }
+
+    async fn projects_id_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &ProjectsIdGetPathParams,
+    ) -> Result<ProjectsIdGetResponse, ApiError> {
+        let project = self.projects().get_project(path_id("id", path_params.id)?).await?;
+        Ok(ProjectsIdGetResponse::Status200_ProjectFound(ProjectMapper::summary_to_api(project)))
+    }
+
+    async fn projects_id_put(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &ProjectsIdPutPathParams,
+        body: &openapi::models::UpdateProject,
+    ) -> Result<ProjectsIdPutResponse, ApiError> {
+        let update_project = ProjectMapper::api_update_to_domain(body.clone());
+        let project = self.projects().update_project(path_id("id", path_params.id)?, update_project).await?;
+        Ok(ProjectsIdPutResponse::Status200_ProjectUpdated(ProjectMapper::summary_to_api(project)))
+    }
+
+    async fn projects_id_tasks_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &ProjectsIdTasksGetPathParams,
+        query_params: &ProjectsIdTasksGetQueryParams,
+    ) -> Result<ProjectsIdTasksGetResponse, ApiError> {
+        let project_id = path_id("id", path_params.id)?;
+        // 存在しないプロジェクトは空の一覧ではなく404に、他の利用者のプロジェクトは403にする
+        self.projects().get_project(project_id).await?;
+
+        let params = ProjectMapper::tasks_query_to_api(query_params);
+        let after = params
+            .cursor
+            .as_deref()
+            .map(|cursor| TaskMapper::decode_cursor(cursor).ok_or(ApiError::InvalidCursor))
+            .transpose()?;
+        let mut query = TaskMapper::api_query_to_domain(&params, after)?;
+        query.project_id = Some(project_id);
+        let tags = query.tags.clone();
+        let page = self.tasks().list_tasks(query).await?;
+        let next_cursor = page.next.as_ref().map(TaskMapper::encode_cursor);
+        let path = format!("/projects/{}/tasks", project_id);
+        Ok(ProjectsIdTasksGetResponse::Status200_ListOfProjectTasks {
+            body: self.present(page.tasks).await?,
+            link: next_cursor.as_deref().map(|cursor| next_page_link(&path, &params, &tags, cursor)),
+            x_next_cursor: next_cursor,
+        })
+    }
+
+    async fn projects_post(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        body: &openapi::models::CreateProject,
+    ) -> Result<ProjectsPostResponse, ApiError> {
+        let create_project = ProjectMapper::api_create_to_domain(body.clone());
+        let project = self.projects().create_project(create_project).await?;
+        Ok(ProjectsPostResponse::Status201_ProjectCreated(ProjectMapper::summary_to_api(project)))
+    }
+}
+
+#[async_trait]
+impl<T, P, U, K> Users<ApiError> for TaskApiImpl<T, P, U, K>
+where
+    T: crate::usecase::task::TaskUsecase + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+    U: crate::usecase::user::UserUsecase + Send + Sync + 'static,
+    K: Send + Sync + 'static,
+{
+    async fn users_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<UsersGetResponse, ApiError> {
+        let users = self.user_usecase.list_users().await?;
+        Ok(UsersGetResponse::Status200_ListOfUsers(users.into_iter().map(UserMapper::domain_to_api).collect()))
+    }
+
+    async fn users_me_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<UsersMeGetResponse, ApiError> {
+        let user = current_user().ok_or(ApiError::UnknownUser)?;
+        Ok(UsersMeGetResponse::Status200_CurrentUser(UserMapper::domain_to_api(user)))
+    }
+
+    async fn users_post(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        body: &openapi::models::CreateUser,
+    ) -> Result<UsersPostResponse, ApiError> {
+        let user = self.user_usecase.create_user(UserMapper::api_create_to_domain(body.clone())).await?;
+        Ok(UsersPostResponse::Status201_UserCreated(UserMapper::domain_to_api(user)))
+    }
+}
+
+#[async_trait]
+impl<T, P, U, K> ApiKeys<ApiError> for TaskApiImpl<T, P, U, K>
+where
+    T: crate::usecase::task::TaskUsecase + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+    U: Send + Sync + 'static,
+    K: crate::usecase::api_key::ApiKeyUsecase + Send + Sync + 'static,
+{
+    async fn api_keys_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<ApiKeysGetResponse, ApiError> {
+        let keys = self.api_key_usecase.list_api_keys().await?;
+        Ok(ApiKeysGetResponse::Status200_ListOfAPIKeys(keys.into_iter().map(ApiKeyMapper::domain_to_api).collect()))
+    }
+
+    async fn api_keys_id_delete(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        path_params: &ApiKeysIdDeletePathParams,
+    ) -> Result<ApiKeysIdDeleteResponse, ApiError> {
+        self.api_key_usecase.revoke_api_key(path_id("id", path_params.id)?).await?;
+        Ok(ApiKeysIdDeleteResponse::Status204_APIKeyRevoked)
+    }
+
+    async fn api_keys_post(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+        body: &openapi::models::CreateApiKey,
+    ) -> Result<ApiKeysPostResponse, ApiError> {
+        let mut create_api_key = ApiKeyMapper::api_create_to_domain(body.clone());
+        // 持ち主を省略した鍵はリクエストの利用者のものにする
+        create_api_key.user_id.get_or_insert_with(current_user_id);
+        let issued = self.api_key_usecase.create_api_key(create_api_key).await?;
+        Ok(ApiKeysPostResponse::Status201_APIKeyCreated(ApiKeyMapper::issued_to_api(issued)))
+    }
+}
+
+/// CSRFトークンはログインとセッションの参照でだけ返す
+fn session_to_api(session: Session) -> openapi::models::Session {
+    openapi::models::Session {
+        user_id: session.user_id as i64,
+        scope: ApiKeyMapper::scope_to_api(session.scope),
+        csrf_token: session.csrf_token,
+        expires_at: session.expires_at,
+    }
+}
+
+#[async_trait]
+impl<T, P, U, K> Auth<ApiError> for TaskApiImpl<T, P, U, K>
+where
+    T: crate::usecase::task::TaskUsecase + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+    U: Send + Sync + 'static,
+    K: Send + Sync + 'static,
+{
+    async fn auth_login_post(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        cookies: &CookieJar,
+    ) -> Result<AuthLoginPostResponse, ApiError> {
+        // 確かめた資格情報がなければログインさせない（セッションのクッキーでは延長させない）
+        // セッションの範囲は資格情報の範囲と同じにし、それより広げない
+        let principal = current_principal()
+            .filter(|principal| !matches!(principal.credential, Credential::Session(_)))
+            .ok_or(ApiError::CredentialsRequired)?;
+        // ログインし直すときは前のセッションを終え、IDを使い回さない
+        if let Some(previous) = self.sessions.session(cookies)? {
+            self.sessions.end(&previous.id)?;
+        }
+        let session = self.sessions.start(principal.user_id, principal.scope)?;
+        Ok(AuthLoginPostResponse::Status200_SessionStarted {
+            set_cookie: self.sessions.cookie(&session).to_string(),
+            body: session_to_api(session),
+        })
+    }
+
+    async fn auth_logout_post(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        cookies: &CookieJar,
+    ) -> Result<AuthLogoutPostResponse, ApiError> {
+        if let Some(session) = self.sessions.session(cookies)? {
+            self.sessions.end(&session.id)?;
+        }
+        Ok(AuthLogoutPostResponse::Status204_SessionEnded { set_cookie: self.sessions.removal_cookie().to_string() })
+    }
+
+    async fn auth_session_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        cookies: &CookieJar,
+    ) -> Result<AuthSessionGetResponse, ApiError> {
+        let session = self.sessions.session(cookies)?.ok_or(ApiError::NoSession)?;
+        Ok(AuthSessionGetResponse::Status200_CurrentSession(session_to_api(session)))
+    }
+}
+
+#[async_trait]
+impl<T, P, U, K> System<ApiError> for TaskApiImpl<T, P, U, K>
+where
+    T: crate::usecase::task::TaskUsecase + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+    U: Send + Sync + 'static,
+    K: Send + Sync + 'static,
+{
+    async fn healthz_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<HealthzGetResponse, ApiError> {
+        Ok(HealthzGetResponse::Status200_TheProcessIsAlive(HealthStatus::new("ok".to_string())))
+    }
+
+    async fn readyz_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<ReadyzGetResponse, ApiError> {
+        match self.usecase.ping().await {
+            Ok(()) => Ok(ReadyzGetResponse::Status200_TheServiceIsReadyToAcceptTraffic(HealthStatus::new("ready".to_string()))),
+            Err(error) => {
+                // 原因はログにだけ残し、プローブには返さない
+                tracing::warn!("readiness check failed: {}", error);
+                Ok(ReadyzGetResponse::Status503_TheTaskRepositoryIsUnavailable(problem::problem(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "NOT_READY",
+                    Some("The task repository is unavailable".to_string()),
+                    None,
+                )))
+            }
+        }
+    }
+
+    async fn version_get(
+        &self,
+        _method: &Method,
+        _host: &Host,
+        _cookies: &CookieJar,
+    ) -> Result<VersionGetResponse, ApiError> {
+        Ok(VersionGetResponse::Status200_BuildInformation(VersionInfo::new(
+            build_info::VERSION.to_string(),
+            build_info::GIT_COMMIT.to_string(),
+            build_info::BUILD_TIME.to_string(),
+            self.usecase.storage_backend().to_string(),
+        )))
+    }
+}
+
 // エラーハンドラーの実装
 #[async_trait]
-impl<T> openapi::apis::ErrorHandler<ApiError> for TaskApiImpl<T>
+impl<T, P, U, K> openapi::apis::ErrorHandler<ApiError> for TaskApiImpl<T, P, U, K>
 where
     T: crate::usecase::task::TaskUsecase + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+    U: Send + Sync + 'static,
+    K: Send + Sync + 'static,
 {
     async fn handle_error(
         &self,
@@ -199,26 +1133,8 @@ where
         _cookies: &axum_extra::extract::CookieJar,
         error: ApiError,
     ) -> Result<axum::response::Response, http::StatusCode> {
-        tracing::error!("API Error: {:?}", error);
-        match error {
-            ApiError::TaskNotFound(_) => {
-                Ok(axum::response::Response::builder()
-                    .status(axum::http::StatusCode::NOT_FOUND)
-                    .body(axum::body::Body::empty())
-                    .unwrap())
-            }
-            ApiError::ValidationError(_) => {
-                Ok(axum::response::Response::builder()
-                    .status(axum::http::StatusCode::BAD_REQUEST)
-                    .body(axum::body::Body::empty())
-                    .unwrap())
-            }
-            ApiError::InternalError(_) => {
-                Ok(axum::response::Response::builder()
-                    .status(axum::http::StatusCode::INTERNAL_SERVER_ERROR)
-                    .body(axum::body::Body::empty())
-                    .unwrap())
-            }
-        }
+        let problem = error.to_problem();
+        tracing::error!(request_id = ?problem.request_id, code = %problem.code, "API Error: {:?}", error);
+        Ok(problem::into_response(problem))
     }
-} 
\ No newline at end of file
+}
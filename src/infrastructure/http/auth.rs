@@ -0,0 +1,226 @@
+use std::sync::Arc;
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use crate::domain::model::api_key::{hash_secret, ApiKeyScope};
+use crate::domain::model::user::{User, DEFAULT_USER_ID};
+use crate::infrastructure::config::AuthConfig;
+use crate::infrastructure::http::jwt::{looks_like_jwt, JwtVerifier};
+use crate::infrastructure::http::problem;
+use crate::usecase::api_key::ApiKeyUsecase;
+use crate::usecase::user::{UserError, UserUsecase};
+
+/// APIキーを受け取るヘッダー（`Authorization: Bearer` の代わりに使える）
+pub static X_API_KEY: HeaderName = HeaderName::from_static("x-api-key");
+
+/// 認証なしで呼べるパス（死活監視とバージョン）
+pub(crate) const PUBLIC_PATHS: &[&str] = &["/healthz", "/readyz", "/version"];
+
+/// 認証に使った資格情報
+#[derive(Debug, Clone, PartialEq)]
+pub enum Credential {
+    /// 設定で渡された管理用キー
+    AdminKey,
+    /// 発行したAPIキー（ID）
+    ApiKey(u64),
+    /// 他のサービスが発行したJWT
+    Jwt {
+        /// `sub` クレーム
+        subject: String,
+    },
+    /// ログインで始めたセッション（ID）
+    Session(String),
+}
+
+/// 認証されたリクエストの主体
+///
+/// 認証のミドルウェアがリクエストの拡張に入れ、利用者を決めるミドルウェアが参照する。
+/// ハンドラーからは `identity::current_principal` で参照できる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Principal {
+    /// リクエストを処理する利用者
+    pub user_id: u64,
+    /// 許された操作の範囲
+    pub scope: ApiKeyScope,
+    pub credential: Credential,
+}
+
+/// 操作に必要な範囲（APIキーの管理と全利用者分を数えるメトリクスは admin、参照だけのメソッドとセッションの操作は read、それ以外は write）
+pub fn required_scope(method: &Method, path: &str) -> ApiKeyScope {
+    if path == "/api-keys" || path.starts_with("/api-keys/") || path == "/metrics" {
+        ApiKeyScope::Admin
+    } else if path.starts_with("/auth/") || matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+        ApiKeyScope::Read
+    } else {
+        ApiKeyScope::Write
+    }
+}
+
+/// `Authorization: Bearer <token>` のトークン
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split_once(' '))
+        .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("bearer"))
+        .map(|(_, token)| token.trim())
+        .filter(|token| !token.is_empty())
+}
+
+/// `X-Api-Key: <key>` の鍵
+fn api_key_header(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(&X_API_KEY)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+}
+
+/// RFC 6750 の `WWW-Authenticate` を付けた 401 / 403
+fn rejection(status: StatusCode, code: &str, detail: String, challenge: String) -> Response {
+    let mut response = problem::into_response(problem::problem(status, code, Some(detail), None));
+    if let Ok(value) = HeaderValue::from_str(&challenge) {
+        response.headers_mut().insert(header::WWW_AUTHENTICATE, value);
+    }
+    response
+}
+
+fn internal_error() -> Response {
+    problem::into_response(problem::problem(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "INTERNAL_ERROR",
+        Some("An unexpected error occurred".to_string()),
+        None,
+    ))
+}
+
+/// APIキーとJWTによる認証の設定
+#[derive(Clone)]
+pub struct Authenticator<K, U> {
+    keys: K,
+    users: U,
+    /// 設定で渡された管理用キーのハッシュ値（既定の利用者として admin の範囲で扱う）
+    admin_key_hash: Option<String>,
+    /// JWTの検証（鍵が設定されていなければ None で、Bearer はすべてAPIキーとして扱う）
+    jwt: Option<Arc<JwtVerifier>>,
+}
+
+impl<K, U> Authenticator<K, U> {
+    pub fn new(keys: K, users: U, config: &AuthConfig, jwt: Option<Arc<JwtVerifier>>) -> Self {
+        Self { keys, users, admin_key_hash: config.admin_key.as_deref().map(hash_secret), jwt }
+    }
+}
+
+impl<K, U> Authenticator<K, U>
+where
+    K: ApiKeyUsecase,
+    U: UserUsecase,
+{
+    /// APIキー（管理用キーを含む）を確かめる
+    async fn api_key_principal(&self, key: &str) -> Result<Principal, Response> {
+        if self.admin_key_hash.as_deref() == Some(hash_secret(key).as_str()) {
+            return Ok(Principal { user_id: DEFAULT_USER_ID, scope: ApiKeyScope::Admin, credential: Credential::AdminKey });
+        }
+        match self.keys.authenticate(key).await {
+            Ok(Some(api_key)) => Ok(Principal { user_id: api_key.user_id, scope: api_key.scope, credential: Credential::ApiKey(api_key.id) }),
+            Ok(None) => Err(rejection(
+                StatusCode::UNAUTHORIZED,
+                "INVALID_API_KEY",
+                "The API key is unknown or has been revoked".to_string(),
+                "Bearer error=\"invalid_token\"".to_string(),
+            )),
+            Err(e) => {
+                tracing::error!("failed to look up API key: {}", e);
+                Err(internal_error())
+            }
+        }
+    }
+
+    /// JWTを検証し、`sub` を利用者のID、なければ利用者名として利用者に対応付ける
+    async fn jwt_principal(&self, verifier: &JwtVerifier, token: &str) -> Result<Principal, Response> {
+        let verified = verifier.verify(token).map_err(|e| {
+            rejection(
+                StatusCode::UNAUTHORIZED,
+                "INVALID_TOKEN",
+                e.to_string(),
+                format!("Bearer error=\"invalid_token\", error_description=\"{}\"", e),
+            )
+        })?;
+        let user: Result<Option<User>, UserError> = match verified.subject.parse::<u64>() {
+            Ok(id) => match self.users.get_user(id).await {
+                Ok(user) => Ok(Some(user)),
+                Err(UserError::NotFound(_)) => Ok(None),
+                Err(e) => Err(e),
+            },
+            Err(_) => self.users.find_user_by_name(&verified.subject).await,
+        };
+        match user {
+            Ok(Some(user)) => Ok(Principal {
+                user_id: user.id,
+                scope: verified.scope,
+                credential: Credential::Jwt { subject: verified.subject },
+            }),
+            Ok(None) => Err(rejection(
+                StatusCode::UNAUTHORIZED,
+                "UNKNOWN_USER",
+                format!("No user matches the token subject '{}'", verified.subject),
+                "Bearer error=\"invalid_token\"".to_string(),
+            )),
+            Err(e) => {
+                tracing::error!("failed to look up user for token subject: {}", e);
+                Err(internal_error())
+            }
+        }
+    }
+}
+
+/// APIキーかJWTを確かめ、操作に必要な範囲が許されていれば `Principal` を付けて処理を続ける
+///
+/// セッションのミドルウェアが `Principal` を付けていれば、その範囲だけを確かめる。
+/// JWTの鍵が設定されていれば、`xxx.yyy.zzz` の形の Bearer トークンをJWTとして検証する。
+/// 資格情報がない・一致しない・失効や期限切れの場合は 401、範囲が足りない場合は 403 にする。
+pub async fn authenticate<K, U>(State(auth): State<Authenticator<K, U>>, mut request: Request, next: Next) -> Response
+where
+    K: ApiKeyUsecase + Clone + 'static,
+    U: UserUsecase + Clone + 'static,
+{
+    let path = request.uri().path();
+    if PUBLIC_PATHS.contains(&path) {
+        return next.run(request).await;
+    }
+    let required = required_scope(request.method(), path);
+
+    // セッションのクッキーで認証済みなら範囲だけを確かめる
+    let principal = match (request.extensions().get::<Principal>(), bearer_token(request.headers()), &auth.jwt) {
+        (Some(principal), _, _) => Ok(principal.clone()),
+        (None, Some(token), Some(verifier)) if looks_like_jwt(token) => auth.jwt_principal(verifier, token).await,
+        (None, Some(key), _) => auth.api_key_principal(key).await,
+        (None, None, _) => match api_key_header(request.headers()) {
+            Some(key) => auth.api_key_principal(key).await,
+            None => {
+                return rejection(
+                    StatusCode::UNAUTHORIZED,
+                    "AUTHENTICATION_REQUIRED",
+                    format!("Send credentials as 'Authorization: Bearer <token>', '{}: <key>' or a session cookie", X_API_KEY),
+                    "Bearer".to_string(),
+                );
+            }
+        },
+    };
+    let principal = match principal {
+        Ok(principal) => principal,
+        Err(response) => return response,
+    };
+
+    if !principal.scope.allows(required) {
+        return rejection(
+            StatusCode::FORBIDDEN,
+            "INSUFFICIENT_SCOPE",
+            format!("This operation requires credentials with the {} scope", required),
+            format!("Bearer error=\"insufficient_scope\", scope=\"{}\"", required),
+        );
+    }
+    request.extensions_mut().insert(principal);
+    next.run(request).await
+}
@@ -0,0 +1,22 @@
+/// タスクの版数からETag（強いエンティティタグ）を作る
+pub fn from_version(version: u64) -> String {
+    format!("\"{}\"", version)
+}
+
+/// If-Match ヘッダーが現在の版数に一致するか（強い比較、`*` は常に一致）
+pub fn if_match(header: &str, version: u64) -> bool {
+    matches(header, version, false)
+}
+
+/// If-None-Match ヘッダーが現在の版数に一致するか（弱い比較、`*` は常に一致）
+pub fn if_none_match(header: &str, version: u64) -> bool {
+    matches(header, version, true)
+}
+
+/// カンマ区切りのエンティティタグのいずれかが現在のETagと一致するか（RFC 9110 8.8.3.2）
+fn matches(header: &str, version: u64, weak: bool) -> bool {
+    let etag = from_version(version);
+    header.split(',').map(str::trim).any(|tag| {
+        tag == "*" || tag == etag || (weak && tag.strip_prefix("W/") == Some(etag.as_str()))
+    })
+}
@@ -1,12 +1,115 @@
+use std::sync::Arc;
+use axum::routing::get;
+use crate::domain::repository::api_key::ApiKeyStorage;
+use crate::domain::repository::history::HistoryStorage;
+use crate::domain::repository::project::ProjectStorage;
+use crate::domain::repository::task::TaskRepository;
+use crate::domain::repository::user::UserStorage;
+use crate::infrastructure::config::Config;
+use crate::infrastructure::events::http::task_events;
+use crate::infrastructure::events::TaskEventBroker;
 use crate::infrastructure::http::api_impl::TaskApiImpl;
+use crate::infrastructure::http::auth::{authenticate, Authenticator};
+use crate::infrastructure::http::identity::{current_user, identify};
+use crate::infrastructure::http::jwt::JwtVerifier;
+use crate::infrastructure::http::problem::ensure_problem_body;
+use crate::infrastructure::http::rate_limit::{limit_rate, RateLimiter};
+use crate::infrastructure::http::request_id::request_context;
+use crate::infrastructure::http::session::{resume_session, SessionManager};
+use crate::infrastructure::metrics::http::{get_metrics, track_requests, MetricsState};
+use crate::infrastructure::metrics::repository::MeteredTaskRepository;
+use crate::infrastructure::metrics::Metrics;
 use openapi::server::new as create_generated_server;
+use crate::interface::gateway::audited::AuditedTaskRepository;
 use crate::interface::gateway::inmemory::InMemoryTaskRepository;
+use crate::interface::gateway::notifying::NotifyingTaskRepository;
+use crate::usecase::api_key::ApiKeyUsecaseImpl;
+use crate::usecase::project::ProjectUsecaseImpl;
 use crate::usecase::task::TaskUsecaseImpl;
+use crate::usecase::user::UserUsecaseImpl;
 
 /// 生成されたサーバーを使用するルーターを作成
 pub fn create_generated_router() -> axum::Router {
-    let repository = InMemoryTaskRepository::new();
-    let task_usecase = TaskUsecaseImpl::new(repository);
-    let api_impl = TaskApiImpl::new(task_usecase);
-    create_generated_server(api_impl)
-} 
\ No newline at end of file
+    create_generated_router_with(InMemoryTaskRepository::new())
+}
+
+/// 指定したリポジトリを使用するルーターを作成
+pub fn create_generated_router_with<R>(repository: R) -> axum::Router
+where
+    R: TaskRepository + ProjectStorage + UserStorage + ApiKeyStorage + HistoryStorage + Clone + 'static,
+{
+    create_generated_router_with_events(repository, Arc::new(TaskEventBroker::new()))
+}
+
+/// 指定したリポジトリと変更の配信先を使用するルーターを作成
+pub fn create_generated_router_with_events<R>(repository: R, events: Arc<TaskEventBroker>) -> axum::Router
+where
+    R: TaskRepository + ProjectStorage + UserStorage + ApiKeyStorage + HistoryStorage + Clone + 'static,
+{
+    create_generated_router_with_config(repository, events, &Config::default(), None)
+}
+
+/// 指定したリポジトリと変更の配信先を使い、設定に従って認証を掛けたルーターを作成
+///
+/// `jwt` は `config.auth.jwt` の鍵ファイルから読み込んだもの（ファイルの読み込みは呼び出し側で行う）。
+pub fn create_generated_router_with_config<R>(
+    repository: R,
+    events: Arc<TaskEventBroker>,
+    config: &Config,
+    jwt: Option<Arc<JwtVerifier>>,
+) -> axum::Router
+where
+    R: TaskRepository + ProjectStorage + UserStorage + ApiKeyStorage + HistoryStorage + Clone + 'static,
+{
+    let metrics = Arc::new(Metrics::new());
+    let project_usecase = ProjectUsecaseImpl::new(repository.projects());
+    let user_usecase = UserUsecaseImpl::new(repository.users());
+    let api_key_usecase = ApiKeyUsecaseImpl::new(repository.api_keys());
+    // 変更履歴はタスクと同じ保存先に記録する
+    let history = Arc::new(repository.history());
+    let repository = AuditedTaskRepository::new(repository, history)
+        .with_actor(Arc::new(|| current_user().map(|user| user.name)));
+    let repository = NotifyingTaskRepository::new(repository, events.clone());
+    let task_usecase = TaskUsecaseImpl::new(MeteredTaskRepository::new(repository, metrics.clone()));
+    let metrics_routes = axum::Router::new()
+        .route("/metrics", get(get_metrics))
+        .with_state(MetricsState { metrics: metrics.clone(), usecase: task_usecase.clone() });
+    let event_routes = axum::Router::new()
+        .route("/tasks/events", get(task_events))
+        .with_state(events);
+    let sessions = SessionManager::new(&config.auth.session);
+    let api_impl = TaskApiImpl::new(task_usecase, project_usecase, user_usecase.clone(), api_key_usecase.clone(), sessions.clone());
+    let router = create_generated_server(api_impl)
+        .merge(metrics_routes)
+        .merge(event_routes)
+        .layer(axum::middleware::from_fn_with_state(user_usecase.clone(), identify::<UserUsecaseImpl<R::Users>>));
+    // 流量は認証した主体ごとに数えるため、認証より内側で制限する
+    let router = if config.rate_limit.enabled {
+        router.layer(axum::middleware::from_fn_with_state(RateLimiter::new(&config.rate_limit), limit_rate))
+    } else {
+        router
+    };
+    // 認証は利用者を決める前に行い、認証した主体の利用者で処理させる
+    let router = if config.auth.is_enabled() || jwt.is_some() {
+        let auth = Authenticator::new(api_key_usecase, user_usecase, &config.auth, jwt);
+        router.layer(axum::middleware::from_fn_with_state(
+            auth,
+            authenticate::<ApiKeyUsecaseImpl<R::ApiKeys>, UserUsecaseImpl<R::Users>>,
+        ))
+    } else {
+        router
+    };
+    // セッションのクッキーは認証より前に確かめ、認証した主体として渡す
+    let router = router.layer(axum::middleware::from_fn_with_state(sessions, resume_session));
+    // 認証に失敗したリクエストも数えるよう、接続元ごとの制限は認証とセッションより外側に置く
+    let router = if config.rate_limit.enabled {
+        router.layer(axum::middleware::from_fn_with_state(RateLimiter::per_address(&config.rate_limit), limit_rate))
+    } else {
+        router
+    };
+    router
+        // 生成コードやエクストラクタが返す本文なしのエラーもProblem形式に揃える
+        .layer(axum::middleware::map_response(ensure_problem_body))
+        .layer(axum::middleware::from_fn_with_state(metrics, track_requests))
+        .layer(axum::middleware::from_fn(request_context))
+}
@@ -0,0 +1,68 @@
+use axum::extract::{Request, State};
+use axum::http::{HeaderName, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use crate::domain::model::user::{User, DEFAULT_USER_ID};
+use crate::infrastructure::http::auth::Principal;
+use crate::infrastructure::http::problem;
+use crate::usecase::user::{UserError, UserUsecase};
+
+/// リクエストが操作する利用者のIDを受け取るヘッダー
+pub static X_USER_ID: HeaderName = HeaderName::from_static("x-user-id");
+
+tokio::task_local! {
+    static CURRENT_USER: User;
+    static CURRENT_PRINCIPAL: Option<Principal>;
+}
+
+/// 処理中のリクエストが操作する利用者（ミドルウェアの外では None）
+pub fn current_user() -> Option<User> {
+    CURRENT_USER.try_with(Clone::clone).ok()
+}
+
+/// 処理中のリクエストを認証した主体（認証しない設定やミドルウェアの外では None）
+pub fn current_principal() -> Option<Principal> {
+    CURRENT_PRINCIPAL.try_with(Clone::clone).ok().flatten()
+}
+
+/// 処理中のリクエストが操作する利用者のID（ミドルウェアの外では既定の利用者）
+pub fn current_user_id() -> u64 {
+    CURRENT_USER.try_with(|user| user.id).unwrap_or(DEFAULT_USER_ID)
+}
+
+fn unknown_user(detail: String) -> Response {
+    problem::into_response(problem::problem(StatusCode::UNAUTHORIZED, "UNKNOWN_USER", Some(detail), None))
+}
+
+/// `X-User-Id` ヘッダーから操作する利用者を決め、処理中は `current_user` で参照できるようにする
+///
+/// 認証済みのリクエストは認証した主体の利用者になり、ヘッダーは使わない。主体は `current_principal` で参照できる。
+/// ヘッダーがなければ既定の利用者になる。形式が不正なIDや存在しない利用者は 401 にする。
+pub async fn identify<U>(State(users): State<U>, request: Request, next: Next) -> Response
+where
+    U: UserUsecase + Clone + 'static,
+{
+    let principal = request.extensions().get::<Principal>().cloned();
+    let user_id = match (principal.as_ref().map(|principal| principal.user_id), request.headers().get(&X_USER_ID)) {
+        (Some(user_id), _) => user_id,
+        (None, None) => DEFAULT_USER_ID,
+        (None, Some(value)) => match value.to_str().ok().and_then(|value| value.trim().parse::<u64>().ok()) {
+            Some(id) => id,
+            None => return unknown_user(format!("{} must be a user ID", X_USER_ID)),
+        },
+    };
+    let user = match users.get_user(user_id).await {
+        Ok(user) => user,
+        Err(UserError::NotFound(id)) => return unknown_user(format!("User with id {} does not exist", id)),
+        Err(e) => {
+            tracing::error!("failed to look up user {}: {}", user_id, e);
+            return problem::into_response(problem::problem(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "INTERNAL_ERROR",
+                Some("An unexpected error occurred".to_string()),
+                None,
+            ));
+        }
+    };
+    CURRENT_USER.scope(user, CURRENT_PRINCIPAL.scope(principal, next.run(request))).await
+}
@@ -0,0 +1,218 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use jsonwebtoken::errors::ErrorKind;
+use jsonwebtoken::jwk::{AlgorithmParameters, JwkSet, KeyAlgorithm};
+use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use thiserror::Error;
+use crate::domain::model::api_key::ApiKeyScope;
+use crate::infrastructure::config::{JwtAlgorithm, JwtConfig, MAX_JWT_SCOPE};
+
+/// 鍵ファイルを読み込めないときのエラー（起動を止める）
+#[derive(Debug, Error)]
+pub enum JwtKeyError {
+    #[error("failed to read {path}: {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("invalid key in {path}: {reason}")]
+    Invalid { path: PathBuf, reason: String },
+}
+
+impl JwtKeyError {
+    fn invalid(path: &Path, reason: impl ToString) -> Self {
+        JwtKeyError::Invalid { path: path.to_path_buf(), reason: reason.to_string() }
+    }
+}
+
+/// トークンを受け付けない理由（401 の詳細に載せる）
+#[derive(Debug, Error, PartialEq)]
+pub enum TokenError {
+    #[error("The token has expired")]
+    Expired,
+    #[error("The token is not valid yet")]
+    NotYetValid,
+    #[error("The token was not issued by a trusted issuer")]
+    InvalidIssuer,
+    #[error("The token is not intended for this API")]
+    InvalidAudience,
+    #[error("The token signature is invalid")]
+    InvalidSignature,
+    #[error("No configured key matches the token")]
+    UnknownKey,
+    #[error("The token is malformed: {0}")]
+    Malformed(String),
+}
+
+impl From<jsonwebtoken::errors::Error> for TokenError {
+    fn from(error: jsonwebtoken::errors::Error) -> Self {
+        match error.kind() {
+            ErrorKind::ExpiredSignature => TokenError::Expired,
+            ErrorKind::ImmatureSignature => TokenError::NotYetValid,
+            ErrorKind::InvalidIssuer => TokenError::InvalidIssuer,
+            ErrorKind::InvalidAudience => TokenError::InvalidAudience,
+            ErrorKind::InvalidSignature => TokenError::InvalidSignature,
+            ErrorKind::InvalidAlgorithm => TokenError::UnknownKey,
+            ErrorKind::MissingRequiredClaim(claim) => TokenError::Malformed(format!("the {} claim is required", claim)),
+            _ => TokenError::Malformed(error.to_string()),
+        }
+    }
+}
+
+/// 検証できたトークンの内容
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedToken {
+    /// `sub` クレーム（利用者のIDか利用者名）
+    pub subject: String,
+    pub scope: ApiKeyScope,
+}
+
+#[derive(Deserialize)]
+struct Claims {
+    sub: String,
+    /// 空白区切りの範囲（OAuth 2.0 の慣習）
+    scope: Option<String>,
+}
+
+struct VerificationKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    key: DecodingKey,
+}
+
+/// ローカルの鍵でJWTを検証する
+///
+/// 署名に加えて `exp`・`nbf`・`iss`・`aud` を確かめ、`exp`・`iss`・`aud`・`sub` のないトークンは受け付けない。
+pub struct JwtVerifier {
+    keys: Vec<VerificationKey>,
+    issuer: String,
+    audience: String,
+    default_scope: ApiKeyScope,
+    leeway_secs: u64,
+}
+
+impl JwtVerifier {
+    /// 設定された鍵ファイルを読み込む（鍵が設定されていなければ None）
+    ///
+    /// 発行者と受信者が設定されていることは `Config::validate` で確認済みとする。
+    pub fn from_config(config: &JwtConfig) -> Result<Option<Self>, JwtKeyError> {
+        let keys = match (&config.key_file, &config.jwks_file) {
+            (Some(path), _) => vec![load_key_file(path, config.algorithm.unwrap_or(JwtAlgorithm::HS256))?],
+            (None, Some(path)) => load_jwks_file(path, config.algorithm)?,
+            (None, None) => return Ok(None),
+        };
+        Ok(Some(Self {
+            keys,
+            issuer: config.issuer.clone().unwrap_or_default(),
+            audience: config.audience.clone().unwrap_or_default(),
+            default_scope: config.default_scope,
+            leeway_secs: config.leeway_secs,
+        }))
+    }
+
+    /// 署名とクレームを検証し、`sub` と範囲を返す
+    pub fn verify(&self, token: &str) -> Result<VerifiedToken, TokenError> {
+        let header = decode_header(token)?;
+        // `kid` があれば同じ `kid` の鍵を優先し、なければ `kid` のない鍵を使う
+        let candidates = || self.keys.iter().filter(|key| key.algorithm == header.alg);
+        let key = header
+            .kid
+            .as_ref()
+            .and_then(|kid| candidates().find(|key| key.kid.as_ref() == Some(kid)))
+            .or_else(|| candidates().find(|key| header.kid.is_none() || key.kid.is_none()))
+            .ok_or(TokenError::UnknownKey)?;
+
+        let mut validation = Validation::new(key.algorithm);
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud", "sub"]);
+        validation.validate_nbf = true;
+        validation.leeway = self.leeway_secs;
+        let claims = decode::<Claims>(token, &key.key, &validation)?.claims;
+
+        if claims.sub.trim().is_empty() {
+            return Err(TokenError::Malformed("the sub claim is empty".to_string()));
+        }
+        let scope = match claims.scope {
+            // 知らない値は無視し、含まれる中で最も広い範囲にする（何も含まれなければ read）
+            // 他のサービスが発行したトークンには admin を与えない
+            Some(scope) => scope
+                .split_whitespace()
+                .filter_map(|value| value.parse().ok())
+                .max()
+                .unwrap_or(ApiKeyScope::Read)
+                .min(MAX_JWT_SCOPE),
+            None => self.default_scope,
+        };
+        Ok(VerifiedToken { subject: claims.sub, scope })
+    }
+}
+
+/// `xxx.yyy.zzz` の形をしていればJWTとみなす（APIキーと見分けるため）
+pub fn looks_like_jwt(token: &str) -> bool {
+    token.split('.').count() == 3
+}
+
+fn to_algorithm(algorithm: JwtAlgorithm) -> Algorithm {
+    match algorithm {
+        JwtAlgorithm::HS256 => Algorithm::HS256,
+        JwtAlgorithm::RS256 => Algorithm::RS256,
+    }
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, JwtKeyError> {
+    std::fs::read(path).map_err(|source| JwtKeyError::Read { path: path.to_path_buf(), source })
+}
+
+/// HS256 ならファイルの中身（末尾の改行は除く）を共有鍵に、RS256 ならPEMの公開鍵として読む
+fn load_key_file(path: &Path, algorithm: JwtAlgorithm) -> Result<VerificationKey, JwtKeyError> {
+    let content = read(path)?;
+    let key = match algorithm {
+        JwtAlgorithm::HS256 => {
+            let secret = content.trim_ascii_end();
+            if secret.is_empty() {
+                return Err(JwtKeyError::invalid(path, "the shared secret is empty"));
+            }
+            DecodingKey::from_secret(secret)
+        }
+        JwtAlgorithm::RS256 => DecodingKey::from_rsa_pem(&content).map_err(|e| JwtKeyError::invalid(path, e))?,
+    };
+    Ok(VerificationKey { kid: None, algorithm: to_algorithm(algorithm), key })
+}
+
+/// JWKSの鍵のうち HS256 と RS256 のものを読む（`alg` のない鍵は `fallback`、なければ鍵の種類で決める）
+fn load_jwks_file(path: &Path, fallback: Option<JwtAlgorithm>) -> Result<Vec<VerificationKey>, JwtKeyError> {
+    let set: JwkSet = serde_json::from_slice(&read(path)?).map_err(|e| JwtKeyError::invalid(path, e))?;
+    let mut kids = HashSet::new();
+    let mut keys = Vec::with_capacity(set.keys.len());
+    for jwk in &set.keys {
+        let algorithm = match (jwk.common.key_algorithm, fallback, &jwk.algorithm) {
+            (Some(KeyAlgorithm::HS256), _, AlgorithmParameters::OctetKey(_)) => JwtAlgorithm::HS256,
+            (Some(KeyAlgorithm::RS256), _, AlgorithmParameters::RSA(_)) => JwtAlgorithm::RS256,
+            (Some(other), _, _) => {
+                return Err(JwtKeyError::invalid(path, format!("unsupported key algorithm {:?}", other)));
+            }
+            (None, Some(JwtAlgorithm::HS256), AlgorithmParameters::OctetKey(_)) => JwtAlgorithm::HS256,
+            (None, Some(JwtAlgorithm::RS256), AlgorithmParameters::RSA(_)) => JwtAlgorithm::RS256,
+            (None, Some(algorithm), _) => {
+                return Err(JwtKeyError::invalid(path, format!("a key does not match the {:?} algorithm", algorithm)));
+            }
+            (None, None, AlgorithmParameters::OctetKey(_)) => JwtAlgorithm::HS256,
+            (None, None, AlgorithmParameters::RSA(_)) => JwtAlgorithm::RS256,
+            (None, None, _) => return Err(JwtKeyError::invalid(path, "only RSA and symmetric keys are supported")),
+        };
+        if let Some(kid) = &jwk.common.key_id {
+            if !kids.insert(kid.clone()) {
+                return Err(JwtKeyError::invalid(path, format!("duplicate kid {}", kid)));
+            }
+        }
+        let key = DecodingKey::from_jwk(jwk).map_err(|e| JwtKeyError::invalid(path, e))?;
+        keys.push(VerificationKey { kid: jwk.common.key_id.clone(), algorithm: to_algorithm(algorithm), key });
+    }
+    if keys.is_empty() {
+        return Err(JwtKeyError::invalid(path, "the key set is empty"));
+    }
+    Ok(keys)
+}
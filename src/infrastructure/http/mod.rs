@@ -1,3 +1,11 @@
 pub mod handlers;
 pub mod api_impl;
-pub mod generated_routes; 
\ No newline at end of file
+pub mod auth;
+pub mod etag;
+pub mod generated_routes;
+pub mod identity;
+pub mod jwt;
+pub mod problem;
+pub mod rate_limit;
+pub mod request_id;
+pub mod session;
\ No newline at end of file
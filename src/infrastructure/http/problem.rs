@@ -0,0 +1,112 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, HeaderValue, StatusCode};
+use axum::response::Response;
+use openapi::models::Problem;
+use crate::domain::model::task::{TaskValidationError, MAX_DESCRIPTION_LENGTH};
+use super::request_id::{current_request_id, new_request_id};
+
+/// RFC 7807 のメディアタイプ
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// 問題本文として読み取る本文の最大サイズ
+const MAX_FALLBACK_BODY: usize = 64 * 1024;
+
+/// エラーコードとフィールドからProblemを組み立てる
+pub fn problem(status: StatusCode, code: &str, detail: Option<String>, field: Option<&str>) -> Problem {
+    Problem {
+        r#type: format!("/problems/{}", code.to_lowercase().replace('_', "-")),
+        title: status.canonical_reason().unwrap_or("Error").to_string(),
+        status: status.as_u16() as i32,
+        detail,
+        code: code.to_string(),
+        field: field.map(str::to_string),
+        request_id: Some(current_request_id().unwrap_or_else(new_request_id)),
+    }
+}
+
+/// ステータスコードだけが分かっている失敗を汎用のProblemに変換する
+pub fn problem_from_status(status: StatusCode, detail: Option<String>) -> Problem {
+    let code = status
+        .canonical_reason()
+        .unwrap_or("Error")
+        .to_uppercase()
+        .replace([' ', '-'], "_");
+    Problem {
+        r#type: "about:blank".to_string(),
+        ..problem(status, &code, detail, None)
+    }
+}
+
+/// 生成コードのバリデーション失敗をProblemに変換する
+pub fn problem_from_validation(field: &str, rule: &str) -> Problem {
+    let (code, detail) = match (field, rule) {
+        ("description", "length") => (
+            "DESCRIPTION_TOO_LONG",
+            TaskValidationError::DescriptionTooLong(MAX_DESCRIPTION_LENGTH).to_string(),
+        ),
+        _ => ("VALIDATION_FAILED", format!("{} failed {} validation", field, rule)),
+    };
+    problem(StatusCode::BAD_REQUEST, code, Some(detail), Some(field))
+}
+
+/// 生成コードのバリデーションエラー文字列（例: "body.description: Validation error: length [...]"）
+/// からフィールド名とルール名を取り出す
+fn parse_validation_failure(text: &str) -> Option<(&str, &str)> {
+    let (path, rest) = text.split_once(": Validation error: ")?;
+    let field = path.rsplit('.').next()?;
+    let rule = rest.split_whitespace().next()?;
+    Some((field, rule))
+}
+
+/// Problemを application/problem+json のレスポンスに変換する
+pub fn into_response(problem: Problem) -> Response {
+    let status = u16::try_from(problem.status)
+        .ok()
+        .and_then(|s| StatusCode::from_u16(s).ok())
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    let body = serde_json::to_vec(&problem).unwrap_or_default();
+
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+    response
+}
+
+/// 生成コードやaxumのエクストラクタが返す本文なしのエラーを Problem に置き換える
+pub async fn ensure_problem_body(response: Response) -> Response {
+    let status = response.status();
+    if !(status.is_client_error() || status.is_server_error()) {
+        return response;
+    }
+
+    let is_problem = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with(PROBLEM_JSON));
+    if is_problem {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let detail = axum::body::to_bytes(body, MAX_FALLBACK_BODY)
+        .await
+        .ok()
+        .map(|bytes| String::from_utf8_lossy(&bytes).trim().to_string())
+        .filter(|text| !text.is_empty());
+
+    let problem = match detail.as_deref().and_then(parse_validation_failure) {
+        Some((field, rule)) if status == StatusCode::BAD_REQUEST => problem_from_validation(field, rule),
+        _ => problem_from_status(status, detail),
+    };
+
+    let mut problem_response = into_response(problem);
+    for (name, value) in parts.headers.iter() {
+        if name != CONTENT_TYPE && name != axum::http::header::CONTENT_LENGTH {
+            problem_response.headers_mut().append(name, value.clone());
+        }
+    }
+    problem_response
+}
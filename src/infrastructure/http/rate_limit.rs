@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Instant;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use crate::infrastructure::config::{RateLimitBudget, RateLimitConfig};
+use crate::infrastructure::http::auth::{Credential, Principal, PUBLIC_PATHS};
+use crate::infrastructure::http::problem;
+
+/// 予算の上限（IETF の RateLimit ヘッダーの草案に合わせる）
+pub static RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+/// 今すぐ送れる残りのリクエスト数
+pub static RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+/// 予算が上限まで戻るまでの秒数
+pub static RATELIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+static X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+
+/// これより多くの依頼元を覚えたら、予算が上限まで戻った依頼元を忘れる
+const MAX_TRACKED_BUCKETS: usize = 10_000;
+
+/// 流量を数える単位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClientKey {
+    /// 発行したAPIキー（ID）
+    ApiKey(u64),
+    /// JWT・セッション・管理用キーで認証した利用者
+    User(u64),
+    /// 認証していないリクエストの接続元（分からなければ None で、まとめて数える）
+    Ip(Option<IpAddr>),
+}
+
+/// 参照と変更で別々に予算を持つ
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    Read,
+    Write,
+}
+
+impl Operation {
+    pub fn of(method: &Method) -> Self {
+        if matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS) {
+            Operation::Read
+        } else {
+            Operation::Write
+        }
+    }
+}
+
+/// 1回分の判定結果（レスポンスヘッダーに載せる）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// 予算が上限まで戻るまでの秒数
+    pub reset_secs: u64,
+    /// 断ったとき、次の1件を送れるまでの秒数
+    pub retry_after_secs: u64,
+}
+
+/// バケットを分ける単位（接続元ごとの制限では操作の種類を分けない）
+type BucketKey = (ClientKey, Option<Operation>);
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// 補充の速さ（1秒あたり）と容量
+#[derive(Debug, Clone, Copy)]
+struct Quota {
+    per_sec: f64,
+    burst: f64,
+    limit: u32,
+}
+
+impl From<RateLimitBudget> for Quota {
+    fn from(budget: RateLimitBudget) -> Self {
+        Self { per_sec: budget.requests_per_minute as f64 / 60.0, burst: budget.burst as f64, limit: budget.burst }
+    }
+}
+
+impl Quota {
+    fn refill(&self, bucket: &mut Bucket, now: Instant) {
+        let elapsed = now.saturating_duration_since(bucket.updated_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.per_sec).min(self.burst);
+        bucket.updated_at = now;
+    }
+
+    fn secs_until(&self, tokens: f64, target: f64) -> u64 {
+        ((target - tokens).max(0.0) / self.per_sec).ceil() as u64
+    }
+}
+
+/// 依頼元と操作の種類ごとのトークンバケット
+///
+/// バケットはメモリ上にだけ持ち、複数のプロセスでは共有しない。
+/// 接続元ごとの制限（`per_address`）では、参照と変更をまとめて1つのバケットで数える。
+#[derive(Clone)]
+pub struct RateLimiter {
+    read: Quota,
+    write: Quota,
+    per_address: bool,
+    trust_forwarded_for: bool,
+    buckets: Arc<Mutex<HashMap<BucketKey, Bucket>>>,
+}
+
+impl RateLimiter {
+    /// 認証した主体（なければ接続元）ごとに、参照と変更を別々の予算で数える
+    pub fn new(config: &RateLimitConfig) -> Self {
+        Self {
+            read: config.read.into(),
+            write: config.write.into(),
+            per_address: false,
+            trust_forwarded_for: config.trust_forwarded_for,
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 認証の結果によらず、接続元のIPアドレスごとに全てのリクエストを数える
+    pub fn per_address(config: &RateLimitConfig) -> Self {
+        Self {
+            read: config.per_address.into(),
+            write: config.per_address.into(),
+            per_address: true,
+            trust_forwarded_for: config.trust_forwarded_for,
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn quota(&self, operation: Operation) -> Quota {
+        match operation {
+            Operation::Read => self.read,
+            Operation::Write => self.write,
+        }
+    }
+
+    /// 1件分の予算を使う（足りなければ使わずに断る）
+    pub fn check(&self, key: ClientKey, operation: Operation) -> Decision {
+        self.check_at(key, operation, Instant::now())
+    }
+
+    /// `now` の時点で1件分の予算を使う
+    pub fn check_at(&self, key: ClientKey, operation: Operation, now: Instant) -> Decision {
+        let quota = self.quota(operation);
+        // 数え途中で panic したスレッドがあっても、バケットの値はそのまま使える
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        if buckets.len() >= MAX_TRACKED_BUCKETS {
+            buckets.retain(|(_, operation), bucket| {
+                let quota = self.quota(operation.unwrap_or(Operation::Read));
+                quota.refill(bucket, now);
+                bucket.tokens < quota.burst
+            });
+        }
+        let slot: BucketKey = (key, (!self.per_address).then_some(operation));
+        let bucket = buckets.entry(slot).or_insert(Bucket { tokens: quota.burst, updated_at: now });
+        quota.refill(bucket, now);
+        let allowed = bucket.tokens >= 1.0;
+        if allowed {
+            bucket.tokens -= 1.0;
+        }
+        Decision {
+            allowed,
+            limit: quota.limit,
+            remaining: bucket.tokens.floor() as u32,
+            reset_secs: quota.secs_until(bucket.tokens, quota.burst),
+            retry_after_secs: if allowed { 0 } else { quota.secs_until(bucket.tokens, 1.0) },
+        }
+    }
+
+    /// 認証した主体があればAPIキーか利用者、なければ接続元のIPアドレスで数える
+    ///
+    /// 接続元ごとの制限では、主体がいても接続元のIPアドレスで数える。
+    pub fn client_key(&self, request: &Request) -> ClientKey {
+        let principal = request.extensions().get::<Principal>().filter(|_| !self.per_address);
+        if let Some(principal) = principal {
+            return match principal.credential {
+                Credential::ApiKey(id) => ClientKey::ApiKey(id),
+                _ => ClientKey::User(principal.user_id),
+            };
+        }
+        let forwarded = self.trust_forwarded_for.then(|| forwarded_for(request.headers())).flatten();
+        let peer = request.extensions().get::<ConnectInfo<SocketAddr>>().map(|ConnectInfo(addr)| addr.ip());
+        ClientKey::Ip(forwarded.or(peer))
+    }
+}
+
+/// `X-Forwarded-For` の先頭（最初のプロキシが見た接続元）
+fn forwarded_for(headers: &HeaderMap) -> Option<IpAddr> {
+    headers
+        .get(&X_FORWARDED_FOR)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(',').next())
+        .and_then(|value| value.trim().parse().ok())
+}
+
+fn set_headers(headers: &mut HeaderMap, decision: &Decision) {
+    headers.insert(RATELIMIT_LIMIT.clone(), HeaderValue::from(decision.limit));
+    headers.insert(RATELIMIT_REMAINING.clone(), HeaderValue::from(decision.remaining));
+    headers.insert(RATELIMIT_RESET.clone(), HeaderValue::from(decision.reset_secs));
+}
+
+/// 依頼元の予算が残っていれば処理を続け、なければ `Retry-After` を付けた 429 にする
+///
+/// どちらの場合も `RateLimit-Limit`・`RateLimit-Remaining`・`RateLimit-Reset` を付ける（内側の制限が付けていればそちらを残す）。
+/// `RateLimiter::new` は認証した主体を使うため認証のミドルウェアより内側に、`RateLimiter::per_address` は
+/// 認証に失敗したリクエストも数えるため外側に置く。死活監視とバージョンは数えない。
+pub async fn limit_rate(State(limiter): State<RateLimiter>, request: Request, next: Next) -> Response {
+    if PUBLIC_PATHS.contains(&request.uri().path()) {
+        return next.run(request).await;
+    }
+    let operation = Operation::of(request.method());
+    let key = limiter.client_key(&request);
+    let decision = limiter.check(key, operation);
+    if !decision.allowed {
+        tracing::debug!(client = ?key, ?operation, "rate limit exceeded");
+        let what = match operation {
+            _ if limiter.per_address => "requests from this address",
+            Operation::Read => "read requests",
+            Operation::Write => "write requests",
+        };
+        let mut response = problem::into_response(problem::problem(
+            StatusCode::TOO_MANY_REQUESTS,
+            "RATE_LIMITED",
+            Some(format!("Too many {}; retry after {} seconds", what, decision.retry_after_secs)),
+            None,
+        ));
+        set_headers(response.headers_mut(), &decision);
+        response.headers_mut().insert(header::RETRY_AFTER, HeaderValue::from(decision.retry_after_secs));
+        return response;
+    }
+    let mut response = next.run(request).await;
+    if !response.headers().contains_key(&RATELIMIT_LIMIT) {
+        set_headers(response.headers_mut(), &decision);
+    }
+    response
+}
@@ -0,0 +1,90 @@
+use std::time::Instant;
+use axum::extract::{MatchedPath, Request};
+use axum::http::{HeaderName, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use tracing::field::Empty;
+use tracing::Instrument;
+use crate::infrastructure::metrics::http::operation_name;
+
+/// リクエストIDを受け渡すヘッダー
+pub static X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// クライアントから受け取るリクエストIDの最大長
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// 処理中のリクエストのID（ミドルウェアの外では None）
+pub fn current_request_id() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+/// エラーとログを突き合わせるためのリクエストIDを生成する
+pub fn new_request_id() -> String {
+    format!("{:016x}", rand::random::<u64>())
+}
+
+/// ログやヘッダーに載せても安全な値だけをクライアントのIDとして受け入れる
+fn accept_request_id(value: &HeaderValue) -> Option<String> {
+    let value = value.to_str().ok()?;
+    let valid = !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LENGTH
+        && value.bytes().all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b':'));
+    valid.then(|| value.to_string())
+}
+
+/// パスのテンプレートで `{id}` に当たる部分を取り出す
+fn task_id(template: &str, path: &str) -> Option<u64> {
+    template
+        .split('/')
+        .zip(path.split('/'))
+        .find(|(segment, _)| *segment == "{id}")
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+/// リクエストIDを受け取るか生成し、リクエストのスパンとレスポンスヘッダーに載せる
+///
+/// 処理中は `current_request_id` で同じIDを参照でき、エラー本文の `request_id` にも使われる。
+pub async fn request_context(request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(&X_REQUEST_ID)
+        .and_then(accept_request_id)
+        .unwrap_or_else(new_request_id);
+
+    let matched = request.extensions().get::<MatchedPath>().map(|path| path.as_str().to_string());
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %request.method(),
+        path = %request.uri().path(),
+        operation = Empty,
+        task_id = Empty,
+        status = Empty,
+        latency_ms = Empty,
+    );
+    if let Some(template) = &matched {
+        span.record("operation", operation_name(request.method(), template));
+        if let Some(id) = task_id(template, request.uri().path()) {
+            span.record("task_id", id);
+        }
+    }
+
+    let started = Instant::now();
+    let mut response = REQUEST_ID
+        .scope(request_id.clone(), next.run(request).instrument(span.clone()))
+        .await;
+    let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+    span.record("status", response.status().as_u16());
+    span.record("latency_ms", latency_ms);
+    span.in_scope(|| tracing::info!("request completed"));
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(X_REQUEST_ID.clone(), value);
+    }
+    response
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+use axum_extra::extract::cookie::{Cookie, SameSite};
+use axum_extra::extract::CookieJar;
+use chrono::{DateTime, Duration, Utc};
+use cookie::Key;
+use rand::distr::{Alphanumeric, SampleString};
+use crate::domain::model::api_key::ApiKeyScope;
+use crate::infrastructure::config::SessionConfig;
+use crate::infrastructure::http::auth::{Credential, Principal, PUBLIC_PATHS, X_API_KEY};
+use crate::infrastructure::http::problem;
+
+/// セッションIDを暗号化して入れるクッキー
+pub const SESSION_COOKIE: &str = "todo_session";
+
+/// セッションのCSRFトークンを受け取るヘッダー
+pub static X_CSRF_TOKEN: HeaderName = HeaderName::from_static("x-csrf-token");
+
+/// 既存のセッションがなくても呼べるパス（期限切れのクッキーが残っていてもログインし直せるように）
+const LOGIN_PATH: &str = "/auth/login";
+
+/// セッションIDを新しくしたあと、古いIDを受け付ける秒数（並行して送られたリクエストのため）
+const ROTATION_GRACE_SECS: i64 = 30;
+
+/// ログインで始めたセッション
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub id: String,
+    pub user_id: u64,
+    pub scope: ApiKeyScope,
+    /// 変更するリクエストに `X-CSRF-Token` で送らせるトークン（IDを新しくしても変わらない）
+    pub csrf_token: String,
+    /// 今のIDを発行した日時（IDを新しくする時期の判断に使う）
+    pub issued_at: DateTime<Utc>,
+    /// セッションが切れる日時（IDを新しくしても延びない）
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Session {
+    fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// セッションの保存先を使えない（ロックを取れない）
+#[derive(Debug, thiserror::Error)]
+#[error("Failed to acquire lock: {0}")]
+pub struct SessionStoreError(String);
+
+fn random_token() -> String {
+    Alphanumeric.sample_string(&mut rand::rng(), 32)
+}
+
+/// セッションの保存と、暗号化したクッキーの読み書き
+///
+/// 暗号化の鍵は起動ごとに作る。セッション自体もメモリ上にしかないため、再起動すると全員ログアウトする。
+#[derive(Clone)]
+pub struct SessionManager {
+    key: Key,
+    sessions: Arc<Mutex<HashMap<String, Session>>>,
+    ttl: Duration,
+    rotate_after: Duration,
+    secure_cookie: bool,
+}
+
+impl SessionManager {
+    pub fn new(config: &SessionConfig) -> Self {
+        Self {
+            key: Key::generate(),
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+            ttl: Duration::from_std(config.ttl()).unwrap_or(Duration::MAX),
+            rotate_after: Duration::from_std(config.rotate_after()).unwrap_or(Duration::MAX),
+            secure_cookie: config.secure_cookie,
+        }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, HashMap<String, Session>>, SessionStoreError> {
+        self.sessions.lock().map_err(|e| SessionStoreError(e.to_string()))
+    }
+
+    /// 利用者のセッションを始める
+    pub fn start(&self, user_id: u64, scope: ApiKeyScope) -> Result<Session, SessionStoreError> {
+        let now = Utc::now();
+        let session = Session {
+            id: random_token(),
+            user_id,
+            scope,
+            csrf_token: random_token(),
+            issued_at: now,
+            expires_at: now.checked_add_signed(self.ttl).unwrap_or(DateTime::<Utc>::MAX_UTC),
+        };
+        let mut sessions = self.lock()?;
+        // 切れたセッションは始めるついでに片付ける
+        sessions.retain(|_, session| !session.is_expired(now));
+        sessions.insert(session.id.clone(), session.clone());
+        Ok(session)
+    }
+
+    /// クッキーのセッション（復号できない・見つからない・切れている場合は None）
+    pub fn session(&self, cookies: &CookieJar) -> Result<Option<Session>, SessionStoreError> {
+        let Some(cookie) = cookies.get(SESSION_COOKIE) else {
+            return Ok(None);
+        };
+        let mut jar = cookie::CookieJar::new();
+        jar.add_original(cookie.clone());
+        let Some(id) = jar.private(&self.key).get(SESSION_COOKIE).map(|cookie| cookie.value().to_string()) else {
+            return Ok(None);
+        };
+        let sessions = self.lock()?;
+        Ok(sessions.get(&id).filter(|session| !session.is_expired(Utc::now())).cloned())
+    }
+
+    /// セッションを終える（見つからなければ何もしない）
+    pub fn end(&self, id: &str) -> Result<(), SessionStoreError> {
+        self.lock()?.remove(id);
+        Ok(())
+    }
+
+    /// IDを新しくする時期か
+    pub fn needs_rotation(&self, session: &Session) -> bool {
+        Utc::now() - session.issued_at >= self.rotate_after
+    }
+
+    /// 同じ利用者・範囲・CSRFトークン・期限のまま、IDだけを新しくする
+    ///
+    /// 古いIDは少しの間だけ受け付ける。
+    pub fn rotate(&self, session: &Session) -> Result<Session, SessionStoreError> {
+        let now = Utc::now();
+        let rotated = Session { id: random_token(), issued_at: now, ..session.clone() };
+        let mut sessions = self.lock()?;
+        if let Some(old) = sessions.get_mut(&session.id) {
+            // 猶予の間に古いIDで届いたリクエストでは、もう一度新しくしない
+            old.issued_at = now;
+            old.expires_at = old.expires_at.min(now + Duration::seconds(ROTATION_GRACE_SECS));
+        }
+        sessions.insert(rotated.id.clone(), rotated.clone());
+        Ok(rotated)
+    }
+
+    /// セッションIDを暗号化したクッキー（JavaScript からは読めず、他のサイトからのリクエストには付かない）
+    pub fn cookie(&self, session: &Session) -> Cookie<'static> {
+        // 秒未満は切り上げ、発行直後は設定した有効期間どおりにする
+        let max_age = ((session.expires_at - Utc::now()).num_milliseconds().max(0) + 999) / 1000;
+        let cookie = Cookie::build((SESSION_COOKIE, session.id.clone()))
+            .path("/")
+            .http_only(true)
+            .secure(self.secure_cookie)
+            .same_site(SameSite::Strict)
+            .max_age(cookie::time::Duration::seconds(max_age))
+            .build();
+        let mut jar = cookie::CookieJar::new();
+        jar.private_mut(&self.key).add(cookie);
+        jar.get(SESSION_COOKIE).cloned().expect("the cookie was just added")
+    }
+
+    /// ブラウザからセッションのクッキーを消すための期限切れのクッキー
+    pub fn removal_cookie(&self) -> Cookie<'static> {
+        Cookie::build((SESSION_COOKIE, ""))
+            .path("/")
+            .http_only(true)
+            .secure(self.secure_cookie)
+            .same_site(SameSite::Strict)
+            .max_age(cookie::time::Duration::ZERO)
+            .build()
+    }
+}
+
+/// 長さによらず同じ時間で比べる（トークンを1文字ずつ推測されないように）
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+fn is_safe(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+fn set_cookie(response: &mut Response, cookie: Cookie<'static>) {
+    if let Ok(value) = HeaderValue::from_str(&cookie.to_string()) {
+        response.headers_mut().append(header::SET_COOKIE, value);
+    }
+}
+
+/// セッションのクッキーがあれば確かめ、`Principal` を付けて処理を続ける
+///
+/// `Authorization` か `X-Api-Key` を送ったリクエストはそちらで認証するため、クッキーは見ない。
+/// 切れたセッションのクッキーは 401 にして消し、変更するリクエストでCSRFトークンが一致しなければ 403 にする。
+/// IDを発行してから時間が経っていれば、応答で新しいIDのクッキーに差し替える。
+pub async fn resume_session(State(sessions): State<SessionManager>, mut request: Request, next: Next) -> Response {
+    let headers = request.headers();
+    let explicit = headers.contains_key(header::AUTHORIZATION) || headers.contains_key(&X_API_KEY);
+    if explicit || PUBLIC_PATHS.contains(&request.uri().path()) {
+        return next.run(request).await;
+    }
+    let cookies = CookieJar::from_headers(headers);
+    if cookies.get(SESSION_COOKIE).is_none() {
+        return next.run(request).await;
+    }
+    let session = match sessions.session(&cookies) {
+        Ok(session) => session,
+        Err(e) => {
+            tracing::error!("failed to look up the session: {}", e);
+            return problem::into_response(problem::problem(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "INTERNAL_ERROR",
+                Some("An unexpected error occurred".to_string()),
+                None,
+            ));
+        }
+    };
+    let Some(session) = session else {
+        if request.uri().path() == LOGIN_PATH {
+            return next.run(request).await;
+        }
+        let mut response = problem::into_response(problem::problem(
+            StatusCode::UNAUTHORIZED,
+            "SESSION_EXPIRED",
+            Some("The session has expired or ended; log in again".to_string()),
+            None,
+        ));
+        set_cookie(&mut response, sessions.removal_cookie());
+        return response;
+    };
+
+    if !is_safe(request.method()) {
+        let presented = request.headers().get(&X_CSRF_TOKEN).map(HeaderValue::as_bytes).unwrap_or_default();
+        if !constant_time_eq(presented, session.csrf_token.as_bytes()) {
+            return problem::into_response(problem::problem(
+                StatusCode::FORBIDDEN,
+                "CSRF_TOKEN_INVALID",
+                Some(format!("Requests made with the session cookie must send the session's CSRF token as {}", X_CSRF_TOKEN)),
+                None,
+            ));
+        }
+    }
+
+    // ログインとログアウトはクッキーを自分で差し替える
+    let rotate = sessions.needs_rotation(&session) && !request.uri().path().starts_with("/auth/");
+    request.extensions_mut().insert(Principal {
+        user_id: session.user_id,
+        scope: session.scope,
+        credential: Credential::Session(session.id.clone()),
+    });
+    let mut response = next.run(request).await;
+    if rotate {
+        // 新しくできなければ今のIDのまま使い続けさせる（次のリクエストでやり直す）
+        match sessions.rotate(&session) {
+            Ok(rotated) => set_cookie(&mut response, sessions.cookie(&rotated)),
+            Err(e) => tracing::error!("failed to rotate the session: {}", e),
+        }
+    }
+    response
+}
@@ -0,0 +1,34 @@
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
+use super::config::{LogConfig, LogFormat};
+
+/// 設定に従ってログの出力先（標準出力）を登録する（2回目以降の呼び出しは何もしない）
+pub fn init(config: &LogConfig) {
+    if let Err(e) = tracing::subscriber::set_global_default(subscriber(config, std::io::stdout)) {
+        tracing::debug!("log subscriber already installed: {}", e);
+    }
+}
+
+/// 設定に従ったサブスクライバーを作る
+///
+/// リクエストごとのスパンの項目（request_id、method、path、status など）は
+/// そのスパン内で出力されるすべてのイベントに付く。
+pub fn subscriber<W>(config: &LogConfig, writer: W) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'a> MakeWriter<'a> + Send + Sync + 'static,
+{
+    let builder = tracing_subscriber::fmt()
+        .with_max_level(config.level.to_level_filter())
+        .with_writer(writer);
+    match config.format {
+        LogFormat::Pretty => Box::new(builder.pretty().finish()),
+        LogFormat::Json => Box::new(
+            builder
+                .json()
+                .flatten_event(true)
+                .with_current_span(true)
+                .with_span_list(false)
+                .finish(),
+        ),
+    }
+}
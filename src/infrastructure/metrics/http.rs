@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use std::time::Instant;
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::{header, HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use super::Metrics;
+use crate::usecase::task::TaskUsecase;
+
+/// どのルートにも一致しなかったリクエストのラベル（パスをそのままラベルにすると値が際限なく増える）
+pub const UNMATCHED_OPERATION: &str = "unmatched";
+
+/// ルートのパスとメソッドから生成コードと同じ操作名を作る（例: `PUT /tasks/{id}` → `tasks_id_put`）
+pub fn operation_name(method: &Method, path: &str) -> String {
+    let mut name: String = path
+        .chars()
+        .filter(|c| !matches!(c, '{' | '}'))
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    name.push('_');
+    name.push_str(&method.as_str().to_ascii_lowercase());
+    name.trim_matches('_').to_string()
+}
+
+/// リクエストごとに件数と処理時間を記録するミドルウェア
+pub async fn track_requests(State(metrics): State<Arc<Metrics>>, request: Request, next: Next) -> Response {
+    let operation = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| operation_name(request.method(), path.as_str()));
+    let started = Instant::now();
+    let response = next.run(request).await;
+
+    let operation = match operation {
+        Some(operation) if response.status() != StatusCode::METHOD_NOT_ALLOWED => operation,
+        _ => UNMATCHED_OPERATION.to_string(),
+    };
+    metrics.observe_request(&operation, response.status().as_u16(), started.elapsed());
+    response
+}
+
+/// `/metrics` のハンドラーが使う状態
+#[derive(Clone)]
+pub struct MetricsState<U> {
+    pub metrics: Arc<Metrics>,
+    pub usecase: U,
+}
+
+/// Prometheus形式でメトリクスを返す（タスク数は取得のたびに数え直す）
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "system",
+    responses(
+        (status = 200, description = "Metrics in the Prometheus text exposition format"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_metrics<U>(State(state): State<MetricsState<U>>) -> Response
+where
+    U: TaskUsecase + Clone + 'static,
+{
+    match state.usecase.get_task_counts().await {
+        Ok(counts) => state.metrics.set_task_counts(counts),
+        // タスク数が取れなくても他のメトリクスは返す
+        Err(error) => tracing::warn!("failed to count tasks for metrics: {}", error),
+    }
+    (
+        [(header::CONTENT_TYPE, HeaderValue::from_static("text/plain; version=0.0.4; charset=utf-8"))],
+        state.metrics.render(),
+    )
+        .into_response()
+}
@@ -0,0 +1,97 @@
+pub mod http;
+pub mod repository;
+
+use std::time::Duration;
+use prometheus::{Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder};
+use crate::domain::repository::task::TaskCounts;
+
+/// Prometheus形式で公開するメトリクス
+///
+/// ルーターごとに独立したレジストリを持つ（テストでルーターを複数作っても値が混ざらない）。
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    repository_duration: HistogramVec,
+    repository_errors: IntCounterVec,
+    tasks: IntGaugeVec,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("todo_http_requests_total", "HTTP requests by operation and response status"),
+            &["operation", "status"],
+        )
+        .expect("valid metric");
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new("todo_http_request_duration_seconds", "HTTP request latency by operation"),
+            &["operation"],
+        )
+        .expect("valid metric");
+        let repository_duration = HistogramVec::new(
+            HistogramOpts::new("todo_repository_operation_duration_seconds", "Task repository call latency by method")
+                .buckets(vec![0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0]),
+            &["operation"],
+        )
+        .expect("valid metric");
+        let repository_errors = IntCounterVec::new(
+            Opts::new("todo_repository_operation_errors_total", "Task repository calls that returned an error, by method"),
+            &["operation"],
+        )
+        .expect("valid metric");
+        let tasks = IntGaugeVec::new(
+            Opts::new("todo_tasks", "Number of tasks by state (total, completed, pending)"),
+            &["state"],
+        )
+        .expect("valid metric");
+
+        for collector in [
+            Box::new(http_requests.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(http_request_duration.clone()),
+            Box::new(repository_duration.clone()),
+            Box::new(repository_errors.clone()),
+            Box::new(tasks.clone()),
+        ] {
+            registry.register(collector).expect("metric names are unique");
+        }
+
+        Self { registry, http_requests, http_request_duration, repository_duration, repository_errors, tasks }
+    }
+
+    /// 処理したHTTPリクエストを記録する
+    pub fn observe_request(&self, operation: &str, status: u16, elapsed: Duration) {
+        self.http_requests.with_label_values(&[operation, &status.to_string()]).inc();
+        self.http_request_duration.with_label_values(&[operation]).observe(elapsed.as_secs_f64());
+    }
+
+    /// リポジトリの呼び出しを記録する
+    pub fn observe_repository(&self, operation: &str, succeeded: bool, elapsed: Duration) {
+        self.repository_duration.with_label_values(&[operation]).observe(elapsed.as_secs_f64());
+        if !succeeded {
+            self.repository_errors.with_label_values(&[operation]).inc();
+        }
+    }
+
+    pub fn set_task_counts(&self, counts: TaskCounts) {
+        self.tasks.with_label_values(&["total"]).set(counts.total as i64);
+        self.tasks.with_label_values(&["completed"]).set(counts.completed as i64);
+        self.tasks.with_label_values(&["pending"]).set(counts.pending() as i64);
+    }
+
+    /// Prometheusのテキスト形式で書き出す
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        // 書き込み先がメモリなので失敗しない
+        let _ = TextEncoder::new().encode(&self.registry.gather(), &mut buffer);
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
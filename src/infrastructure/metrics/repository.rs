@@ -0,0 +1,125 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Instant;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use super::Metrics;
+use crate::domain::model::history::TaskHistoryEntry;
+use crate::domain::model::task::{CreateTask, Task, UpdateTask};
+use crate::domain::repository::task::{
+    BatchMode, BatchOutcome, ChildPolicy, TagCount, TaskCounts, TaskError, TaskOperation, TaskOperationOutcome, TaskPage,
+    TaskQuery, TaskRepository,
+};
+
+/// 呼び出しごとの処理時間とエラーを記録するリポジトリのラッパー
+///
+/// 既定の実装を持つメソッドも内側のリポジトリへ委譲し、内側の上書きを活かす。
+#[derive(Clone)]
+pub struct MeteredTaskRepository<R> {
+    inner: R,
+    metrics: Arc<Metrics>,
+}
+
+impl<R> MeteredTaskRepository<R> {
+    pub fn new(inner: R, metrics: Arc<Metrics>) -> Self {
+        Self { inner, metrics }
+    }
+
+    async fn observe<T>(&self, operation: &str, call: impl Future<Output = Result<T, TaskError>>) -> Result<T, TaskError> {
+        let started = Instant::now();
+        let result = call.await;
+        self.metrics.observe_repository(operation, result.is_ok(), started.elapsed());
+        result
+    }
+}
+
+#[async_trait]
+impl<R: TaskRepository> TaskRepository for MeteredTaskRepository<R> {
+    async fn get_all(&self) -> Result<Vec<Task>, TaskError> {
+        self.observe("get_all", self.inner.get_all()).await
+    }
+
+    async fn get_by_id(&self, id: u64) -> Result<Task, TaskError> {
+        self.observe("get_by_id", self.inner.get_by_id(id)).await
+    }
+
+    async fn create(&self, task: CreateTask) -> Result<Task, TaskError> {
+        self.observe("create", self.inner.create(task)).await
+    }
+
+    async fn update(&self, id: u64, update_task: UpdateTask) -> Result<Task, TaskError> {
+        self.observe("update", self.inner.update(id, update_task)).await
+    }
+
+    async fn delete(&self, id: u64) -> Result<(), TaskError> {
+        self.observe("delete", self.inner.delete(id)).await
+    }
+
+    async fn complete(&self, id: u64) -> Result<Task, TaskError> {
+        self.observe("complete", self.inner.complete(id)).await
+    }
+
+    async fn force_complete(&self, id: u64, expected_version: Option<u64>) -> Result<Task, TaskError> {
+        self.observe("force_complete", self.inner.force_complete(id, expected_version)).await
+    }
+
+    async fn uncomplete(&self, id: u64) -> Result<Task, TaskError> {
+        self.observe("uncomplete", self.inner.uncomplete(id)).await
+    }
+
+    async fn update_if_version(&self, id: u64, update_task: UpdateTask, expected_version: u64) -> Result<Task, TaskError> {
+        self.observe("update_if_version", self.inner.update_if_version(id, update_task, expected_version)).await
+    }
+
+    async fn delete_if_version(&self, id: u64, expected_version: u64) -> Result<(), TaskError> {
+        self.observe("delete_if_version", self.inner.delete_if_version(id, expected_version)).await
+    }
+
+    async fn delete_with_children(&self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> Result<Vec<TaskOperationOutcome>, TaskError> {
+        self.observe("delete_with_children", self.inner.delete_with_children(id, policy, expected_version)).await
+    }
+
+    async fn apply_batch(&self, operations: Vec<TaskOperation>, mode: BatchMode) -> Result<BatchOutcome, TaskError> {
+        self.observe("apply_batch", self.inner.apply_batch(operations, mode)).await
+    }
+
+    async fn get_trash(&self) -> Result<Vec<Task>, TaskError> {
+        self.observe("get_trash", self.inner.get_trash()).await
+    }
+
+    async fn restore(&self, id: u64) -> Result<Task, TaskError> {
+        self.observe("restore", self.inner.restore(id)).await
+    }
+
+    async fn purge_trash(&self, deleted_before: Option<DateTime<Utc>>, owner_id: Option<u64>) -> Result<usize, TaskError> {
+        self.observe("purge_trash", self.inner.purge_trash(deleted_before, owner_id)).await
+    }
+
+    async fn get_history(&self, id: u64) -> Result<Vec<TaskHistoryEntry>, TaskError> {
+        self.observe("get_history", self.inner.get_history(id)).await
+    }
+
+    async fn query(&self, query: TaskQuery) -> Result<TaskPage, TaskError> {
+        self.observe("query", self.inner.query(query)).await
+    }
+
+    async fn count_tasks(&self) -> Result<TaskCounts, TaskError> {
+        self.observe("count_tasks", self.inner.count_tasks()).await
+    }
+
+    async fn ping(&self) -> Result<(), TaskError> {
+        self.observe("ping", self.inner.ping()).await
+    }
+
+    fn backend_name(&self) -> &'static str {
+        self.inner.backend_name()
+    }
+
+    async fn shutdown(&self) -> Result<(), TaskError> {
+        self.observe("shutdown", self.inner.shutdown()).await
+    }
+
+    async fn get_tag_counts(&self) -> Result<Vec<TagCount>, TaskError> {
+        self.observe("get_tag_counts", self.inner.get_tag_counts()).await
+    }
+}
@@ -1,2 +1,7 @@
+pub mod build_info;
+pub mod config;
+pub mod events;
 pub mod http;
-pub mod server; 
\ No newline at end of file
+pub mod logging;
+pub mod metrics;
+pub mod server;
\ No newline at end of file
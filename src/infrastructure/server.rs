@@ -1,11 +1,274 @@
-use super::http::generated_routes::create_generated_router;
+use std::future::{Future, IntoFuture};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use axum::extract::{DefaultBodyLimit, Request};
+use axum::http::{header, HeaderName, HeaderValue, Method};
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::Router;
+use thiserror::Error;
+use tokio::net::TcpListener;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use super::config::{Config, CorsConfig, StorageBackend};
+use super::events::TaskEventBroker;
+use super::http::auth::X_API_KEY;
+use super::http::generated_routes::create_generated_router_with_config;
+use super::http::identity::X_USER_ID;
+use super::http::jwt::{JwtKeyError, JwtVerifier};
+use super::http::rate_limit::{RATELIMIT_LIMIT, RATELIMIT_REMAINING, RATELIMIT_RESET};
+use super::http::request_id::X_REQUEST_ID;
+use super::http::session::X_CSRF_TOKEN;
+use crate::domain::repository::task::{TaskError, TaskRepository};
+use crate::interface::gateway::inmemory::InMemoryTaskRepository;
+use crate::interface::gateway::sqlite::SqliteTaskRepository;
 
-pub async fn start_server() {
-    // 生成されたルーターを使用
-    let app = create_generated_router();
+#[derive(Debug, Error)]
+pub enum ServerError {
+    #[error("failed to open storage: {0}")]
+    Storage(#[from] TaskError),
+    #[error("failed to load JWT keys: {0}")]
+    JwtKeys(#[from] JwtKeyError),
+    #[error("failed to bind {addr}: {source}")]
+    Bind {
+        addr: SocketAddr,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("server error: {0}")]
+    Serve(#[source] std::io::Error),
+}
 
-    let listener = tokio::net::TcpListener::bind("127.0.0.1:3000").await.unwrap();
-    println!("Server running on http://127.0.0.1:3000");
+/// 設定に従ってストレージを開き、ルーターを組み立てる
+pub fn build_router(config: &Config) -> Result<Router, ServerError> {
+    build(config).map(|app| app.router)
+}
 
-    axum::serve(listener, app).await.unwrap();
-} 
\ No newline at end of file
+/// 停止時に後始末が必要なものを含むアプリケーション
+struct App {
+    router: Router,
+    repository: Box<dyn TaskRepository>,
+    events: Arc<TaskEventBroker>,
+}
+
+fn build(config: &Config) -> Result<App, ServerError> {
+    let events = Arc::new(TaskEventBroker::new());
+    let jwt = JwtVerifier::from_config(&config.auth.jwt)?.map(Arc::new);
+    let (router, repository): (Router, Box<dyn TaskRepository>) = match config.storage.backend {
+        StorageBackend::Memory => {
+            let repository = InMemoryTaskRepository::new();
+            (create_generated_router_with_config(repository.clone(), events.clone(), config, jwt), Box::new(repository))
+        }
+        StorageBackend::Sqlite => {
+            let repository = SqliteTaskRepository::open(&config.storage.path)?;
+            (create_generated_router_with_config(repository.clone(), events.clone(), config, jwt), Box::new(repository))
+        }
+    };
+    Ok(App { router: apply_http_config(router, config), repository, events })
+}
+
+/// 本文サイズの上限とCORSを設定に従って掛ける
+pub fn apply_http_config(router: Router, config: &Config) -> Router {
+    let router = router.layer(DefaultBodyLimit::max(config.limits.max_body_bytes));
+    match cors_layer(&config.cors) {
+        Some(cors) => router.layer(cors),
+        None => router,
+    }
+}
+
+/// 許可するオリジンがなければCORSヘッダーは付けない
+fn cors_layer(config: &CorsConfig) -> Option<CorsLayer> {
+    if config.allowed_origins.is_empty() {
+        return None;
+    }
+
+    let allow_origin = if config.allowed_origins.iter().any(|origin| origin == "*") {
+        AllowOrigin::any()
+    } else {
+        // 起動時の検証で HeaderValue に変換できることは確認済み
+        AllowOrigin::list(config.allowed_origins.iter().filter_map(|origin| HeaderValue::from_str(origin).ok()))
+    };
+
+    Some(
+        CorsLayer::new()
+            .allow_origin(allow_origin)
+            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
+            .allow_headers([
+                header::AUTHORIZATION,
+                header::CONTENT_TYPE,
+                header::IF_MATCH,
+                header::IF_NONE_MATCH,
+                X_API_KEY.clone(),
+                X_CSRF_TOKEN.clone(),
+                X_REQUEST_ID.clone(),
+                X_USER_ID.clone(),
+            ])
+            .expose_headers([
+                header::ETAG,
+                header::LINK,
+                header::RETRY_AFTER,
+                HeaderName::from_static("x-next-cursor"),
+                X_REQUEST_ID.clone(),
+                RATELIMIT_LIMIT.clone(),
+                RATELIMIT_REMAINING.clone(),
+                RATELIMIT_RESET.clone(),
+            ]),
+    )
+}
+
+/// 停止処理の結果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShutdownSummary {
+    /// 受け付けたリクエストの総数
+    pub requests_served: u64,
+    /// 期限までに終わらず打ち切ったリクエストの数
+    pub abandoned_requests: usize,
+    /// 停止の合図から処理中のリクエストが片付くまでの時間
+    pub drain_time: Duration,
+}
+
+/// 処理中と処理済みのリクエスト数
+#[derive(Default)]
+struct RequestCounter {
+    in_flight: AtomicUsize,
+    served: AtomicU64,
+}
+
+/// レスポンスを返すか途中で破棄されたときに処理中の数を減らす
+struct InFlightGuard(Arc<RequestCounter>);
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+async fn count_requests(counter: Arc<RequestCounter>, request: Request, next: Next) -> Response {
+    counter.in_flight.fetch_add(1, Ordering::SeqCst);
+    counter.served.fetch_add(1, Ordering::SeqCst);
+    let _guard = InFlightGuard(counter);
+    next.run(request).await
+}
+
+pub async fn start_server(config: Config) -> Result<(), ServerError> {
+    let app = build(&config)?;
+
+    let addr = config.server.bind;
+    let listener = TcpListener::bind(addr)
+        .await
+        .map_err(|source| ServerError::Bind { addr, source })?;
+    let local_addr = listener.local_addr().unwrap_or(addr);
+    println!("Server running on http://{}", local_addr);
+
+    // イベントの配信を止めないと、開いたままのストリームが停止を妨げる
+    let events = app.events;
+    let shutdown = async move {
+        shutdown_signal().await;
+        events.close();
+    };
+    serve(listener, app.router, app.repository.as_ref(), shutdown, config.server.shutdown_timeout()).await?;
+    // 書き込みを反映し終えた接続は、プロセスの終了を待たずにここで閉じる
+    drop(app.repository);
+    Ok(())
+}
+
+/// `shutdown` が完了するまでリクエストを受け付け、その後は新しい接続を断って
+/// 処理中のリクエストを `drain_timeout` まで待ってからリポジトリを閉じる
+pub async fn serve<R, F>(
+    listener: TcpListener,
+    router: Router,
+    repository: &R,
+    shutdown: F,
+    drain_timeout: Duration,
+) -> Result<ShutdownSummary, ServerError>
+where
+    R: TaskRepository + ?Sized,
+    F: Future<Output = ()> + Send + 'static,
+{
+    let counter = Arc::new(RequestCounter::default());
+    let router = router.layer(middleware::from_fn({
+        let counter = counter.clone();
+        move |request, next| count_requests(counter.clone(), request, next)
+    }));
+
+    let (signalled_tx, signalled_rx) = tokio::sync::watch::channel(None::<Instant>);
+    // 流量制限で接続元のIPアドレスを使えるようにする
+    let server = axum::serve(listener, router.into_make_service_with_connect_info::<SocketAddr>())
+        .with_graceful_shutdown(async move {
+            shutdown.await;
+            let _ = signalled_tx.send(Some(Instant::now()));
+        })
+        .into_future();
+    tokio::pin!(server);
+
+    // 合図を受けた時刻から期限を数える（合図の前にサーバーが止まった場合は待たない）
+    let mut deadline_rx = signalled_rx.clone();
+    let deadline = async move {
+        let signalled = deadline_rx.wait_for(Option::is_some).await.is_ok();
+        if signalled {
+            tokio::time::sleep(drain_timeout).await;
+        } else {
+            std::future::pending::<()>().await;
+        }
+    };
+
+    let abandoned_requests = tokio::select! {
+        result = &mut server => {
+            result.map_err(ServerError::Serve)?;
+            0
+        }
+        _ = deadline => {
+            let abandoned = counter.in_flight.load(Ordering::SeqCst);
+            tracing::warn!(abandoned, "shutdown deadline of {:?} exceeded; abandoning in-flight requests", drain_timeout);
+            abandoned
+        }
+    };
+    let signalled_at = *signalled_rx.borrow();
+
+    repository.shutdown().await?;
+
+    let summary = ShutdownSummary {
+        requests_served: counter.served.load(Ordering::SeqCst),
+        abandoned_requests,
+        drain_time: signalled_at.map(|at| at.elapsed()).unwrap_or_default(),
+    };
+    tracing::info!(
+        requests_served = summary.requests_served,
+        abandoned_requests = summary.abandoned_requests,
+        drain_time_ms = summary.drain_time.as_millis() as u64,
+        "server shut down"
+    );
+    Ok(summary)
+}
+
+/// SIGTERM または Ctrl+C（SIGINT）を待つ
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+    tracing::info!("shutdown signal received; draining in-flight requests");
+}
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::model::event::TaskChange;
+use crate::domain::model::history::{FieldChange, NewTaskHistoryEntry, TaskHistoryEntry, TaskHistoryOperation};
+use crate::domain::model::task::{CreateTask, Task, UpdateTask};
+use crate::domain::repository::history::TaskHistoryRepository;
+use crate::domain::repository::task::{
+    BatchMode, BatchOutcome, ChildPolicy, TagCount, TaskCounts, TaskError, TaskOperation, TaskOperationOutcome, TaskPage,
+    TaskQuery, TaskRepository,
+};
+
+/// 操作した利用者を返す関数（分からない場合は None）
+pub type ActorResolver = Arc<dyn Fn() -> Option<String> + Send + Sync>;
+
+/// 書き込みが成功するたびに変更履歴を追記するリポジトリのラッパー
+///
+/// 変更前の内容を知るため、更新・削除・復元の前に現在のタスクを読む。履歴の追記に失敗しても
+/// 書き込みは取り消さず、エラーをログに残す。
+#[derive(Clone)]
+pub struct AuditedTaskRepository<R> {
+    inner: R,
+    history: Arc<dyn TaskHistoryRepository>,
+    actor: ActorResolver,
+}
+
+impl<R: TaskRepository> AuditedTaskRepository<R> {
+    pub fn new(inner: R, history: Arc<dyn TaskHistoryRepository>) -> Self {
+        Self { inner, history, actor: Arc::new(|| None) }
+    }
+
+    /// 履歴に記録する利用者の求め方を設定する
+    pub fn with_actor(mut self, actor: ActorResolver) -> Self {
+        self.actor = actor;
+        self
+    }
+
+    async fn record(&self, operation: TaskHistoryOperation, previous: Option<&Task>, current: Option<&Task>) {
+        let Some(task_id) = current.or(previous).map(|task| task.id) else {
+            return;
+        };
+        let entry = NewTaskHistoryEntry {
+            task_id,
+            operation,
+            changes: FieldChange::diff(previous, current),
+            actor: (self.actor)(),
+            recorded_at: Utc::now(),
+        };
+        if let Err(e) = self.history.append(entry).await {
+            tracing::error!(task_id, operation = operation.as_str(), "failed to record task history: {}", e);
+        }
+    }
+
+    /// 更新の結果を記録する（内容が変わらなかった場合は記録しない）
+    async fn record_update(&self, previous: Option<Task>, result: &Result<Task, TaskError>) {
+        let Ok(task) = result else {
+            return;
+        };
+        let operation = match &previous {
+            Some(previous) => match TaskChange::between(previous, task) {
+                Some(change) => change.kind.into(),
+                None => return,
+            },
+            None => TaskHistoryOperation::Updated,
+        };
+        self.record(operation, previous.as_ref(), Some(task)).await;
+    }
+
+    async fn record_delete(&self, previous: Option<Task>, result: &Result<(), TaskError>) {
+        if let (Some(previous), Ok(())) = (previous, result) {
+            let trashed = self.find_trashed(previous.id).await;
+            self.record(TaskHistoryOperation::Deleted, Some(&previous), trashed.as_ref()).await;
+        }
+    }
+
+    /// 1件分の操作の結果を記録する（操作の前後のタスクが分かるので読み直さない）
+    async fn record_outcome(&self, outcome: &TaskOperationOutcome) {
+        match (&outcome.previous, &outcome.current) {
+            (None, Some(task)) => self.record(TaskHistoryOperation::Created, None, Some(task)).await,
+            (Some(previous), Some(task)) => {
+                if let Some(change) = TaskChange::between(previous, task) {
+                    self.record(change.kind.into(), Some(previous), Some(task)).await;
+                }
+            }
+            (Some(previous), None) => {
+                let trashed = self.find_trashed(previous.id).await;
+                self.record(TaskHistoryOperation::Deleted, Some(previous), trashed.as_ref()).await;
+            }
+            (None, None) => {}
+        }
+    }
+
+    /// ゴミ箱にあるタスクを探す（ゴミ箱を持たない実装では見つからない）
+    async fn find_trashed(&self, id: u64) -> Option<Task> {
+        let trash = self.inner.get_trash().await.ok()?;
+        trash.into_iter().find(|task| task.id == id)
+    }
+}
+
+#[async_trait]
+impl<R: TaskRepository> TaskRepository for AuditedTaskRepository<R> {
+    async fn get_all(&self) -> Result<Vec<Task>, TaskError> {
+        self.inner.get_all().await
+    }
+
+    async fn get_by_id(&self, id: u64) -> Result<Task, TaskError> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn create(&self, task: CreateTask) -> Result<Task, TaskError> {
+        let task = self.inner.create(task).await?;
+        self.record(TaskHistoryOperation::Created, None, Some(&task)).await;
+        Ok(task)
+    }
+
+    async fn update(&self, id: u64, update_task: UpdateTask) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.update(id, update_task).await;
+        self.record_update(previous, &result).await;
+        result
+    }
+
+    async fn delete(&self, id: u64) -> Result<(), TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.delete(id).await;
+        self.record_delete(previous, &result).await;
+        result
+    }
+
+    async fn complete(&self, id: u64) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.complete(id).await;
+        self.record_update(previous, &result).await;
+        result
+    }
+
+    async fn force_complete(&self, id: u64, expected_version: Option<u64>) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.force_complete(id, expected_version).await;
+        self.record_update(previous, &result).await;
+        result
+    }
+
+    async fn uncomplete(&self, id: u64) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.uncomplete(id).await;
+        self.record_update(previous, &result).await;
+        result
+    }
+
+    async fn update_if_version(&self, id: u64, update_task: UpdateTask, expected_version: u64) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.update_if_version(id, update_task, expected_version).await;
+        self.record_update(previous, &result).await;
+        result
+    }
+
+    async fn delete_if_version(&self, id: u64, expected_version: u64) -> Result<(), TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.delete_if_version(id, expected_version).await;
+        self.record_delete(previous, &result).await;
+        result
+    }
+
+    async fn delete_with_children(&self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> Result<Vec<TaskOperationOutcome>, TaskError> {
+        let outcomes = self.inner.delete_with_children(id, policy, expected_version).await?;
+        for outcome in &outcomes {
+            self.record_outcome(outcome).await;
+        }
+        Ok(outcomes)
+    }
+
+    /// 保存された場合だけ、成功した操作を順に記録する
+    async fn apply_batch(&self, operations: Vec<TaskOperation>, mode: BatchMode) -> Result<BatchOutcome, TaskError> {
+        let outcome = self.inner.apply_batch(operations, mode).await?;
+        if !outcome.committed {
+            return Ok(outcome);
+        }
+        for result in outcome.results.iter().flatten() {
+            self.record_outcome(result).await;
+        }
+        Ok(outcome)
+    }
+
+    async fn get_trash(&self) -> Result<Vec<Task>, TaskError> {
+        self.inner.get_trash().await
+    }
+
+    async fn restore(&self, id: u64) -> Result<Task, TaskError> {
+        let previous = self.find_trashed(id).await;
+        let task = self.inner.restore(id).await?;
+        self.record(TaskHistoryOperation::Restored, previous.as_ref(), Some(&task)).await;
+        Ok(task)
+    }
+
+    /// 完全に削除したタスクを、削除前後のゴミ箱の違いから求めて記録する
+    async fn purge_trash(&self, deleted_before: Option<DateTime<Utc>>, owner_id: Option<u64>) -> Result<usize, TaskError> {
+        let before = self.inner.get_trash().await?;
+        let purged = self.inner.purge_trash(deleted_before, owner_id).await?;
+        if purged > 0 {
+            let remaining = self.inner.get_trash().await?;
+            for task in before.iter().filter(|task| !remaining.iter().any(|kept| kept.id == task.id)) {
+                self.record(TaskHistoryOperation::Purged, Some(task), None).await;
+            }
+        }
+        Ok(purged)
+    }
+
+    async fn get_history(&self, id: u64) -> Result<Vec<TaskHistoryEntry>, TaskError> {
+        self.history.get_by_task(id).await
+    }
+
+    async fn query(&self, query: TaskQuery) -> Result<TaskPage, TaskError> {
+        self.inner.query(query).await
+    }
+
+    async fn count_tasks(&self) -> Result<TaskCounts, TaskError> {
+        self.inner.count_tasks().await
+    }
+
+    async fn ping(&self) -> Result<(), TaskError> {
+        self.inner.ping().await
+    }
+
+    fn backend_name(&self) -> &'static str {
+        self.inner.backend_name()
+    }
+
+    async fn shutdown(&self) -> Result<(), TaskError> {
+        self.inner.shutdown().await
+    }
+
+    async fn get_tag_counts(&self) -> Result<Vec<TagCount>, TaskError> {
+        self.inner.get_tag_counts().await
+    }
+}
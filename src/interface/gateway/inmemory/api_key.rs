@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use chrono::Utc;
+use crate::domain::model::api_key::{ApiKey, ApiKeyValidationError};
+use crate::domain::repository::api_key::{ApiKeyError, ApiKeyRepository, NewApiKey};
+use crate::domain::repository::user::{UserError, UserRepository};
+use super::user::InMemoryUserRepository;
+
+/// APIキーとIDの採番
+struct ApiKeyState {
+    keys: BTreeMap<u64, ApiKey>,
+    next_id: u64,
+}
+
+impl Default for ApiKeyState {
+    fn default() -> Self {
+        Self { keys: BTreeMap::new(), next_id: 1 }
+    }
+}
+
+/// メモリ上にAPIキーを保持するリポジトリ（鍵の持ち主は同じ保存先の利用者から探す）
+#[derive(Clone)]
+pub struct InMemoryApiKeyRepository {
+    state: Arc<Mutex<ApiKeyState>>,
+    users: InMemoryUserRepository,
+}
+
+impl InMemoryApiKeyRepository {
+    pub fn new(users: InMemoryUserRepository) -> Self {
+        Self { state: Arc::new(Mutex::new(ApiKeyState::default())), users }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, ApiKeyState>, ApiKeyError> {
+        self.state.lock().map_err(|e| {
+            ApiKeyError::RepositoryError(Box::new(std::io::Error::other(
+                format!("Failed to acquire lock: {}", e),
+            )))
+        })
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for InMemoryApiKeyRepository {
+    async fn get_all(&self) -> Result<Vec<ApiKey>, ApiKeyError> {
+        Ok(self.lock()?.keys.values().cloned().collect())
+    }
+
+    async fn find_by_hash(&self, secret_hash: &str) -> Result<Option<ApiKey>, ApiKeyError> {
+        Ok(self.lock()?.keys.values().find(|key| key.secret_hash == secret_hash).cloned())
+    }
+
+    async fn create(&self, new_key: NewApiKey) -> Result<ApiKey, ApiKeyError> {
+        // 利用者は削除できないため、確かめてから鍵を作っても食い違わない
+        match self.users.get_by_id(new_key.user_id).await {
+            Ok(_) => {}
+            Err(UserError::NotFound(id)) => return Err(ApiKeyValidationError::UserNotFound(id).into()),
+            Err(e) => return Err(ApiKeyError::RepositoryError(Box::new(e))),
+        }
+        let mut state = self.lock()?;
+        let key = ApiKey {
+            id: state.next_id,
+            name: new_key.name.trim().to_string(),
+            scope: new_key.scope,
+            user_id: new_key.user_id,
+            prefix: new_key.prefix,
+            secret_hash: new_key.secret_hash,
+            created_at: Utc::now(),
+            revoked_at: None,
+        };
+        state.keys.insert(key.id, key.clone());
+        state.next_id += 1;
+        Ok(key)
+    }
+
+    async fn revoke(&self, id: u64) -> Result<ApiKey, ApiKeyError> {
+        let mut state = self.lock()?;
+        let key = state.keys.get_mut(&id).ok_or(ApiKeyError::NotFound(id))?;
+        key.revoke();
+        Ok(key.clone())
+    }
+}
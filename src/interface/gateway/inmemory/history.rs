@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use crate::domain::model::history::{NewTaskHistoryEntry, TaskHistoryEntry};
+use crate::domain::repository::history::TaskHistoryRepository;
+use crate::domain::repository::task::TaskError;
+
+#[derive(Default)]
+struct HistoryState {
+    next_id: u64,
+    entries_by_task: HashMap<u64, Vec<TaskHistoryEntry>>,
+}
+
+/// メモリ上に変更履歴を保持するリポジトリ（プロセスを終了すると消える）
+#[derive(Clone, Default)]
+pub struct InMemoryTaskHistoryRepository {
+    state: Arc<Mutex<HistoryState>>,
+}
+
+impl InMemoryTaskHistoryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, HistoryState>, TaskError> {
+        self.state.lock().map_err(|e| {
+            TaskError::RepositoryError(Box::new(std::io::Error::other(
+                format!("Failed to acquire lock: {}", e),
+            )))
+        })
+    }
+}
+
+#[async_trait]
+impl TaskHistoryRepository for InMemoryTaskHistoryRepository {
+    async fn append(&self, entry: NewTaskHistoryEntry) -> Result<TaskHistoryEntry, TaskError> {
+        let mut state = self.lock()?;
+        state.next_id += 1;
+        let entry = TaskHistoryEntry::new(state.next_id, entry);
+        state.entries_by_task.entry(entry.task_id).or_default().push(entry.clone());
+        Ok(entry)
+    }
+
+    async fn get_by_task(&self, task_id: u64) -> Result<Vec<TaskHistoryEntry>, TaskError> {
+        Ok(self.lock()?.entries_by_task.get(&task_id).cloned().unwrap_or_default())
+    }
+}
@@ -1,3 +1,11 @@
+pub mod api_key;
+pub mod history;
+pub mod project;
 pub mod task;
+pub mod user;
 
+pub use api_key::*;
+pub use history::*;
+pub use project::*;
 pub use task::*;
+pub use user::*;
\ No newline at end of file
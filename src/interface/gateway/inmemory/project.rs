@@ -0,0 +1,137 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use crate::domain::model::project::{CreateProject, Project, ProjectValidationError, UpdateProject, INBOX_PROJECT_ID};
+use crate::domain::model::task::Task;
+use crate::domain::model::user::DEFAULT_USER_ID;
+use crate::domain::repository::project::{ProjectError, ProjectRepository};
+use crate::domain::repository::task::TaskCounts;
+
+/// プロジェクトとIDの採番
+pub(super) struct ProjectState {
+    projects: BTreeMap<u64, Project>,
+    next_id: u64,
+}
+
+impl ProjectState {
+    /// 利用者がタスクを入れられるプロジェクトか（他の利用者のプロジェクトは存在しないものとして扱う）
+    pub(super) fn is_visible_to(&self, id: u64, user_id: u64) -> bool {
+        self.projects.get(&id).is_some_and(|project| project.is_visible_to(user_id))
+    }
+
+    /// 利用者から見える中に同じ名前のプロジェクトがあればエラー（`except` は名前を変えるプロジェクト自身）
+    fn ensure_unique(&self, name: &str, owner_id: u64, except: Option<u64>) -> Result<(), ProjectError> {
+        let mut visible = self.projects.values().filter(|project| project.is_visible_to(owner_id));
+        match visible.find(|project| Some(project.id) != except && project.has_name(name)) {
+            Some(project) => Err(ProjectValidationError::DuplicateName(project.name.clone()).into()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 最初は受信箱だけがある
+impl Default for ProjectState {
+    fn default() -> Self {
+        Self {
+            projects: BTreeMap::from([(INBOX_PROJECT_ID, Project::inbox())]),
+            next_id: INBOX_PROJECT_ID + 1,
+        }
+    }
+}
+
+/// メモリ上にプロジェクトを保持するリポジトリ（タスクのリポジトリと状態を共有する）
+///
+/// ロックの順序はタスクのリポジトリに揃え、tasks → trash → projects の順に取得する。
+#[derive(Clone)]
+pub struct InMemoryProjectRepository {
+    tasks: Arc<Mutex<HashMap<u64, Task>>>,
+    trash: Arc<Mutex<HashMap<u64, Task>>>,
+    state: Arc<Mutex<ProjectState>>,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> Result<MutexGuard<'_, T>, ProjectError> {
+    mutex.lock().map_err(|e| {
+        ProjectError::RepositoryError(Box::new(std::io::Error::other(
+            format!("Failed to acquire lock: {}", e),
+        )))
+    })
+}
+
+impl InMemoryProjectRepository {
+    pub(super) fn new(
+        tasks: Arc<Mutex<HashMap<u64, Task>>>,
+        trash: Arc<Mutex<HashMap<u64, Task>>>,
+        state: Arc<Mutex<ProjectState>>,
+    ) -> Self {
+        Self { tasks, trash, state }
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for InMemoryProjectRepository {
+    async fn get_all(&self) -> Result<Vec<Project>, ProjectError> {
+        Ok(lock(&self.state)?.projects.values().cloned().collect())
+    }
+
+    async fn get_by_id(&self, id: u64) -> Result<Project, ProjectError> {
+        lock(&self.state)?.projects.get(&id).cloned().ok_or(ProjectError::NotFound(id))
+    }
+
+    async fn create(&self, create_project: CreateProject) -> Result<Project, ProjectError> {
+        create_project.validate()?;
+        let owner_id = create_project.owner_id.unwrap_or(DEFAULT_USER_ID);
+        let mut state = lock(&self.state)?;
+        state.ensure_unique(&create_project.name, owner_id, None)?;
+        let project = Project::new(state.next_id, &create_project.name, owner_id)?;
+        state.projects.insert(project.id, project.clone());
+        state.next_id += 1;
+        Ok(project)
+    }
+
+    async fn update(&self, id: u64, update_project: UpdateProject) -> Result<Project, ProjectError> {
+        update_project.validate()?;
+        let mut state = lock(&self.state)?;
+        let mut project = state.projects.get(&id).cloned().ok_or(ProjectError::NotFound(id))?;
+        if let Some(name) = update_project.name {
+            state.ensure_unique(&name, project.owner_id, Some(id))?;
+            project.rename(&name)?;
+        }
+        state.projects.insert(id, project.clone());
+        Ok(project)
+    }
+
+    async fn delete(&self, id: u64) -> Result<(), ProjectError> {
+        let tasks = lock(&self.tasks)?;
+        let mut trash = lock(&self.trash)?;
+        let mut state = lock(&self.state)?;
+        if !state.projects.contains_key(&id) {
+            return Err(ProjectError::NotFound(id));
+        }
+        if id == INBOX_PROJECT_ID {
+            return Err(ProjectError::InvalidOperation("The inbox cannot be deleted".to_string()));
+        }
+        if tasks.values().any(|task| task.project_id == id) {
+            return Err(ProjectError::NotEmpty(id));
+        }
+        for task in trash.values_mut().filter(|task| task.project_id == id) {
+            let previous = task.clone();
+            task.set_project(INBOX_PROJECT_ID);
+            task.advance_version(&previous);
+        }
+        state.projects.remove(&id);
+        Ok(())
+    }
+
+    async fn count_tasks(&self, owner_id: Option<u64>) -> Result<BTreeMap<u64, TaskCounts>, ProjectError> {
+        let tasks = lock(&self.tasks)?;
+        let mut counts: BTreeMap<u64, TaskCounts> = BTreeMap::new();
+        for task in tasks.values().filter(|task| owner_id.is_none_or(|owner_id| task.owner_id == owner_id)) {
+            let count = counts.entry(task.project_id).or_default();
+            count.total += 1;
+            if task.completed {
+                count.completed += 1;
+            }
+        }
+        Ok(counts)
+    }
+}
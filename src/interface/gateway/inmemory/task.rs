@@ -1,76 +1,129 @@
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, Mutex, MutexGuard};
 use async_trait::async_trait;
-use crate::domain::model::task::{Task, CreateTask, UpdateTask};
-use crate::domain::repository::task::{TaskRepository, TaskError};
+use chrono::{DateTime, Utc};
+use crate::domain::model::project::INBOX_PROJECT_ID;
+use crate::domain::model::task::{Task, CreateTask, UpdateTask, TaskValidationError};
+use crate::domain::model::user::DEFAULT_USER_ID;
+use crate::domain::repository::api_key::ApiKeyStorage;
+use crate::domain::repository::history::HistoryStorage;
+use crate::domain::repository::project::ProjectStorage;
+use crate::domain::repository::user::UserStorage;
+use crate::domain::repository::task::{
+    ensure_dependency, ensure_parent, ensure_unblocked, ensure_version, BatchMode, BatchOutcome, ChildPolicy, TagCount, TagMatch, TaskCounts, TaskError, TaskOperation,
+    TaskOperationOutcome, TaskPage, TaskQuery, TaskRepository,
+};
+use super::api_key::InMemoryApiKeyRepository;
+use super::history::InMemoryTaskHistoryRepository;
+use super::project::{InMemoryProjectRepository, ProjectState};
+use super::user::InMemoryUserRepository;
 
-#[derive(Clone)]
-pub struct InMemoryTaskRepository {
-    tasks: Arc<Mutex<HashMap<u64, Task>>>,
-    next_id: Arc<Mutex<u64>>,
+/// タグからタスクIDを引く索引
+#[derive(Default)]
+struct TagIndex {
+    tasks_by_tag: HashMap<String, BTreeSet<u64>>,
 }
 
-impl InMemoryTaskRepository {
-    pub fn new() -> Self {
-        Self {
-            tasks: Arc::new(Mutex::new(HashMap::new())),
-            next_id: Arc::new(Mutex::new(1)),
+impl TagIndex {
+    fn insert(&mut self, id: u64, tags: &BTreeSet<String>) {
+        for tag in tags {
+            self.tasks_by_tag.entry(tag.clone()).or_default().insert(id);
         }
     }
-}
 
-#[async_trait]
-impl TaskRepository for InMemoryTaskRepository {
-    async fn get_all(&self) -> Result<Vec<Task>, TaskError> {
-        let tasks = self.tasks.lock().map_err(|e| {
-            TaskError::RepositoryError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to acquire lock: {}", e),
-            )))
-        })?;
-        
-        Ok(tasks.values().cloned().collect())
+    fn remove(&mut self, id: u64, tags: &BTreeSet<String>) {
+        for tag in tags {
+            if let Some(ids) = self.tasks_by_tag.get_mut(tag) {
+                ids.remove(&id);
+                if ids.is_empty() {
+                    self.tasks_by_tag.remove(tag);
+                }
+            }
+        }
     }
 
-    async fn get_by_id(&self, id: u64) -> Result<Task, TaskError> {
-        let tasks = self.tasks.lock().map_err(|e| {
-            TaskError::RepositoryError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to acquire lock: {}", e),
-            )))
-        })?;
-        
-        tasks.get(&id)
-            .cloned()
-            .ok_or(TaskError::NotFound(id))
+    /// タグの条件に合うタスクのID
+    fn candidates(&self, tags: &[String], tag_match: TagMatch) -> BTreeSet<u64> {
+        let mut sets = tags.iter().map(|tag| self.tasks_by_tag.get(tag));
+        match tag_match {
+            TagMatch::All => {
+                let Some(Some(first)) = sets.next() else {
+                    return BTreeSet::new();
+                };
+                sets.try_fold(first.clone(), |acc, ids| {
+                    ids.map(|ids| acc.intersection(ids).copied().collect())
+                })
+                .unwrap_or_default()
+            }
+            TagMatch::Any => sets.flatten().flatten().copied().collect(),
+        }
     }
 
-    async fn create(&self, create_task: CreateTask) -> Result<Task, TaskError> {
+    fn counts(&self) -> Vec<TagCount> {
+        let mut counts: Vec<TagCount> = self
+            .tasks_by_tag
+            .iter()
+            .map(|(tag, ids)| TagCount { tag: tag.clone(), count: ids.len() })
+            .collect();
+        counts.sort_by(|a, b| a.tag.cmp(&b.tag));
+        counts
+    }
+}
+
+/// ロックの順序は tasks → trash → next_id → tag_index → projects に揃える
+#[derive(Clone)]
+pub struct InMemoryTaskRepository {
+    tasks: Arc<Mutex<HashMap<u64, Task>>>,
+    /// ゴミ箱に移したタスク（タグの索引には含めない）
+    trash: Arc<Mutex<HashMap<u64, Task>>>,
+    next_id: Arc<Mutex<u64>>,
+    tag_index: Arc<Mutex<TagIndex>>,
+    /// タスクの所属先（`projects()` で返すリポジトリと共有する）
+    projects: Arc<Mutex<ProjectState>>,
+    /// タスクを持つ利用者（タスクの書き込みとは独立しているため、ロックの順序に含めない）
+    users: InMemoryUserRepository,
+    /// 利用者の代わりにAPIを呼ぶための鍵（利用者と同じくロックの順序に含めない）
+    api_keys: InMemoryApiKeyRepository,
+    /// タスクの変更履歴（書き込みの後に追記するため、ロックの順序に含めない）
+    history: InMemoryTaskHistoryRepository,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> Result<MutexGuard<'_, T>, TaskError> {
+    mutex.lock().map_err(|e| {
+        TaskError::RepositoryError(Box::new(std::io::Error::other(
+            format!("Failed to acquire lock: {}", e),
+        )))
+    })
+}
+
+/// 書き込みに必要なロックをすべて取得した状態
+struct Store<'a> {
+    tasks: MutexGuard<'a, HashMap<u64, Task>>,
+    trash: MutexGuard<'a, HashMap<u64, Task>>,
+    next_id: MutexGuard<'a, u64>,
+    tag_index: MutexGuard<'a, TagIndex>,
+    projects: MutexGuard<'a, ProjectState>,
+}
+
+impl Store<'_> {
+    fn create(&mut self, create_task: CreateTask) -> Result<Task, TaskError> {
         // バリデーション
         create_task.validate()?;
+        let owner_id = create_task.owner_id.unwrap_or(DEFAULT_USER_ID);
+        self.ensure_project(create_task.project_id.unwrap_or(INBOX_PROJECT_ID), owner_id)?;
+        if let Some(parent_id) = create_task.parent_id {
+            ensure_parent(None, parent_id, |id| Ok(self.parent_of(id)))?;
+        }
 
-        let mut tasks = self.tasks.lock().map_err(|e| {
-            TaskError::RepositoryError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to acquire lock: {}", e),
-            )))
-        })?;
-        
-        let mut next_id = self.next_id.lock().map_err(|e| {
-            TaskError::RepositoryError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to acquire lock: {}", e),
-            )))
-        })?;
-        
-        let task = Task::new(*next_id, create_task.description)?;
-        tasks.insert(*next_id, task.clone());
-        *next_id += 1;
-        
+        let task = Task::from_create(*self.next_id, create_task)?;
+        self.tag_index.insert(task.id, &task.tags);
+        self.tasks.insert(task.id, task.clone());
+        *self.next_id += 1;
         Ok(task)
     }
 
-    async fn update(&self, id: u64, update_task: UpdateTask) -> Result<Task, TaskError> {
+    /// 部分更新を適用し、更新前と更新後のタスクを返す
+    fn update(&mut self, id: u64, update_task: UpdateTask, expected_version: Option<u64>) -> Result<(Task, Task), TaskError> {
         if update_task.is_empty() {
             return Err(TaskError::InvalidOperation("Update task cannot be empty".to_string()));
         }
@@ -78,17 +131,14 @@ impl TaskRepository for InMemoryTaskRepository {
         // バリデーション
         update_task.validate()?;
 
-        let mut tasks = self.tasks.lock().map_err(|e| {
-            TaskError::RepositoryError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to acquire lock: {}", e),
-            )))
-        })?;
-        
-        let mut task = tasks.get(&id)
+        let previous = self.tasks.get(&id)
             .cloned()
             .ok_or(TaskError::NotFound(id))?;
-        
+        if let Some(expected_version) = expected_version {
+            ensure_version(&previous, expected_version)?;
+        }
+        let mut task = previous.clone();
+
         // 部分更新の適用
         if let Some(description) = update_task.description {
             task.update_description(description)?;
@@ -101,57 +151,431 @@ impl TaskRepository for InMemoryTaskRepository {
                 task.uncomplete();
             }
         }
+
+        if let Some(priority) = update_task.priority {
+            task.set_priority(priority);
+        }
+
+        if let Some(due_at) = update_task.due_at {
+            task.set_due_at(due_at)?;
+        }
+
+        if let Some(project_id) = update_task.project_id {
+            self.ensure_project(project_id, task.owner_id)?;
+            task.set_project(project_id);
+        }
+
+        if let Some(parent_id) = update_task.parent_id {
+            if let Some(parent_id) = parent_id {
+                ensure_parent(Some(id), parent_id, |id| Ok(self.parent_of(id)))?;
+            }
+            task.set_parent(parent_id)?;
+        }
+
+        if let Some(recurrence) = update_task.recurrence {
+            task.set_recurrence(recurrence);
+        }
+
+        for &dependency in &update_task.add_dependencies {
+            ensure_dependency(id, dependency, |id| Ok(self.dependencies_of(id)))?;
+        }
+        task.add_dependencies(&update_task.add_dependencies)?;
+        task.remove_dependencies(&update_task.remove_dependencies);
+
+        task.add_tags(&update_task.add_tags)?;
+        task.remove_tags(&update_task.remove_tags)?;
+        if task.completed && !previous.completed {
+            ensure_unblocked(id, self.blockers(&task))?;
+        }
+        task.advance_version(&previous);
+
+        // タグが変わった場合は索引を更新する
+        if previous.tags != task.tags {
+            self.tag_index.remove(id, &previous.tags);
+            self.tag_index.insert(id, &task.tags);
+        }
         
-        tasks.insert(id, task.clone());
-        Ok(task)
+        self.tasks.insert(id, task.clone());
+        Ok((previous, task))
     }
 
-    async fn delete(&self, id: u64) -> Result<(), TaskError> {
-        let mut tasks = self.tasks.lock().map_err(|e| {
-            TaskError::RepositoryError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to acquire lock: {}", e),
-            )))
-        })?;
-        
-        if tasks.remove(&id).is_some() {
+    /// 完了状態を変える（`force` でなければ、未完了の依存先が残るタスクは完了にしない）
+    fn set_completed(&mut self, id: u64, completed: bool, force: bool) -> Result<(Task, Task), TaskError> {
+        let previous = self.tasks.get(&id)
+            .cloned()
+            .ok_or(TaskError::NotFound(id))?;
+
+        let mut task = previous.clone();
+        if completed {
+            if !force && !previous.completed {
+                ensure_unblocked(id, self.blockers(&previous))?;
+            }
+            task.complete();
+        } else {
+            task.uncomplete();
+        }
+        task.advance_version(&previous);
+        self.tasks.insert(id, task.clone());
+        Ok((previous, task))
+    }
+
+    /// タスクの持ち主が使えるプロジェクトか確かめる
+    fn ensure_project(&self, project_id: u64, owner_id: u64) -> Result<(), TaskError> {
+        if self.projects.is_visible_to(project_id, owner_id) {
             Ok(())
         } else {
-            Err(TaskError::NotFound(id))
+            Err(TaskValidationError::ProjectNotFound(project_id).into())
         }
     }
 
-    async fn complete(&self, id: u64) -> Result<Task, TaskError> {
-        let mut tasks = self.tasks.lock().map_err(|e| {
-            TaskError::RepositoryError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to acquire lock: {}", e),
-            )))
-        })?;
-        
-        let mut task = tasks.get(&id)
-            .cloned()
-            .ok_or(TaskError::NotFound(id))?;
-        
-        task.complete();
-        tasks.insert(id, task.clone());
+    /// タスクの親（タスクが存在しなければ None）
+    fn parent_of(&self, id: u64) -> Option<Option<u64>> {
+        self.tasks.get(&id).map(|task| task.parent_id)
+    }
+
+    /// タスクの依存先（タスクが存在しなければ None）
+    fn dependencies_of(&self, id: u64) -> Option<BTreeSet<u64>> {
+        self.tasks.get(&id).map(|task| task.depends_on.clone())
+    }
+
+    /// 未完了の依存先のID（ゴミ箱にあるタスクは待たない）
+    fn blockers(&self, task: &Task) -> Vec<u64> {
+        task.depends_on
+            .iter()
+            .copied()
+            .filter(|id| self.tasks.get(id).is_some_and(|dependency| !dependency.completed))
+            .collect()
+    }
+
+    /// 直下の子タスクのID
+    fn children_of(&self, id: u64) -> Vec<u64> {
+        let mut ids: Vec<u64> = self.tasks.values().filter(|task| task.parent_id == Some(id)).map(|task| task.id).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// ゴミ箱に移し、移す前のタスクを返す（子タスクがあれば移さない）
+    fn delete(&mut self, id: u64, expected_version: Option<u64>) -> Result<Task, TaskError> {
+        let task = self.tasks.get(&id).ok_or(TaskError::NotFound(id))?;
+        if let Some(expected_version) = expected_version {
+            ensure_version(task, expected_version)?;
+        }
+        if !self.children_of(id).is_empty() {
+            return Err(TaskError::HasChildren(id));
+        }
+        self.trash_task(id)
+    }
+
+    /// 子タスクの扱いを指定して削除し、変更したタスクの前後を返す（先頭は指定したタスク）
+    fn delete_with_children(&mut self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> Result<Vec<TaskOperationOutcome>, TaskError> {
+        let task = self.tasks.get(&id).ok_or(TaskError::NotFound(id))?;
+        if let Some(expected_version) = expected_version {
+            ensure_version(task, expected_version)?;
+        }
+        let children = self.children_of(id);
+        if !children.is_empty() && policy == ChildPolicy::Reject {
+            return Err(TaskError::HasChildren(id));
+        }
+
+        let mut outcomes = vec![TaskOperationOutcome { previous: Some(self.trash_task(id)?), current: None }];
+        match policy {
+            ChildPolicy::Reject => {}
+            ChildPolicy::Cascade => {
+                let mut pending = children;
+                while let Some(descendant) = pending.pop() {
+                    pending.extend(self.children_of(descendant));
+                    outcomes.push(TaskOperationOutcome { previous: Some(self.trash_task(descendant)?), current: None });
+                }
+            }
+            ChildPolicy::Orphan => {
+                for child in children {
+                    let update = UpdateTask { parent_id: Some(None), ..UpdateTask::default() };
+                    let (previous, task) = self.update(child, update, None)?;
+                    outcomes.push(TaskOperationOutcome { previous: Some(previous), current: Some(task) });
+                }
+            }
+        }
+        Ok(outcomes)
+    }
+
+    fn trash_task(&mut self, id: u64) -> Result<Task, TaskError> {
+        let previous = self.tasks.remove(&id).ok_or(TaskError::NotFound(id))?;
+        self.tag_index.remove(id, &previous.tags);
+        let mut trashed = previous.clone();
+        trashed.trash(Utc::now());
+        trashed.advance_version(&previous);
+        self.trash.insert(id, trashed);
+        Ok(previous)
+    }
+
+    fn restore(&mut self, id: u64) -> Result<Task, TaskError> {
+        let previous = self.trash.remove(&id).ok_or(TaskError::NotFound(id))?;
+        let mut task = previous.clone();
+        task.restore();
+        // 親が既にない場合は最上位のタスクとして戻す
+        if task.parent_id.is_some_and(|parent_id| !self.tasks.contains_key(&parent_id)) {
+            task.set_parent(None)?;
+        }
+        // ゴミ箱にある間に循環ができていれば、その依存を外して戻す
+        let cyclic: Vec<u64> = task.depends_on
+            .iter()
+            .copied()
+            .filter(|&dependency| matches!(
+                ensure_dependency(id, dependency, |id| Ok(self.dependencies_of(id))),
+                Err(TaskError::ValidationError(TaskValidationError::DependencyCycle(_)))
+            ))
+            .collect();
+        task.remove_dependencies(&cyclic);
+        task.advance_version(&previous);
+        self.tag_index.insert(id, &task.tags);
+        self.tasks.insert(id, task.clone());
         Ok(task)
     }
 
-    async fn uncomplete(&self, id: u64) -> Result<Task, TaskError> {
-        let mut tasks = self.tasks.lock().map_err(|e| {
-            TaskError::RepositoryError(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to acquire lock: {}", e),
-            )))
-        })?;
-        
-        let mut task = tasks.get(&id)
+    fn apply(&mut self, operation: TaskOperation) -> Result<TaskOperationOutcome, TaskError> {
+        let (previous, current) = match operation {
+            TaskOperation::Create(create_task) => (None, Some(self.create(create_task)?)),
+            TaskOperation::Update { id, update } => {
+                let (previous, task) = self.update(id, update, None)?;
+                (Some(previous), Some(task))
+            }
+            TaskOperation::Complete(id) => {
+                let (previous, task) = self.set_completed(id, true, false)?;
+                (Some(previous), Some(task))
+            }
+            TaskOperation::Uncomplete(id) => {
+                let (previous, task) = self.set_completed(id, false, false)?;
+                (Some(previous), Some(task))
+            }
+            TaskOperation::Delete(id) => (Some(self.delete(id, None)?), None),
+        };
+        Ok(TaskOperationOutcome { previous, current })
+    }
+
+    /// 操作の結果を取り消し、操作前の状態に戻す
+    fn revert(&mut self, outcome: &TaskOperationOutcome) {
+        if let Some(current) = &outcome.current {
+            self.tasks.remove(&current.id);
+            self.tag_index.remove(current.id, &current.tags);
+        }
+        if let Some(previous) = &outcome.previous {
+            self.trash.remove(&previous.id);
+            self.tag_index.insert(previous.id, &previous.tags);
+            self.tasks.insert(previous.id, previous.clone());
+        }
+    }
+}
+
+impl InMemoryTaskRepository {
+    pub fn new() -> Self {
+        let users = InMemoryUserRepository::new();
+        Self {
+            tasks: Arc::new(Mutex::new(HashMap::new())),
+            trash: Arc::new(Mutex::new(HashMap::new())),
+            next_id: Arc::new(Mutex::new(1)),
+            tag_index: Arc::new(Mutex::new(TagIndex::default())),
+            projects: Arc::new(Mutex::new(ProjectState::default())),
+            users: users.clone(),
+            api_keys: InMemoryApiKeyRepository::new(users),
+            history: InMemoryTaskHistoryRepository::new(),
+        }
+    }
+
+    fn lock_tag_index(&self) -> Result<MutexGuard<'_, TagIndex>, TaskError> {
+        lock(&self.tag_index)
+    }
+
+    fn lock_tasks(&self) -> Result<MutexGuard<'_, HashMap<u64, Task>>, TaskError> {
+        lock(&self.tasks)
+    }
+
+    /// 書き込み用にすべてのロックを決まった順序で取得する
+    fn lock_store(&self) -> Result<Store<'_>, TaskError> {
+        let tasks = lock(&self.tasks)?;
+        let trash = lock(&self.trash)?;
+        let next_id = lock(&self.next_id)?;
+        let tag_index = lock(&self.tag_index)?;
+        let projects = lock(&self.projects)?;
+        Ok(Store { tasks, trash, next_id, tag_index, projects })
+    }
+}
+
+impl ProjectStorage for InMemoryTaskRepository {
+    type Projects = InMemoryProjectRepository;
+
+    fn projects(&self) -> InMemoryProjectRepository {
+        InMemoryProjectRepository::new(self.tasks.clone(), self.trash.clone(), self.projects.clone())
+    }
+}
+
+impl UserStorage for InMemoryTaskRepository {
+    type Users = InMemoryUserRepository;
+
+    fn users(&self) -> InMemoryUserRepository {
+        self.users.clone()
+    }
+}
+
+impl HistoryStorage for InMemoryTaskRepository {
+    type History = InMemoryTaskHistoryRepository;
+
+    fn history(&self) -> InMemoryTaskHistoryRepository {
+        self.history.clone()
+    }
+}
+
+impl ApiKeyStorage for InMemoryTaskRepository {
+    type ApiKeys = InMemoryApiKeyRepository;
+
+    fn api_keys(&self) -> InMemoryApiKeyRepository {
+        self.api_keys.clone()
+    }
+}
+
+impl Default for InMemoryTaskRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl TaskRepository for InMemoryTaskRepository {
+    async fn get_all(&self) -> Result<Vec<Task>, TaskError> {
+        let tasks = self.lock_tasks()?;
+        Ok(tasks.values().cloned().collect())
+    }
+
+    async fn get_by_id(&self, id: u64) -> Result<Task, TaskError> {
+        let tasks = self.lock_tasks()?;
+        tasks.get(&id)
             .cloned()
-            .ok_or(TaskError::NotFound(id))?;
-        
-        task.uncomplete();
-        tasks.insert(id, task.clone());
-        Ok(task)
+            .ok_or(TaskError::NotFound(id))
+    }
+
+    async fn create(&self, create_task: CreateTask) -> Result<Task, TaskError> {
+        self.lock_store()?.create(create_task)
+    }
+
+    async fn update(&self, id: u64, update_task: UpdateTask) -> Result<Task, TaskError> {
+        self.lock_store()?.update(id, update_task, None).map(|(_, task)| task)
+    }
+
+    async fn delete(&self, id: u64) -> Result<(), TaskError> {
+        self.lock_store()?.delete(id, None).map(|_| ())
+    }
+
+    async fn update_if_version(&self, id: u64, update_task: UpdateTask, expected_version: u64) -> Result<Task, TaskError> {
+        self.lock_store()?.update(id, update_task, Some(expected_version)).map(|(_, task)| task)
+    }
+
+    async fn delete_if_version(&self, id: u64, expected_version: u64) -> Result<(), TaskError> {
+        self.lock_store()?.delete(id, Some(expected_version)).map(|_| ())
+    }
+
+    async fn delete_with_children(&self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> Result<Vec<TaskOperationOutcome>, TaskError> {
+        self.lock_store()?.delete_with_children(id, policy, expected_version)
+    }
+
+    async fn complete(&self, id: u64) -> Result<Task, TaskError> {
+        self.lock_store()?.set_completed(id, true, false).map(|(_, task)| task)
+    }
+
+    async fn uncomplete(&self, id: u64) -> Result<Task, TaskError> {
+        self.lock_store()?.set_completed(id, false, false).map(|(_, task)| task)
+    }
+
+    async fn force_complete(&self, id: u64, expected_version: Option<u64>) -> Result<Task, TaskError> {
+        let mut store = self.lock_store()?;
+        if let Some(expected_version) = expected_version {
+            ensure_version(store.tasks.get(&id).ok_or(TaskError::NotFound(id))?, expected_version)?;
+        }
+        store.set_completed(id, true, true).map(|(_, task)| task)
+    }
+
+    /// ロックを一度だけ取得して全操作を実行する
+    ///
+    /// `Atomic` で失敗した場合は、それまでの操作を逆順に取り消し、IDの採番も元に戻す。
+    async fn apply_batch(&self, operations: Vec<TaskOperation>, mode: BatchMode) -> Result<BatchOutcome, TaskError> {
+        let mut store = self.lock_store()?;
+        let first_id = *store.next_id;
+        let mut results = Vec::with_capacity(operations.len());
+        for operation in operations {
+            let result = store.apply(operation);
+            let failed = result.is_err();
+            results.push(result);
+            if failed && mode == BatchMode::Atomic {
+                for outcome in results.iter().rev().filter_map(|result| result.as_ref().ok()) {
+                    store.revert(outcome);
+                }
+                *store.next_id = first_id;
+                return Ok(BatchOutcome { committed: false, results });
+            }
+        }
+        Ok(BatchOutcome { committed: true, results })
+    }
+
+    async fn get_trash(&self) -> Result<Vec<Task>, TaskError> {
+        let mut tasks: Vec<Task> = lock(&self.trash)?.values().cloned().collect();
+        tasks.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at).then(b.id.cmp(&a.id)));
+        Ok(tasks)
+    }
+
+    async fn restore(&self, id: u64) -> Result<Task, TaskError> {
+        self.lock_store()?.restore(id)
+    }
+
+    async fn purge_trash(&self, deleted_before: Option<DateTime<Utc>>, owner_id: Option<u64>) -> Result<usize, TaskError> {
+        let mut trash = lock(&self.trash)?;
+        let before = trash.len();
+        trash.retain(|_, task| {
+            if owner_id.is_some_and(|owner_id| task.owner_id != owner_id) {
+                return true;
+            }
+            match (deleted_before, task.deleted_at) {
+                (Some(cutoff), Some(deleted_at)) => deleted_at >= cutoff,
+                _ => false,
+            }
+        });
+        Ok(before - trash.len())
     }
-} 
\ No newline at end of file
+
+    async fn query(&self, query: TaskQuery) -> Result<TaskPage, TaskError> {
+        let tasks = self.lock_tasks()?;
+
+        // タグで絞り込む場合は索引から候補を引き、全件の走査を避ける
+        let matched = if query.tags.is_empty() {
+            tasks.values().filter(|task| query.matches(task)).cloned().collect()
+        } else {
+            let candidates = self.lock_tag_index()?.candidates(&query.tags, query.tag_match);
+            candidates
+                .iter()
+                .filter_map(|id| tasks.get(id))
+                .filter(|task| query.matches(task))
+                .cloned()
+                .collect()
+        };
+        Ok(query.apply(matched))
+    }
+
+    async fn get_tag_counts(&self) -> Result<Vec<TagCount>, TaskError> {
+        Ok(self.lock_tag_index()?.counts())
+    }
+
+    async fn count_tasks(&self) -> Result<TaskCounts, TaskError> {
+        let tasks = self.lock_tasks()?;
+        Ok(TaskCounts {
+            total: tasks.len(),
+            completed: tasks.values().filter(|task| task.completed).count(),
+        })
+    }
+
+    /// 書き込み中のパニックでロックが壊れていないか確かめる
+    async fn ping(&self) -> Result<(), TaskError> {
+        drop(self.lock_store()?);
+        Ok(())
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "memory"
+    }
+}
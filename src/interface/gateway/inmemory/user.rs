@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use crate::domain::model::user::{CreateUser, User, UserValidationError, DEFAULT_USER_ID};
+use crate::domain::repository::user::{UserError, UserRepository};
+
+/// 利用者とIDの採番
+struct UserState {
+    users: BTreeMap<u64, User>,
+    next_id: u64,
+}
+
+/// 最初は既定の利用者だけがいる
+impl Default for UserState {
+    fn default() -> Self {
+        Self {
+            users: BTreeMap::from([(DEFAULT_USER_ID, User::default_user())]),
+            next_id: DEFAULT_USER_ID + 1,
+        }
+    }
+}
+
+/// メモリ上に利用者を保持するリポジトリ
+#[derive(Clone, Default)]
+pub struct InMemoryUserRepository {
+    state: Arc<Mutex<UserState>>,
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, UserState>, UserError> {
+        self.state.lock().map_err(|e| {
+            UserError::RepositoryError(Box::new(std::io::Error::other(
+                format!("Failed to acquire lock: {}", e),
+            )))
+        })
+    }
+}
+
+#[async_trait]
+impl UserRepository for InMemoryUserRepository {
+    async fn get_all(&self) -> Result<Vec<User>, UserError> {
+        Ok(self.lock()?.users.values().cloned().collect())
+    }
+
+    async fn get_by_id(&self, id: u64) -> Result<User, UserError> {
+        self.lock()?.users.get(&id).cloned().ok_or(UserError::NotFound(id))
+    }
+
+    async fn create(&self, create_user: CreateUser) -> Result<User, UserError> {
+        create_user.validate()?;
+        let mut state = self.lock()?;
+        if let Some(user) = state.users.values().find(|user| user.has_name(&create_user.name)) {
+            return Err(UserValidationError::DuplicateName(user.name.clone()).into());
+        }
+        let user = User::new(state.next_id, &create_user.name)?;
+        state.users.insert(user.id, user.clone());
+        state.next_id += 1;
+        Ok(user)
+    }
+}
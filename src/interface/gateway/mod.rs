@@ -1 +1,4 @@
-pub mod inmemory; 
\ No newline at end of file
+pub mod audited;
+pub mod inmemory;
+pub mod notifying;
+pub mod sqlite;
\ No newline at end of file
@@ -0,0 +1,3 @@
+pub mod task;
+
+pub use task::*;
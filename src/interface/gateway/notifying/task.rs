@@ -0,0 +1,188 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::model::event::{TaskChange, TaskChangeKind};
+use crate::domain::model::history::TaskHistoryEntry;
+use crate::domain::model::task::{CreateTask, Task, UpdateTask};
+use crate::domain::repository::event::TaskChangePublisher;
+use crate::domain::repository::task::{
+    BatchMode, BatchOutcome, ChildPolicy, TagCount, TaskCounts, TaskError, TaskOperation, TaskOperationOutcome, TaskPage, TaskQuery,
+    TaskRepository,
+};
+
+/// 書き込みが成功するたびに変更を通知するリポジトリのラッパー
+///
+/// 変更の種類を決めるため、更新と削除の前に現在のタスクを読む。読んでから書くまでの間に
+/// 他の更新が割り込んだ場合、通知される種類がずれることがある（タスクの内容は書き込み後のもの）。
+#[derive(Clone)]
+pub struct NotifyingTaskRepository<R> {
+    inner: R,
+    publisher: Arc<dyn TaskChangePublisher>,
+}
+
+impl<R: TaskRepository> NotifyingTaskRepository<R> {
+    pub fn new(inner: R, publisher: Arc<dyn TaskChangePublisher>) -> Self {
+        Self { inner, publisher }
+    }
+
+    /// 更新の結果を通知する（内容が変わらなかった場合は通知しない）
+    fn publish_update(&self, previous: Option<Task>, result: &Result<Task, TaskError>) {
+        let Ok(task) = result else {
+            return;
+        };
+        let change = match previous {
+            Some(previous) => TaskChange::between(&previous, task),
+            None => Some(TaskChange::new(TaskChangeKind::Updated, task.clone())),
+        };
+        if let Some(change) = change {
+            self.publisher.publish(change);
+        }
+    }
+
+    fn publish_delete(&self, previous: Option<Task>, result: &Result<(), TaskError>) {
+        if let (Some(task), Ok(())) = (previous, result) {
+            self.publisher.publish(TaskChange::new(TaskChangeKind::Deleted, task));
+        }
+    }
+
+    /// 1件分の操作の結果を通知する（操作の前後のタスクが分かるので読み直さない）
+    fn publish_outcome(&self, outcome: &TaskOperationOutcome) {
+        let change = match (&outcome.previous, &outcome.current) {
+            (None, Some(task)) => Some(TaskChange::new(TaskChangeKind::Created, task.clone())),
+            (Some(previous), Some(task)) => TaskChange::between(previous, task),
+            (Some(task), None) => Some(TaskChange::new(TaskChangeKind::Deleted, task.clone())),
+            (None, None) => None,
+        };
+        if let Some(change) = change {
+            self.publisher.publish(change);
+        }
+    }
+}
+
+#[async_trait]
+impl<R: TaskRepository> TaskRepository for NotifyingTaskRepository<R> {
+    async fn get_all(&self) -> Result<Vec<Task>, TaskError> {
+        self.inner.get_all().await
+    }
+
+    async fn get_by_id(&self, id: u64) -> Result<Task, TaskError> {
+        self.inner.get_by_id(id).await
+    }
+
+    async fn create(&self, task: CreateTask) -> Result<Task, TaskError> {
+        let task = self.inner.create(task).await?;
+        self.publisher.publish(TaskChange::new(TaskChangeKind::Created, task.clone()));
+        Ok(task)
+    }
+
+    async fn update(&self, id: u64, update_task: UpdateTask) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.update(id, update_task).await;
+        self.publish_update(previous, &result);
+        result
+    }
+
+    async fn delete(&self, id: u64) -> Result<(), TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.delete(id).await;
+        self.publish_delete(previous, &result);
+        result
+    }
+
+    async fn complete(&self, id: u64) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.complete(id).await;
+        self.publish_update(previous, &result);
+        result
+    }
+
+    async fn force_complete(&self, id: u64, expected_version: Option<u64>) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.force_complete(id, expected_version).await;
+        self.publish_update(previous, &result);
+        result
+    }
+
+    async fn uncomplete(&self, id: u64) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.uncomplete(id).await;
+        self.publish_update(previous, &result);
+        result
+    }
+
+    async fn update_if_version(&self, id: u64, update_task: UpdateTask, expected_version: u64) -> Result<Task, TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.update_if_version(id, update_task, expected_version).await;
+        self.publish_update(previous, &result);
+        result
+    }
+
+    async fn delete_if_version(&self, id: u64, expected_version: u64) -> Result<(), TaskError> {
+        let previous = self.inner.get_by_id(id).await.ok();
+        let result = self.inner.delete_if_version(id, expected_version).await;
+        self.publish_delete(previous, &result);
+        result
+    }
+
+    async fn delete_with_children(&self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> Result<Vec<TaskOperationOutcome>, TaskError> {
+        let outcomes = self.inner.delete_with_children(id, policy, expected_version).await?;
+        for outcome in &outcomes {
+            self.publish_outcome(outcome);
+        }
+        Ok(outcomes)
+    }
+
+    /// 保存された場合だけ、成功した操作を順に通知する
+    async fn apply_batch(&self, operations: Vec<TaskOperation>, mode: BatchMode) -> Result<BatchOutcome, TaskError> {
+        let outcome = self.inner.apply_batch(operations, mode).await?;
+        if outcome.committed {
+            for result in outcome.results.iter().flatten() {
+                self.publish_outcome(result);
+            }
+        }
+        Ok(outcome)
+    }
+
+    async fn get_trash(&self) -> Result<Vec<Task>, TaskError> {
+        self.inner.get_trash().await
+    }
+
+    async fn restore(&self, id: u64) -> Result<Task, TaskError> {
+        let task = self.inner.restore(id).await?;
+        self.publisher.publish(TaskChange::new(TaskChangeKind::Restored, task.clone()));
+        Ok(task)
+    }
+
+    /// ゴミ箱のタスクは既に削除を通知済みのため、完全に削除しても通知しない
+    async fn purge_trash(&self, deleted_before: Option<DateTime<Utc>>, owner_id: Option<u64>) -> Result<usize, TaskError> {
+        self.inner.purge_trash(deleted_before, owner_id).await
+    }
+
+    async fn get_history(&self, id: u64) -> Result<Vec<TaskHistoryEntry>, TaskError> {
+        self.inner.get_history(id).await
+    }
+
+    async fn query(&self, query: TaskQuery) -> Result<TaskPage, TaskError> {
+        self.inner.query(query).await
+    }
+
+    async fn count_tasks(&self) -> Result<TaskCounts, TaskError> {
+        self.inner.count_tasks().await
+    }
+
+    async fn ping(&self) -> Result<(), TaskError> {
+        self.inner.ping().await
+    }
+
+    fn backend_name(&self) -> &'static str {
+        self.inner.backend_name()
+    }
+
+    async fn shutdown(&self) -> Result<(), TaskError> {
+        self.inner.shutdown().await
+    }
+
+    async fn get_tag_counts(&self) -> Result<Vec<TagCount>, TaskError> {
+        self.inner.get_tag_counts().await
+    }
+}
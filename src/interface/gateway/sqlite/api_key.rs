@@ -0,0 +1,124 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use chrono::Utc;
+use rusqlite::types::Type;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use crate::domain::model::api_key::{ApiKey, ApiKeyValidationError};
+use crate::domain::repository::api_key::{ApiKeyError, ApiKeyRepository, NewApiKey};
+
+const SELECT_COLUMNS: &str = "SELECT id, name, scope, user_id, prefix, secret_hash, created_at, revoked_at FROM api_keys";
+
+/// SQLiteファイルにAPIキーを永続化するリポジトリ（タスクのリポジトリと接続を共有する）
+#[derive(Clone)]
+pub struct SqliteApiKeyRepository {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteApiKeyRepository {
+    pub(super) fn new(conn: Arc<Mutex<Connection>>) -> Self {
+        Self { conn }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Connection>, ApiKeyError> {
+        self.conn.lock().map_err(|e| {
+            ApiKeyError::RepositoryError(Box::new(std::io::Error::other(
+                format!("Failed to acquire lock: {}", e),
+            )))
+        })
+    }
+}
+
+fn storage_error(e: rusqlite::Error) -> ApiKeyError {
+    ApiKeyError::RepositoryError(Box::new(e))
+}
+
+fn row_to_api_key(row: &Row<'_>) -> rusqlite::Result<ApiKey> {
+    let scope: String = row.get(2)?;
+    Ok(ApiKey {
+        id: row.get::<_, i64>(0)? as u64,
+        name: row.get(1)?,
+        scope: scope
+            .parse()
+            .map_err(|e: String| rusqlite::Error::FromSqlConversionFailure(2, Type::Text, e.into()))?,
+        user_id: row.get::<_, i64>(3)? as u64,
+        prefix: row.get(4)?,
+        secret_hash: row.get(5)?,
+        created_at: row.get(6)?,
+        revoked_at: row.get(7)?,
+    })
+}
+
+fn find_api_key(conn: &Connection, id: u64) -> Result<ApiKey, ApiKeyError> {
+    conn.query_row(&format!("{} WHERE id = ?1", SELECT_COLUMNS), params![id as i64], row_to_api_key)
+        .optional()
+        .map_err(storage_error)?
+        .ok_or(ApiKeyError::NotFound(id))
+}
+
+#[async_trait]
+impl ApiKeyRepository for SqliteApiKeyRepository {
+    async fn get_all(&self) -> Result<Vec<ApiKey>, ApiKeyError> {
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare(&format!("{} ORDER BY id", SELECT_COLUMNS))
+            .map_err(storage_error)?;
+        let keys = stmt
+            .query_map([], row_to_api_key)
+            .map_err(storage_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(storage_error)?;
+        Ok(keys)
+    }
+
+    async fn find_by_hash(&self, secret_hash: &str) -> Result<Option<ApiKey>, ApiKeyError> {
+        let conn = self.lock()?;
+        conn.query_row(&format!("{} WHERE secret_hash = ?1", SELECT_COLUMNS), params![secret_hash], row_to_api_key)
+            .optional()
+            .map_err(storage_error)
+    }
+
+    async fn create(&self, new_key: NewApiKey) -> Result<ApiKey, ApiKeyError> {
+        let conn = self.lock()?;
+        let user_exists = conn
+            .query_row("SELECT 1 FROM users WHERE id = ?1", params![new_key.user_id as i64], |_| Ok(()))
+            .optional()
+            .map_err(storage_error)?
+            .is_some();
+        if !user_exists {
+            return Err(ApiKeyValidationError::UserNotFound(new_key.user_id).into());
+        }
+
+        // IDはINSERT後に確定するため、仮のIDで組み立てる
+        let mut key = ApiKey {
+            id: 0,
+            name: new_key.name.trim().to_string(),
+            scope: new_key.scope,
+            user_id: new_key.user_id,
+            prefix: new_key.prefix,
+            secret_hash: new_key.secret_hash,
+            created_at: Utc::now(),
+            revoked_at: None,
+        };
+        conn.execute(
+            "INSERT INTO api_keys (name, scope, user_id, prefix, secret_hash, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![key.name, key.scope.as_str(), key.user_id as i64, key.prefix, key.secret_hash, key.created_at],
+        )
+        .map_err(storage_error)?;
+        key.id = conn.last_insert_rowid() as u64;
+        Ok(key)
+    }
+
+    async fn revoke(&self, id: u64) -> Result<ApiKey, ApiKeyError> {
+        let conn = self.lock()?;
+        let mut key = find_api_key(&conn, id)?;
+        if key.is_active() {
+            key.revoke();
+            conn.execute(
+                "UPDATE api_keys SET revoked_at = ?1 WHERE id = ?2",
+                params![key.revoked_at, id as i64],
+            )
+            .map_err(storage_error)?;
+        }
+        Ok(key)
+    }
+}
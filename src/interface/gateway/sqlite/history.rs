@@ -0,0 +1,110 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use rusqlite::types::Type;
+use rusqlite::{params, Connection, Row};
+use serde_json::{json, Value};
+use crate::domain::model::history::{FieldChange, NewTaskHistoryEntry, TaskHistoryEntry};
+use crate::domain::repository::history::TaskHistoryRepository;
+use crate::domain::repository::task::TaskError;
+
+/// SQLiteファイルに変更履歴を永続化するリポジトリ（タスクのリポジトリと接続を共有する）
+#[derive(Clone)]
+pub struct SqliteTaskHistoryRepository {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteTaskHistoryRepository {
+    pub(super) fn new(conn: Arc<Mutex<Connection>>) -> Self {
+        Self { conn }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Connection>, TaskError> {
+        self.conn.lock().map_err(|e| {
+            TaskError::RepositoryError(Box::new(std::io::Error::other(
+                format!("Failed to acquire lock: {}", e),
+            )))
+        })
+    }
+}
+
+fn storage_error(e: rusqlite::Error) -> TaskError {
+    TaskError::RepositoryError(Box::new(e))
+}
+
+/// 変更の一覧は `[{"field", "before", "after"}]` のJSONとして1列に保存する
+fn changes_to_json(changes: &[FieldChange]) -> String {
+    Value::Array(
+        changes
+            .iter()
+            .map(|change| json!({ "field": change.field, "before": change.before, "after": change.after }))
+            .collect(),
+    )
+    .to_string()
+}
+
+fn changes_from_json(text: &str) -> Option<Vec<FieldChange>> {
+    let Value::Array(items) = serde_json::from_str(text).ok()? else {
+        return None;
+    };
+    items
+        .into_iter()
+        .map(|mut item| {
+            Some(FieldChange {
+                field: item.get("field")?.as_str()?.to_string(),
+                before: Some(item["before"].take()).filter(|value| !value.is_null()),
+                after: Some(item["after"].take()).filter(|value| !value.is_null()),
+            })
+        })
+        .collect()
+}
+
+fn row_to_entry(row: &Row<'_>) -> rusqlite::Result<TaskHistoryEntry> {
+    let operation: String = row.get(2)?;
+    let changes: String = row.get(3)?;
+    Ok(TaskHistoryEntry {
+        id: row.get::<_, i64>(0)? as u64,
+        task_id: row.get::<_, i64>(1)? as u64,
+        operation: operation
+            .parse()
+            .map_err(|e: String| rusqlite::Error::FromSqlConversionFailure(2, Type::Text, e.into()))?,
+        changes: changes_from_json(&changes).ok_or_else(|| {
+            rusqlite::Error::FromSqlConversionFailure(3, Type::Text, "malformed history changes".into())
+        })?,
+        actor: row.get(4)?,
+        recorded_at: row.get(5)?,
+    })
+}
+
+#[async_trait]
+impl TaskHistoryRepository for SqliteTaskHistoryRepository {
+    async fn append(&self, entry: NewTaskHistoryEntry) -> Result<TaskHistoryEntry, TaskError> {
+        let conn = self.lock()?;
+        conn.execute(
+            "INSERT INTO task_history (task_id, operation, changes, actor, recorded_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![
+                entry.task_id as i64,
+                entry.operation.as_str(),
+                changes_to_json(&entry.changes),
+                entry.actor,
+                entry.recorded_at
+            ],
+        )
+        .map_err(storage_error)?;
+        Ok(TaskHistoryEntry::new(conn.last_insert_rowid() as u64, entry))
+    }
+
+    async fn get_by_task(&self, task_id: u64) -> Result<Vec<TaskHistoryEntry>, TaskError> {
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, task_id, operation, changes, actor, recorded_at FROM task_history WHERE task_id = ?1 ORDER BY id",
+            )
+            .map_err(storage_error)?;
+        let entries = stmt
+            .query_map(params![task_id as i64], row_to_entry)
+            .map_err(storage_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(storage_error)?;
+        Ok(entries)
+    }
+}
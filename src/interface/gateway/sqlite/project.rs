@@ -0,0 +1,181 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use crate::domain::model::project::{CreateProject, Project, ProjectValidationError, UpdateProject, INBOX_PROJECT_ID};
+use crate::domain::model::user::DEFAULT_USER_ID;
+use crate::domain::repository::project::{ProjectError, ProjectRepository};
+use crate::domain::repository::task::TaskCounts;
+use super::task::ACTIVE;
+
+const SELECT_COLUMNS: &str = "SELECT id, name, created_at, updated_at, owner_id FROM projects";
+
+/// SQLiteファイルにプロジェクトを永続化するリポジトリ（タスクのリポジトリと接続を共有する）
+#[derive(Clone)]
+pub struct SqliteProjectRepository {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteProjectRepository {
+    pub(super) fn new(conn: Arc<Mutex<Connection>>) -> Self {
+        Self { conn }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Connection>, ProjectError> {
+        self.conn.lock().map_err(|e| {
+            ProjectError::RepositoryError(Box::new(std::io::Error::other(
+                format!("Failed to acquire lock: {}", e),
+            )))
+        })
+    }
+}
+
+fn storage_error(e: rusqlite::Error) -> ProjectError {
+    ProjectError::RepositoryError(Box::new(e))
+}
+
+fn row_to_project(row: &Row<'_>) -> rusqlite::Result<Project> {
+    Ok(Project {
+        id: row.get::<_, i64>(0)? as u64,
+        name: row.get(1)?,
+        created_at: row.get(2)?,
+        updated_at: row.get(3)?,
+        owner_id: row.get::<_, i64>(4)? as u64,
+    })
+}
+
+/// 利用者がタスクを入れられるプロジェクトか（受信箱は誰でも使える）
+pub(super) fn project_visible_to(conn: &Connection, id: u64, user_id: u64) -> rusqlite::Result<bool> {
+    conn.query_row(
+        "SELECT 1 FROM projects WHERE id = ?1 AND (id = ?2 OR owner_id = ?3)",
+        params![id as i64, INBOX_PROJECT_ID as i64, user_id as i64],
+        |_| Ok(()),
+    )
+    .optional()
+    .map(|found| found.is_some())
+}
+
+fn find_project(conn: &Connection, id: u64) -> Result<Project, ProjectError> {
+    conn.query_row(&format!("{} WHERE id = ?1", SELECT_COLUMNS), params![id as i64], row_to_project)
+        .optional()
+        .map_err(storage_error)?
+        .ok_or(ProjectError::NotFound(id))
+}
+
+fn find_all(conn: &Connection) -> Result<Vec<Project>, ProjectError> {
+    let mut stmt = conn
+        .prepare(&format!("{} ORDER BY id", SELECT_COLUMNS))
+        .map_err(storage_error)?;
+    let projects = stmt
+        .query_map([], row_to_project)
+        .map_err(storage_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(storage_error)?;
+    Ok(projects)
+}
+
+/// 利用者から見える中に同じ名前のプロジェクトがあればエラー（大文字・小文字を区別しないため、比較はRust側で行う）
+fn ensure_unique(conn: &Connection, name: &str, owner_id: u64, except: Option<u64>) -> Result<(), ProjectError> {
+    let mut visible = find_all(conn)?.into_iter().filter(|project| project.is_visible_to(owner_id));
+    match visible.find(|project| Some(project.id) != except && project.has_name(name)) {
+        Some(project) => Err(ProjectValidationError::DuplicateName(project.name).into()),
+        None => Ok(()),
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for SqliteProjectRepository {
+    async fn get_all(&self) -> Result<Vec<Project>, ProjectError> {
+        let conn = self.lock()?;
+        find_all(&conn)
+    }
+
+    async fn get_by_id(&self, id: u64) -> Result<Project, ProjectError> {
+        let conn = self.lock()?;
+        find_project(&conn, id)
+    }
+
+    async fn create(&self, create_project: CreateProject) -> Result<Project, ProjectError> {
+        create_project.validate()?;
+        let owner_id = create_project.owner_id.unwrap_or(DEFAULT_USER_ID);
+        let conn = self.lock()?;
+        ensure_unique(&conn, &create_project.name, owner_id, None)?;
+
+        // IDはINSERT後に確定するため、仮のIDで組み立てる
+        let mut project = Project::new(0, &create_project.name, owner_id)?;
+        conn.execute(
+            "INSERT INTO projects (name, created_at, updated_at, owner_id) VALUES (?1, ?2, ?3, ?4)",
+            params![project.name, project.created_at, project.updated_at, owner_id as i64],
+        )
+        .map_err(storage_error)?;
+        project.id = conn.last_insert_rowid() as u64;
+        Ok(project)
+    }
+
+    async fn update(&self, id: u64, update_project: UpdateProject) -> Result<Project, ProjectError> {
+        update_project.validate()?;
+        let conn = self.lock()?;
+        let mut project = find_project(&conn, id)?;
+        if let Some(name) = update_project.name {
+            ensure_unique(&conn, &name, project.owner_id, Some(id))?;
+            project.rename(&name)?;
+        }
+        conn.execute(
+            "UPDATE projects SET name = ?1, updated_at = ?2 WHERE id = ?3",
+            params![project.name, project.updated_at, id as i64],
+        )
+        .map_err(storage_error)?;
+        Ok(project)
+    }
+
+    async fn delete(&self, id: u64) -> Result<(), ProjectError> {
+        let conn = self.lock()?;
+        find_project(&conn, id)?;
+        if id == INBOX_PROJECT_ID {
+            return Err(ProjectError::InvalidOperation("The inbox cannot be deleted".to_string()));
+        }
+        let remaining: i64 = conn
+            .query_row(
+                &format!("SELECT COUNT(*) FROM tasks WHERE project_id = ?1 AND {}", ACTIVE),
+                params![id as i64],
+                |row| row.get(0),
+            )
+            .map_err(storage_error)?;
+        if remaining > 0 {
+            return Err(ProjectError::NotEmpty(id));
+        }
+
+        let tx = conn.unchecked_transaction().map_err(storage_error)?;
+        tx.execute(
+            "UPDATE tasks SET project_id = ?1, version = version + 1, updated_at = ?2 WHERE project_id = ?3",
+            params![INBOX_PROJECT_ID as i64, Utc::now(), id as i64],
+        )
+        .map_err(storage_error)?;
+        tx.execute("DELETE FROM projects WHERE id = ?1", params![id as i64])
+            .map_err(storage_error)?;
+        tx.commit().map_err(storage_error)
+    }
+
+    async fn count_tasks(&self, owner_id: Option<u64>) -> Result<BTreeMap<u64, TaskCounts>, ProjectError> {
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT project_id, COUNT(*), COALESCE(SUM(completed), 0) FROM tasks WHERE {} AND (?1 IS NULL OR owner_id = ?1) GROUP BY project_id",
+                ACTIVE
+            ))
+            .map_err(storage_error)?;
+        let counts = stmt
+            .query_map(params![owner_id.map(|id| id as i64)], |row| {
+                let counts = TaskCounts {
+                    total: row.get::<_, i64>(1)? as usize,
+                    completed: row.get::<_, i64>(2)? as usize,
+                };
+                Ok((row.get::<_, i64>(0)? as u64, counts))
+            })
+            .map_err(storage_error)?
+            .collect::<Result<BTreeMap<_, _>, _>>()
+            .map_err(storage_error)?;
+        Ok(counts)
+    }
+}
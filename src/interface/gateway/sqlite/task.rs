@@ -0,0 +1,914 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use rusqlite::types::Type;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Row, ToSql};
+use crate::domain::model::project::{Project, INBOX_PROJECT_ID};
+use crate::domain::model::recurrence::Recurrence;
+use crate::domain::model::user::{User, DEFAULT_USER_ID};
+use crate::domain::model::task::{Task, CreateTask, UpdateTask, TaskPriority, TaskValidationError};
+use crate::domain::repository::task::{
+    ensure_dependency, ensure_parent, ensure_unblocked, ensure_version, BatchMode, BatchOutcome, ChildPolicy, SortDirection, TagCount, TagMatch, TaskCounts, TaskError, TaskOperation,
+    TaskOperationOutcome, TaskPage, TaskQuery, TaskRepository, TaskSortField,
+};
+use crate::domain::repository::api_key::ApiKeyStorage;
+use crate::domain::repository::history::HistoryStorage;
+use crate::domain::repository::project::ProjectStorage;
+use crate::domain::repository::user::UserStorage;
+use super::api_key::SqliteApiKeyRepository;
+use super::history::SqliteTaskHistoryRepository;
+use super::project::{project_visible_to, SqliteProjectRepository};
+use super::user::SqliteUserRepository;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS tasks (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    description TEXT    NOT NULL,
+    completed   INTEGER NOT NULL DEFAULT 0,
+    created_at  TEXT    NOT NULL,
+    updated_at  TEXT    NOT NULL,
+    priority    TEXT,
+    due_at      TEXT,
+    version     INTEGER NOT NULL DEFAULT 1,
+    deleted_at  TEXT,
+    parent_id   INTEGER,
+    recurrence  TEXT,
+    project_id  INTEGER NOT NULL DEFAULT 1,
+    owner_id    INTEGER NOT NULL DEFAULT 1
+);
+CREATE TABLE IF NOT EXISTS task_tags (
+    task_id INTEGER NOT NULL,
+    tag     TEXT    NOT NULL,
+    PRIMARY KEY (task_id, tag)
+);
+CREATE TABLE IF NOT EXISTS task_dependencies (
+    task_id    INTEGER NOT NULL,
+    depends_on INTEGER NOT NULL,
+    PRIMARY KEY (task_id, depends_on)
+);
+CREATE TABLE IF NOT EXISTS projects (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    name       TEXT    NOT NULL,
+    created_at TEXT    NOT NULL,
+    updated_at TEXT    NOT NULL,
+    owner_id   INTEGER NOT NULL DEFAULT 1
+);
+CREATE TABLE IF NOT EXISTS users (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    name       TEXT    NOT NULL,
+    created_at TEXT    NOT NULL
+);
+CREATE TABLE IF NOT EXISTS api_keys (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    name        TEXT    NOT NULL,
+    scope       TEXT    NOT NULL,
+    user_id     INTEGER NOT NULL,
+    prefix      TEXT    NOT NULL,
+    secret_hash TEXT    NOT NULL UNIQUE,
+    created_at  TEXT    NOT NULL,
+    revoked_at  TEXT
+);
+CREATE TABLE IF NOT EXISTS task_history (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    task_id     INTEGER NOT NULL,
+    operation   TEXT    NOT NULL,
+    changes     TEXT    NOT NULL,
+    actor       TEXT,
+    recorded_at TEXT    NOT NULL
+);
+";
+
+/// タスクに後から追加した列（既存のデータベースにはALTER TABLEで追加する）
+const ADDED_COLUMNS: &[(&str, &str)] = &[
+    ("priority", "TEXT"),
+    ("due_at", "TEXT"),
+    ("version", "INTEGER NOT NULL DEFAULT 1"),
+    ("deleted_at", "TEXT"),
+    ("parent_id", "INTEGER"),
+    ("recurrence", "TEXT"),
+    // 既存のタスクは受信箱に入れる
+    ("project_id", "INTEGER NOT NULL DEFAULT 1"),
+    // 既存のタスクは既定の利用者のものにする
+    ("owner_id", "INTEGER NOT NULL DEFAULT 1"),
+];
+
+/// プロジェクトに後から追加した列
+const ADDED_PROJECT_COLUMNS: &[(&str, &str)] = &[
+    // 既存のプロジェクトは既定の利用者のものにする
+    ("owner_id", "INTEGER NOT NULL DEFAULT 1"),
+];
+
+const INDEXES: &str = "
+CREATE INDEX IF NOT EXISTS idx_tasks_created_at ON tasks (created_at, id);
+CREATE INDEX IF NOT EXISTS idx_tasks_updated_at ON tasks (updated_at, id);
+CREATE INDEX IF NOT EXISTS idx_tasks_due_at ON tasks (due_at);
+CREATE INDEX IF NOT EXISTS idx_task_tags_tag ON task_tags (tag, task_id);
+CREATE INDEX IF NOT EXISTS idx_tasks_deleted_at ON tasks (deleted_at);
+CREATE INDEX IF NOT EXISTS idx_tasks_parent_id ON tasks (parent_id);
+CREATE INDEX IF NOT EXISTS idx_task_dependencies_depends_on ON task_dependencies (depends_on);
+CREATE INDEX IF NOT EXISTS idx_tasks_project_id ON tasks (project_id);
+CREATE INDEX IF NOT EXISTS idx_tasks_owner_id ON tasks (owner_id);
+CREATE INDEX IF NOT EXISTS idx_projects_owner_id ON projects (owner_id);
+CREATE INDEX IF NOT EXISTS idx_task_history_task_id ON task_history (task_id, id);
+";
+
+// タグには ',' を使えないため、区切り文字として連結して1行で取得する（依存先のIDも同様）
+const SELECT_COLUMNS: &str = "SELECT id, description, completed, created_at, updated_at, priority, due_at, \
+    (SELECT group_concat(tag, ',') FROM task_tags WHERE task_id = tasks.id), version, deleted_at, parent_id, \
+    (SELECT group_concat(depends_on, ',') FROM task_dependencies WHERE task_id = tasks.id), recurrence, project_id, owner_id FROM tasks";
+
+/// ゴミ箱にないタスクだけを対象にする条件
+pub(super) const ACTIVE: &str = "deleted_at IS NULL";
+
+/// SQLiteファイルにタスクを永続化するリポジトリ
+#[derive(Clone)]
+pub struct SqliteTaskRepository {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteTaskRepository {
+    /// 指定パスのSQLiteファイルをWALモードで開き、スキーマを作成する
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, TaskError> {
+        let conn = Connection::open(path).map_err(storage_error)?;
+        // 書き込みはWALへ追記し、停止時のチェックポイントでデータベースファイルへ反映する
+        conn.query_row("PRAGMA journal_mode = WAL", [], |_| Ok(())).map_err(storage_error)?;
+        Self::with_connection(conn)
+    }
+
+    /// プロセス内だけで完結するSQLiteデータベースを作成する（テスト用）
+    pub fn open_in_memory() -> Result<Self, TaskError> {
+        let conn = Connection::open_in_memory().map_err(storage_error)?;
+        Self::with_connection(conn)
+    }
+
+    fn with_connection(conn: Connection) -> Result<Self, TaskError> {
+        conn.execute_batch(SCHEMA).map_err(storage_error)?;
+        add_missing_columns(&conn, "tasks", ADDED_COLUMNS).map_err(storage_error)?;
+        add_missing_columns(&conn, "projects", ADDED_PROJECT_COLUMNS).map_err(storage_error)?;
+        conn.execute_batch(INDEXES).map_err(storage_error)?;
+        let inbox = Project::inbox();
+        conn.execute(
+            "INSERT OR IGNORE INTO projects (id, name, created_at, updated_at, owner_id) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![inbox.id as i64, inbox.name, inbox.created_at, inbox.updated_at, inbox.owner_id as i64],
+        )
+        .map_err(storage_error)?;
+        let default_user = User::default_user();
+        conn.execute(
+            "INSERT OR IGNORE INTO users (id, name, created_at) VALUES (?1, ?2, ?3)",
+            params![default_user.id as i64, default_user.name, default_user.created_at],
+        )
+        .map_err(storage_error)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Connection>, TaskError> {
+        self.conn.lock().map_err(|e| {
+            TaskError::RepositoryError(Box::new(std::io::Error::other(
+                format!("Failed to acquire lock: {}", e),
+            )))
+        })
+    }
+}
+
+impl ProjectStorage for SqliteTaskRepository {
+    type Projects = SqliteProjectRepository;
+
+    fn projects(&self) -> SqliteProjectRepository {
+        SqliteProjectRepository::new(self.conn.clone())
+    }
+}
+
+impl UserStorage for SqliteTaskRepository {
+    type Users = SqliteUserRepository;
+
+    fn users(&self) -> SqliteUserRepository {
+        SqliteUserRepository::new(self.conn.clone())
+    }
+}
+
+impl HistoryStorage for SqliteTaskRepository {
+    type History = SqliteTaskHistoryRepository;
+
+    fn history(&self) -> SqliteTaskHistoryRepository {
+        SqliteTaskHistoryRepository::new(self.conn.clone())
+    }
+}
+
+impl ApiKeyStorage for SqliteTaskRepository {
+    type ApiKeys = SqliteApiKeyRepository;
+
+    fn api_keys(&self) -> SqliteApiKeyRepository {
+        SqliteApiKeyRepository::new(self.conn.clone())
+    }
+}
+
+fn storage_error(e: rusqlite::Error) -> TaskError {
+    TaskError::RepositoryError(Box::new(e))
+}
+
+/// 古いスキーマで作成されたデータベースに不足している列を追加する
+fn add_missing_columns(conn: &Connection, table: &str, columns: &[(&str, &str)]) -> rusqlite::Result<()> {
+    let existing = conn
+        .prepare("SELECT name FROM pragma_table_info(?1)")?
+        .query_map(params![table], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    for (name, definition) in columns {
+        if !existing.iter().any(|column| column == name) {
+            conn.execute_batch(&format!("ALTER TABLE {} ADD COLUMN {} {}", table, name, definition))?;
+        }
+    }
+    Ok(())
+}
+
+fn row_to_task(row: &Row<'_>) -> rusqlite::Result<Task> {
+    let priority = row
+        .get::<_, Option<String>>(5)?
+        .map(|value| value.parse::<TaskPriority>())
+        .transpose()
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(5, Type::Text, e.into()))?;
+
+    let tags: BTreeSet<String> = row
+        .get::<_, Option<String>>(7)?
+        .map(|tags| tags.split(',').map(str::to_string).collect())
+        .unwrap_or_default();
+
+    let depends_on: BTreeSet<u64> = row
+        .get::<_, Option<String>>(11)?
+        .map(|ids| ids.split(',').map(str::parse).collect::<Result<_, _>>())
+        .transpose()
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(11, Type::Text, Box::new(e)))?
+        .unwrap_or_default();
+
+    let recurrence = row
+        .get::<_, Option<String>>(12)?
+        .map(|rule| rule.parse::<Recurrence>())
+        .transpose()
+        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(12, Type::Text, e.into()))?;
+
+    Ok(Task {
+        id: row.get::<_, i64>(0)? as u64,
+        description: row.get(1)?,
+        completed: row.get(2)?,
+        priority,
+        due_at: row.get(6)?,
+        tags,
+        version: row.get::<_, i64>(8)? as u64,
+        created_at: row.get(3)?,
+        updated_at: row.get(4)?,
+        deleted_at: row.get(9)?,
+        parent_id: row.get::<_, Option<i64>>(10)?.map(|id| id as u64),
+        depends_on,
+        recurrence,
+        project_id: row.get::<_, i64>(13)? as u64,
+        owner_id: row.get::<_, i64>(14)? as u64,
+    })
+}
+
+fn find_task(conn: &Connection, id: u64) -> Result<Task, TaskError> {
+    conn.query_row(&format!("{} WHERE id = ?1 AND {}", SELECT_COLUMNS, ACTIVE), params![id as i64], row_to_task)
+        .optional()
+        .map_err(storage_error)?
+        .ok_or(TaskError::NotFound(id))
+}
+
+/// タスクの親（ゴミ箱にないタスクが存在しなければ None）
+fn find_parent(conn: &Connection, id: u64) -> Result<Option<Option<u64>>, TaskError> {
+    conn.query_row(&format!("SELECT parent_id FROM tasks WHERE id = ?1 AND {}", ACTIVE), params![id as i64], |row| {
+        row.get::<_, Option<i64>>(0)
+    })
+    .optional()
+    .map(|parent| parent.map(|parent| parent.map(|id| id as u64)))
+    .map_err(storage_error)
+}
+
+/// タスクの依存先（ゴミ箱にないタスクが存在しなければ None）
+fn find_dependencies(conn: &Connection, id: u64) -> Result<Option<BTreeSet<u64>>, TaskError> {
+    match find_task(conn, id) {
+        Ok(task) => Ok(Some(task.depends_on)),
+        Err(TaskError::NotFound(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// 未完了の依存先のID（ゴミ箱にあるタスクは待たない）
+fn find_blockers(conn: &Connection, task: &Task) -> Result<Vec<u64>, TaskError> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT completed FROM tasks WHERE id = ?1 AND {}", ACTIVE))
+        .map_err(storage_error)?;
+    let mut blockers = Vec::new();
+    for &id in &task.depends_on {
+        let completed = stmt
+            .query_row(params![id as i64], |row| row.get::<_, bool>(0))
+            .optional()
+            .map_err(storage_error)?;
+        if completed == Some(false) {
+            blockers.push(id);
+        }
+    }
+    Ok(blockers)
+}
+
+/// 直下の子タスクのID
+fn find_children(conn: &Connection, id: u64) -> Result<Vec<u64>, TaskError> {
+    let mut stmt = conn
+        .prepare(&format!("SELECT id FROM tasks WHERE parent_id = ?1 AND {} ORDER BY id", ACTIVE))
+        .map_err(storage_error)?;
+    let ids = stmt
+        .query_map(params![id as i64], |row| row.get::<_, i64>(0))
+        .map_err(storage_error)?
+        .map(|id| id.map(|id| id as u64))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(storage_error)?;
+    Ok(ids)
+}
+
+/// タスクの持ち主が使えるプロジェクトか確かめる（他の利用者のプロジェクトは存在しないものとして扱う）
+fn ensure_project(conn: &Connection, project_id: u64, owner_id: u64) -> Result<(), TaskError> {
+    if project_visible_to(conn, project_id, owner_id).map_err(storage_error)? {
+        Ok(())
+    } else {
+        Err(TaskValidationError::ProjectNotFound(project_id).into())
+    }
+}
+
+fn find_trashed_task(conn: &Connection, id: u64) -> Result<Task, TaskError> {
+    conn.query_row(&format!("{} WHERE id = ?1 AND deleted_at IS NOT NULL", SELECT_COLUMNS), params![id as i64], row_to_task)
+        .optional()
+        .map_err(storage_error)?
+        .ok_or(TaskError::NotFound(id))
+}
+
+/// セーブポイントの中で書き込み、失敗したら取り消す
+///
+/// トランザクションと違って入れ子にできるため、一括処理のセーブポイントの中からも呼べる。
+fn with_savepoint<T>(conn: &Connection, write: impl FnOnce() -> Result<T, TaskError>) -> Result<T, TaskError> {
+    conn.execute_batch("SAVEPOINT task_write").map_err(storage_error)?;
+    match write() {
+        Ok(value) => {
+            conn.execute_batch("RELEASE task_write").map_err(storage_error)?;
+            Ok(value)
+        }
+        Err(e) => {
+            // 取り消しに失敗しても、呼び出し側には元のエラーを返す
+            if let Err(rollback_error) = conn.execute_batch("ROLLBACK TO task_write; RELEASE task_write") {
+                tracing::error!("failed to roll back task write: {}", rollback_error);
+            }
+            Err(e)
+        }
+    }
+}
+
+fn insert_task(conn: &Connection, create_task: CreateTask) -> Result<Task, TaskError> {
+    // バリデーション
+    create_task.validate()?;
+    let owner_id = create_task.owner_id.unwrap_or(DEFAULT_USER_ID);
+    ensure_project(conn, create_task.project_id.unwrap_or(INBOX_PROJECT_ID), owner_id)?;
+    if let Some(parent_id) = create_task.parent_id {
+        ensure_parent(None, parent_id, |id| find_parent(conn, id))?;
+    }
+
+    // IDはINSERT後に確定するため、仮のIDでタスクを組み立てる
+    let mut task = Task::from_create(0, create_task)?;
+    // 本体とタグは一緒に書き込み、途中で失敗したらタグの欠けたタスクを残さない
+    with_savepoint(conn, || {
+        conn.execute(
+            "INSERT INTO tasks (description, completed, created_at, updated_at, priority, due_at, version, parent_id, recurrence, project_id, owner_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![
+                task.description,
+                task.completed,
+                task.created_at,
+                task.updated_at,
+                task.priority.map(|p| p.as_str()),
+                task.due_at,
+                task.version as i64,
+                task.parent_id.map(|id| id as i64),
+                task.recurrence.as_ref().map(Recurrence::to_string),
+                task.project_id as i64,
+                task.owner_id as i64,
+            ],
+        )
+        .map_err(storage_error)?;
+        task.id = conn.last_insert_rowid() as u64;
+        save_tags(conn, &task)?;
+
+        Ok(task)
+    })
+}
+
+fn save_task(conn: &Connection, task: &Task) -> Result<(), TaskError> {
+    with_savepoint(conn, || {
+        conn.execute(
+            "UPDATE tasks SET description = ?1, completed = ?2, updated_at = ?3, priority = ?4, due_at = ?5, version = ?6, deleted_at = ?7, parent_id = ?8, recurrence = ?9, project_id = ?10 WHERE id = ?11",
+            params![
+                task.description,
+                task.completed,
+                task.updated_at,
+                task.priority.map(|p| p.as_str()),
+                task.due_at,
+                task.version as i64,
+                task.deleted_at,
+                task.parent_id.map(|id| id as i64),
+                task.recurrence.as_ref().map(Recurrence::to_string),
+                task.project_id as i64,
+                task.id as i64,
+            ],
+        )
+        .map_err(storage_error)?;
+
+        // タグは付け替えて保存する
+        conn.execute("DELETE FROM task_tags WHERE task_id = ?1", params![task.id as i64])
+            .map_err(storage_error)?;
+        save_tags(conn, task)?;
+
+        conn.execute("DELETE FROM task_dependencies WHERE task_id = ?1", params![task.id as i64])
+            .map_err(storage_error)?;
+        for depends_on in &task.depends_on {
+            conn.execute(
+                "INSERT INTO task_dependencies (task_id, depends_on) VALUES (?1, ?2)",
+                params![task.id as i64, *depends_on as i64],
+            )
+            .map_err(storage_error)?;
+        }
+        Ok(())
+    })
+}
+
+fn save_tags(conn: &Connection, task: &Task) -> Result<(), TaskError> {
+    for tag in &task.tags {
+        conn.execute(
+            "INSERT INTO task_tags (task_id, tag) VALUES (?1, ?2)",
+            params![task.id as i64, tag],
+        )
+        .map_err(storage_error)?;
+    }
+    Ok(())
+}
+
+/// 部分更新を適用して保存し、更新前と更新後のタスクを返す（呼び出し側が接続のロックを保持している前提）
+fn update_task_row(conn: &Connection, id: u64, update_task: UpdateTask, expected_version: Option<u64>) -> Result<(Task, Task), TaskError> {
+    if update_task.is_empty() {
+        return Err(TaskError::InvalidOperation("Update task cannot be empty".to_string()));
+    }
+
+    // バリデーション
+    update_task.validate()?;
+
+    let previous = find_task(conn, id)?;
+    if let Some(expected_version) = expected_version {
+        ensure_version(&previous, expected_version)?;
+    }
+    let mut task = previous.clone();
+
+    // 部分更新の適用
+    if let Some(description) = update_task.description {
+        task.update_description(description)?;
+    }
+
+    if let Some(completed) = update_task.completed {
+        if completed {
+            task.complete();
+        } else {
+            task.uncomplete();
+        }
+    }
+
+    if let Some(priority) = update_task.priority {
+        task.set_priority(priority);
+    }
+
+    if let Some(due_at) = update_task.due_at {
+        task.set_due_at(due_at)?;
+    }
+
+    if let Some(project_id) = update_task.project_id {
+        ensure_project(conn, project_id, task.owner_id)?;
+        task.set_project(project_id);
+    }
+
+    if let Some(parent_id) = update_task.parent_id {
+        if let Some(parent_id) = parent_id {
+            ensure_parent(Some(id), parent_id, |id| find_parent(conn, id))?;
+        }
+        task.set_parent(parent_id)?;
+    }
+
+    if let Some(recurrence) = update_task.recurrence {
+        task.set_recurrence(recurrence);
+    }
+
+    for &dependency in &update_task.add_dependencies {
+        ensure_dependency(id, dependency, |id| find_dependencies(conn, id))?;
+    }
+    task.add_dependencies(&update_task.add_dependencies)?;
+    task.remove_dependencies(&update_task.remove_dependencies);
+
+    task.add_tags(&update_task.add_tags)?;
+    task.remove_tags(&update_task.remove_tags)?;
+    if task.completed && !previous.completed {
+        ensure_unblocked(id, find_blockers(conn, &task)?)?;
+    }
+    task.advance_version(&previous);
+
+    save_task(conn, &task)?;
+    Ok((previous, task))
+}
+
+/// 完了状態を変える（`force` でなければ、未完了の依存先が残るタスクは完了にしない）
+fn set_completed_row(conn: &Connection, id: u64, completed: bool, force: bool) -> Result<(Task, Task), TaskError> {
+    let previous = find_task(conn, id)?;
+
+    let mut task = previous.clone();
+    if completed {
+        if !force && !previous.completed {
+            ensure_unblocked(id, find_blockers(conn, &previous)?)?;
+        }
+        task.complete();
+    } else {
+        task.uncomplete();
+    }
+    task.advance_version(&previous);
+    save_task(conn, &task)?;
+    Ok((previous, task))
+}
+
+/// ゴミ箱に移し、移す前のタスクを返す（子タスクがあれば移さない）
+fn delete_task_row(conn: &Connection, id: u64, expected_version: Option<u64>) -> Result<Task, TaskError> {
+    let previous = find_task(conn, id)?;
+    if let Some(expected_version) = expected_version {
+        ensure_version(&previous, expected_version)?;
+    }
+    if !find_children(conn, id)?.is_empty() {
+        return Err(TaskError::HasChildren(id));
+    }
+    trash_task_row(conn, previous)
+}
+
+/// 子タスクの扱いを指定して削除し、変更したタスクの前後を返す（先頭は指定したタスク）
+fn delete_tree_rows(conn: &Connection, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> Result<Vec<TaskOperationOutcome>, TaskError> {
+    let target = find_task(conn, id)?;
+    if let Some(expected_version) = expected_version {
+        ensure_version(&target, expected_version)?;
+    }
+    let children = find_children(conn, id)?;
+    if !children.is_empty() && policy == ChildPolicy::Reject {
+        return Err(TaskError::HasChildren(id));
+    }
+
+    with_savepoint(conn, || {
+        let mut outcomes = vec![TaskOperationOutcome { previous: Some(trash_task_row(conn, target)?), current: None }];
+        match policy {
+            ChildPolicy::Reject => {}
+            ChildPolicy::Cascade => {
+                let mut pending = children;
+                while let Some(descendant) = pending.pop() {
+                    pending.extend(find_children(conn, descendant)?);
+                    let previous = trash_task_row(conn, find_task(conn, descendant)?)?;
+                    outcomes.push(TaskOperationOutcome { previous: Some(previous), current: None });
+                }
+            }
+            ChildPolicy::Orphan => {
+                for child in children {
+                    let update = UpdateTask { parent_id: Some(None), ..UpdateTask::default() };
+                    let (previous, task) = update_task_row(conn, child, update, None)?;
+                    outcomes.push(TaskOperationOutcome { previous: Some(previous), current: Some(task) });
+                }
+            }
+        }
+        Ok(outcomes)
+    })
+}
+
+/// ゴミ箱に移し、移す前のタスクを返す（タグは元に戻せるよう残しておく）
+fn trash_task_row(conn: &Connection, previous: Task) -> Result<Task, TaskError> {
+    let mut task = previous.clone();
+    task.trash(Utc::now());
+    task.advance_version(&previous);
+    save_task(conn, &task)?;
+    Ok(previous)
+}
+
+fn apply_operation(conn: &Connection, operation: TaskOperation) -> Result<TaskOperationOutcome, TaskError> {
+    let (previous, current) = match operation {
+        TaskOperation::Create(create_task) => (None, Some(insert_task(conn, create_task)?)),
+        TaskOperation::Update { id, update } => {
+            let (previous, task) = update_task_row(conn, id, update, None)?;
+            (Some(previous), Some(task))
+        }
+        TaskOperation::Complete(id) => {
+            let (previous, task) = set_completed_row(conn, id, true, false)?;
+            (Some(previous), Some(task))
+        }
+        TaskOperation::Uncomplete(id) => {
+            let (previous, task) = set_completed_row(conn, id, false, false)?;
+            (Some(previous), Some(task))
+        }
+        TaskOperation::Delete(id) => (Some(delete_task_row(conn, id, None)?), None),
+    };
+    Ok(TaskOperationOutcome { previous, current })
+}
+
+#[async_trait]
+impl TaskRepository for SqliteTaskRepository {
+    async fn get_all(&self) -> Result<Vec<Task>, TaskError> {
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare(&format!("{} WHERE {} ORDER BY id", SELECT_COLUMNS, ACTIVE))
+            .map_err(storage_error)?;
+        let tasks = stmt
+            .query_map([], row_to_task)
+            .map_err(storage_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(storage_error)?;
+        Ok(tasks)
+    }
+
+    async fn get_by_id(&self, id: u64) -> Result<Task, TaskError> {
+        let conn = self.lock()?;
+        find_task(&conn, id)
+    }
+
+    async fn create(&self, create_task: CreateTask) -> Result<Task, TaskError> {
+        let conn = self.lock()?;
+        insert_task(&conn, create_task)
+    }
+
+    async fn update(&self, id: u64, update_task: UpdateTask) -> Result<Task, TaskError> {
+        let conn = self.lock()?;
+        update_task_row(&conn, id, update_task, None).map(|(_, task)| task)
+    }
+
+    async fn delete(&self, id: u64) -> Result<(), TaskError> {
+        let conn = self.lock()?;
+        delete_task_row(&conn, id, None).map(|_| ())
+    }
+
+    async fn update_if_version(&self, id: u64, update_task: UpdateTask, expected_version: u64) -> Result<Task, TaskError> {
+        let conn = self.lock()?;
+        update_task_row(&conn, id, update_task, Some(expected_version)).map(|(_, task)| task)
+    }
+
+    async fn delete_if_version(&self, id: u64, expected_version: u64) -> Result<(), TaskError> {
+        let conn = self.lock()?;
+        delete_task_row(&conn, id, Some(expected_version)).map(|_| ())
+    }
+
+    async fn delete_with_children(&self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> Result<Vec<TaskOperationOutcome>, TaskError> {
+        let conn = self.lock()?;
+        delete_tree_rows(&conn, id, policy, expected_version)
+    }
+
+    async fn complete(&self, id: u64) -> Result<Task, TaskError> {
+        let conn = self.lock()?;
+        set_completed_row(&conn, id, true, false).map(|(_, task)| task)
+    }
+
+    async fn uncomplete(&self, id: u64) -> Result<Task, TaskError> {
+        let conn = self.lock()?;
+        set_completed_row(&conn, id, false, false).map(|(_, task)| task)
+    }
+
+    async fn force_complete(&self, id: u64, expected_version: Option<u64>) -> Result<Task, TaskError> {
+        let conn = self.lock()?;
+        if let Some(expected_version) = expected_version {
+            ensure_version(&find_task(&conn, id)?, expected_version)?;
+        }
+        set_completed_row(&conn, id, true, true).map(|(_, task)| task)
+    }
+
+    /// 全操作を1つのセーブポイントの中で実行する
+    ///
+    /// `Atomic` で失敗した場合はセーブポイントまで巻き戻す。`BestEffort` では操作ごとに書き込みが完結する。
+    async fn apply_batch(&self, operations: Vec<TaskOperation>, mode: BatchMode) -> Result<BatchOutcome, TaskError> {
+        let conn = self.lock()?;
+        conn.execute_batch("SAVEPOINT task_batch").map_err(storage_error)?;
+        let mut results = Vec::with_capacity(operations.len());
+        for operation in operations {
+            let result = apply_operation(&conn, operation);
+            let failed = result.is_err();
+            results.push(result);
+            if failed && mode == BatchMode::Atomic {
+                conn.execute_batch("ROLLBACK TO task_batch; RELEASE task_batch").map_err(storage_error)?;
+                return Ok(BatchOutcome { committed: false, results });
+            }
+        }
+        conn.execute_batch("RELEASE task_batch").map_err(storage_error)?;
+        Ok(BatchOutcome { committed: true, results })
+    }
+
+    async fn get_trash(&self) -> Result<Vec<Task>, TaskError> {
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare(&format!("{} WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC, id DESC", SELECT_COLUMNS))
+            .map_err(storage_error)?;
+        let tasks = stmt
+            .query_map([], row_to_task)
+            .map_err(storage_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(storage_error)?;
+        Ok(tasks)
+    }
+
+    async fn restore(&self, id: u64) -> Result<Task, TaskError> {
+        let conn = self.lock()?;
+        let previous = find_trashed_task(&conn, id)?;
+        let mut task = previous.clone();
+        task.restore();
+        // 親が既にない場合は最上位のタスクとして戻す
+        if let Some(parent_id) = task.parent_id {
+            if find_parent(&conn, parent_id)?.is_none() {
+                task.set_parent(None)?;
+            }
+        }
+        // ゴミ箱にある間に循環ができていれば、その依存を外して戻す
+        let mut cyclic = Vec::new();
+        for &dependency in &task.depends_on {
+            match ensure_dependency(id, dependency, |id| find_dependencies(&conn, id)) {
+                Err(TaskError::ValidationError(TaskValidationError::DependencyCycle(_))) => cyclic.push(dependency),
+                Err(TaskError::ValidationError(_)) | Ok(()) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        task.remove_dependencies(&cyclic);
+        task.advance_version(&previous);
+        save_task(&conn, &task)?;
+        Ok(task)
+    }
+
+    async fn purge_trash(&self, deleted_before: Option<DateTime<Utc>>, owner_id: Option<u64>) -> Result<usize, TaskError> {
+        // 日時はすべて同じ書式で保存しているため、文字列比較で時系列順になる
+        let mut condition = "deleted_at IS NOT NULL".to_string();
+        let mut values: Vec<Box<dyn ToSql>> = Vec::new();
+        if let Some(before) = deleted_before {
+            condition.push_str(" AND deleted_at < ?");
+            values.push(Box::new(before));
+        }
+        if let Some(owner_id) = owner_id {
+            condition.push_str(" AND owner_id = ?");
+            values.push(Box::new(owner_id as i64));
+        }
+        let conn = self.lock()?;
+        with_savepoint(&conn, || {
+            conn.execute(
+                &format!("DELETE FROM task_tags WHERE task_id IN (SELECT id FROM tasks WHERE {})", condition),
+                params_from_iter(values.iter()),
+            )
+            .map_err(storage_error)?;
+            conn.execute(
+                &format!("DELETE FROM task_dependencies WHERE task_id IN (SELECT id FROM tasks WHERE {})", condition),
+                params_from_iter(values.iter()),
+            )
+            .map_err(storage_error)?;
+            conn.execute(&format!("DELETE FROM tasks WHERE {}", condition), params_from_iter(values.iter()))
+                .map_err(storage_error)
+        })
+    }
+
+    async fn query(&self, query: TaskQuery) -> Result<TaskPage, TaskError> {
+        let column = match query.sort {
+            TaskSortField::Id => "id",
+            TaskSortField::CreatedAt => "created_at",
+            TaskSortField::UpdatedAt => "updated_at",
+        };
+        let (direction, comparison) = match query.direction {
+            SortDirection::Asc => ("ASC", ">"),
+            SortDirection::Desc => ("DESC", "<"),
+        };
+
+        let mut conditions = vec![ACTIVE.to_string()];
+        let mut values: Vec<Box<dyn ToSql>> = Vec::new();
+        if let Some(completed) = query.completed {
+            conditions.push("completed = ?".to_string());
+            values.push(Box::new(completed));
+        }
+        if !query.tags.is_empty() {
+            let placeholders = vec!["?"; query.tags.len()].join(", ");
+            // 同じタグを重ねて指定しても、求める種類の数は増やさない
+            let distinct_tags = query.tags.iter().collect::<BTreeSet<_>>().len();
+            let having = match query.tag_match {
+                TagMatch::All => format!(" GROUP BY task_id HAVING COUNT(DISTINCT tag) = {}", distinct_tags),
+                TagMatch::Any => String::new(),
+            };
+            conditions.push(format!(
+                "id IN (SELECT task_id FROM task_tags WHERE tag IN ({}){})",
+                placeholders, having
+            ));
+            for tag in &query.tags {
+                values.push(Box::new(tag.clone()));
+            }
+        }
+        if let Some(parent_id) = query.parent_id {
+            conditions.push("parent_id = ?".to_string());
+            values.push(Box::new(parent_id as i64));
+        }
+        if let Some(project_id) = query.project_id {
+            conditions.push("project_id = ?".to_string());
+            values.push(Box::new(project_id as i64));
+        }
+        if let Some(owner_id) = query.owner_id {
+            conditions.push("owner_id = ?".to_string());
+            values.push(Box::new(owner_id as i64));
+        }
+        if let Some(due_from) = query.due_from {
+            conditions.push("due_at >= ?".to_string());
+            values.push(Box::new(due_from));
+        }
+        if let Some(due_before) = query.due_before {
+            conditions.push("due_at < ?".to_string());
+            values.push(Box::new(due_before));
+        }
+        if let Some(after) = &query.after {
+            // 日時はすべて同じ書式で保存しているため、文字列比較で時系列順になる
+            match after.key(query.sort) {
+                (Some(at), id) => {
+                    conditions.push(format!("({}, id) {} (?, ?)", column, comparison));
+                    values.push(Box::new(at));
+                    values.push(Box::new(id as i64));
+                }
+                (None, id) => {
+                    conditions.push(format!("id {} ?", comparison));
+                    values.push(Box::new(id as i64));
+                }
+            }
+        }
+
+        let filter = format!(" WHERE {}", conditions.join(" AND "));
+        let order = if column == "id" {
+            format!("id {}", direction)
+        } else {
+            format!("{} {}, id {}", column, direction, direction)
+        };
+        // 続きの有無を判定するため1件多く取得する（-1は件数制限なし）
+        values.push(Box::new(query.limit.map_or(-1, |limit| limit as i64 + 1)));
+
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare(&format!("{}{} ORDER BY {} LIMIT ?", SELECT_COLUMNS, filter, order))
+            .map_err(storage_error)?;
+        let tasks = stmt
+            .query_map(params_from_iter(values.iter()), row_to_task)
+            .map_err(storage_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(storage_error)?;
+
+        Ok(TaskPage::from_window(tasks, query.limit))
+    }
+
+    async fn get_tag_counts(&self) -> Result<Vec<TagCount>, TaskError> {
+        let conn = self.lock()?;
+        let mut stmt = conn
+            .prepare(&format!(
+                "SELECT tag, COUNT(*) FROM task_tags JOIN tasks ON tasks.id = task_tags.task_id \
+                 WHERE {} GROUP BY tag ORDER BY tag",
+                ACTIVE
+            ))
+            .map_err(storage_error)?;
+        let counts = stmt
+            .query_map([], |row| {
+                Ok(TagCount {
+                    tag: row.get(0)?,
+                    count: row.get::<_, i64>(1)? as usize,
+                })
+            })
+            .map_err(storage_error)?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(storage_error)?;
+        Ok(counts)
+    }
+
+    async fn count_tasks(&self) -> Result<TaskCounts, TaskError> {
+        let conn = self.lock()?;
+        conn.query_row(
+            &format!("SELECT COUNT(*), COALESCE(SUM(completed), 0) FROM tasks WHERE {}", ACTIVE),
+            [],
+            |row| {
+                Ok(TaskCounts {
+                    total: row.get::<_, i64>(0)? as usize,
+                    completed: row.get::<_, i64>(1)? as usize,
+                })
+            },
+        )
+        .map_err(storage_error)
+    }
+
+    async fn ping(&self) -> Result<(), TaskError> {
+        let conn = self.lock()?;
+        conn.query_row("SELECT 1", [], |_| Ok(())).map_err(storage_error)
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "sqlite"
+    }
+
+    async fn shutdown(&self) -> Result<(), TaskError> {
+        let conn = self.lock()?;
+        // 統計情報を更新してから、WALに残った書き込みをデータベースファイルへ反映してWALを空にする
+        conn.execute_batch("PRAGMA optimize;").map_err(storage_error)?;
+        conn.query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |_| Ok(()))
+            .map_err(storage_error)
+    }
+}
@@ -0,0 +1,86 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+use async_trait::async_trait;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use crate::domain::model::user::{CreateUser, User, UserValidationError};
+use crate::domain::repository::user::{UserError, UserRepository};
+
+const SELECT_COLUMNS: &str = "SELECT id, name, created_at FROM users";
+
+/// SQLiteファイルに利用者を永続化するリポジトリ（タスクのリポジトリと接続を共有する）
+#[derive(Clone)]
+pub struct SqliteUserRepository {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SqliteUserRepository {
+    pub(super) fn new(conn: Arc<Mutex<Connection>>) -> Self {
+        Self { conn }
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Connection>, UserError> {
+        self.conn.lock().map_err(|e| {
+            UserError::RepositoryError(Box::new(std::io::Error::other(
+                format!("Failed to acquire lock: {}", e),
+            )))
+        })
+    }
+}
+
+fn storage_error(e: rusqlite::Error) -> UserError {
+    UserError::RepositoryError(Box::new(e))
+}
+
+fn row_to_user(row: &Row<'_>) -> rusqlite::Result<User> {
+    Ok(User {
+        id: row.get::<_, i64>(0)? as u64,
+        name: row.get(1)?,
+        created_at: row.get(2)?,
+    })
+}
+
+fn find_all(conn: &Connection) -> Result<Vec<User>, UserError> {
+    let mut stmt = conn
+        .prepare(&format!("{} ORDER BY id", SELECT_COLUMNS))
+        .map_err(storage_error)?;
+    let users = stmt
+        .query_map([], row_to_user)
+        .map_err(storage_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(storage_error)?;
+    Ok(users)
+}
+
+#[async_trait]
+impl UserRepository for SqliteUserRepository {
+    async fn get_all(&self) -> Result<Vec<User>, UserError> {
+        let conn = self.lock()?;
+        find_all(&conn)
+    }
+
+    async fn get_by_id(&self, id: u64) -> Result<User, UserError> {
+        let conn = self.lock()?;
+        conn.query_row(&format!("{} WHERE id = ?1", SELECT_COLUMNS), params![id as i64], row_to_user)
+            .optional()
+            .map_err(storage_error)?
+            .ok_or(UserError::NotFound(id))
+    }
+
+    async fn create(&self, create_user: CreateUser) -> Result<User, UserError> {
+        create_user.validate()?;
+        let conn = self.lock()?;
+        // 大文字・小文字を区別しないため、比較はRust側で行う
+        if let Some(user) = find_all(&conn)?.into_iter().find(|user| user.has_name(&create_user.name)) {
+            return Err(UserValidationError::DuplicateName(user.name).into());
+        }
+
+        // IDはINSERT後に確定するため、仮のIDで組み立てる
+        let mut user = User::new(0, &create_user.name)?;
+        conn.execute(
+            "INSERT INTO users (name, created_at) VALUES (?1, ?2)",
+            params![user.name, user.created_at],
+        )
+        .map_err(storage_error)?;
+        user.id = conn.last_insert_rowid() as u64;
+        Ok(user)
+    }
+}
@@ -0,0 +1,56 @@
+use crate::domain::model::api_key::{ApiKey, ApiKeyScope, CreateApiKey};
+use crate::usecase::api_key::IssuedApiKey;
+use openapi::models::{ApiKey as ApiApiKey, ApiKeyScope as ApiApiKeyScope, CreateApiKey as ApiCreateApiKey, CreatedApiKey as ApiCreatedApiKey};
+use openapi::types::Nullable;
+
+/// APIキーのドメインモデルとAPIモデル間の変換を行うマッパー
+pub struct ApiKeyMapper;
+
+impl ApiKeyMapper {
+    /// 鍵のハッシュ値はAPIに出さない
+    pub fn domain_to_api(api_key: ApiKey) -> ApiApiKey {
+        ApiApiKey {
+            id: api_key.id as i64,
+            name: api_key.name,
+            scope: Self::scope_to_api(api_key.scope),
+            user_id: api_key.user_id as i64,
+            prefix: api_key.prefix,
+            created_at: api_key.created_at,
+            revoked_at: Some(match api_key.revoked_at {
+                Some(revoked_at) => Nullable::Present(revoked_at),
+                None => Nullable::Null,
+            }),
+        }
+    }
+
+    pub fn issued_to_api(issued: IssuedApiKey) -> ApiCreatedApiKey {
+        ApiCreatedApiKey {
+            key: issued.secret,
+            api_key: Self::domain_to_api(issued.api_key),
+        }
+    }
+
+    pub fn api_create_to_domain(api_create: ApiCreateApiKey) -> CreateApiKey {
+        CreateApiKey {
+            name: api_create.name,
+            scope: Self::scope_to_domain(api_create.scope),
+            user_id: api_create.user_id.map(|id| id as u64),
+        }
+    }
+
+    pub fn scope_to_api(scope: ApiKeyScope) -> ApiApiKeyScope {
+        match scope {
+            ApiKeyScope::Read => ApiApiKeyScope::Read,
+            ApiKeyScope::Write => ApiApiKeyScope::Write,
+            ApiKeyScope::Admin => ApiApiKeyScope::Admin,
+        }
+    }
+
+    fn scope_to_domain(scope: ApiApiKeyScope) -> ApiKeyScope {
+        match scope {
+            ApiApiKeyScope::Read => ApiKeyScope::Read,
+            ApiApiKeyScope::Write => ApiKeyScope::Write,
+            ApiApiKeyScope::Admin => ApiKeyScope::Admin,
+        }
+    }
+}
@@ -1 +1,4 @@
-pub mod task; 
\ No newline at end of file
+pub mod api_key;
+pub mod project;
+pub mod task;
+pub mod user;
\ No newline at end of file
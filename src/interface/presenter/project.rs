@@ -0,0 +1,43 @@
+use crate::domain::model::project::{CreateProject, UpdateProject};
+use crate::usecase::project::ProjectSummary;
+use openapi::models::{CreateProject as ApiCreateProject, Project as ApiProject, ProjectsIdTasksGetQueryParams, TasksGetQueryParams, UpdateProject as ApiUpdateProject};
+
+/// プロジェクトのドメインモデルとAPIモデル間の変換を行うマッパー
+pub struct ProjectMapper;
+
+impl ProjectMapper {
+    /// タスク数を付けたプロジェクトをAPIのProjectに変換
+    pub fn summary_to_api(summary: ProjectSummary) -> ApiProject {
+        ApiProject {
+            id: summary.project.id as i64,
+            inbox: summary.project.is_inbox(),
+            name: summary.project.name,
+            owner_id: summary.project.owner_id as i64,
+            pending_count: summary.counts.pending() as i64,
+            completed_count: summary.counts.completed as i64,
+            created_at: summary.project.created_at,
+            updated_at: summary.project.updated_at,
+        }
+    }
+
+    pub fn api_create_to_domain(api_create: ApiCreateProject) -> CreateProject {
+        CreateProject { name: api_create.name, owner_id: None }
+    }
+
+    pub fn api_update_to_domain(api_update: ApiUpdateProject) -> UpdateProject {
+        UpdateProject { name: api_update.name }
+    }
+
+    /// プロジェクトのタスク一覧の条件を、タスク一覧と同じ条件に読み替える
+    pub fn tasks_query_to_api(params: &ProjectsIdTasksGetQueryParams) -> TasksGetQueryParams {
+        TasksGetQueryParams {
+            limit: params.limit,
+            cursor: params.cursor.clone(),
+            sort: params.sort,
+            order: params.order,
+            completed: params.completed,
+            tag: params.tag.clone(),
+            tag_mode: params.tag_mode,
+        }
+    }
+}
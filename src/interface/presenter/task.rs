@@ -1,5 +1,11 @@
-use crate::domain::model::task::{Task, CreateTask, UpdateTask};
-use openapi::models::{Task as ApiTask, CreateTask as ApiCreateTask, UpdateTask as ApiUpdateTask};
+use std::collections::BTreeSet;
+use chrono::DateTime;
+use crate::domain::model::history::{FieldChange, TaskHistoryEntry, TaskHistoryOperation};
+use crate::domain::model::recurrence::{Frequency, Recurrence};
+use crate::domain::model::task::{normalize_tag, Task, CreateTask, UpdateTask, TaskNode, TaskPriority, TaskValidationError};
+use crate::domain::repository::task::{ChildPolicy, SortDirection, TagCount, TagMatch, TaskCursor, TaskOperation, TaskQuery, TaskSortField};
+use openapi::models::{RecurrenceFrequency, RecurrenceRule, Weekday as ApiWeekday, BulkOperation, BulkOperationType, ChildDeletePolicy, FieldChange as ApiFieldChange, TaskHistoryEntry as ApiTaskHistoryEntry, TaskHistoryOperation as ApiTaskHistoryOperation, Task as ApiTask, CreateTask as ApiCreateTask, UpdateTask as ApiUpdateTask, SortOrder, TagCount as ApiTagCount, TagMatchMode, TaskPriority as ApiTaskPriority, TaskSortField as ApiTaskSortField, TaskTreeNode as ApiTaskTreeNode, TasksGetQueryParams};
+use openapi::types::Nullable;
 
 /// ドメインモデルとAPIモデル間の変換を行うマッパー
 pub struct TaskMapper;
@@ -11,8 +17,50 @@ impl TaskMapper {
             id: domain_task.id as i64, // u64 -> i64 変換
             description: domain_task.description,
             completed: domain_task.completed,
+            priority: domain_task.priority.map(Self::priority_to_api),
+            due_at: domain_task.due_at,
+            tags: domain_task.tags.into_iter().collect(),
+            version: domain_task.version as i64,
             created_at: domain_task.created_at,
             updated_at: domain_task.updated_at,
+            deleted_at: domain_task.deleted_at,
+            owner_id: domain_task.owner_id as i64,
+            project_id: domain_task.project_id as i64,
+            parent_id: domain_task.parent_id.map(|id| id as i64),
+            completion_percentage: None,
+            depends_on: domain_task.depends_on.into_iter().map(|id| id as i64).collect(),
+            blocked: None,
+            recurrence: domain_task.recurrence.map(Self::recurrence_to_api),
+        }
+    }
+
+    /// 未完了の依存先が残っているか（`blocked` に含まれるか）を付けてAPIのTaskに変換
+    pub fn domain_to_api_with_blocked(domain_task: Task, blocked: &BTreeSet<u64>) -> ApiTask {
+        let is_blocked = blocked.contains(&domain_task.id);
+        ApiTask {
+            blocked: Some(is_blocked),
+            ..Self::domain_to_api(domain_task)
+        }
+    }
+
+    /// 階層のタスクを、直下の子タスクの完了率を付けてAPIのTaskに変換
+    pub fn node_task_to_api(node: TaskNode, blocked: &BTreeSet<u64>) -> ApiTask {
+        let completion_percentage = node.completion_percentage();
+        ApiTask {
+            completion_percentage: completion_percentage.map(i32::from),
+            ..Self::domain_to_api_with_blocked(node.task, blocked)
+        }
+    }
+
+    /// ドメインのタスク階層をAPIのタスク階層に変換
+    pub fn node_to_api(node: TaskNode, blocked: &BTreeSet<u64>) -> ApiTaskTreeNode {
+        let completion_percentage = node.completion_percentage();
+        ApiTaskTreeNode {
+            task: ApiTask {
+                completion_percentage: completion_percentage.map(i32::from),
+                ..Self::domain_to_api_with_blocked(node.task, blocked)
+            },
+            children: node.children.into_iter().map(|child| Self::node_to_api(child, blocked)).collect(),
         }
     }
 
@@ -22,8 +70,18 @@ impl TaskMapper {
             id: api_task.id as u64, // i64 -> u64 変換
             description: api_task.description,
             completed: api_task.completed,
+            priority: api_task.priority.map(Self::priority_to_domain),
+            due_at: api_task.due_at,
+            tags: api_task.tags.into_iter().collect(),
+            version: api_task.version as u64,
             created_at: api_task.created_at,
             updated_at: api_task.updated_at,
+            deleted_at: api_task.deleted_at,
+            owner_id: api_task.owner_id.max(0) as u64,
+            project_id: api_task.project_id.max(0) as u64,
+            parent_id: api_task.parent_id.map(|id| id.max(0) as u64),
+            depends_on: api_task.depends_on.into_iter().map(|id| id.max(0) as u64).collect(),
+            recurrence: api_task.recurrence.and_then(|recurrence| Self::recurrence_to_domain(recurrence).ok()),
         }
     }
 
@@ -31,12 +89,33 @@ impl TaskMapper {
     pub fn domain_create_to_api(domain_create: CreateTask) -> ApiCreateTask {
         ApiCreateTask {
             description: domain_create.description,
+            priority: domain_create.priority.map(Self::priority_to_api),
+            due_at: domain_create.due_at,
+            project_id: domain_create.project_id.map(|id| id as i64),
+            parent_id: domain_create.parent_id.map(|id| id as i64),
+            recurrence: domain_create.recurrence.map(Self::recurrence_to_api),
         }
     }
 
     /// APIのCreateTaskをドメインのCreateTaskに変換
     pub fn api_create_to_domain(api_create: ApiCreateTask) -> Result<CreateTask, crate::domain::model::task::TaskValidationError> {
-        CreateTask::new(api_create.description)
+        let create_task = Self::create_fields_to_domain(api_create)?;
+        create_task.validate()?;
+        Ok(create_task)
+    }
+
+    fn create_fields_to_domain(api_create: ApiCreateTask) -> Result<CreateTask, TaskValidationError> {
+        Ok(CreateTask {
+            description: api_create.description,
+            priority: api_create.priority.map(Self::priority_to_domain),
+            due_at: api_create.due_at,
+            // 持ち主はリクエストの利用者から決める
+            owner_id: None,
+            project_id: api_create.project_id.map(|id| id.max(0) as u64),
+            parent_id: api_create.parent_id.map(|id| id.max(0) as u64),
+            recurrence: api_create.recurrence.map(Self::recurrence_to_domain).transpose()?,
+            tags: Vec::new(),
+        })
     }
 
     /// ドメインのUpdateTaskをAPIのUpdateTaskに変換
@@ -44,12 +123,177 @@ impl TaskMapper {
         ApiUpdateTask {
             description: domain_update.description,
             completed: domain_update.completed,
+            priority: domain_update.priority.map(Self::priority_to_api),
+            due_at: domain_update.due_at.map(|due_at| match due_at {
+                Some(due_at) => Nullable::Present(due_at),
+                None => Nullable::Null,
+            }),
+            project_id: domain_update.project_id.map(|id| id as i64),
+            parent_id: domain_update.parent_id.map(|parent_id| match parent_id {
+                Some(parent_id) => Nullable::Present(parent_id as i64),
+                None => Nullable::Null,
+            }),
+            recurrence: domain_update.recurrence.flatten().map(Self::recurrence_to_api),
         }
     }
 
     /// APIのUpdateTaskをドメインのUpdateTaskに変換
     pub fn api_update_to_domain(api_update: ApiUpdateTask) -> Result<UpdateTask, crate::domain::model::task::TaskValidationError> {
-        UpdateTask::new(api_update.description, api_update.completed)
+        let update_task = Self::update_fields_to_domain(api_update)?;
+        update_task.validate()?;
+        Ok(update_task)
+    }
+
+    fn update_fields_to_domain(api_update: ApiUpdateTask) -> Result<UpdateTask, TaskValidationError> {
+        Ok(UpdateTask {
+            description: api_update.description,
+            completed: api_update.completed,
+            priority: api_update.priority.map(Self::priority_to_domain),
+            due_at: api_update.due_at.map(|due_at| match due_at {
+                Nullable::Present(due_at) => Some(due_at),
+                Nullable::Null => None,
+            }),
+            project_id: api_update.project_id.map(|id| id.max(0) as u64),
+            parent_id: api_update.parent_id.map(|parent_id| match parent_id {
+                Nullable::Present(parent_id) => Some(parent_id.max(0) as u64),
+                Nullable::Null => None,
+            }),
+            recurrence: api_update.recurrence.map(Self::recurrence_to_domain).transpose()?.map(Some),
+            ..UpdateTask::default()
+        })
+    }
+
+    /// 一括処理の1件分の操作をドメインの操作に変換
+    ///
+    /// 項目の組み合わせが操作の種類に合わない場合は理由を返す。内容の検証は操作ごとの結果として
+    /// 報告するため、ここでは行わない。
+    pub fn api_bulk_operation_to_domain(operation: BulkOperation) -> Result<TaskOperation, String> {
+        if operation.op != BulkOperationType::Create && operation.task.is_some() {
+            return Err("task is only allowed for create".to_string());
+        }
+        if operation.op != BulkOperationType::Update && operation.changes.is_some() {
+            return Err("changes is only allowed for update".to_string());
+        }
+        let id = match (operation.op, operation.id) {
+            (BulkOperationType::Create, Some(_)) => return Err("id is not allowed for create".to_string()),
+            (BulkOperationType::Create, None) => 0,
+            (_, Some(id)) if id > 0 => id as u64,
+            (_, Some(_)) => return Err("id must be positive".to_string()),
+            (_, None) => return Err("id is required".to_string()),
+        };
+
+        match operation.op {
+            BulkOperationType::Create => {
+                let task = operation.task.ok_or("task is required for create")?;
+                let create_task = Self::create_fields_to_domain(task).map_err(|e| e.to_string())?;
+                Ok(TaskOperation::Create(create_task))
+            }
+            BulkOperationType::Update => {
+                let changes = operation.changes.ok_or("changes is required for update")?;
+                let update = Self::update_fields_to_domain(changes).map_err(|e| e.to_string())?;
+                Ok(TaskOperation::Update { id, update })
+            }
+            BulkOperationType::Complete => Ok(TaskOperation::Complete(id)),
+            BulkOperationType::Uncomplete => Ok(TaskOperation::Uncomplete(id)),
+            BulkOperationType::Delete => Ok(TaskOperation::Delete(id)),
+        }
+    }
+
+    /// 削除時の子タスクの扱いをドメインの値に変換（指定がなければ削除を拒否する）
+    pub fn child_policy_to_domain(policy: Option<ChildDeletePolicy>) -> ChildPolicy {
+        match policy {
+            Some(ChildDeletePolicy::Cascade) => ChildPolicy::Cascade,
+            Some(ChildDeletePolicy::Orphan) => ChildPolicy::Orphan,
+            Some(ChildDeletePolicy::Reject) | None => ChildPolicy::Reject,
+        }
+    }
+
+    /// ドメインの繰り返しの規則をAPIの規則に変換（RRULE の文字列も付ける）
+    pub fn recurrence_to_api(recurrence: Recurrence) -> RecurrenceRule {
+        RecurrenceRule {
+            rrule: Some(recurrence.to_string()),
+            frequency: Some(match recurrence.frequency {
+                Frequency::Daily => RecurrenceFrequency::Daily,
+                Frequency::Weekly => RecurrenceFrequency::Weekly,
+                Frequency::Monthly => RecurrenceFrequency::Monthly,
+            }),
+            interval: Some(recurrence.interval as i32),
+            weekdays: (!recurrence.weekdays.is_empty())
+                .then(|| recurrence.weekdays.iter().copied().map(Self::weekday_to_api).collect()),
+            day_of_month: recurrence.month_day.map(|day| day as i32),
+            until: recurrence.until,
+        }
+    }
+
+    /// APIの繰り返しの規則をドメインの規則に変換（RRULE と個別の項目はどちらか一方だけ指定できる）
+    pub fn recurrence_to_domain(rule: RecurrenceRule) -> Result<Recurrence, TaskValidationError> {
+        let fields_given = rule.frequency.is_some()
+            || rule.interval.is_some()
+            || rule.weekdays.is_some()
+            || rule.day_of_month.is_some()
+            || rule.until.is_some();
+        match (rule.rrule, rule.frequency) {
+            (Some(_), _) if fields_given => Err(TaskValidationError::InvalidRecurrence(
+                "give either rrule or frequency, not both".to_string(),
+            )),
+            (Some(rrule), _) => rrule.parse(),
+            (None, None) => Err(TaskValidationError::InvalidRecurrence("frequency or rrule is required".to_string())),
+            (None, Some(frequency)) => Recurrence::new(
+                match frequency {
+                    RecurrenceFrequency::Daily => Frequency::Daily,
+                    RecurrenceFrequency::Weekly => Frequency::Weekly,
+                    RecurrenceFrequency::Monthly => Frequency::Monthly,
+                },
+                rule.interval.unwrap_or(1).max(0) as u32,
+                rule.weekdays.unwrap_or_default().into_iter().map(Self::weekday_to_domain).collect(),
+                rule.day_of_month.map(|day| day.max(0) as u32),
+                rule.until,
+            ),
+        }
+    }
+
+    fn weekday_to_api(weekday: chrono::Weekday) -> ApiWeekday {
+        match weekday {
+            chrono::Weekday::Mon => ApiWeekday::Monday,
+            chrono::Weekday::Tue => ApiWeekday::Tuesday,
+            chrono::Weekday::Wed => ApiWeekday::Wednesday,
+            chrono::Weekday::Thu => ApiWeekday::Thursday,
+            chrono::Weekday::Fri => ApiWeekday::Friday,
+            chrono::Weekday::Sat => ApiWeekday::Saturday,
+            chrono::Weekday::Sun => ApiWeekday::Sunday,
+        }
+    }
+
+    fn weekday_to_domain(weekday: ApiWeekday) -> chrono::Weekday {
+        match weekday {
+            ApiWeekday::Monday => chrono::Weekday::Mon,
+            ApiWeekday::Tuesday => chrono::Weekday::Tue,
+            ApiWeekday::Wednesday => chrono::Weekday::Wed,
+            ApiWeekday::Thursday => chrono::Weekday::Thu,
+            ApiWeekday::Friday => chrono::Weekday::Fri,
+            ApiWeekday::Saturday => chrono::Weekday::Sat,
+            ApiWeekday::Sunday => chrono::Weekday::Sun,
+        }
+    }
+
+    /// ドメインの優先度をAPIの優先度に変換
+    pub fn priority_to_api(priority: TaskPriority) -> ApiTaskPriority {
+        match priority {
+            TaskPriority::Low => ApiTaskPriority::Low,
+            TaskPriority::Normal => ApiTaskPriority::Normal,
+            TaskPriority::High => ApiTaskPriority::High,
+            TaskPriority::Urgent => ApiTaskPriority::Urgent,
+        }
+    }
+
+    /// APIの優先度をドメインの優先度に変換
+    pub fn priority_to_domain(priority: ApiTaskPriority) -> TaskPriority {
+        match priority {
+            ApiTaskPriority::Low => TaskPriority::Low,
+            ApiTaskPriority::Normal => TaskPriority::Normal,
+            ApiTaskPriority::High => TaskPriority::High,
+            ApiTaskPriority::Urgent => TaskPriority::Urgent,
+        }
     }
 
     /// ドメインのTaskのベクターをAPIのTaskのベクターに変換
@@ -61,4 +305,107 @@ impl TaskMapper {
     pub fn api_vec_to_domain(api_tasks: Vec<ApiTask>) -> Vec<Task> {
         api_tasks.into_iter().map(Self::api_to_domain).collect()
     }
-} 
\ No newline at end of file
+
+    /// APIの一覧取得パラメータをドメインの取得条件に変換（タグは正規化して検証し、重複を除く）
+    pub fn api_query_to_domain(params: &TasksGetQueryParams, after: Option<TaskCursor>) -> Result<TaskQuery, TaskValidationError> {
+        let mut tags = params
+            .tag
+            .iter()
+            .flatten()
+            .map(|tag| normalize_tag(tag))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut seen = BTreeSet::new();
+        tags.retain(|tag| seen.insert(tag.clone()));
+
+        Ok(TaskQuery {
+            completed: params.completed,
+            sort: match params.sort {
+                Some(ApiTaskSortField::CreatedAt) => TaskSortField::CreatedAt,
+                Some(ApiTaskSortField::UpdatedAt) => TaskSortField::UpdatedAt,
+                Some(ApiTaskSortField::Id) | None => TaskSortField::Id,
+            },
+            direction: match params.order {
+                Some(SortOrder::Desc) => SortDirection::Desc,
+                Some(SortOrder::Asc) | None => SortDirection::Asc,
+            },
+            tags,
+            tag_match: match params.tag_mode {
+                Some(TagMatchMode::Any) => TagMatch::Any,
+                Some(TagMatchMode::All) | None => TagMatch::All,
+            },
+            after,
+            limit: params.limit.map(|limit| limit.max(0) as usize),
+            ..TaskQuery::default()
+        })
+    }
+
+    /// ドメインの変更履歴をAPIの変更履歴に変換
+    pub fn history_to_api(history: Vec<TaskHistoryEntry>) -> Vec<ApiTaskHistoryEntry> {
+        history
+            .into_iter()
+            .map(|entry| ApiTaskHistoryEntry {
+                id: entry.id as i64,
+                task_id: entry.task_id as i64,
+                operation: Self::history_operation_to_api(entry.operation),
+                changes: entry.changes.into_iter().map(Self::field_change_to_api).collect(),
+                actor: entry.actor,
+                recorded_at: entry.recorded_at,
+            })
+            .collect()
+    }
+
+    fn history_operation_to_api(operation: TaskHistoryOperation) -> ApiTaskHistoryOperation {
+        match operation {
+            TaskHistoryOperation::Created => ApiTaskHistoryOperation::Created,
+            TaskHistoryOperation::Updated => ApiTaskHistoryOperation::Updated,
+            TaskHistoryOperation::Completed => ApiTaskHistoryOperation::Completed,
+            TaskHistoryOperation::Uncompleted => ApiTaskHistoryOperation::Uncompleted,
+            TaskHistoryOperation::Deleted => ApiTaskHistoryOperation::Deleted,
+            TaskHistoryOperation::Restored => ApiTaskHistoryOperation::Restored,
+            TaskHistoryOperation::Purged => ApiTaskHistoryOperation::Purged,
+        }
+    }
+
+    fn field_change_to_api(change: FieldChange) -> ApiFieldChange {
+        // 生成コードの任意のJSON値は serde_json::Value から変換する以外に作れない
+        let value = |value: Option<serde_json::Value>| value.and_then(|value| serde_json::from_value(value).ok());
+        ApiFieldChange {
+            field: change.field,
+            before: value(change.before),
+            after: value(change.after),
+        }
+    }
+
+    /// ドメインのタグ集計をAPIのタグ集計に変換
+    pub fn tag_counts_to_api(counts: Vec<TagCount>) -> Vec<ApiTagCount> {
+        counts
+            .into_iter()
+            .map(|count| ApiTagCount {
+                tag: count.tag,
+                count: count.count as i64,
+            })
+            .collect()
+    }
+
+    /// カーソルをクライアントに渡す文字列に変換（"ID.作成日時ナノ秒.更新日時ナノ秒"）
+    pub fn encode_cursor(cursor: &TaskCursor) -> String {
+        format!(
+            "{}.{}.{}",
+            cursor.id,
+            cursor.created_at.timestamp_nanos_opt().unwrap_or_default(),
+            cursor.updated_at.timestamp_nanos_opt().unwrap_or_default(),
+        )
+    }
+
+    /// クライアントから受け取ったカーソル文字列を復元（不正な場合はNone）
+    pub fn decode_cursor(value: &str) -> Option<TaskCursor> {
+        let mut parts = value.split('.');
+        let id = parts.next()?.parse().ok()?;
+        let created_at = DateTime::from_timestamp_nanos(parts.next()?.parse().ok()?);
+        let updated_at = DateTime::from_timestamp_nanos(parts.next()?.parse().ok()?);
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(TaskCursor { id, created_at, updated_at })
+    }
+}
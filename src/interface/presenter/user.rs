@@ -0,0 +1,19 @@
+use crate::domain::model::user::{CreateUser, User};
+use openapi::models::{CreateUser as ApiCreateUser, User as ApiUser};
+
+/// 利用者のドメインモデルとAPIモデル間の変換を行うマッパー
+pub struct UserMapper;
+
+impl UserMapper {
+    pub fn domain_to_api(user: User) -> ApiUser {
+        ApiUser {
+            id: user.id as i64,
+            name: user.name,
+            created_at: user.created_at,
+        }
+    }
+
+    pub fn api_create_to_domain(api_create: ApiCreateUser) -> CreateUser {
+        CreateUser { name: api_create.name }
+    }
+}
@@ -0,0 +1,116 @@
+use rand::distr::{Alphanumeric, SampleString};
+use crate::domain::model::api_key::{hash_secret, ApiKey, ApiKeyValidationError, CreateApiKey, API_KEY_DISPLAY_LENGTH, API_KEY_PREFIX};
+use crate::domain::model::user::DEFAULT_USER_ID;
+use crate::domain::repository::api_key::{ApiKeyError as RepositoryApiKeyError, ApiKeyRepository, NewApiKey};
+use thiserror::Error;
+
+/// 鍵の先頭の目印に続けるランダムな文字数
+const SECRET_LENGTH: usize = 32;
+
+#[derive(Debug, Error)]
+pub enum ApiKeyError {
+    #[error("API key not found with id: {0}")]
+    NotFound(u64),
+    #[error("Validation error: {0}")]
+    Validation(#[from] ApiKeyValidationError),
+    #[error("Repository error: {0}")]
+    Repository(String),
+}
+
+/// リポジトリのエラーを種類を保ったままユースケースのエラーに変換する
+impl From<RepositoryApiKeyError> for ApiKeyError {
+    fn from(error: RepositoryApiKeyError) -> Self {
+        match error {
+            RepositoryApiKeyError::NotFound(id) => ApiKeyError::NotFound(id),
+            RepositoryApiKeyError::ValidationError(e) => ApiKeyError::Validation(e),
+            RepositoryApiKeyError::RepositoryError(e) => ApiKeyError::Repository(e.to_string()),
+        }
+    }
+}
+
+/// 発行したAPIキーと、一度だけ返す鍵そのもの
+#[derive(Debug, Clone)]
+pub struct IssuedApiKey {
+    pub api_key: ApiKey,
+    pub secret: String,
+}
+
+pub trait ApiKeyUsecase: Send + Sync {
+    fn list_api_keys<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<ApiKey>, ApiKeyError>> + Send + 'a>>;
+    fn create_api_key<'a>(&'a self, create_api_key: CreateApiKey) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<IssuedApiKey, ApiKeyError>> + Send + 'a>>;
+    fn revoke_api_key<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<ApiKey, ApiKeyError>> + Send + 'a>>;
+    fn authenticate<'a>(&'a self, secret: &'a str) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Option<ApiKey>, ApiKeyError>> + Send + 'a>>;
+}
+
+pub struct ApiKeyUsecaseImpl<R>
+where
+    R: ApiKeyRepository + Send + Sync + 'static,
+{
+    repository: R,
+}
+
+impl<R> ApiKeyUsecaseImpl<R>
+where
+    R: ApiKeyRepository + Send + Sync + 'static,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    /// 失効したものも含めてID順に取得する
+    pub async fn list_api_keys(&self) -> Result<Vec<ApiKey>, ApiKeyError> {
+        self.repository.get_all().await.map_err(ApiKeyError::from)
+    }
+
+    /// 鍵を発行する（鍵そのものは保存しないため、返した後は取り出せない）
+    pub async fn create_api_key(&self, create_api_key: CreateApiKey) -> Result<IssuedApiKey, ApiKeyError> {
+        create_api_key.validate()?;
+        let secret = format!("{}{}", API_KEY_PREFIX, Alphanumeric.sample_string(&mut rand::rng(), SECRET_LENGTH));
+        let new_key = NewApiKey {
+            name: create_api_key.name,
+            scope: create_api_key.scope,
+            user_id: create_api_key.user_id.unwrap_or(DEFAULT_USER_ID),
+            prefix: secret[..API_KEY_DISPLAY_LENGTH].to_string(),
+            secret_hash: hash_secret(&secret),
+        };
+        let api_key = self.repository.create(new_key).await?;
+        Ok(IssuedApiKey { api_key, secret })
+    }
+
+    pub async fn revoke_api_key(&self, id: u64) -> Result<ApiKey, ApiKeyError> {
+        self.repository.revoke(id).await.map_err(ApiKeyError::from)
+    }
+
+    /// 失効していない鍵と一致すれば返す
+    pub async fn authenticate(&self, secret: &str) -> Result<Option<ApiKey>, ApiKeyError> {
+        let key = self.repository.find_by_hash(&hash_secret(secret)).await?;
+        Ok(key.filter(ApiKey::is_active))
+    }
+}
+
+impl<R> Clone for ApiKeyUsecaseImpl<R>
+where
+    R: ApiKeyRepository + Send + Sync + Clone + 'static,
+{
+    fn clone(&self) -> Self {
+        Self { repository: self.repository.clone() }
+    }
+}
+
+impl<R> ApiKeyUsecase for ApiKeyUsecaseImpl<R>
+where
+    R: ApiKeyRepository + Send + Sync + 'static,
+{
+    fn list_api_keys<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<ApiKey>, ApiKeyError>> + Send + 'a>> {
+        Box::pin(self.list_api_keys())
+    }
+    fn create_api_key<'a>(&'a self, create_api_key: CreateApiKey) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<IssuedApiKey, ApiKeyError>> + Send + 'a>> {
+        Box::pin(self.create_api_key(create_api_key))
+    }
+    fn revoke_api_key<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<ApiKey, ApiKeyError>> + Send + 'a>> {
+        Box::pin(self.revoke_api_key(id))
+    }
+    fn authenticate<'a>(&'a self, secret: &'a str) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Option<ApiKey>, ApiKeyError>> + Send + 'a>> {
+        Box::pin(self.authenticate(secret))
+    }
+}
@@ -1,3 +1,9 @@
+pub mod api_key;
+pub mod project;
 pub mod task;
+pub mod user;
 
-pub use task::*; 
\ No newline at end of file
+pub use api_key::*;
+pub use project::*;
+pub use task::*;
+pub use user::*;
\ No newline at end of file
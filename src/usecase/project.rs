@@ -0,0 +1,183 @@
+use crate::domain::model::project::{CreateProject, Project, ProjectValidationError, UpdateProject};
+use crate::domain::repository::project::{ProjectError as RepositoryProjectError, ProjectRepository};
+use crate::domain::repository::task::TaskCounts;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ProjectError {
+    #[error("Project not found with id: {0}")]
+    NotFound(u64),
+    #[error("Project {0} belongs to another user")]
+    Forbidden(u64),
+    #[error("Validation error: {0}")]
+    Validation(#[from] ProjectValidationError),
+    #[error("Invalid operation: {0}")]
+    InvalidOperation(String),
+    #[error("Project {0} still has tasks")]
+    NotEmpty(u64),
+    #[error("Repository error: {0}")]
+    Repository(String),
+}
+
+/// リポジトリのエラーを種類を保ったままユースケースのエラーに変換する
+impl From<RepositoryProjectError> for ProjectError {
+    fn from(error: RepositoryProjectError) -> Self {
+        match error {
+            RepositoryProjectError::NotFound(id) => ProjectError::NotFound(id),
+            RepositoryProjectError::ValidationError(e) => ProjectError::Validation(e),
+            RepositoryProjectError::InvalidOperation(msg) => ProjectError::InvalidOperation(msg),
+            RepositoryProjectError::NotEmpty(id) => ProjectError::NotEmpty(id),
+            RepositoryProjectError::RepositoryError(e) => ProjectError::Repository(e.to_string()),
+        }
+    }
+}
+
+/// プロジェクトとそのタスク数
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectSummary {
+    pub project: Project,
+    pub counts: TaskCounts,
+}
+
+pub trait ProjectUsecase: Send + Sync {
+    /// 指定した利用者のプロジェクト（と受信箱）だけを扱い、タスク数もその利用者のタスクだけで数えるユースケース
+    fn for_owner(&self, owner_id: u64) -> Self
+    where
+        Self: Sized;
+    fn list_projects<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<ProjectSummary>, ProjectError>> + Send + 'a>>;
+    fn get_project<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<ProjectSummary, ProjectError>> + Send + 'a>>;
+    fn create_project<'a>(&'a self, create_project: CreateProject) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<ProjectSummary, ProjectError>> + Send + 'a>>;
+    fn update_project<'a>(&'a self, id: u64, update_project: UpdateProject) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<ProjectSummary, ProjectError>> + Send + 'a>>;
+    fn delete_project<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ProjectError>> + Send + 'a>>;
+}
+
+pub struct ProjectUsecaseImpl<R>
+where
+    R: ProjectRepository + Send + Sync + 'static,
+{
+    repository: R,
+    /// 操作する利用者（None なら利用者を問わずすべてのプロジェクトとタスクを扱う）
+    owner_id: Option<u64>,
+}
+
+impl<R> ProjectUsecaseImpl<R>
+where
+    R: ProjectRepository + Send + Sync + 'static,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository, owner_id: None }
+    }
+
+    /// 見られるプロジェクトをID順に、タスク数を付けて取得する
+    pub async fn list_projects(&self) -> Result<Vec<ProjectSummary>, ProjectError> {
+        let projects = self.repository.get_all().await?;
+        let counts = self.repository.count_tasks(self.owner_id).await?;
+        Ok(projects
+            .into_iter()
+            .filter(|project| self.can_see(project))
+            .map(|project| {
+                let counts = counts.get(&project.id).copied().unwrap_or_default();
+                ProjectSummary { project, counts }
+            })
+            .collect())
+    }
+
+    pub async fn get_project(&self, id: u64) -> Result<ProjectSummary, ProjectError> {
+        let project = self.repository.get_by_id(id).await?;
+        self.ensure_visible(&project)?;
+        self.summarize(project).await
+    }
+
+    pub async fn create_project(&self, mut create_project: CreateProject) -> Result<ProjectSummary, ProjectError> {
+        create_project.validate()?;
+        if self.owner_id.is_some() {
+            create_project.owner_id = self.owner_id;
+        }
+        let project = self.repository.create(create_project).await?;
+        Ok(ProjectSummary { project, counts: TaskCounts::default() })
+    }
+
+    pub async fn update_project(&self, id: u64, update_project: UpdateProject) -> Result<ProjectSummary, ProjectError> {
+        update_project.validate()?;
+        self.authorize(id).await?;
+        let project = self.repository.update(id, update_project).await?;
+        self.summarize(project).await
+    }
+
+    /// タスクの残っていないプロジェクトを削除する（ゴミ箱のタスクは受信箱に移る）
+    pub async fn delete_project(&self, id: u64) -> Result<(), ProjectError> {
+        self.authorize(id).await?;
+        self.repository.delete(id).await.map_err(ProjectError::from)
+    }
+
+    /// 受信箱と自分のプロジェクトだけが見える（利用者を指定していなければすべて見える）
+    fn can_see(&self, project: &Project) -> bool {
+        self.owner_id.is_none_or(|owner_id| project.is_visible_to(owner_id))
+    }
+
+    fn ensure_visible(&self, project: &Project) -> Result<(), ProjectError> {
+        if !self.can_see(project) {
+            return Err(ProjectError::Forbidden(project.id));
+        }
+        Ok(())
+    }
+
+    /// IDで指定したプロジェクトを変更してよいか確かめる（受信箱を変えられるのは持ち主だけ）
+    ///
+    /// 持ち主は作成後に変わらないため、確かめてから書き込むまでの間に結果は変わらない。
+    async fn authorize(&self, id: u64) -> Result<(), ProjectError> {
+        let Some(owner_id) = self.owner_id else {
+            return Ok(());
+        };
+        let project = self.repository.get_by_id(id).await?;
+        if project.owner_id != owner_id {
+            return Err(ProjectError::Forbidden(id));
+        }
+        Ok(())
+    }
+
+    async fn summarize(&self, project: Project) -> Result<ProjectSummary, ProjectError> {
+        let counts = self.repository.count_tasks(self.owner_id).await?.remove(&project.id).unwrap_or_default();
+        Ok(ProjectSummary { project, counts })
+    }
+}
+
+impl<R> Clone for ProjectUsecaseImpl<R>
+where
+    R: ProjectRepository + Send + Sync + Clone + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repository: self.repository.clone(),
+            owner_id: self.owner_id,
+        }
+    }
+}
+
+impl<R> ProjectUsecase for ProjectUsecaseImpl<R>
+where
+    R: ProjectRepository + Send + Sync + Clone + 'static,
+{
+    fn for_owner(&self, owner_id: u64) -> Self {
+        Self {
+            repository: self.repository.clone(),
+            owner_id: Some(owner_id),
+        }
+    }
+
+    fn list_projects<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<ProjectSummary>, ProjectError>> + Send + 'a>> {
+        Box::pin(self.list_projects())
+    }
+    fn get_project<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<ProjectSummary, ProjectError>> + Send + 'a>> {
+        Box::pin(self.get_project(id))
+    }
+    fn create_project<'a>(&'a self, create_project: CreateProject) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<ProjectSummary, ProjectError>> + Send + 'a>> {
+        Box::pin(self.create_project(create_project))
+    }
+    fn update_project<'a>(&'a self, id: u64, update_project: UpdateProject) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<ProjectSummary, ProjectError>> + Send + 'a>> {
+        Box::pin(self.update_project(id, update_project))
+    }
+    fn delete_project<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), ProjectError>> + Send + 'a>> {
+        Box::pin(self.delete_project(id))
+    }
+}
@@ -1,30 +1,112 @@
-use crate::domain::model::task::{CreateTask, Task, UpdateTask};
-use crate::domain::repository::task::TaskRepository;
+use std::collections::{BTreeSet, HashMap};
+use crate::domain::model::history::TaskHistoryEntry;
+use crate::domain::model::task::{blocked_task_ids, dependency_order, CreateTask, Task, TaskNode, UpdateTask};
+use crate::domain::repository::task::{BatchMode, BatchOutcome, ChildPolicy, TagCount, TaskCounts, TaskOperation, TaskOperationOutcome, TaskRepository, TaskError as RepositoryTaskError, TaskPage, TaskQuery};
 use crate::domain::model::task::TaskValidationError;
 use thiserror::Error;
 
+/// 件数の指定がない一覧取得で返す件数
+pub const DEFAULT_PAGE_LIMIT: usize = 100;
+/// 一覧取得で一度に返せる最大件数
+pub const MAX_PAGE_LIMIT: usize = 1000;
+/// 一括処理で一度に受け付ける最大の操作数
+pub const MAX_BATCH_OPERATIONS: usize = 1000;
+/// 繰り返しの予定の日時（早い順）
+pub type Occurrences = Vec<chrono::DateTime<chrono::Utc>>;
+
+/// 件数の指定がない繰り返しの予定の取得で返す件数
+pub const DEFAULT_OCCURRENCE_PREVIEW: usize = 5;
+/// 繰り返しの予定を一度に返せる最大件数
+pub const MAX_OCCURRENCE_PREVIEW: usize = 100;
+
 #[derive(Debug, Error)]
 pub enum TaskError {
     #[error("Task not found with id: {0}")]
     NotFound(u64),
+    #[error("Task {0} belongs to another user")]
+    Forbidden(u64),
     #[error("Validation error: {0}")]
     Validation(#[from] TaskValidationError),
+    #[error("Invalid operation: {0}")]
+    InvalidOperation(String),
+    #[error("Task {id} has been modified (current version {actual}, expected {expected})")]
+    VersionMismatch { id: u64, expected: u64, actual: u64 },
+    #[error("Task {0} has child tasks")]
+    HasChildren(u64),
+    #[error("Task {id} is blocked by incomplete tasks {blockers:?}")]
+    Blocked { id: u64, blockers: Vec<u64> },
     #[error("Repository error: {0}")]
     Repository(String),
 }
 
+/// リポジトリのエラーを種類を保ったままユースケースのエラーに変換する
+impl From<RepositoryTaskError> for TaskError {
+    fn from(error: RepositoryTaskError) -> Self {
+        match error {
+            RepositoryTaskError::NotFound(id) => TaskError::NotFound(id),
+            RepositoryTaskError::ValidationError(e) => TaskError::Validation(e),
+            RepositoryTaskError::InvalidOperation(msg) => TaskError::InvalidOperation(msg),
+            RepositoryTaskError::VersionMismatch { id, expected, actual } => TaskError::VersionMismatch { id, expected, actual },
+            RepositoryTaskError::HasChildren(id) => TaskError::HasChildren(id),
+            RepositoryTaskError::Blocked { id, blockers } => TaskError::Blocked { id, blockers },
+            RepositoryTaskError::RepositoryError(e) => TaskError::Repository(e.to_string()),
+        }
+    }
+}
+
+/// 作成時の履歴に記録された持ち主（持ち主は作成後に変わらない）
+fn recorded_owner(history: &[TaskHistoryEntry]) -> Option<u64> {
+    history
+        .iter()
+        .flat_map(|entry| &entry.changes)
+        .find(|change| change.field == "owner_id")
+        .and_then(|change| change.after.as_ref())
+        .and_then(|owner_id| owner_id.as_u64())
+}
+
 pub trait TaskUsecase: Send + Sync {
+    /// 指定した利用者のタスクだけを扱うユースケース
+    fn for_owner(&self, owner_id: u64) -> Self
+    where
+        Self: Sized;
     fn get_all_tasks<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>>;
+    fn list_tasks<'a>(&'a self, query: TaskQuery) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<TaskPage, TaskError>> + Send + 'a>>;
     fn get_task_by_id<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
     fn create_task<'a>(&'a self, create_task: CreateTask) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
     fn update_task<'a>(&'a self, id: u64, update_task: UpdateTask) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
     fn delete_task<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), TaskError>> + Send + 'a>>;
+    fn update_task_if_version<'a>(&'a self, id: u64, update_task: UpdateTask, expected_version: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
+    fn delete_task_if_version<'a>(&'a self, id: u64, expected_version: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), TaskError>> + Send + 'a>>;
     fn complete_task<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
     fn uncomplete_task<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
     fn get_completed_tasks<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>>;
     fn get_pending_tasks<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>>;
     fn search_tasks<'a>(&'a self, query: &'a str) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>>;
     fn get_tasks_by_status<'a>(&'a self, completed: bool) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>>;
+    fn get_overdue_tasks<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>>;
+    fn get_tasks_due_within<'a>(&'a self, window: chrono::Duration) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>>;
+    fn add_tags<'a>(&'a self, id: u64, tags: Vec<String>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
+    fn remove_tag<'a>(&'a self, id: u64, tag: String) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
+    fn get_tag_counts<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<TagCount>, TaskError>> + Send + 'a>>;
+    fn get_task_counts<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<TaskCounts, TaskError>> + Send + 'a>>;
+    fn ping<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), TaskError>> + Send + 'a>>;
+    fn storage_backend(&self) -> &'static str;
+    fn apply_batch<'a>(&'a self, operations: Vec<TaskOperation>, mode: BatchMode) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<BatchOutcome<TaskError>, TaskError>> + Send + 'a>>;
+    fn get_trash<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>>;
+    fn restore_task<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
+    fn purge_trash<'a>(&'a self, older_than: Option<chrono::Duration>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, TaskError>> + Send + 'a>>;
+    fn get_task_history<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<TaskHistoryEntry>, TaskError>> + Send + 'a>>;
+    fn delete_task_with_children<'a>(&'a self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), TaskError>> + Send + 'a>>;
+    fn get_children<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<TaskNode>, TaskError>> + Send + 'a>>;
+    fn get_task_tree<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<TaskNode>, TaskError>> + Send + 'a>>;
+    fn get_subtask_counts<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<TaskCounts, TaskError>> + Send + 'a>>;
+    fn add_dependencies<'a>(&'a self, id: u64, depends_on: Vec<u64>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
+    fn remove_dependency<'a>(&'a self, id: u64, depends_on: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
+    fn force_complete_task<'a>(&'a self, id: u64, expected_version: Option<u64>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
+    fn get_blocked_task_ids<'a>(&'a self, tasks: &'a [Task]) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<BTreeSet<u64>, TaskError>> + Send + 'a>>;
+    fn get_ready_tasks<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>>;
+    fn preview_occurrences<'a>(&'a self, id: u64, count: Option<usize>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Occurrences, TaskError>> + Send + 'a>>;
+    fn stop_recurrence<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>>;
 }
 
 pub struct TaskUsecaseImpl<R>
@@ -32,6 +114,8 @@ where
     R: TaskRepository + Send + Sync + 'static,
 {
     repository: R,
+    /// 操作する利用者（None なら利用者を問わずすべてのタスクを扱う）
+    owner_id: Option<u64>,
 }
 
 impl<R> TaskUsecaseImpl<R>
@@ -39,53 +123,84 @@ where
     R: TaskRepository + Send + Sync + 'static,
 {
     pub fn new(repository: R) -> Self {
-        Self { repository }
+        Self { repository, owner_id: None }
     }
 
     pub async fn get_all_tasks(&self) -> Result<Vec<Task>, TaskError> {
-        self.repository.get_all().await.map_err(|e| TaskError::Repository(e.to_string()))
+        self.owned_tasks().await
+    }
+
+    /// 絞り込み・並び替え済みのタスクを1ページ分取得する
+    pub async fn list_tasks(&self, mut query: TaskQuery) -> Result<TaskPage, TaskError> {
+        query.limit = Some(query.limit.unwrap_or(DEFAULT_PAGE_LIMIT).clamp(1, MAX_PAGE_LIMIT));
+        self.repository.query(self.scope(query)).await.map_err(TaskError::from)
     }
 
     pub async fn get_task_by_id(&self, id: u64) -> Result<Task, TaskError> {
-        self.repository.get_by_id(id).await.map_err(|e| TaskError::Repository(e.to_string()))
+        let task = self.repository.get_by_id(id).await.map_err(TaskError::from)?;
+        self.ensure_owner(&task)?;
+        Ok(task)
     }
 
-    pub async fn create_task(&self, create_task: CreateTask) -> Result<Task, TaskError> {
+    pub async fn create_task(&self, mut create_task: CreateTask) -> Result<Task, TaskError> {
         create_task.validate()?;
-        self.repository.create(create_task).await.map_err(|e| TaskError::Repository(e.to_string()))
+        self.assign_owner(&mut create_task).await?;
+        self.repository.create(create_task).await.map_err(TaskError::from)
     }
 
     pub async fn update_task(&self, id: u64, update_task: UpdateTask) -> Result<Task, TaskError> {
         update_task.validate()?;
-        self.repository.update(id, update_task).await.map_err(|e| TaskError::Repository(e.to_string()))
+        self.authorize_update(id, &update_task).await?;
+        let task = self.repository.update(id, update_task).await.map_err(TaskError::from)?;
+        self.roll_over(task).await
     }
 
     pub async fn delete_task(&self, id: u64) -> Result<(), TaskError> {
         // Check if task exists before deleting
-        self.repository.get_by_id(id).await.map_err(|e| TaskError::Repository(e.to_string()))?;
-        self.repository.delete(id).await.map_err(|e| TaskError::Repository(e.to_string()))
+        let task = self.repository.get_by_id(id).await.map_err(TaskError::from)?;
+        self.ensure_owner(&task)?;
+        self.repository.delete(id).await.map_err(TaskError::from)
     }
 
+    /// 現在の版数が `expected_version` の場合だけ更新する（他の更新と競合した場合は VersionMismatch）
+    pub async fn update_task_if_version(&self, id: u64, update_task: UpdateTask, expected_version: u64) -> Result<Task, TaskError> {
+        update_task.validate()?;
+        self.authorize_update(id, &update_task).await?;
+        let task = self.repository
+            .update_if_version(id, update_task, expected_version)
+            .await
+            .map_err(TaskError::from)?;
+        self.roll_over(task).await
+    }
+
+    /// 現在の版数が `expected_version` の場合だけ削除する
+    pub async fn delete_task_if_version(&self, id: u64, expected_version: u64) -> Result<(), TaskError> {
+        self.authorize(id).await?;
+        self.repository.delete_if_version(id, expected_version).await.map_err(TaskError::from)
+    }
+
+    /// タスクを完了にする（繰り返すタスクは次の回を作る）
     pub async fn complete_task(&self, id: u64) -> Result<Task, TaskError> {
-        self.repository.complete(id).await.map_err(|e| TaskError::Repository(e.to_string()))
+        self.authorize(id).await?;
+        let task = self.repository.complete(id).await.map_err(TaskError::from)?;
+        self.roll_over(task).await
     }
 
     pub async fn uncomplete_task(&self, id: u64) -> Result<Task, TaskError> {
-        self.repository.uncomplete(id).await.map_err(|e| TaskError::Repository(e.to_string()))
+        self.authorize(id).await?;
+        self.repository.uncomplete(id).await.map_err(TaskError::from)
     }
 
     pub async fn get_completed_tasks(&self) -> Result<Vec<Task>, TaskError> {
-        let all_tasks = self.repository.get_all().await.map_err(|e| TaskError::Repository(e.to_string()))?;
-        Ok(all_tasks.into_iter().filter(|t| t.completed).collect())
+        self.get_tasks_by_status(true).await
     }
 
     pub async fn get_pending_tasks(&self) -> Result<Vec<Task>, TaskError> {
-        let all_tasks = self.repository.get_all().await.map_err(|e| TaskError::Repository(e.to_string()))?;
-        Ok(all_tasks.into_iter().filter(|t| !t.completed).collect())
+        self.get_tasks_by_status(false).await
     }
 
     pub async fn search_tasks(&self, query: &str) -> Result<Vec<Task>, TaskError> {
-        let all_tasks = self.repository.get_all().await.map_err(|e| TaskError::Repository(e.to_string()))?;
+        let all_tasks = self.owned_tasks().await?;
         let filtered_tasks: Vec<Task> = all_tasks
             .into_iter()
             .filter(|task| {
@@ -96,8 +211,394 @@ where
     }
 
     pub async fn get_tasks_by_status(&self, completed: bool) -> Result<Vec<Task>, TaskError> {
-        let all_tasks = self.repository.get_all().await.map_err(|e| TaskError::Repository(e.to_string()))?;
-        Ok(all_tasks.into_iter().filter(|t| t.completed == completed).collect())
+        let query = TaskQuery {
+            completed: Some(completed),
+            ..TaskQuery::default()
+        };
+        let page = self.repository.query(self.scope(query)).await.map_err(TaskError::from)?;
+        Ok(page.tasks)
+    }
+
+    /// 未完了のまま期限を過ぎたタスクを期限の早い順に取得する
+    pub async fn get_overdue_tasks(&self) -> Result<Vec<Task>, TaskError> {
+        let query = TaskQuery {
+            completed: Some(false),
+            due_before: Some(chrono::Utc::now()),
+            ..TaskQuery::default()
+        };
+        self.get_tasks_by_due_date(query).await
+    }
+
+    /// 現在から指定した期間内に期限を迎える未完了のタスクを期限の早い順に取得する
+    pub async fn get_tasks_due_within(&self, window: chrono::Duration) -> Result<Vec<Task>, TaskError> {
+        let now = chrono::Utc::now();
+        let query = TaskQuery {
+            completed: Some(false),
+            due_from: Some(now),
+            due_before: Some(now + window),
+            ..TaskQuery::default()
+        };
+        self.get_tasks_by_due_date(query).await
+    }
+
+    pub async fn add_tags(&self, id: u64, tags: Vec<String>) -> Result<Task, TaskError> {
+        let update_task = UpdateTask {
+            add_tags: tags,
+            ..UpdateTask::default()
+        };
+        self.update_task(id, update_task).await
+    }
+
+    pub async fn remove_tag(&self, id: u64, tag: String) -> Result<Task, TaskError> {
+        let update_task = UpdateTask {
+            remove_tags: vec![tag],
+            ..UpdateTask::default()
+        };
+        self.update_task(id, update_task).await
+    }
+
+    pub async fn get_tag_counts(&self) -> Result<Vec<TagCount>, TaskError> {
+        match self.owner_id {
+            Some(_) => Ok(TagCount::count(self.owned_tasks().await?)),
+            None => self.repository.get_tag_counts().await.map_err(TaskError::from),
+        }
+    }
+
+    pub async fn get_task_counts(&self) -> Result<TaskCounts, TaskError> {
+        match self.owner_id {
+            Some(_) => {
+                let tasks = self.owned_tasks().await?;
+                Ok(TaskCounts {
+                    total: tasks.len(),
+                    completed: tasks.iter().filter(|task| task.completed).count(),
+                })
+            }
+            None => self.repository.count_tasks().await.map_err(TaskError::from),
+        }
+    }
+
+    /// リポジトリに到達できるか確かめる
+    pub async fn ping(&self) -> Result<(), TaskError> {
+        self.repository.ping().await.map_err(TaskError::from)
+    }
+
+    pub fn storage_backend(&self) -> &'static str {
+        self.repository.backend_name()
+    }
+
+    /// 複数の操作をまとめて実行する（操作ごとの失敗は結果に含まれる）
+    pub async fn apply_batch(&self, operations: Vec<TaskOperation>, mode: BatchMode) -> Result<BatchOutcome<TaskError>, TaskError> {
+        if operations.is_empty() {
+            return Err(TaskError::InvalidOperation("a batch needs at least one operation".to_string()));
+        }
+        if operations.len() > MAX_BATCH_OPERATIONS {
+            return Err(TaskError::InvalidOperation(format!(
+                "a batch can contain at most {} operations",
+                MAX_BATCH_OPERATIONS
+            )));
+        }
+        let mut operations = operations;
+        for operation in &mut operations {
+            match operation {
+                TaskOperation::Create(create_task) => self.assign_owner(create_task).await?,
+                TaskOperation::Update { id, update } => self.authorize_update(*id, update).await?,
+                TaskOperation::Complete(id) | TaskOperation::Uncomplete(id) | TaskOperation::Delete(id) => self.authorize(*id).await?,
+            }
+        }
+        let mut outcome = self.repository.apply_batch(operations, mode).await.map_err(TaskError::from)?;
+        if outcome.committed {
+            for result in outcome.results.iter_mut().flatten() {
+                if let Some(task) = result.current.take() {
+                    result.current = Some(self.roll_over(task).await?);
+                }
+            }
+        }
+        Ok(outcome.map_err(TaskError::from))
+    }
+
+    /// ゴミ箱にあるタスクを取得する
+    pub async fn get_trash(&self) -> Result<Vec<Task>, TaskError> {
+        let trash = self.repository.get_trash().await.map_err(TaskError::from)?;
+        Ok(trash.into_iter().filter(|task| self.ensure_owner(task).is_ok()).collect())
+    }
+
+    pub async fn restore_task(&self, id: u64) -> Result<Task, TaskError> {
+        if self.owner_id.is_some() {
+            let trash = self.repository.get_trash().await.map_err(TaskError::from)?;
+            if let Some(task) = trash.iter().find(|task| task.id == id) {
+                self.ensure_owner(task)?;
+            }
+        }
+        self.repository.restore(id).await.map_err(TaskError::from)
+    }
+
+    /// ゴミ箱のタスクを完全に削除する（`older_than` を指定すると、それ以上前に削除したものだけ）
+    pub async fn purge_trash(&self, older_than: Option<chrono::Duration>) -> Result<usize, TaskError> {
+        let deleted_before = older_than.map(|older_than| chrono::Utc::now() - older_than);
+        self.repository.purge_trash(deleted_before, self.owner_id).await.map_err(TaskError::from)
+    }
+
+    /// タスクの変更履歴を取得する（削除したタスクの履歴も残る）
+    pub async fn get_task_history(&self, id: u64) -> Result<Vec<TaskHistoryEntry>, TaskError> {
+        let history = self.repository.get_history(id).await.map_err(TaskError::from)?;
+        if let Some(owner_id) = self.owner_id {
+            // ゴミ箱のタスクも持ち主を確かめ、完全に削除したタスクは作成時の記録から持ち主を読み取る
+            let task_owner = match self.repository.get_by_id(id).await {
+                Ok(task) => Some(task.owner_id),
+                Err(RepositoryTaskError::NotFound(_)) => {
+                    let trash = self.repository.get_trash().await.map_err(TaskError::from)?;
+                    match trash.into_iter().find(|task| task.id == id) {
+                        Some(task) => Some(task.owner_id),
+                        None => recorded_owner(&history),
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            };
+            match task_owner {
+                None => return Err(TaskError::NotFound(id)),
+                Some(task_owner) if task_owner != owner_id => return Err(TaskError::Forbidden(id)),
+                Some(_) => {}
+            }
+        }
+        if history.is_empty() {
+            // 履歴を記録する前からあるタスクは空の履歴、存在しないタスクは NotFound
+            self.repository.get_by_id(id).await.map_err(TaskError::from)?;
+        }
+        Ok(history)
+    }
+
+    /// 子タスクの扱いを指定してタスクを削除する（`expected_version` を指定すると版数を確かめる）
+    pub async fn delete_task_with_children(&self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> Result<(), TaskError> {
+        self.authorize(id).await?;
+        self.repository
+            .delete_with_children(id, policy, expected_version)
+            .await
+            .map(|_| ())
+            .map_err(TaskError::from)
+    }
+
+    /// 直下の子タスクを、それぞれの子孫を含めてID順に取得する
+    pub async fn get_children(&self, id: u64) -> Result<Vec<TaskNode>, TaskError> {
+        self.authorize(id).await?;
+        let mut pending = self.get_task_tree().await?;
+        while let Some(node) = pending.pop() {
+            if node.task.id == id {
+                return Ok(node.children);
+            }
+            pending.extend(node.children);
+        }
+        Err(TaskError::NotFound(id))
+    }
+
+    /// すべてのタスクを親子関係に従って組み立てる
+    pub async fn get_task_tree(&self) -> Result<Vec<TaskNode>, TaskError> {
+        let tasks = self.owned_tasks().await?;
+        Ok(TaskNode::build_forest(tasks))
+    }
+
+    /// 直下の子タスクの数と、そのうち完了したものの数
+    pub async fn get_subtask_counts(&self, id: u64) -> Result<TaskCounts, TaskError> {
+        let query = TaskQuery {
+            parent_id: Some(id),
+            ..TaskQuery::default()
+        };
+        let children = self.repository.query(self.scope(query)).await.map_err(TaskError::from)?.tasks;
+        Ok(TaskCounts {
+            total: children.len(),
+            completed: children.iter().filter(|task| task.completed).count(),
+        })
+    }
+
+    /// 依存先を追加する（存在しないタスクや循環する依存は指定できない）
+    pub async fn add_dependencies(&self, id: u64, depends_on: Vec<u64>) -> Result<Task, TaskError> {
+        let update_task = UpdateTask {
+            add_dependencies: depends_on,
+            ..UpdateTask::default()
+        };
+        self.update_task(id, update_task).await
+    }
+
+    pub async fn remove_dependency(&self, id: u64, depends_on: u64) -> Result<Task, TaskError> {
+        let update_task = UpdateTask {
+            remove_dependencies: vec![depends_on],
+            ..UpdateTask::default()
+        };
+        self.update_task(id, update_task).await
+    }
+
+    /// 未完了の依存先が残っていても完了にする（`expected_version` を指定すると版数を確かめる）
+    pub async fn force_complete_task(&self, id: u64, expected_version: Option<u64>) -> Result<Task, TaskError> {
+        self.authorize(id).await?;
+        let task = self.repository.force_complete(id, expected_version).await.map_err(TaskError::from)?;
+        self.roll_over(task).await
+    }
+
+    /// `tasks` のうち、未完了の依存先が残っているタスクのID
+    pub async fn get_blocked_task_ids(&self, tasks: &[Task]) -> Result<BTreeSet<u64>, TaskError> {
+        let dependencies: BTreeSet<u64> = tasks
+            .iter()
+            .filter(|task| !task.completed)
+            .flat_map(|task| task.depends_on.iter().copied())
+            .collect();
+        let mut completed = HashMap::with_capacity(dependencies.len());
+        for id in dependencies {
+            match self.repository.get_by_id(id).await {
+                Ok(task) => {
+                    completed.insert(id, task.completed);
+                }
+                // ゴミ箱に移したタスクは待たない
+                Err(RepositoryTaskError::NotFound(_)) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(blocked_task_ids(tasks, |id| completed.get(&id).copied()))
+    }
+
+    /// すぐに着手できる（未完了の依存先がない）未完了のタスクを、依存関係の順に取得する
+    pub async fn get_ready_tasks(&self) -> Result<Vec<Task>, TaskError> {
+        let tasks = self.owned_tasks().await?;
+        let completed: HashMap<u64, bool> = tasks.iter().map(|task| (task.id, task.completed)).collect();
+        let blocked = blocked_task_ids(&tasks, |id| completed.get(&id).copied());
+        let pending: Vec<Task> = tasks.into_iter().filter(|task| !task.completed).collect();
+        Ok(dependency_order(pending)
+            .into_iter()
+            .filter(|task| !blocked.contains(&task.id))
+            .collect())
+    }
+
+    /// 繰り返しの予定（現在の期限、なければ現在時刻より後の期限）を最大 `count` 件取得する
+    pub async fn preview_occurrences(&self, id: u64, count: Option<usize>) -> Result<Occurrences, TaskError> {
+        let task = self.get_task_by_id(id).await?;
+        let recurrence = task
+            .recurrence
+            .ok_or_else(|| TaskError::InvalidOperation(format!("Task {} does not recur", id)))?;
+        let count = count.unwrap_or(DEFAULT_OCCURRENCE_PREVIEW).clamp(1, MAX_OCCURRENCE_PREVIEW);
+        Ok(recurrence.occurrences(task.due_at.unwrap_or_else(chrono::Utc::now), count))
+    }
+
+    /// 繰り返しをやめる（完了しても次の回を作らない）
+    pub async fn stop_recurrence(&self, id: u64) -> Result<Task, TaskError> {
+        let update_task = UpdateTask {
+            recurrence: Some(None),
+            ..UpdateTask::default()
+        };
+        self.authorize(id).await?;
+        self.repository.update(id, update_task).await.map_err(TaskError::from)
+    }
+
+    /// 完了した繰り返すタスクの規則を次の回のタスクに引き継ぎ、規則を外した完了済みのタスクを返す
+    ///
+    /// 引き継ぎは1つの一括処理として行うため、変更履歴と通知にも残る。失敗した場合は規則が残るので、
+    /// もう一度完了にすると引き継ぎをやり直せる。
+    async fn roll_over(&self, task: Task) -> Result<Task, TaskError> {
+        if !task.completed || task.recurrence.is_none() {
+            return Ok(task);
+        }
+        let stop = UpdateTask {
+            recurrence: Some(None),
+            ..UpdateTask::default()
+        };
+        let mut operations = vec![TaskOperation::Update { id: task.id, update: stop }];
+        operations.extend(task.next_occurrence(chrono::Utc::now()).map(TaskOperation::Create));
+        let outcome = self.repository.apply_batch(operations, BatchMode::Atomic).await.map_err(TaskError::from)?;
+        let mut results = outcome.results.into_iter();
+        match results.next() {
+            Some(Ok(TaskOperationOutcome { current: Some(current), .. })) if outcome.committed => Ok(current),
+            Some(Err(e)) => Err(e.into()),
+            _ => match results.find_map(Result::err) {
+                Some(e) => Err(e.into()),
+                None => Err(TaskError::InvalidOperation(format!("Task {} could not start its next occurrence", task.id))),
+            },
+        }
+    }
+
+    async fn get_tasks_by_due_date(&self, query: TaskQuery) -> Result<Vec<Task>, TaskError> {
+        let mut tasks = self.repository.query(self.scope(query)).await.map_err(TaskError::from)?.tasks;
+        tasks.sort_by_key(|task| (task.due_at, task.id));
+        Ok(tasks)
+    }
+
+    /// 操作する利用者のタスクだけに絞り込む
+    fn scope(&self, mut query: TaskQuery) -> TaskQuery {
+        if self.owner_id.is_some() {
+            query.owner_id = self.owner_id;
+        }
+        query
+    }
+
+    /// 操作する利用者のタスクをすべて取得する
+    async fn owned_tasks(&self) -> Result<Vec<Task>, TaskError> {
+        match self.owner_id {
+            Some(_) => Ok(self.repository.query(self.scope(TaskQuery::default())).await.map_err(TaskError::from)?.tasks),
+            None => self.repository.get_all().await.map_err(TaskError::from),
+        }
+    }
+
+    /// 他の利用者のタスクなら Forbidden
+    fn ensure_owner(&self, task: &Task) -> Result<(), TaskError> {
+        match self.owner_id {
+            Some(owner_id) if task.owner_id != owner_id => Err(TaskError::Forbidden(task.id)),
+            _ => Ok(()),
+        }
+    }
+
+    /// IDで指定したタスクを操作してよいか確かめる
+    ///
+    /// 持ち主は作成後に変わらないため、確かめてから書き込むまでの間に結果は変わらない。
+    /// 存在しないタスクはこの後の操作で NotFound になる。
+    async fn authorize(&self, id: u64) -> Result<(), TaskError> {
+        if self.owner_id.is_none() {
+            return Ok(());
+        }
+        match self.repository.get_by_id(id).await {
+            Ok(task) => self.ensure_owner(&task),
+            Err(RepositoryTaskError::NotFound(_)) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// 参照先が他の利用者のタスクか（存在しないタスクはリポジトリが確かめる）
+    async fn is_foreign(&self, id: u64) -> Result<bool, TaskError> {
+        match self.authorize(id).await {
+            Ok(()) => Ok(false),
+            Err(TaskError::Forbidden(_)) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 作成するタスクを操作する利用者のものにする（他の利用者のタスクは親にできない）
+    async fn assign_owner(&self, create_task: &mut CreateTask) -> Result<(), TaskError> {
+        let Some(owner_id) = self.owner_id else {
+            return Ok(());
+        };
+        create_task.owner_id = Some(owner_id);
+        if let Some(parent_id) = create_task.parent_id {
+            if self.is_foreign(parent_id).await? {
+                return Err(TaskValidationError::ParentNotFound(parent_id).into());
+            }
+        }
+        Ok(())
+    }
+
+    /// 更新するタスクと、新しく参照する親・依存先が操作する利用者のものか確かめる
+    ///
+    /// 他の利用者のタスクは、参照先としては存在しないものとして扱う。
+    async fn authorize_update(&self, id: u64, update_task: &UpdateTask) -> Result<(), TaskError> {
+        if self.owner_id.is_none() {
+            return Ok(());
+        }
+        self.authorize(id).await?;
+        if let Some(Some(parent_id)) = update_task.parent_id {
+            if self.is_foreign(parent_id).await? {
+                return Err(TaskValidationError::ParentNotFound(parent_id).into());
+            }
+        }
+        for &dependency_id in &update_task.add_dependencies {
+            if self.is_foreign(dependency_id).await? {
+                return Err(TaskValidationError::DependencyNotFound(dependency_id).into());
+            }
+        }
+        Ok(())
     }
 }
 
@@ -108,17 +609,28 @@ where
     fn clone(&self) -> Self {
         Self {
             repository: self.repository.clone(),
+            owner_id: self.owner_id,
         }
     }
 }
 
 impl<R> TaskUsecase for TaskUsecaseImpl<R>
 where
-    R: TaskRepository + Send + Sync + 'static,
+    R: TaskRepository + Send + Sync + Clone + 'static,
 {
+    fn for_owner(&self, owner_id: u64) -> Self {
+        Self {
+            repository: self.repository.clone(),
+            owner_id: Some(owner_id),
+        }
+    }
+
     fn get_all_tasks<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>> {
         Box::pin(self.get_all_tasks())
     }
+    fn list_tasks<'a>(&'a self, query: TaskQuery) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<TaskPage, TaskError>> + Send + 'a>> {
+        Box::pin(self.list_tasks(query))
+    }
     fn get_task_by_id<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
         Box::pin(self.get_task_by_id(id))
     }
@@ -131,6 +643,12 @@ where
     fn delete_task<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), TaskError>> + Send + 'a>> {
         Box::pin(self.delete_task(id))
     }
+    fn update_task_if_version<'a>(&'a self, id: u64, update_task: UpdateTask, expected_version: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
+        Box::pin(self.update_task_if_version(id, update_task, expected_version))
+    }
+    fn delete_task_if_version<'a>(&'a self, id: u64, expected_version: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), TaskError>> + Send + 'a>> {
+        Box::pin(self.delete_task_if_version(id, expected_version))
+    }
     fn complete_task<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
         Box::pin(self.complete_task(id))
     }
@@ -149,4 +667,76 @@ where
     fn get_tasks_by_status<'a>(&'a self, completed: bool) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>> {
         Box::pin(self.get_tasks_by_status(completed))
     }
-} 
\ No newline at end of file
+    fn get_overdue_tasks<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_overdue_tasks())
+    }
+    fn get_tasks_due_within<'a>(&'a self, window: chrono::Duration) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_tasks_due_within(window))
+    }
+    fn add_tags<'a>(&'a self, id: u64, tags: Vec<String>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
+        Box::pin(self.add_tags(id, tags))
+    }
+    fn remove_tag<'a>(&'a self, id: u64, tag: String) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
+        Box::pin(self.remove_tag(id, tag))
+    }
+    fn get_tag_counts<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<TagCount>, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_tag_counts())
+    }
+    fn get_task_counts<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<TaskCounts, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_task_counts())
+    }
+    fn ping<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), TaskError>> + Send + 'a>> {
+        Box::pin(self.ping())
+    }
+    fn storage_backend(&self) -> &'static str {
+        self.storage_backend()
+    }
+    fn apply_batch<'a>(&'a self, operations: Vec<TaskOperation>, mode: BatchMode) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<BatchOutcome<TaskError>, TaskError>> + Send + 'a>> {
+        Box::pin(self.apply_batch(operations, mode))
+    }
+    fn get_trash<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_trash())
+    }
+    fn restore_task<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
+        Box::pin(self.restore_task(id))
+    }
+    fn purge_trash<'a>(&'a self, older_than: Option<chrono::Duration>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<usize, TaskError>> + Send + 'a>> {
+        Box::pin(self.purge_trash(older_than))
+    }
+    fn get_task_history<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<TaskHistoryEntry>, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_task_history(id))
+    }
+    fn delete_task_with_children<'a>(&'a self, id: u64, policy: ChildPolicy, expected_version: Option<u64>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), TaskError>> + Send + 'a>> {
+        Box::pin(self.delete_task_with_children(id, policy, expected_version))
+    }
+    fn get_children<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<TaskNode>, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_children(id))
+    }
+    fn get_task_tree<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<TaskNode>, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_task_tree())
+    }
+    fn get_subtask_counts<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<TaskCounts, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_subtask_counts(id))
+    }
+    fn add_dependencies<'a>(&'a self, id: u64, depends_on: Vec<u64>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
+        Box::pin(self.add_dependencies(id, depends_on))
+    }
+    fn remove_dependency<'a>(&'a self, id: u64, depends_on: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
+        Box::pin(self.remove_dependency(id, depends_on))
+    }
+    fn force_complete_task<'a>(&'a self, id: u64, expected_version: Option<u64>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
+        Box::pin(self.force_complete_task(id, expected_version))
+    }
+    fn get_blocked_task_ids<'a>(&'a self, tasks: &'a [Task]) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<BTreeSet<u64>, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_blocked_task_ids(tasks))
+    }
+    fn get_ready_tasks<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<Task>, TaskError>> + Send + 'a>> {
+        Box::pin(self.get_ready_tasks())
+    }
+    fn preview_occurrences<'a>(&'a self, id: u64, count: Option<usize>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Occurrences, TaskError>> + Send + 'a>> {
+        Box::pin(self.preview_occurrences(id, count))
+    }
+    fn stop_recurrence<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Task, TaskError>> + Send + 'a>> {
+        Box::pin(self.stop_recurrence(id))
+    }
+}
@@ -0,0 +1,95 @@
+use crate::domain::model::user::{CreateUser, User, UserValidationError};
+use crate::domain::repository::user::{UserError as RepositoryUserError, UserRepository};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum UserError {
+    #[error("User not found with id: {0}")]
+    NotFound(u64),
+    #[error("Validation error: {0}")]
+    Validation(#[from] UserValidationError),
+    #[error("Repository error: {0}")]
+    Repository(String),
+}
+
+/// リポジトリのエラーを種類を保ったままユースケースのエラーに変換する
+impl From<RepositoryUserError> for UserError {
+    fn from(error: RepositoryUserError) -> Self {
+        match error {
+            RepositoryUserError::NotFound(id) => UserError::NotFound(id),
+            RepositoryUserError::ValidationError(e) => UserError::Validation(e),
+            RepositoryUserError::RepositoryError(e) => UserError::Repository(e.to_string()),
+        }
+    }
+}
+
+pub trait UserUsecase: Send + Sync {
+    fn list_users<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<User>, UserError>> + Send + 'a>>;
+    fn get_user<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<User, UserError>> + Send + 'a>>;
+    fn find_user_by_name<'a>(&'a self, name: &'a str) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Option<User>, UserError>> + Send + 'a>>;
+    fn create_user<'a>(&'a self, create_user: CreateUser) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<User, UserError>> + Send + 'a>>;
+}
+
+pub struct UserUsecaseImpl<R>
+where
+    R: UserRepository + Send + Sync + 'static,
+{
+    repository: R,
+}
+
+impl<R> UserUsecaseImpl<R>
+where
+    R: UserRepository + Send + Sync + 'static,
+{
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn list_users(&self) -> Result<Vec<User>, UserError> {
+        self.repository.get_all().await.map_err(UserError::from)
+    }
+
+    pub async fn get_user(&self, id: u64) -> Result<User, UserError> {
+        self.repository.get_by_id(id).await.map_err(UserError::from)
+    }
+
+    /// 利用者名で探す（大文字・小文字は区別しない）
+    pub async fn find_user_by_name(&self, name: &str) -> Result<Option<User>, UserError> {
+        let users = self.repository.get_all().await?;
+        Ok(users.into_iter().find(|user| user.has_name(name)))
+    }
+
+    pub async fn create_user(&self, create_user: CreateUser) -> Result<User, UserError> {
+        create_user.validate()?;
+        self.repository.create(create_user).await.map_err(UserError::from)
+    }
+}
+
+impl<R> Clone for UserUsecaseImpl<R>
+where
+    R: UserRepository + Send + Sync + Clone + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            repository: self.repository.clone(),
+        }
+    }
+}
+
+impl<R> UserUsecase for UserUsecaseImpl<R>
+where
+    R: UserRepository + Send + Sync + 'static,
+{
+    fn list_users<'a>(&'a self) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Vec<User>, UserError>> + Send + 'a>> {
+        Box::pin(self.list_users())
+    }
+    fn get_user<'a>(&'a self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<User, UserError>> + Send + 'a>> {
+        Box::pin(self.get_user(id))
+    }
+    fn find_user_by_name<'a>(&'a self, name: &'a str) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<Option<User>, UserError>> + Send + 'a>> {
+        Box::pin(self.find_user_by_name(name))
+    }
+    fn create_user<'a>(&'a self, create_user: CreateUser) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<User, UserError>> + Send + 'a>> {
+        Box::pin(self.create_user(create_user))
+    }
+}
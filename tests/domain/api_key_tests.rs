@@ -0,0 +1,59 @@
+use chrono::Utc;
+use todo_api::domain::model::api_key::{hash_secret, ApiKey, ApiKeyScope, ApiKeyValidationError, CreateApiKey, MAX_API_KEY_NAME_LENGTH};
+
+#[test]
+fn test_scopes_include_lower_scopes() {
+    assert!(ApiKeyScope::Admin.allows(ApiKeyScope::Write));
+    assert!(ApiKeyScope::Write.allows(ApiKeyScope::Read));
+    assert!(ApiKeyScope::Read.allows(ApiKeyScope::Read));
+    assert!(!ApiKeyScope::Read.allows(ApiKeyScope::Write));
+    assert!(!ApiKeyScope::Write.allows(ApiKeyScope::Admin));
+
+    assert_eq!("write".parse::<ApiKeyScope>(), Ok(ApiKeyScope::Write));
+    assert_eq!(ApiKeyScope::Admin.to_string(), "admin");
+    assert!("owner".parse::<ApiKeyScope>().is_err());
+    assert_eq!(serde_json::to_value(ApiKeyScope::Read).unwrap(), "read");
+}
+
+#[test]
+fn test_secret_hash_is_stable_hex() {
+    let hash = hash_secret("todo_secret");
+    assert_eq!(hash.len(), 64);
+    assert!(hash.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+    assert_eq!(hash, hash_secret("todo_secret"));
+    assert_ne!(hash, hash_secret("todo_secreT"));
+}
+
+#[test]
+fn test_create_api_key_validation() {
+    let create = |name: &str| CreateApiKey { name: name.to_string(), scope: ApiKeyScope::Read, user_id: None };
+    assert!(create("ci").validate().is_ok());
+    assert!(matches!(create("  ").validate(), Err(ApiKeyValidationError::EmptyName)));
+    assert!(matches!(
+        create(&"k".repeat(MAX_API_KEY_NAME_LENGTH + 1)).validate(),
+        Err(ApiKeyValidationError::NameTooLong(_))
+    ));
+}
+
+#[test]
+fn test_revoked_key_is_inactive_and_hash_is_not_serialized() {
+    let mut key = ApiKey {
+        id: 1,
+        name: "ci".to_string(),
+        scope: ApiKeyScope::Write,
+        user_id: 1,
+        prefix: "todo_abcdefg".to_string(),
+        secret_hash: hash_secret("todo_abcdefgh"),
+        created_at: Utc::now(),
+        revoked_at: None,
+    };
+    assert!(key.is_active());
+    let json = serde_json::to_value(&key).unwrap();
+    assert!(json.get("secret_hash").is_none());
+
+    key.revoke();
+    let revoked_at = key.revoked_at;
+    assert!(!key.is_active());
+    key.revoke();
+    assert_eq!(key.revoked_at, revoked_at);
+}
@@ -0,0 +1,60 @@
+use todo_api::domain::model::task::{blocked_task_ids, dependency_order, Task, TaskValidationError};
+
+fn task(id: u64, depends_on: &[u64], completed: bool) -> Task {
+    let mut task = Task::new(id, format!("Task {}", id)).unwrap();
+    task.depends_on = depends_on.iter().copied().collect();
+    task.completed = completed;
+    task
+}
+
+#[test]
+fn test_dependency_order_puts_dependencies_first() {
+    let tasks = vec![
+        task(1, &[3], false),
+        task(2, &[], false),
+        task(3, &[4], false),
+        task(4, &[], false),
+        // 一覧に含まれないタスクへの依存は無視する
+        task(5, &[99], false),
+    ];
+
+    let order: Vec<u64> = dependency_order(tasks).iter().map(|task| task.id).collect();
+    assert_eq!(order, vec![2, 4, 3, 1, 5]);
+}
+
+#[test]
+fn test_dependency_order_keeps_cyclic_tasks_at_the_end() {
+    let tasks = vec![task(1, &[2], false), task(2, &[1], false), task(3, &[], false)];
+
+    let order: Vec<u64> = dependency_order(tasks).iter().map(|task| task.id).collect();
+    assert_eq!(order, vec![3, 1, 2]);
+}
+
+#[test]
+fn test_blocked_task_ids_only_counts_pending_dependencies() {
+    let tasks = vec![
+        task(1, &[], true),
+        task(2, &[], false),
+        task(3, &[1], false),
+        task(4, &[1, 2], false),
+        // 完了済みのタスクは待たない
+        task(5, &[2], true),
+        task(6, &[99], false),
+    ];
+    let completed = |id: u64| tasks.iter().find(|task| task.id == id).map(|task| task.completed);
+
+    let blocked: Vec<u64> = blocked_task_ids(&tasks, completed).into_iter().collect();
+    assert_eq!(blocked, vec![4]);
+}
+
+#[test]
+fn test_add_dependencies_rejects_the_task_itself() {
+    let mut task = task(1, &[], false);
+    assert!(matches!(task.add_dependencies(&[2, 1]), Err(TaskValidationError::DependencyCycle(1))));
+    assert!(task.depends_on.is_empty());
+
+    task.add_dependencies(&[3, 2, 3]).unwrap();
+    assert_eq!(task.depends_on.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+    task.remove_dependencies(&[3, 7]);
+    assert_eq!(task.depends_on.iter().copied().collect::<Vec<_>>(), vec![2]);
+}
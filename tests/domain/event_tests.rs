@@ -0,0 +1,35 @@
+use todo_api::domain::model::event::{TaskChange, TaskChangeKind};
+use todo_api::domain::model::task::Task;
+
+fn task() -> Task {
+    Task::new(1, "Task".to_string()).unwrap()
+}
+
+fn changed(previous: &Task, change: impl FnOnce(&mut Task)) -> Task {
+    let mut task = previous.clone();
+    change(&mut task);
+    task.advance_version(previous);
+    task
+}
+
+#[test]
+fn test_task_change_kind_between_versions() {
+    let previous = task();
+
+    let completed = changed(&previous, |task| task.complete());
+    assert_eq!(TaskChange::between(&previous, &completed).unwrap().kind, TaskChangeKind::Completed);
+    let uncompleted = changed(&completed, |task| task.uncomplete());
+    assert_eq!(TaskChange::between(&completed, &uncompleted).unwrap().kind, TaskChangeKind::Uncompleted);
+
+    // 完了状態と一緒に他の項目も変わった場合は更新として扱う
+    let both = changed(&previous, |task| {
+        task.complete();
+        task.update_description("Renamed".to_string()).unwrap();
+    });
+    let change = TaskChange::between(&previous, &both).unwrap();
+    assert_eq!(change.kind, TaskChangeKind::Updated);
+    assert_eq!(change.task, both);
+
+    // 版数が進んでいなければ変更なし
+    assert!(TaskChange::between(&previous, &previous.clone()).is_none());
+}
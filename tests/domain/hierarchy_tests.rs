@@ -0,0 +1,54 @@
+use todo_api::domain::model::task::{completion_percentage, Task, TaskNode, TaskValidationError};
+
+fn task(id: u64, parent_id: Option<u64>, completed: bool) -> Task {
+    let mut task = Task::new(id, format!("Task {}", id)).unwrap();
+    task.parent_id = parent_id;
+    task.completed = completed;
+    task
+}
+
+#[test]
+fn test_build_forest_nests_tasks_under_their_parents() {
+    let tasks = vec![
+        task(4, Some(2), false),
+        task(3, None, false),
+        task(2, Some(1), true),
+        task(1, None, false),
+        // 親が含まれないタスクは最上位に置く
+        task(5, Some(99), false),
+    ];
+
+    let forest = TaskNode::build_forest(tasks);
+    let roots: Vec<u64> = forest.iter().map(|node| node.task.id).collect();
+    assert_eq!(roots, vec![1, 3, 5]);
+    assert_eq!(forest[0].children.len(), 1);
+    assert_eq!(forest[0].children[0].task.id, 2);
+    assert_eq!(forest[0].children[0].children[0].task.id, 4);
+    assert!(forest[1].children.is_empty());
+}
+
+#[test]
+fn test_completion_percentage_counts_direct_children() {
+    let forest = TaskNode::build_forest(vec![
+        task(1, None, false),
+        task(2, Some(1), true),
+        task(3, Some(1), false),
+        task(4, Some(1), false),
+        task(5, Some(4), true),
+    ]);
+    assert_eq!(forest[0].completion_percentage(), Some(33));
+    assert_eq!(forest[0].children[2].completion_percentage(), Some(100));
+    assert_eq!(forest[0].children[0].completion_percentage(), None);
+    assert_eq!(completion_percentage(0, 0), None);
+    assert_eq!(completion_percentage(1, 2), Some(50));
+}
+
+#[test]
+fn test_set_parent_rejects_the_task_itself() {
+    let mut task = task(1, None, false);
+    assert!(matches!(task.set_parent(Some(1)), Err(TaskValidationError::ParentCycle(1))));
+    assert_eq!(task.parent_id, None);
+
+    task.set_parent(Some(2)).unwrap();
+    assert_eq!(task.parent_id, Some(2));
+}
@@ -0,0 +1,43 @@
+use serde_json::json;
+use todo_api::domain::model::history::FieldChange;
+use todo_api::domain::model::task::{Task, TaskPriority};
+
+fn task() -> Task {
+    Task::new(1, "Task".to_string()).unwrap()
+}
+
+#[test]
+fn test_field_change_diff_lists_changed_fields_only() {
+    let before = task();
+    let mut after = before.clone();
+    after.update_description("Renamed".to_string()).unwrap();
+    after.set_priority(TaskPriority::High);
+    after.add_tags(&["work".to_string()]).unwrap();
+    after.advance_version(&before);
+
+    // 版数や更新日時は差分に含めない
+    let changes = FieldChange::diff(Some(&before), Some(&after));
+    let fields: Vec<&str> = changes.iter().map(|change| change.field.as_str()).collect();
+    assert_eq!(fields, vec!["description", "priority", "tags"]);
+    assert_eq!(changes[0].before, Some(json!("Task")));
+    assert_eq!(changes[0].after, Some(json!("Renamed")));
+    assert_eq!(changes[1].before, None);
+    assert_eq!(changes[1].after, Some(json!("high")));
+    assert_eq!(changes[2].after, Some(json!(["work"])));
+
+    assert!(FieldChange::diff(Some(&before), Some(&before)).is_empty());
+}
+
+#[test]
+fn test_field_change_diff_for_created_and_removed_tasks() {
+    let task = task();
+
+    let created = FieldChange::diff(None, Some(&task));
+    let fields: Vec<&str> = created.iter().map(|change| change.field.as_str()).collect();
+    assert_eq!(fields, vec!["completed", "depends_on", "description", "owner_id", "project_id", "tags"]);
+    assert!(created.iter().all(|change| change.before.is_none()));
+
+    let removed = FieldChange::diff(Some(&task), None);
+    assert_eq!(removed.len(), 6);
+    assert!(removed.iter().all(|change| change.after.is_none()));
+}
@@ -1 +1,9 @@
-pub mod task_tests; 
\ No newline at end of file
+pub mod event_tests;
+pub mod hierarchy_tests;
+pub mod history_tests;
+pub mod task_tests;
+pub mod dependency_tests;
+pub mod recurrence_tests;
+pub mod project_tests;
+pub mod user_tests;
+pub mod api_key_tests;
\ No newline at end of file
@@ -0,0 +1,57 @@
+use todo_api::domain::model::project::{CreateProject, Project, ProjectValidationError, UpdateProject, INBOX_PROJECT_ID, MAX_PROJECT_NAME_LENGTH};
+use todo_api::domain::model::task::{CreateTask, Task};
+use todo_api::domain::model::user::DEFAULT_USER_ID;
+
+#[test]
+fn test_project_name_is_trimmed_and_validated() {
+    let project = Project::new(2, "  Work  ", DEFAULT_USER_ID).unwrap();
+    assert_eq!(project.name, "Work");
+    assert!(!project.is_inbox());
+    assert!(project.is_visible_to(DEFAULT_USER_ID));
+    assert!(!project.is_visible_to(DEFAULT_USER_ID + 1));
+
+    assert!(matches!(Project::new(2, "   ", DEFAULT_USER_ID), Err(ProjectValidationError::EmptyName)));
+    let too_long = "a".repeat(MAX_PROJECT_NAME_LENGTH + 1);
+    assert!(matches!(Project::new(2, &too_long, DEFAULT_USER_ID), Err(ProjectValidationError::NameTooLong(_))));
+    assert!(Project::new(2, &"a".repeat(MAX_PROJECT_NAME_LENGTH), DEFAULT_USER_ID).is_ok());
+
+    assert!(CreateProject { name: "".to_string(), owner_id: None }.validate().is_err());
+    assert!(UpdateProject { name: None }.validate().is_ok());
+    assert!(UpdateProject { name: Some(" ".to_string()) }.validate().is_err());
+}
+
+#[test]
+fn test_project_rename_and_name_comparison() {
+    let mut project = Project::new(2, "Work", DEFAULT_USER_ID).unwrap();
+    let updated_at = project.updated_at;
+
+    project.rename("Work ").unwrap();
+    assert_eq!(project.updated_at, updated_at);
+    project.rename("Office").unwrap();
+    assert_eq!(project.name, "Office");
+    assert!(project.updated_at >= updated_at);
+    assert!(project.rename("").is_err());
+    assert_eq!(project.name, "Office");
+
+    assert!(project.has_name("office"));
+    assert!(project.has_name(" OFFICE "));
+    assert!(!project.has_name("offices"));
+}
+
+#[test]
+fn test_tasks_default_to_the_inbox() {
+    let inbox = Project::inbox();
+    assert_eq!(inbox.id, INBOX_PROJECT_ID);
+    assert!(inbox.is_inbox());
+    assert!(inbox.is_visible_to(DEFAULT_USER_ID));
+    assert!(inbox.is_visible_to(DEFAULT_USER_ID + 1));
+
+    let task = Task::from_create(1, CreateTask { description: "Task".to_string(), ..Default::default() }).unwrap();
+    assert_eq!(task.project_id, INBOX_PROJECT_ID);
+
+    let create_task = CreateTask { description: "Task".to_string(), project_id: Some(3), ..Default::default() };
+    let mut task = Task::from_create(2, create_task).unwrap();
+    assert_eq!(task.project_id, 3);
+    task.set_project(INBOX_PROJECT_ID);
+    assert_eq!(task.project_id, INBOX_PROJECT_ID);
+}
@@ -0,0 +1,130 @@
+use chrono::{DateTime, Duration, TimeZone, Utc, Weekday};
+use todo_api::domain::model::recurrence::{Frequency, Recurrence};
+use todo_api::domain::model::task::{CreateTask, Task, TaskValidationError};
+
+fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, 9, 0, 0).unwrap()
+}
+
+fn rule(rrule: &str) -> Recurrence {
+    rrule.parse().unwrap()
+}
+
+#[test]
+fn test_recurrence_rrule_round_trip() {
+    let recurrence = rule("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TH,MO,TH;UNTIL=20300101");
+    assert_eq!(recurrence.frequency, Frequency::Weekly);
+    assert_eq!(recurrence.interval, 2);
+    assert_eq!(recurrence.weekdays, vec![Weekday::Mon, Weekday::Thu]);
+    assert_eq!(recurrence.until, Some(Utc.with_ymd_and_hms(2030, 1, 1, 23, 59, 59).unwrap()));
+    assert_eq!(recurrence.to_string(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;UNTIL=20300101T235959Z");
+    assert_eq!(rule(&recurrence.to_string()), recurrence);
+    assert_eq!(rule("freq=daily").to_string(), "FREQ=DAILY");
+}
+
+#[test]
+fn test_recurrence_rejects_unsupported_rules() {
+    for rrule in [
+        "",
+        "INTERVAL=2",
+        "FREQ=YEARLY",
+        "FREQ=DAILY;INTERVAL=0",
+        "FREQ=DAILY;BYDAY=MO",
+        "FREQ=WEEKLY;BYMONTHDAY=3",
+        "FREQ=MONTHLY;BYMONTHDAY=32",
+        "FREQ=WEEKLY;BYDAY=1MO",
+        "FREQ=DAILY;COUNT=3",
+        "FREQ=DAILY;UNTIL=tomorrow",
+    ] {
+        assert!(
+            matches!(rrule.parse::<Recurrence>(), Err(TaskValidationError::InvalidRecurrence(_))),
+            "{rrule} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_recurrence_daily_and_until() {
+    let recurrence = rule("FREQ=DAILY;INTERVAL=3;UNTIL=20300110T090000Z");
+    assert_eq!(
+        recurrence.occurrences(at(2030, 1, 1), 10),
+        vec![at(2030, 1, 4), at(2030, 1, 7), at(2030, 1, 10)]
+    );
+    assert_eq!(recurrence.next_after(at(2030, 1, 1), at(2030, 1, 10)), None);
+}
+
+#[test]
+fn test_recurrence_weekly_on_weekdays() {
+    // 2030-01-01 は火曜日
+    let recurrence = rule("FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH");
+    assert_eq!(
+        recurrence.occurrences(at(2030, 1, 1), 4),
+        vec![at(2030, 1, 3), at(2030, 1, 14), at(2030, 1, 17), at(2030, 1, 28)]
+    );
+    // 曜日の指定がなければ同じ曜日
+    assert_eq!(rule("FREQ=WEEKLY").next_after(at(2030, 1, 1), at(2030, 1, 1)), Some(at(2030, 1, 8)));
+}
+
+#[test]
+fn test_recurrence_monthly_clamps_to_last_day() {
+    let recurrence = rule("FREQ=MONTHLY;BYMONTHDAY=31");
+    assert_eq!(
+        recurrence.occurrences(at(2030, 1, 15), 4),
+        vec![at(2030, 1, 31), at(2030, 2, 28), at(2030, 3, 31), at(2030, 4, 30)]
+    );
+    assert_eq!(
+        rule("FREQ=MONTHLY;INTERVAL=6").occurrences(at(2030, 8, 31), 2),
+        vec![at(2031, 2, 28), at(2031, 8, 31)]
+    );
+}
+
+#[test]
+fn test_recurrence_monthly_keeps_the_day_of_the_start() {
+    // 日の指定がなければ起点の日に合わせ、2月末に繰り上げた後も31日に戻る
+    let monthly = rule("FREQ=MONTHLY");
+    assert_eq!(
+        monthly.occurrences(at(2030, 1, 31), 3),
+        vec![at(2030, 2, 28), at(2030, 3, 31), at(2030, 4, 30)]
+    );
+    assert_eq!(monthly.next_after(at(2030, 1, 31), at(2030, 2, 28)), Some(at(2030, 3, 31)));
+    assert_eq!(monthly.occurrences(at(2032, 1, 31), 2), vec![at(2032, 2, 29), at(2032, 3, 31)]);
+
+    // 完了して作られる次の回にも起点の日を引き継ぐ
+    let create_task = CreateTask {
+        description: "Pay the rent".to_string(),
+        due_at: Some(at(2030, 1, 31)),
+        recurrence: Some(monthly),
+        ..Default::default()
+    };
+    let january = Task::from_create(1, create_task).unwrap();
+    let february = Task::from_create(2, january.next_occurrence(at(2030, 1, 1)).unwrap()).unwrap();
+    assert_eq!(february.due_at, Some(at(2030, 2, 28)));
+    assert_eq!(february.recurrence.as_ref().unwrap().to_string(), "FREQ=MONTHLY;BYMONTHDAY=31");
+    let march = february.next_occurrence(at(2030, 1, 1)).unwrap();
+    assert_eq!(march.due_at, Some(at(2030, 3, 31)));
+}
+
+#[test]
+fn test_next_occurrence_copies_the_task() {
+    let now = Utc::now();
+    let create_task = CreateTask {
+        description: "Water the plants".to_string(),
+        due_at: Some(now + Duration::hours(1)),
+        recurrence: Some(rule("FREQ=DAILY")),
+        tags: vec!["home".to_string()],
+        ..Default::default()
+    };
+    let task = Task::from_create(1, create_task).unwrap();
+
+    let next = task.next_occurrence(now).unwrap();
+    assert_eq!(next.description, "Water the plants");
+    assert_eq!(next.due_at, Some(now + Duration::hours(25)));
+    assert_eq!(next.recurrence, task.recurrence);
+    assert_eq!(next.tags, vec!["home".to_string()]);
+
+    // 期限を過ぎていれば現在より後の回まで進める
+    assert_eq!(task.next_occurrence(now + Duration::days(3)).unwrap().due_at, Some(now + Duration::hours(73)));
+
+    let once = Task::from_create(2, CreateTask::new("Once".to_string()).unwrap()).unwrap();
+    assert!(once.next_occurrence(now).is_none());
+}
@@ -1,4 +1,5 @@
-use todo_api::domain::model::task::{Task, CreateTask, UpdateTask, TaskValidationError};
+use chrono::{Duration, Utc};
+use todo_api::domain::model::task::{normalize_tag, Task, CreateTask, UpdateTask, TaskPriority, TaskValidationError, MAX_TAGS_PER_TASK};
 
 #[test]
 fn test_task_creation() {
@@ -91,4 +92,132 @@ fn test_update_task_is_empty() {
 
     let non_empty_update = UpdateTask::new(Some("Task".to_string()), None).unwrap();
     assert!(!non_empty_update.is_empty());
-} 
\ No newline at end of file
+}
+
+#[test]
+fn test_task_from_create_with_priority_and_due_at() {
+    let due_at = Utc::now() + Duration::days(1);
+    let create_task = CreateTask {
+        description: "Planned task".to_string(),
+        priority: Some(TaskPriority::High),
+        due_at: Some(due_at),
+        parent_id: None,
+        recurrence: None,
+        tags: Vec::new(),
+        project_id: None,
+        owner_id: None,
+    };
+    let task = Task::from_create(1, create_task).unwrap();
+    assert_eq!(task.priority, Some(TaskPriority::High));
+    assert_eq!(task.due_at, Some(due_at));
+}
+
+#[test]
+fn test_task_validation_due_before_creation() {
+    let create_task = CreateTask {
+        description: "Late task".to_string(),
+        due_at: Some(Utc::now() - Duration::hours(1)),
+        ..Default::default()
+    };
+    let result = Task::from_create(1, create_task);
+    assert!(matches!(result, Err(TaskValidationError::DueBeforeCreation)));
+}
+
+#[test]
+fn test_task_set_due_at() {
+    let mut task = Task::new(1, "Test task".to_string()).unwrap();
+    let due_at = task.created_at + Duration::days(2);
+
+    task.set_due_at(Some(due_at)).unwrap();
+    assert_eq!(task.due_at, Some(due_at));
+
+    // 作成日時より前の期限は拒否され、元の期限が保たれる
+    let result = task.set_due_at(Some(task.created_at - Duration::days(1)));
+    assert!(matches!(result, Err(TaskValidationError::DueBeforeCreation)));
+    assert_eq!(task.due_at, Some(due_at));
+
+    // Noneで期限を解除できる
+    task.set_due_at(None).unwrap();
+    assert_eq!(task.due_at, None);
+}
+
+#[test]
+fn test_task_is_overdue() {
+    let mut task = Task::new(1, "Test task".to_string()).unwrap();
+    let due_at = task.created_at + Duration::hours(1);
+    task.set_due_at(Some(due_at)).unwrap();
+
+    assert!(!task.is_overdue(due_at - Duration::minutes(1)));
+    assert!(task.is_overdue(due_at + Duration::minutes(1)));
+
+    // 完了済みのタスクは期限切れとみなさない
+    task.complete();
+    assert!(!task.is_overdue(due_at + Duration::minutes(1)));
+}
+
+#[test]
+fn test_update_task_is_empty_with_priority_and_due_at() {
+    let priority_update = UpdateTask { priority: Some(TaskPriority::Low), ..Default::default() };
+    assert!(!priority_update.is_empty());
+
+    // 期限の解除も変更として扱う
+    let clear_due_update = UpdateTask { due_at: Some(None), ..Default::default() };
+    assert!(!clear_due_update.is_empty());
+}
+
+#[test]
+fn test_normalize_tag() {
+    assert_eq!(normalize_tag("  Work ").unwrap(), "work");
+    assert_eq!(normalize_tag("v1.2_release-notes").unwrap(), "v1.2_release-notes");
+
+    for invalid in ["", "   ", "two words", "a,b", "日本語", &"a".repeat(33)] {
+        assert!(matches!(normalize_tag(invalid), Err(TaskValidationError::InvalidTag(tag)) if tag == invalid));
+    }
+}
+
+#[test]
+fn test_task_add_and_remove_tags() {
+    let mut task = Task::new(1, "Test task".to_string()).unwrap();
+    let created_updated_at = task.updated_at;
+
+    task.add_tags(&["Work".to_string(), "home".to_string(), "work".to_string()]).unwrap();
+    assert_eq!(task.tags.iter().collect::<Vec<_>>(), vec!["home", "work"]);
+    assert!(task.updated_at >= created_updated_at);
+
+    // 付いていないタグを外しても何も変わらない
+    let updated_at = task.updated_at;
+    task.remove_tags(&["missing".to_string()]).unwrap();
+    assert_eq!(task.updated_at, updated_at);
+
+    task.remove_tags(&["HOME".to_string()]).unwrap();
+    assert_eq!(task.tags.iter().collect::<Vec<_>>(), vec!["work"]);
+}
+
+#[test]
+fn test_task_add_tags_rejects_invalid_and_too_many() {
+    let mut task = Task::new(1, "Test task".to_string()).unwrap();
+
+    assert!(matches!(task.add_tags(&["bad tag".to_string()]), Err(TaskValidationError::InvalidTag(_))));
+    assert!(task.tags.is_empty());
+
+    let too_many: Vec<String> = (0..=MAX_TAGS_PER_TASK).map(|i| format!("tag{}", i)).collect();
+    assert!(matches!(task.add_tags(&too_many), Err(TaskValidationError::TooManyTags(MAX_TAGS_PER_TASK))));
+    assert!(task.tags.is_empty());
+}
+
+#[test]
+fn test_task_advance_version_only_when_changed() {
+    let mut task = Task::new(1, "Test task".to_string()).unwrap();
+    assert_eq!(task.version, 1);
+
+    let previous = task.clone();
+    task.remove_tags(&["missing".to_string()]).unwrap();
+    task.advance_version(&previous);
+    assert_eq!(task.version, 1);
+
+    let previous = task.clone();
+    task.update_description("Updated".to_string()).unwrap();
+    task.set_priority(TaskPriority::High);
+    task.advance_version(&previous);
+    assert_eq!(task.version, 2);
+}
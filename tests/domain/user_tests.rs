@@ -0,0 +1,38 @@
+use todo_api::domain::model::task::{CreateTask, Task};
+use todo_api::domain::model::user::{CreateUser, User, UserValidationError, DEFAULT_USER_ID, DEFAULT_USER_NAME, MAX_USER_NAME_LENGTH};
+
+#[test]
+fn test_user_name_is_trimmed_and_validated() {
+    let user = User::new(2, "  alice  ").unwrap();
+    assert_eq!(user.name, "alice");
+
+    assert!(matches!(User::new(2, "  "), Err(UserValidationError::EmptyName)));
+    let too_long = "a".repeat(MAX_USER_NAME_LENGTH + 1);
+    assert!(matches!(User::new(2, &too_long), Err(UserValidationError::NameTooLong(_))));
+    assert!(User::new(2, &"a".repeat(MAX_USER_NAME_LENGTH)).is_ok());
+    assert!(matches!(User::new(2, "alice smith"), Err(UserValidationError::InvalidName(_))));
+    assert!(User::new(2, "alice.smith-2_b").is_ok());
+
+    assert!(CreateUser { name: "bob".to_string() }.validate().is_ok());
+    assert!(CreateUser { name: "bob!".to_string() }.validate().is_err());
+}
+
+#[test]
+fn test_default_user_and_name_comparison() {
+    let user = User::default_user();
+    assert_eq!(user.id, DEFAULT_USER_ID);
+    assert_eq!(user.name, DEFAULT_USER_NAME);
+    assert!(user.has_name("DEFAULT"));
+    assert!(user.has_name(" default "));
+    assert!(!user.has_name("defaults"));
+}
+
+#[test]
+fn test_tasks_default_to_the_default_user() {
+    let task = Task::from_create(1, CreateTask { description: "Task".to_string(), ..Default::default() }).unwrap();
+    assert_eq!(task.owner_id, DEFAULT_USER_ID);
+
+    let create_task = CreateTask { description: "Task".to_string(), owner_id: Some(2), ..Default::default() };
+    let task = Task::from_create(2, create_task).unwrap();
+    assert_eq!(task.owner_id, 2);
+}
@@ -0,0 +1,275 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+use todo_api::domain::model::api_key::ApiKeyScope;
+use todo_api::infrastructure::config::{
+    Config, ConfigError, ConfigOverrides, JwtAlgorithm, LogFormat, LogLevel, RateLimitBudget, StorageBackend,
+    DEFAULT_MAX_BODY_BYTES, DEFAULT_PER_ADDRESS_RATE_LIMIT, DEFAULT_READ_RATE_LIMIT, DEFAULT_WRITE_RATE_LIMIT,
+};
+
+fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    move |name| vars.get(name).cloned()
+}
+
+fn config_file(content: &str) -> tempfile::NamedTempFile {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(content.as_bytes()).unwrap();
+    file
+}
+
+#[test]
+fn test_defaults() {
+    let config = Config::load_with_env(None, &ConfigOverrides::default(), env(&[])).unwrap();
+    assert_eq!(config.server.bind.to_string(), "127.0.0.1:3000");
+    assert_eq!(config.storage.backend, StorageBackend::Memory);
+    assert_eq!(config.log.level, LogLevel::Info);
+    assert_eq!(config.log.format, LogFormat::Pretty);
+    assert!(config.cors.allowed_origins.is_empty());
+    assert_eq!(config.limits.max_body_bytes, DEFAULT_MAX_BODY_BYTES);
+    assert_eq!(config.server.shutdown_timeout(), Duration::from_secs(30));
+}
+
+#[test]
+fn test_file_then_env_then_cli() {
+    let file = config_file(
+        r#"
+[server]
+bind = "0.0.0.0:8080"
+
+[storage]
+backend = "sqlite"
+path = "/var/lib/todo/todo.db"
+
+[log]
+level = "debug"
+
+[cors]
+allowed_origins = ["https://example.com"]
+"#,
+    );
+
+    // ファイルの値（書かれていない項目は既定値）
+    let config = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), env(&[])).unwrap();
+    assert_eq!(config.server.bind.to_string(), "0.0.0.0:8080");
+    assert_eq!(config.storage.backend, StorageBackend::Sqlite);
+    assert_eq!(config.storage.path, PathBuf::from("/var/lib/todo/todo.db"));
+    assert_eq!(config.log.level, LogLevel::Debug);
+    assert_eq!(config.limits.max_body_bytes, DEFAULT_MAX_BODY_BYTES);
+
+    // 環境変数はファイルより優先される
+    let vars = env(&[
+        ("TODO_API_BIND", "127.0.0.1:9000"),
+        ("TODO_API_LOG_LEVEL", "WARN"),
+        ("TODO_API_LOG_FORMAT", "json"),
+        ("TODO_API_CORS_ORIGINS", "https://a.example, https://b.example"),
+        ("TODO_API_MAX_BODY_BYTES", "1024"),
+        ("TODO_API_SHUTDOWN_TIMEOUT_SECS", "5"),
+    ]);
+    let config = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), &vars).unwrap();
+    assert_eq!(config.server.bind.to_string(), "127.0.0.1:9000");
+    assert_eq!(config.log.level, LogLevel::Warn);
+    assert_eq!(config.log.format, LogFormat::Json);
+    assert_eq!(config.cors.allowed_origins, vec!["https://a.example", "https://b.example"]);
+    assert_eq!(config.limits.max_body_bytes, 1024);
+    assert_eq!(config.server.shutdown_timeout(), Duration::from_secs(5));
+
+    // コマンドラインは環境変数より優先される
+    let overrides = ConfigOverrides {
+        bind: Some("127.0.0.1:9100".parse().unwrap()),
+        storage: Some(StorageBackend::Memory),
+        ..ConfigOverrides::default()
+    };
+    let config = Config::load_with_env(Some(file.path()), &overrides, &vars).unwrap();
+    assert_eq!(config.server.bind.to_string(), "127.0.0.1:9100");
+    assert_eq!(config.storage.backend, StorageBackend::Memory);
+    assert_eq!(config.log.level, LogLevel::Warn);
+}
+
+#[test]
+fn test_config_path_from_env() {
+    let file = config_file("[log]\nlevel = \"error\"\n");
+    let path = file.path().to_str().unwrap().to_string();
+
+    let config = Config::load_with_env(None, &ConfigOverrides::default(), env(&[("TODO_API_CONFIG", &path)])).unwrap();
+    assert_eq!(config.log.level, LogLevel::Error);
+}
+
+#[test]
+fn test_invalid_values_are_reported() {
+    let load = |vars: &[(&str, &str)]| Config::load_with_env(None, &ConfigOverrides::default(), env(vars));
+
+    let error = load(&[("TODO_API_STORAGE", "postgres")]).unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidValue { name, .. } if name == "TODO_API_STORAGE"));
+    assert!(error.to_string().contains("memory, sqlite"), "{}", error);
+
+    assert!(matches!(load(&[("TODO_API_BIND", "localhost")]), Err(ConfigError::InvalidValue { .. })));
+    assert!(matches!(load(&[("TODO_API_MAX_BODY_BYTES", "-1")]), Err(ConfigError::InvalidValue { .. })));
+    assert!(matches!(load(&[("TODO_API_SHUTDOWN_TIMEOUT_SECS", "soon")]), Err(ConfigError::InvalidValue { .. })));
+    assert!(matches!(load(&[("TODO_API_LOG_FORMAT", "xml")]), Err(ConfigError::InvalidValue { .. })));
+
+    let error = load(&[("TODO_API_MAX_BODY_BYTES", "0")]).unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidValue { name, .. } if name == "limits.max_body_bytes"));
+
+    let error = load(&[("TODO_API_CORS_ORIGINS", "example.com")]).unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidValue { name, .. } if name == "cors.allowed_origins"));
+}
+
+#[test]
+fn test_invalid_file_is_reported() {
+    let unknown_field = config_file("[server]\nport = 3000\n");
+    let error = Config::load_with_env(Some(unknown_field.path()), &ConfigOverrides::default(), env(&[])).unwrap_err();
+    assert!(matches!(error, ConfigError::Parse { .. }));
+    assert!(error.to_string().contains("port"), "{}", error);
+
+    let missing = PathBuf::from("/nonexistent/todo_api.toml");
+    let error = Config::load_with_env(Some(&missing), &ConfigOverrides::default(), env(&[])).unwrap_err();
+    assert!(matches!(error, ConfigError::Read { .. }));
+}
+
+#[test]
+fn test_auth_settings() {
+    let file = config_file("[auth]\nrequire_api_key = false\n");
+    let config = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), env(&[])).unwrap();
+    assert!(!config.auth.require_api_key);
+    assert_eq!(config.auth.admin_key, None);
+
+    let vars = env(&[("TODO_API_REQUIRE_API_KEY", "true"), ("TODO_API_ADMIN_KEY", "bootstrap-admin-key-0123")]);
+    let config = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), &vars).unwrap();
+    assert!(config.auth.require_api_key);
+    assert_eq!(config.auth.admin_key.as_deref(), Some("bootstrap-admin-key-0123"));
+
+    let overrides = ConfigOverrides { require_api_key: true, ..ConfigOverrides::default() };
+    let config = Config::load_with_env(None, &overrides, env(&[])).unwrap();
+    assert!(config.auth.require_api_key);
+
+    // 短い管理用キーは拒否し、鍵そのものはエラーに載せない
+    let error = Config::load_with_env(None, &ConfigOverrides::default(), env(&[("TODO_API_ADMIN_KEY", "secret")])).unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidValue { name, .. } if name == "auth.admin_key"));
+    assert!(!error.to_string().contains("secret"), "{}", error);
+    let error = Config::load_with_env(None, &ConfigOverrides::default(), env(&[("TODO_API_REQUIRE_API_KEY", "yes")])).unwrap_err();
+    assert!(matches!(error, ConfigError::InvalidValue { .. }));
+}
+
+#[test]
+fn test_jwt_settings() {
+    let file = config_file(
+        r#"
+[auth.jwt]
+jwks_file = "/etc/todo/jwks.json"
+issuer = "https://auth.internal"
+audience = "todo-api"
+default_scope = "write"
+"#,
+    );
+    let config = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), env(&[])).unwrap();
+    assert!(config.auth.is_enabled());
+    assert_eq!(config.auth.jwt.jwks_file, Some(PathBuf::from("/etc/todo/jwks.json")));
+    assert_eq!(config.auth.jwt.default_scope, ApiKeyScope::Write);
+    assert_eq!(config.auth.jwt.leeway_secs, 30);
+
+    let vars = env(&[
+        ("TODO_API_JWT_JWKS_FILE", "/run/secrets/jwks.json"),
+        ("TODO_API_JWT_ISSUER", "https://sso.internal"),
+        ("TODO_API_JWT_AUDIENCE", "tasks"),
+    ]);
+    let config = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), &vars).unwrap();
+    assert_eq!(config.auth.jwt.jwks_file, Some(PathBuf::from("/run/secrets/jwks.json")));
+    assert_eq!(config.auth.jwt.issuer.as_deref(), Some("https://sso.internal"));
+    assert_eq!(config.auth.jwt.audience.as_deref(), Some("tasks"));
+
+    let load = |vars: &[(&str, &str)]| Config::load_with_env(None, &ConfigOverrides::default(), env(vars));
+    assert!(!load(&[]).unwrap().auth.is_enabled());
+    let key_only = [("TODO_API_JWT_KEY_FILE", "/etc/todo/jwt.key"), ("TODO_API_JWT_ISSUER", "i"), ("TODO_API_JWT_AUDIENCE", "a")];
+    let error = load(&key_only).unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidValue { name, .. } if name == "auth.jwt.algorithm"));
+    let with_algorithm = [key_only.as_slice(), &[("TODO_API_JWT_ALGORITHM", "rs256")]].concat();
+    assert_eq!(load(&with_algorithm).unwrap().auth.jwt.algorithm, Some(JwtAlgorithm::RS256));
+
+    let error = load(&[("TODO_API_JWT_JWKS_FILE", "/etc/todo/jwks.json"), ("TODO_API_JWT_ISSUER", "i")]).unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidValue { name, .. } if name == "auth.jwt.audience"));
+    assert!(matches!(load(&[("TODO_API_JWT_ALGORITHM", "ES256")]), Err(ConfigError::InvalidValue { .. })));
+    let admin = config_file("[auth.jwt]\ndefault_scope = \"admin\"\n");
+    let error = Config::load_with_env(Some(admin.path()), &ConfigOverrides::default(), env(&[])).unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidValue { name, .. } if name == "auth.jwt.default_scope"));
+    let both = [("TODO_API_JWT_KEY_FILE", "/a"), ("TODO_API_JWT_JWKS_FILE", "/b")];
+    assert!(matches!(load(&both), Err(ConfigError::InvalidValue { name, .. }) if name == "auth.jwt.key_file"));
+}
+
+#[test]
+fn test_session_settings() {
+    let config = Config::load_with_env(None, &ConfigOverrides::default(), env(&[])).unwrap();
+    assert_eq!(config.auth.session.ttl(), Duration::from_secs(8 * 60 * 60));
+    assert_eq!(config.auth.session.rotate_after(), Duration::from_secs(15 * 60));
+    assert!(config.auth.session.secure_cookie);
+    // セッションはAPIキーやJWTのように認証を必須にはしない
+    assert!(!config.auth.is_enabled());
+
+    let file = config_file(
+        r#"
+[auth.session]
+ttl_secs = 3600
+rotate_after_secs = 300
+secure_cookie = false
+"#,
+    );
+    let config = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), env(&[])).unwrap();
+    assert_eq!(config.auth.session.ttl(), Duration::from_secs(3600));
+    assert_eq!(config.auth.session.rotate_after(), Duration::from_secs(300));
+    assert!(!config.auth.session.secure_cookie);
+
+    let vars = env(&[("TODO_API_SESSION_TTL_SECS", "600")]);
+    let config = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), &vars).unwrap();
+    assert_eq!(config.auth.session.ttl(), Duration::from_secs(600));
+
+    let load = |vars: &[(&str, &str)]| Config::load_with_env(None, &ConfigOverrides::default(), env(vars));
+    assert!(matches!(load(&[("TODO_API_SESSION_TTL_SECS", "0")]), Err(ConfigError::InvalidValue { name, .. }) if name == "auth.session.ttl_secs"));
+    assert!(matches!(load(&[("TODO_API_SESSION_TTL_SECS", "soon")]), Err(ConfigError::InvalidValue { .. })));
+}
+
+#[test]
+fn test_rate_limit_settings() {
+    let config = Config::load_with_env(None, &ConfigOverrides::default(), env(&[])).unwrap();
+    assert!(!config.rate_limit.enabled);
+    assert_eq!(config.rate_limit.read, DEFAULT_READ_RATE_LIMIT);
+    assert_eq!(config.rate_limit.write, DEFAULT_WRITE_RATE_LIMIT);
+    assert_eq!(config.rate_limit.per_address, DEFAULT_PER_ADDRESS_RATE_LIMIT);
+
+    let file = config_file(
+        r#"
+[rate_limit]
+enabled = true
+trust_forwarded_for = true
+
+[rate_limit.write]
+requests_per_minute = 10
+burst = 5
+
+[rate_limit.per_address]
+requests_per_minute = 300
+burst = 50
+"#,
+    );
+    let config = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), env(&[])).unwrap();
+    assert!(config.rate_limit.enabled);
+    assert!(config.rate_limit.trust_forwarded_for);
+    assert_eq!(config.rate_limit.read, DEFAULT_READ_RATE_LIMIT);
+    assert_eq!(config.rate_limit.write, RateLimitBudget { requests_per_minute: 10, burst: 5 });
+    assert_eq!(config.rate_limit.per_address, RateLimitBudget { requests_per_minute: 300, burst: 50 });
+    let vars = env(&[("TODO_API_RATE_LIMIT_ENABLED", "false")]);
+    assert!(!Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), &vars).unwrap().rate_limit.enabled);
+
+    let file = config_file("[rate_limit.read]\nrequests_per_minute = 0\nburst = 5\n");
+    let error = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), env(&[])).unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidValue { name, .. } if name == "rate_limit.read.requests_per_minute"));
+    let file = config_file("[rate_limit.per_address]\nrequests_per_minute = 60\nburst = 0\n");
+    let error = Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), env(&[])).unwrap_err();
+    assert!(matches!(&error, ConfigError::InvalidValue { name, .. } if name == "rate_limit.per_address.burst"));
+    // 予算は両方の値を書く
+    let file = config_file("[rate_limit.read]\nburst = 5\n");
+    assert!(matches!(
+        Config::load_with_env(Some(file.path()), &ConfigOverrides::default(), env(&[])),
+        Err(ConfigError::Parse { .. })
+    ));
+}
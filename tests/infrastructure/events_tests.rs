@@ -0,0 +1,48 @@
+use tokio::sync::broadcast::error::TryRecvError;
+use todo_api::domain::model::event::{TaskChange, TaskChangeKind};
+use todo_api::domain::model::task::Task;
+use todo_api::domain::repository::event::TaskChangePublisher;
+use todo_api::infrastructure::events::TaskEventBroker;
+
+fn publish(broker: &TaskEventBroker, id: u64) {
+    broker.publish(TaskChange::new(TaskChangeKind::Created, Task::new(id, format!("Task {}", id)).unwrap()));
+}
+
+fn ids(events: &[todo_api::infrastructure::events::TaskEvent]) -> Vec<u64> {
+    events.iter().map(|event| event.id).collect()
+}
+
+#[test]
+fn test_replay_buffer_is_bounded() {
+    let broker = TaskEventBroker::with_capacity(2);
+    for id in 1..=3 {
+        publish(&broker, id);
+    }
+
+    // 新しい購読者には再送しない
+    assert!(broker.subscribe(None).unwrap().replay.is_empty());
+    // 指定したIDより後で、バッファに残っているものだけを再送する
+    assert_eq!(ids(&broker.subscribe(Some(0)).unwrap().replay), vec![2, 3]);
+    assert_eq!(ids(&broker.subscribe(Some(2)).unwrap().replay), vec![3]);
+    assert!(broker.subscribe(Some(3)).unwrap().replay.is_empty());
+    // 知らないID（再起動前など）ならバッファの全件
+    assert_eq!(ids(&broker.subscribe(Some(99)).unwrap().replay), vec![2, 3]);
+}
+
+#[test]
+fn test_subscribers_receive_events_after_subscribing() {
+    let broker = TaskEventBroker::new();
+    publish(&broker, 1);
+    let mut subscription = broker.subscribe(Some(0)).unwrap();
+    publish(&broker, 2);
+
+    assert_eq!(ids(&subscription.replay), vec![1]);
+    let event = subscription.receiver.try_recv().unwrap();
+    assert_eq!((event.id, event.kind, event.task.id), (2, TaskChangeKind::Created, 2));
+    assert!(matches!(subscription.receiver.try_recv(), Err(TryRecvError::Empty)));
+
+    // 停止すると受信口は閉じ、新しい購読もできない
+    broker.close();
+    assert!(matches!(subscription.receiver.try_recv(), Err(TryRecvError::Closed)));
+    assert!(broker.subscribe(None).is_none());
+}
@@ -0,0 +1,173 @@
+use std::io::Write;
+use std::path::PathBuf;
+use chrono::Utc;
+use jsonwebtoken::{encode, EncodingKey, Header};
+use serde_json::{json, Value};
+use todo_api::domain::model::api_key::ApiKeyScope;
+use todo_api::infrastructure::config::{JwtAlgorithm, JwtConfig};
+use todo_api::infrastructure::http::jwt::{looks_like_jwt, JwtKeyError, JwtVerifier, TokenError};
+
+const SECRET: &[u8] = b"shared-secret-for-tests-0123456789";
+const JWKS_SECRET: &[u8] = b"jwks-shared-secret-for-tests-0123";
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/jwt").join(name)
+}
+
+fn secret_file(content: &[u8]) -> tempfile::NamedTempFile {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(content).unwrap();
+    file
+}
+
+fn jwt_config(key_file: Option<PathBuf>, jwks_file: Option<PathBuf>, algorithm: Option<JwtAlgorithm>) -> JwtConfig {
+    JwtConfig {
+        key_file,
+        jwks_file,
+        algorithm,
+        issuer: Some("https://auth.internal".to_string()),
+        audience: Some("todo-api".to_string()),
+        ..JwtConfig::default()
+    }
+}
+
+fn claims(overrides: Value) -> Value {
+    let now = Utc::now().timestamp();
+    let mut claims = json!({"sub": "2", "iss": "https://auth.internal", "aud": "todo-api", "iat": now, "exp": now + 300});
+    for (name, value) in overrides.as_object().unwrap() {
+        if value.is_null() {
+            claims.as_object_mut().unwrap().remove(name);
+        } else {
+            claims[name] = value.clone();
+        }
+    }
+    claims
+}
+
+fn hs256(claims: &Value) -> String {
+    encode(&Header::default(), claims, &EncodingKey::from_secret(SECRET)).unwrap()
+}
+
+fn rs256(kid: Option<&str>, claims: &Value) -> String {
+    let mut header = Header::new(jsonwebtoken::Algorithm::RS256);
+    header.kid = kid.map(str::to_string);
+    let key = EncodingKey::from_rsa_pem(&std::fs::read(fixture("rs256_private.pem")).unwrap()).unwrap();
+    encode(&header, claims, &key).unwrap()
+}
+
+fn hs256_verifier() -> (JwtVerifier, tempfile::NamedTempFile) {
+    // 末尾の改行は鍵に含めない
+    let file = secret_file(&[SECRET, b"\n"].concat());
+    let config = jwt_config(Some(file.path().to_path_buf()), None, Some(JwtAlgorithm::HS256));
+    (JwtVerifier::from_config(&config).unwrap().unwrap(), file)
+}
+
+#[test]
+fn test_hs256_token_claims_are_validated() {
+    let (verifier, _file) = hs256_verifier();
+    let now = Utc::now().timestamp();
+
+    let verified = verifier.verify(&hs256(&claims(json!({})))).unwrap();
+    assert_eq!(verified.subject, "2");
+    assert_eq!(verified.scope, ApiKeyScope::Read);
+
+    let cases = [
+        (json!({"exp": now - 120}), TokenError::Expired),
+        (json!({"nbf": now + 120}), TokenError::NotYetValid),
+        (json!({"iss": "https://other.internal"}), TokenError::InvalidIssuer),
+        (json!({"aud": "billing-api"}), TokenError::InvalidAudience),
+    ];
+    for (overrides, expected) in cases {
+        assert_eq!(verifier.verify(&hs256(&claims(overrides.clone()))), Err(expected), "{}", overrides);
+    }
+    for missing in ["exp", "iss", "aud", "sub"] {
+        let token = hs256(&claims(json!({ missing: null })));
+        assert!(matches!(verifier.verify(&token), Err(TokenError::Malformed(_))), "{}", missing);
+    }
+
+    // 時計のずれは許容範囲内なら受け付ける
+    assert!(verifier.verify(&hs256(&claims(json!({"exp": now - 5})))).is_ok());
+
+    let forged = encode(&Header::default(), &claims(json!({})), &EncodingKey::from_secret(b"another-secret")).unwrap();
+    assert_eq!(verifier.verify(&forged), Err(TokenError::InvalidSignature));
+    assert!(matches!(verifier.verify("not.a.token"), Err(TokenError::Malformed(_))));
+}
+
+#[test]
+fn test_scope_claim_maps_to_the_widest_known_scope_up_to_write() {
+    let (verifier, _file) = hs256_verifier();
+    let scope_of = |scope: Value| verifier.verify(&hs256(&claims(json!({"scope": scope})))).unwrap().scope;
+
+    assert_eq!(scope_of(json!("read write")), ApiKeyScope::Write);
+    assert_eq!(scope_of(json!("profile admin")), ApiKeyScope::Write);
+    assert_eq!(scope_of(json!("admin")), ApiKeyScope::Write);
+    assert_eq!(scope_of(json!("profile email")), ApiKeyScope::Read);
+
+    let file = secret_file(SECRET);
+    let config = JwtConfig { default_scope: ApiKeyScope::Write, ..jwt_config(Some(file.path().to_path_buf()), None, Some(JwtAlgorithm::HS256)) };
+    let verifier = JwtVerifier::from_config(&config).unwrap().unwrap();
+    assert_eq!(verifier.verify(&hs256(&claims(json!({})))).unwrap().scope, ApiKeyScope::Write);
+}
+
+#[test]
+fn test_rs256_public_key_file() {
+    let config = jwt_config(Some(fixture("rs256_public.pem")), None, Some(JwtAlgorithm::RS256));
+    let verifier = JwtVerifier::from_config(&config).unwrap().unwrap();
+    assert_eq!(verifier.verify(&rs256(None, &claims(json!({"sub": "alice"})))).unwrap().subject, "alice");
+
+    // RS256 の鍵しかなければ、HS256 のトークンは受け付けない
+    assert_eq!(verifier.verify(&hs256(&claims(json!({})))), Err(TokenError::UnknownKey));
+}
+
+#[test]
+fn test_public_key_cannot_be_used_as_hmac_secret() {
+    let public_key = std::fs::read(fixture("rs256_public.pem")).unwrap();
+    let forged = encode(&Header::default(), &claims(json!({})), &EncodingKey::from_secret(&public_key)).unwrap();
+
+    let config = jwt_config(Some(fixture("rs256_public.pem")), None, Some(JwtAlgorithm::RS256));
+    let verifier = JwtVerifier::from_config(&config).unwrap().unwrap();
+    assert!(verifier.verify(&forged).is_err());
+}
+
+#[test]
+fn test_jwks_keys_are_selected_by_kid_and_algorithm() {
+    let verifier = JwtVerifier::from_config(&jwt_config(None, Some(fixture("jwks.json")), None)).unwrap().unwrap();
+
+    assert!(verifier.verify(&rs256(Some("rsa-2026"), &claims(json!({})))).is_ok());
+    assert!(verifier.verify(&rs256(None, &claims(json!({})))).is_ok());
+    assert_eq!(verifier.verify(&rs256(Some("rsa-2019"), &claims(json!({})))), Err(TokenError::UnknownKey));
+
+    let header = Header { kid: Some("hmac-2026".to_string()), ..Header::default() };
+    let token = encode(&header, &claims(json!({})), &EncodingKey::from_secret(JWKS_SECRET)).unwrap();
+    assert!(verifier.verify(&token).is_ok());
+    // HS256 の鍵と違う共有鍵で署名されたものは拒否する
+    assert_eq!(verifier.verify(&hs256(&claims(json!({})))), Err(TokenError::InvalidSignature));
+}
+
+#[test]
+fn test_key_files_are_checked_on_load() {
+    assert!(JwtVerifier::from_config(&JwtConfig::default()).unwrap().is_none());
+
+    let missing = jwt_config(Some(PathBuf::from("/nonexistent/jwt.key")), None, Some(JwtAlgorithm::HS256));
+    assert!(matches!(JwtVerifier::from_config(&missing), Err(JwtKeyError::Read { .. })));
+
+    let empty = secret_file(b"\n");
+    let config = jwt_config(Some(empty.path().to_path_buf()), None, Some(JwtAlgorithm::HS256));
+    assert!(matches!(JwtVerifier::from_config(&config), Err(JwtKeyError::Invalid { .. })));
+
+    let not_pem = secret_file(b"not a key");
+    let config = jwt_config(Some(not_pem.path().to_path_buf()), None, Some(JwtAlgorithm::RS256));
+    assert!(matches!(JwtVerifier::from_config(&config), Err(JwtKeyError::Invalid { .. })));
+
+    for jwks in [r#"{"keys": []}"#, r#"{"keys": [{"kty": "EC", "crv": "P-256", "x": "AA", "y": "AA"}]}"#, "[]"] {
+        let file = secret_file(jwks.as_bytes());
+        let config = jwt_config(None, Some(file.path().to_path_buf()), None);
+        assert!(matches!(JwtVerifier::from_config(&config), Err(JwtKeyError::Invalid { .. })), "{}", jwks);
+    }
+}
+
+#[test]
+fn test_jwt_shape_is_told_apart_from_api_keys() {
+    assert!(looks_like_jwt("aaa.bbb.ccc"));
+    assert!(!looks_like_jwt("todo_AbCdEf0123456789"));
+}
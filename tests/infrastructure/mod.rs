@@ -0,0 +1,4 @@
+pub mod config_tests;
+pub mod events_tests; 
+pub mod jwt_tests;
+pub mod rate_limit_tests;
\ No newline at end of file
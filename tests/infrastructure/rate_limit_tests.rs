@@ -0,0 +1,88 @@
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::{Duration, Instant};
+use todo_api::infrastructure::config::{RateLimitBudget, RateLimitConfig};
+use todo_api::infrastructure::http::rate_limit::{ClientKey, Operation, RateLimiter};
+
+fn limiter() -> RateLimiter {
+    RateLimiter::new(&RateLimitConfig {
+        enabled: true,
+        read: RateLimitBudget { requests_per_minute: 120, burst: 4 },
+        write: RateLimitBudget { requests_per_minute: 30, burst: 2 },
+        ..RateLimitConfig::default()
+    })
+}
+
+#[test]
+fn test_burst_is_spent_then_refilled_over_time() {
+    let limiter = limiter();
+    let key = ClientKey::ApiKey(1);
+    let start = Instant::now();
+
+    let remaining: Vec<u32> = (0..4).map(|_| limiter.check_at(key, Operation::Read, start).remaining).collect();
+    assert_eq!(remaining, vec![3, 2, 1, 0]);
+    let denied = limiter.check_at(key, Operation::Read, start);
+    assert!(!denied.allowed);
+    assert_eq!(denied.limit, 4);
+    // 1分に120件なら、0.5秒で1件分戻り、2秒で上限まで戻る
+    assert_eq!(denied.retry_after_secs, 1);
+    assert_eq!(denied.reset_secs, 2);
+
+    let later = limiter.check_at(key, Operation::Read, start + Duration::from_millis(500));
+    assert!(later.allowed);
+    assert_eq!(later.remaining, 0);
+    let full = limiter.check_at(key, Operation::Read, start + Duration::from_secs(60));
+    assert_eq!(full.remaining, 3);
+}
+
+#[test]
+fn test_budgets_are_separate_per_client_and_operation() {
+    let limiter = limiter();
+    let start = Instant::now();
+    let alice = ClientKey::User(2);
+
+    for _ in 0..2 {
+        assert!(limiter.check_at(alice, Operation::Write, start).allowed);
+    }
+    let denied = limiter.check_at(alice, Operation::Write, start);
+    assert!(!denied.allowed);
+    // 1分に30件なら、次の1件まで2秒
+    assert_eq!(denied.retry_after_secs, 2);
+
+    // 変更の予算を使い切っても参照はでき、他の依頼元の予算も減らない
+    assert!(limiter.check_at(alice, Operation::Read, start).allowed);
+    assert!(limiter.check_at(ClientKey::User(3), Operation::Write, start).allowed);
+    assert!(limiter.check_at(ClientKey::ApiKey(2), Operation::Write, start).allowed);
+    let ip = ClientKey::Ip(Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 7))));
+    assert!(limiter.check_at(ip, Operation::Write, start).allowed);
+}
+
+#[test]
+fn test_per_address_budget_counts_reads_and_writes_together() {
+    let limiter = RateLimiter::per_address(&RateLimitConfig {
+        enabled: true,
+        per_address: RateLimitBudget { requests_per_minute: 60, burst: 3 },
+        ..RateLimitConfig::default()
+    });
+    let ip = ClientKey::Ip(Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 7))));
+    let start = Instant::now();
+
+    for operation in [Operation::Read, Operation::Write, Operation::Read] {
+        assert!(limiter.check_at(ip, operation, start).allowed);
+    }
+    let denied = limiter.check_at(ip, Operation::Write, start);
+    assert!(!denied.allowed);
+    assert_eq!(denied.limit, 3);
+    assert_eq!(denied.retry_after_secs, 1);
+    assert!(limiter.check_at(ClientKey::Ip(None), Operation::Write, start).allowed);
+}
+
+#[test]
+fn test_operation_follows_the_method() {
+    use axum::http::Method;
+    for method in [Method::GET, Method::HEAD, Method::OPTIONS] {
+        assert_eq!(Operation::of(&method), Operation::Read, "{}", method);
+    }
+    for method in [Method::POST, Method::PUT, Method::PATCH, Method::DELETE] {
+        assert_eq!(Operation::of(&method), Operation::Write, "{}", method);
+    }
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+use axum::body::Body;
+use axum::http::{header::{CONTENT_TYPE, WWW_AUTHENTICATE}, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::config::Config;
+use todo_api::infrastructure::events::TaskEventBroker;
+use todo_api::infrastructure::http::generated_routes::{create_generated_router, create_generated_router_with_config};
+use todo_api::interface::gateway::inmemory::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::SqliteTaskRepository;
+use tower::ServiceExt;
+
+const ADMIN_KEY: &str = "bootstrap-admin-key-0123";
+
+fn auth_config() -> Config {
+    let mut config = Config::default();
+    config.auth.require_api_key = true;
+    config.auth.admin_key = Some(ADMIN_KEY.to_string());
+    config
+}
+
+fn app_with_auth() -> Router {
+    create_generated_router_with_config(InMemoryTaskRepository::new(), Arc::new(TaskEventBroker::new()), &auth_config(), None)
+}
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, Option<String>, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let challenge = response.headers().get(WWW_AUTHENTICATE).map(|value| value.to_str().unwrap().to_string());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, challenge, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str, key: Option<&str>, body: Option<serde_json::Value>) -> Request<Body> {
+    let builder = Request::builder().method(method).uri(uri).header("host", "localhost");
+    let builder = match key {
+        Some(key) => builder.header("authorization", format!("Bearer {}", key)),
+        None => builder,
+    };
+    match body {
+        Some(body) => builder.header(CONTENT_TYPE, "application/json").body(Body::from(body.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    }
+}
+
+/// 管理用キーで鍵を発行し、鍵そのものを返す
+async fn issue(app: &Router, name: &str, scope: &str, user_id: Option<u64>) -> String {
+    let body = json!({"name": name, "scope": scope, "user_id": user_id});
+    let (status, _, body) = send(app, request("POST", "/api-keys", Some(ADMIN_KEY), Some(body))).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", body);
+    body["key"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_requests_without_valid_key_are_rejected() {
+    let app = app_with_auth();
+
+    let (status, challenge, body) = send(&app, request("GET", "/tasks", None, None)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "AUTHENTICATION_REQUIRED");
+    assert_eq!(challenge.as_deref(), Some("Bearer"));
+
+    let (status, challenge, body) = send(&app, request("DELETE", "/tasks/1", Some("todo_guessed"), None)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "INVALID_API_KEY");
+    assert_eq!(challenge.as_deref(), Some("Bearer error=\"invalid_token\""));
+
+    // 死活監視とバージョンは鍵なしで呼べる
+    for path in ["/healthz", "/readyz", "/version"] {
+        let (status, _, _) = send(&app, request("GET", path, None, None)).await;
+        assert_eq!(status, StatusCode::OK, "{}", path);
+    }
+}
+
+#[tokio::test]
+async fn test_scopes_are_checked_per_operation() {
+    let app = app_with_auth();
+    let reader = issue(&app, "dashboard", "read", None).await;
+    let writer = issue(&app, "ci", "write", None).await;
+    assert!(reader.starts_with("todo_"));
+
+    let (status, _, _) = send(&app, request("POST", "/tasks", Some(&writer), Some(json!({"description": "Task"})))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _, body) = send(&app, request("GET", "/tasks", Some(&reader), None)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    // 参照だけの鍵ではタスクを消せない
+    let (status, challenge, body) = send(&app, request("DELETE", "/tasks/1", Some(&reader), None)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["code"], "INSUFFICIENT_SCOPE");
+    assert_eq!(challenge.as_deref(), Some("Bearer error=\"insufficient_scope\", scope=\"write\""));
+    let (status, _, _) = send(&app, request("GET", "/tasks/1", Some(&reader), None)).await;
+    assert_eq!(status, StatusCode::OK);
+
+    // APIキーの管理には admin の範囲が要る
+    let (status, _, _) = send(&app, request("GET", "/api-keys", Some(&writer), None)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let admin = issue(&app, "ops", "admin", None).await;
+    let (status, _, body) = send(&app, request("GET", "/api-keys", Some(&admin), None)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_metrics_require_admin_scope() {
+    let app = app_with_auth();
+    let reader = issue(&app, "dashboard", "read", None).await;
+    let writer = issue(&app, "ci", "write", None).await;
+    let admin = issue(&app, "ops", "admin", None).await;
+
+    // 全利用者のタスク数が見えるため、参照や変更の鍵では取得できない
+    for key in [&reader, &writer] {
+        let (status, challenge, body) = send(&app, request("GET", "/metrics", Some(key), None)).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(body["code"], "INSUFFICIENT_SCOPE");
+        assert_eq!(challenge.as_deref(), Some("Bearer error=\"insufficient_scope\", scope=\"admin\""));
+    }
+    let (status, _, _) = send(&app, request("GET", "/metrics", Some(&admin), None)).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _, _) = send(&app, request("GET", "/metrics", Some(ADMIN_KEY), None)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_key_can_be_sent_as_x_api_key() {
+    let app = app_with_auth();
+    let key = issue(&app, "script", "read", None).await;
+
+    let request = Request::get("/tasks").header("host", "localhost").header("x-api-key", &key).body(Body::empty()).unwrap();
+    let (status, _, _) = send(&app, request).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_revoked_key_is_rejected_and_secret_is_never_listed() {
+    let app = app_with_auth();
+    let (_, _, created) = send(&app, request("POST", "/api-keys", Some(ADMIN_KEY), Some(json!({"name": "ci", "scope": "write"})))).await;
+    let key = created["key"].as_str().unwrap().to_string();
+    assert_eq!(created["api_key"]["prefix"], key[..12]);
+    assert_eq!(created["api_key"]["revoked_at"], serde_json::Value::Null);
+
+    let (_, _, body) = send(&app, request("GET", "/api-keys", Some(ADMIN_KEY), None)).await;
+    assert!(!body.to_string().contains(&key));
+    assert!(body[0].get("secret_hash").is_none());
+
+    let id = created["api_key"]["id"].as_u64().unwrap();
+    let (status, _, _) = send(&app, request("DELETE", &format!("/api-keys/{}", id), Some(ADMIN_KEY), None)).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _, body) = send(&app, request("GET", "/tasks", Some(&key), None)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "INVALID_API_KEY");
+
+    let (status, _, body) = send(&app, request("DELETE", "/api-keys/99", Some(ADMIN_KEY), None)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "API_KEY_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn test_key_acts_as_its_user() {
+    let repository = SqliteTaskRepository::open_in_memory().unwrap();
+    let app = create_generated_router_with_config(repository, Arc::new(TaskEventBroker::new()), &auth_config(), None);
+    let (_, _, alice) = send(&app, request("POST", "/users", Some(ADMIN_KEY), Some(json!({"name": "alice"})))).await;
+    let alice_key = issue(&app, "alice", "write", alice["id"].as_u64()).await;
+
+    // 鍵の利用者で処理され、X-User-Id では別の利用者になれない
+    let mut create = request("POST", "/tasks", Some(&alice_key), Some(json!({"description": "Alice's"})));
+    create.headers_mut().insert("x-user-id", "1".parse().unwrap());
+    let (_, _, task) = send(&app, create).await;
+    assert_eq!(task["owner_id"], alice["id"]);
+    let (_, _, me) = send(&app, request("GET", "/users/me", Some(&alice_key), None)).await;
+    assert_eq!(me["name"], "alice");
+    let (_, _, body) = send(&app, request("GET", "/tasks", Some(ADMIN_KEY), None)).await;
+    assert!(body.as_array().unwrap().is_empty());
+
+    let (status, _, body) = send(&app, request("POST", "/api-keys", Some(ADMIN_KEY), Some(json!({"name": "x", "scope": "read", "user_id": 9})))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "USER_NOT_FOUND");
+    assert_eq!(body["field"], "user_id");
+}
+
+#[tokio::test]
+async fn test_auth_is_off_by_default() {
+    let app = create_generated_router();
+    let (status, _, _) = send(&app, request("DELETE", "/tasks/trash", None, None)).await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _, body) = send(&app, request("POST", "/api-keys", None, Some(json!({"name": "first", "scope": "admin"})))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["api_key"]["user_id"], 1);
+}
@@ -0,0 +1,143 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::http::generated_routes::{create_generated_router, create_generated_router_with};
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn get(uri: &str) -> Request<Body> {
+    Request::get(uri).header("host", "localhost").body(Body::empty()).unwrap()
+}
+
+fn post_json(uri: &str, body: &serde_json::Value) -> Request<Body> {
+    Request::post(uri)
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn statuses(body: &serde_json::Value) -> Vec<&str> {
+    body["results"].as_array().unwrap().iter().map(|r| r["status"].as_str().unwrap()).collect()
+}
+
+async fn assert_bulk_operations(app: Router) {
+    let batch = json!({"operations": [
+        {"op": "create", "task": {"description": "First", "priority": "high"}},
+        {"op": "create", "task": {"description": "Second"}},
+        {"op": "update", "id": 1, "changes": {"description": "First (edited)"}},
+        {"op": "complete", "id": 2},
+    ]});
+    let (status, body) = send(&app, post_json("/tasks/bulk", &batch)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["committed"], json!(true));
+    assert_eq!(statuses(&body), vec!["succeeded"; 4]);
+    assert_eq!(body["results"][0]["op"], json!("create"));
+    assert_eq!(body["results"][2]["index"], json!(2));
+    assert_eq!(body["results"][2]["task"]["description"], json!("First (edited)"));
+    assert_eq!(body["results"][3]["task"]["completed"], json!(true));
+
+    let batch = json!({"operations": [
+        {"op": "uncomplete", "id": 2},
+        {"op": "delete", "id": 1},
+    ]});
+    let (_, body) = send(&app, post_json("/tasks/bulk", &batch)).await;
+    assert_eq!(statuses(&body), vec!["succeeded"; 2]);
+    // 削除の結果には削除したタスクが入る
+    assert_eq!(body["results"][1]["task"]["id"], json!(1));
+
+    let (_, tasks) = send(&app, get("/tasks")).await;
+    assert_eq!(tasks.as_array().unwrap().len(), 1);
+    assert_eq!(tasks[0]["completed"], json!(false));
+}
+
+#[tokio::test]
+async fn test_bulk_operations_in_memory() {
+    assert_bulk_operations(create_generated_router()).await;
+}
+
+#[tokio::test]
+async fn test_bulk_operations_sqlite() {
+    assert_bulk_operations(create_generated_router_with(SqliteTaskRepository::open_in_memory().unwrap())).await;
+}
+
+#[tokio::test]
+async fn test_atomic_bulk_rolls_back_on_failure() {
+    let app = create_generated_router();
+    send(&app, post_json("/tasks", &json!({"description": "Existing"}))).await;
+
+    let batch = json!({"operations": [
+        {"op": "create", "task": {"description": "New"}},
+        {"op": "complete", "id": 1},
+        {"op": "update", "id": 1, "changes": {"description": ""}},
+        {"op": "delete", "id": 1},
+    ]});
+    let (status, body) = send(&app, post_json("/tasks/bulk", &batch)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["committed"], json!(false));
+    assert_eq!(statuses(&body), vec!["rolled_back", "rolled_back", "failed", "skipped"]);
+    assert_eq!(body["results"][2]["error"]["code"], json!("DESCRIPTION_EMPTY"));
+    assert_eq!(body["results"][2]["error"]["status"], json!(400));
+    assert!(body["results"][3].get("task").is_none());
+
+    let (_, tasks) = send(&app, get("/tasks")).await;
+    assert_eq!(tasks.as_array().unwrap().len(), 1);
+    assert_eq!(tasks[0]["completed"], json!(false));
+    assert_eq!(tasks[0]["version"], json!(1));
+}
+
+#[tokio::test]
+async fn test_best_effort_bulk_applies_what_it_can() {
+    let app = create_generated_router();
+    send(&app, post_json("/tasks", &json!({"description": "Existing"}))).await;
+
+    let batch = json!({"atomic": false, "operations": [
+        {"op": "delete", "id": 42},
+        {"op": "complete", "id": 1},
+        {"op": "create", "task": {"description": "New"}},
+    ]});
+    let (status, body) = send(&app, post_json("/tasks/bulk", &batch)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["committed"], json!(true));
+    assert_eq!(statuses(&body), vec!["failed", "succeeded", "succeeded"]);
+    assert_eq!(body["results"][0]["error"]["code"], json!("TASK_NOT_FOUND"));
+    assert_eq!(body["results"][0]["error"]["status"], json!(404));
+
+    let (_, tasks) = send(&app, get("/tasks")).await;
+    assert_eq!(tasks.as_array().unwrap().len(), 2);
+    assert_eq!(tasks[0]["completed"], json!(true));
+}
+
+#[tokio::test]
+async fn test_malformed_bulk_is_rejected() {
+    let app = create_generated_router();
+
+    for (operation, reason) in [
+        (json!({"op": "complete"}), "operations[0]: id is required"),
+        (json!({"op": "create", "id": 1, "task": {"description": "Task"}}), "operations[0]: id is not allowed for create"),
+        (json!({"op": "create"}), "operations[0]: task is required for create"),
+        (json!({"op": "delete", "id": 1, "changes": {"completed": true}}), "operations[0]: changes is only allowed for update"),
+    ] {
+        let (status, body) = send(&app, post_json("/tasks/bulk", &json!({"operations": [operation]}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{}", reason);
+        assert_eq!(body["code"], json!("INVALID_OPERATION"));
+        assert_eq!(body["detail"], json!(reason));
+    }
+
+    let (status, _) = send(&app, post_json("/tasks/bulk", &json!({"operations": []}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _) = send(&app, post_json("/tasks/bulk", &json!({"operations": [{"op": "archive", "id": 1}]}))).await;
+    assert!(status.is_client_error());
+
+    let (_, tasks) = send(&app, get("/tasks")).await;
+    assert_eq!(tasks, json!([]));
+}
@@ -0,0 +1,120 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str) -> Request<Body> {
+    Request::builder().method(method).uri(uri).header("host", "localhost").body(Body::empty()).unwrap()
+}
+
+fn with_json(method: &str, uri: &str, body: &serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn ids(body: &serde_json::Value) -> Vec<i64> {
+    body.as_array().unwrap().iter().map(|task| task["id"].as_i64().unwrap()).collect()
+}
+
+/// 1: Design ← 2: Build ← 3: Ship、4: Write docs（依存なし）
+async fn create_pipeline(app: &Router) {
+    for description in ["Design", "Build", "Ship", "Write docs"] {
+        send(app, with_json("POST", "/tasks", &json!({"description": description}))).await;
+    }
+    send(app, with_json("POST", "/tasks/2/dependencies", &json!({"depends_on": [1]}))).await;
+    send(app, with_json("POST", "/tasks/3/dependencies", &json!({"depends_on": [2]}))).await;
+}
+
+#[tokio::test]
+async fn test_dependencies_block_completion_until_done() {
+    let app = create_generated_router();
+    create_pipeline(&app).await;
+
+    let (status, body) = send(&app, request("GET", "/tasks/3")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["depends_on"], json!([2]));
+    assert_eq!(body["blocked"], true);
+
+    let (status, body) = send(&app, request("PUT", "/tasks/2/complete")).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "TASK_BLOCKED");
+
+    let (status, body) = send(&app, with_json("PUT", "/tasks/2", &json!({"completed": true}))).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "TASK_BLOCKED");
+
+    let (status, body) = send(&app, request("PUT", "/tasks/1/complete")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["blocked"], false);
+    let (status, body) = send(&app, request("PUT", "/tasks/2/complete")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["completed"], true);
+
+    let (_, body) = send(&app, request("GET", "/tasks")).await;
+    let blocked: Vec<bool> = body.as_array().unwrap().iter().map(|task| task["blocked"].as_bool().unwrap()).collect();
+    assert_eq!(blocked, vec![false, false, false, false]);
+}
+
+#[tokio::test]
+async fn test_force_completes_a_blocked_task() {
+    let app = create_generated_router();
+    create_pipeline(&app).await;
+
+    let (status, body) = send(&app, request("PUT", "/tasks/3/complete?force=true")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["completed"], true);
+    assert_eq!(body["blocked"], false);
+}
+
+#[tokio::test]
+async fn test_invalid_dependencies_are_rejected() {
+    let app = create_generated_router();
+    create_pipeline(&app).await;
+
+    let (status, body) = send(&app, with_json("POST", "/tasks/1/dependencies", &json!({"depends_on": [3]}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "DEPENDENCY_CYCLE");
+    assert_eq!(body["field"], "depends_on");
+
+    let (status, body) = send(&app, with_json("POST", "/tasks/1/dependencies", &json!({"depends_on": [99]}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "DEPENDENCY_NOT_FOUND");
+
+    let (status, _) = send(&app, with_json("POST", "/tasks/99/dependencies", &json!({"depends_on": [1]}))).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_ready_lists_unblocked_pending_tasks() {
+    let app = create_generated_router();
+    create_pipeline(&app).await;
+
+    let (status, body) = send(&app, request("GET", "/tasks/ready")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ids(&body), vec![1, 4]);
+
+    send(&app, request("PUT", "/tasks/1/complete")).await;
+    let (_, body) = send(&app, request("GET", "/tasks/ready")).await;
+    assert_eq!(ids(&body), vec![2, 4]);
+
+    // 依存を外すとすぐに着手できる
+    let (status, body) = send(&app, request("DELETE", "/tasks/3/dependencies/2")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["depends_on"], json!([]));
+    let (_, body) = send(&app, request("GET", "/tasks/ready")).await;
+    assert_eq!(ids(&body), vec![2, 3, 4]);
+}
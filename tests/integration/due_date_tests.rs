@@ -0,0 +1,103 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use chrono::{Duration, Utc};
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+async fn send(app: &Router, method: &str, uri: &str, body: Option<String>) -> (StatusCode, serde_json::Value) {
+    let mut builder = Request::builder().method(method).uri(uri).header("host", "localhost");
+    if body.is_some() {
+        builder = builder.header(CONTENT_TYPE, "application/json");
+    }
+    let request = builder.body(body.map(Body::from).unwrap_or_else(Body::empty)).unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null))
+}
+
+fn ids(body: &serde_json::Value) -> Vec<i64> {
+    body.as_array().unwrap().iter().map(|t| t["id"].as_i64().unwrap()).collect()
+}
+
+#[tokio::test]
+async fn test_priority_and_due_at_round_trip() {
+    let app = create_generated_router();
+    let due_at = (Utc::now() + Duration::days(1)).to_rfc3339();
+
+    let body = serde_json::json!({"description": "Planned", "priority": "high", "due_at": due_at});
+    let (status, task) = send(&app, "POST", "/tasks", Some(body.to_string())).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(task["priority"], "high");
+    assert!(task["due_at"].is_string());
+
+    // 優先度のみの更新と、nullによる期限の解除
+    let uri = format!("/tasks/{}", task["id"]);
+    let (status, task) = send(&app, "PUT", &uri, Some(r#"{"priority":"urgent"}"#.to_string())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["priority"], "urgent");
+    assert!(task["due_at"].is_string());
+
+    let (status, task) = send(&app, "PUT", &uri, Some(r#"{"due_at":null}"#.to_string())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(task.get("due_at").is_none());
+    assert_eq!(task["priority"], "urgent");
+}
+
+#[tokio::test]
+async fn test_due_at_in_the_past_is_rejected() {
+    let app = create_generated_router();
+    let due_at = (Utc::now() - Duration::hours(1)).to_rfc3339();
+
+    let body = serde_json::json!({"description": "Late", "due_at": due_at});
+    let (status, problem) = send(&app, "POST", "/tasks", Some(body.to_string())).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(problem["code"], "DUE_AT_BEFORE_CREATION");
+    assert_eq!(problem["field"], "due_at");
+}
+
+#[tokio::test]
+async fn test_unknown_priority_is_rejected() {
+    let app = create_generated_router();
+
+    let body = serde_json::json!({"description": "Task", "priority": "critical"});
+    let (status, _) = send(&app, "POST", "/tasks", Some(body.to_string())).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn test_overdue_and_due_soon() {
+    let app = create_generated_router();
+    let now = Utc::now();
+    for (description, due_at) in [
+        ("Soon", Some(now + Duration::milliseconds(500))),
+        ("Tomorrow", Some(now + Duration::hours(20))),
+        ("Next week", Some(now + Duration::days(7))),
+        ("Someday", None),
+    ] {
+        let body = serde_json::json!({"description": description, "due_at": due_at.map(|d| d.to_rfc3339())});
+        let (status, _) = send(&app, "POST", "/tasks", Some(body.to_string())).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    let (status, body) = send(&app, "GET", "/tasks/due-soon", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ids(&body), vec![1, 2]);
+
+    let (_, body) = send(&app, "GET", "/tasks/due-soon?hours=200", None).await;
+    assert_eq!(ids(&body), vec![1, 2, 3]);
+
+    let (_, body) = send(&app, "GET", "/tasks/overdue", None).await;
+    assert!(ids(&body).is_empty());
+
+    // 期限を過ぎると期限切れの一覧に移る
+    tokio::time::sleep(std::time::Duration::from_millis(700)).await;
+    let (_, body) = send(&app, "GET", "/tasks/overdue", None).await;
+    assert_eq!(ids(&body), vec![1]);
+    let (_, body) = send(&app, "GET", "/tasks/due-soon", None).await;
+    assert_eq!(ids(&body), vec![2]);
+
+    let (status, _) = send(&app, "GET", "/tasks/due-soon?hours=0", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
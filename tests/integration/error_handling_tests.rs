@@ -1,22 +1,29 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
 use todo_api::domain::model::task::{CreateTask, UpdateTask};
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
 use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
-use todo_api::usecase::task::TaskUsecase;
+use todo_api::usecase::task::{TaskError, TaskUsecaseImpl};
+use tower::ServiceExt;
 
 #[tokio::test]
 async fn test_validation_errors() {
     let repository = InMemoryTaskRepository::new();
-    let usecase = TaskUsecase::new(repository);
+    let usecase = TaskUsecaseImpl::new(repository);
     
     // 空の説明でタスクを作成しようとするとエラー
     let empty_task = CreateTask {
         description: "".to_string(),
+        ..Default::default()
     };
     let result = usecase.create_task(empty_task).await;
-    assert!(result.is_err());
+    assert!(matches!(result, Err(TaskError::Validation(_))));
     
     // 空白のみの説明でタスクを作成しようとするとエラー
     let whitespace_task = CreateTask {
         description: "   ".to_string(),
+        ..Default::default()
     };
     let result = usecase.create_task(whitespace_task).await;
     assert!(result.is_err());
@@ -25,6 +32,7 @@ async fn test_validation_errors() {
     let long_description = "a".repeat(1001);
     let long_task = CreateTask {
         description: long_description,
+        ..Default::default()
     };
     let result = usecase.create_task(long_task).await;
     assert!(result.is_err());
@@ -33,29 +41,134 @@ async fn test_validation_errors() {
 #[tokio::test]
 async fn test_not_found_errors() {
     let repository = InMemoryTaskRepository::new();
-    let usecase = TaskUsecase::new(repository);
+    let usecase = TaskUsecaseImpl::new(repository);
     
     // 存在しないタスクを取得しようとするとエラー
     let result = usecase.get_task_by_id(999).await;
-    assert!(result.is_err());
+    assert!(matches!(result, Err(TaskError::NotFound(999))));
     
     // 存在しないタスクを更新しようとするとエラー
     let update_task = UpdateTask {
         description: Some("Updated".to_string()),
         completed: None,
+        ..Default::default()
     };
     let result = usecase.update_task(999, update_task).await;
-    assert!(result.is_err());
+    assert!(matches!(result, Err(TaskError::NotFound(999))));
     
     // 存在しないタスクを削除しようとするとエラー
     let result = usecase.delete_task(999).await;
-    assert!(result.is_err());
+    assert!(matches!(result, Err(TaskError::NotFound(999))));
     
     // 存在しないタスクを完了しようとするとエラー
     let result = usecase.complete_task(999).await;
-    assert!(result.is_err());
+    assert!(matches!(result, Err(TaskError::NotFound(999))));
     
     // 存在しないタスクを未完了にしようとするとエラー
     let result = usecase.uncomplete_task(999).await;
-    assert!(result.is_err());
-} 
\ No newline at end of file
+    assert!(matches!(result, Err(TaskError::NotFound(999))));
+}
+
+#[tokio::test]
+async fn test_empty_update_is_invalid_operation() {
+    let repository = InMemoryTaskRepository::new();
+    let usecase = TaskUsecaseImpl::new(repository);
+    let task = usecase.create_task(CreateTask { description: "Task".to_string(), ..Default::default() }).await.unwrap();
+
+    // 何も変更しない更新は不正な操作
+    let update_task = UpdateTask {
+        description: None,
+        completed: None,
+        ..Default::default()
+    };
+    let result = usecase.update_task(task.id, update_task).await;
+    assert!(matches!(result, Err(TaskError::InvalidOperation(_))));
+}
+
+async fn send(app: &Router, method: &str, uri: &str, body: Option<&str>) -> (StatusCode, serde_json::Value) {
+    let mut builder = Request::builder().method(method).uri(uri).header("host", "localhost");
+    if body.is_some() {
+        builder = builder.header(CONTENT_TYPE, "application/json");
+    }
+    let request = builder
+        .body(body.map(|b| Body::from(b.to_string())).unwrap_or_else(Body::empty))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null))
+}
+
+#[tokio::test]
+async fn test_http_not_found_status() {
+    let app = create_generated_router();
+
+    // 存在しないタスクへの操作はすべて404になる
+    for (method, uri, body) in [
+        ("GET", "/tasks/999", None),
+        ("PUT", "/tasks/999", Some(r#"{"description":"Updated"}"#)),
+        ("DELETE", "/tasks/999", None),
+        ("PUT", "/tasks/999/complete", None),
+        ("PUT", "/tasks/999/uncomplete", None),
+    ] {
+        let (status, problem) = send(&app, method, uri, body).await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "{} {}", method, uri);
+        assert_eq!(problem["code"], "TASK_NOT_FOUND", "{} {}", method, uri);
+    }
+}
+
+#[tokio::test]
+async fn test_http_validation_status() {
+    let app = create_generated_router();
+    let (status, task) = send(&app, "POST", "/tasks", Some(r#"{"description":"Task"}"#)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let uri = format!("/tasks/{}", task["id"]);
+
+    let (status, problem) = send(&app, "POST", "/tasks", Some(r#"{"description":""}"#)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(problem["code"], "DESCRIPTION_EMPTY");
+
+    let (status, problem) = send(&app, "PUT", &uri, Some(r#"{"description":"  "}"#)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(problem["code"], "DESCRIPTION_EMPTY");
+}
+
+#[tokio::test]
+async fn test_http_empty_update_status() {
+    let app = create_generated_router();
+    let (_, task) = send(&app, "POST", "/tasks", Some(r#"{"description":"Task"}"#)).await;
+    let uri = format!("/tasks/{}", task["id"]);
+
+    let (status, problem) = send(&app, "PUT", &uri, Some("{}")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(problem["code"], "INVALID_OPERATION");
+    assert_eq!(problem["detail"], "Update task cannot be empty");
+}
+
+#[tokio::test]
+async fn test_http_negative_path_id_status() {
+    let app = create_generated_router();
+    send(&app, "POST", "/tasks", Some(r#"{"description":"Task"}"#)).await;
+
+    // 負のIDは別のIDとして扱わず、どのパスでも400にする
+    for (method, uri, field) in [
+        ("GET", "/tasks/-1", "id"),
+        ("DELETE", "/tasks/-1", "id"),
+        ("PUT", "/tasks/-1/complete", "id"),
+        ("GET", "/projects/-1", "id"),
+        ("DELETE", "/tasks/1/dependencies/-2", "dependency_id"),
+    ] {
+        let (status, problem) = send(&app, method, uri, None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{} {}", method, uri);
+        assert_eq!(problem["code"], "INVALID_PATH_ID", "{} {}", method, uri);
+        assert_eq!(problem["field"], field, "{} {}", method, uri);
+    }
+    let (_, problem) = send(&app, "GET", "/tasks/-1", None).await;
+    assert!(!problem["detail"].as_str().unwrap().contains("18446744073709551615"));
+
+    // 本文で指定する依存先のIDも同じ扱いにする
+    let (status, problem) = send(&app, "POST", "/tasks/1/dependencies", Some(r#"{"depends_on":[-3]}"#)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(problem["code"], "INVALID_PATH_ID");
+    assert_eq!(problem["field"], "depends_on");
+}
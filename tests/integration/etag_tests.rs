@@ -0,0 +1,117 @@
+use axum::body::Body;
+use axum::http::{header::{CONTENT_TYPE, ETAG, IF_MATCH, IF_NONE_MATCH}, Request, StatusCode};
+use axum::Router;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+struct TestResponse {
+    status: StatusCode,
+    etag: Option<String>,
+    body: serde_json::Value,
+}
+
+async fn send(app: &Router, request: Request<Body>) -> TestResponse {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let etag = response.headers().get(ETAG).map(|v| v.to_str().unwrap().to_string());
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    TestResponse {
+        status,
+        etag,
+        body: serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null),
+    }
+}
+
+fn request(method: &str, uri: &str, condition: Option<(&str, &str)>, body: Option<&str>) -> Request<Body> {
+    let mut builder = Request::builder().method(method).uri(uri).header("host", "localhost");
+    if let Some((name, value)) = condition {
+        builder = builder.header(name, value);
+    }
+    match body {
+        Some(body) => builder
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    }
+}
+
+async fn create_task(app: &Router) -> String {
+    let response = send(app, request("POST", "/tasks", None, Some(r#"{"description":"Task 1"}"#))).await;
+    assert_eq!(response.status, StatusCode::CREATED);
+    assert_eq!(response.body["version"], 1);
+    response.etag.unwrap()
+}
+
+#[tokio::test]
+async fn test_single_task_responses_carry_etag() {
+    let app = create_generated_router();
+    let etag = create_task(&app).await;
+    assert_eq!(etag, "\"1\"");
+
+    let response = send(&app, request("GET", "/tasks/1", None, None)).await;
+    assert_eq!(response.etag.as_deref(), Some("\"1\""));
+
+    let response = send(&app, request("PUT", "/tasks/1/complete", None, None)).await;
+    assert_eq!(response.etag.as_deref(), Some("\"2\""));
+    assert_eq!(response.body["version"], 2);
+}
+
+#[tokio::test]
+async fn test_if_none_match_returns_not_modified() {
+    let app = create_generated_router();
+    let etag = create_task(&app).await;
+
+    let response = send(&app, request("GET", "/tasks/1", Some((IF_NONE_MATCH.as_str(), &etag)), None)).await;
+    assert_eq!(response.status, StatusCode::NOT_MODIFIED);
+    assert_eq!(response.etag.as_deref(), Some(etag.as_str()));
+    assert_eq!(response.body, serde_json::Value::Null);
+
+    // 弱い比較で判定する
+    let weak = format!("W/{}", etag);
+    let response = send(&app, request("GET", "/tasks/1", Some((IF_NONE_MATCH.as_str(), &weak)), None)).await;
+    assert_eq!(response.status, StatusCode::NOT_MODIFIED);
+
+    send(&app, request("PUT", "/tasks/1/complete", None, None)).await;
+    let response = send(&app, request("GET", "/tasks/1", Some((IF_NONE_MATCH.as_str(), &etag)), None)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body["completed"], true);
+}
+
+#[tokio::test]
+async fn test_if_match_prevents_lost_update() {
+    let app = create_generated_router();
+    let etag = create_task(&app).await;
+    let if_match = Some((IF_MATCH.as_str(), etag.as_str()));
+
+    // 1人目の更新は成功する
+    let first = send(&app, request("PUT", "/tasks/1", if_match, Some(r#"{"description":"First"}"#))).await;
+    assert_eq!(first.status, StatusCode::OK);
+    assert_eq!(first.etag.as_deref(), Some("\"2\""));
+
+    // 同じETagを使った2人目の更新は412になり、上書きされない
+    let second = send(&app, request("PUT", "/tasks/1", if_match, Some(r#"{"description":"Second"}"#))).await;
+    assert_eq!(second.status, StatusCode::PRECONDITION_FAILED);
+    assert_eq!(second.body["code"], "PRECONDITION_FAILED");
+    let current = send(&app, request("GET", "/tasks/1", None, None)).await;
+    assert_eq!(current.body["description"], "First");
+
+    for (method, uri) in [("PUT", "/tasks/1/complete"), ("PUT", "/tasks/1/uncomplete"), ("DELETE", "/tasks/1")] {
+        let response = send(&app, request(method, uri, if_match, None)).await;
+        assert_eq!(response.status, StatusCode::PRECONDITION_FAILED, "{} {}", method, uri);
+    }
+
+    // 現在のETagやワイルドカードなら通る
+    let response = send(&app, request("PUT", "/tasks/1/complete", Some((IF_MATCH.as_str(), "\"9\", \"2\"")), None)).await;
+    assert_eq!(response.status, StatusCode::OK);
+    let response = send(&app, request("DELETE", "/tasks/1", Some((IF_MATCH.as_str(), "*")), None)).await;
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn test_if_match_on_missing_task_returns_not_found() {
+    let app = create_generated_router();
+
+    let response = send(&app, request("DELETE", "/tasks/99", Some((IF_MATCH.as_str(), "*")), None)).await;
+    assert_eq!(response.status, StatusCode::NOT_FOUND);
+}
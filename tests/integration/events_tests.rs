@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use std::time::Duration;
+use axum::body::{Body, BodyDataStream};
+use axum::http::{header::CONTENT_TYPE, Method, Request, StatusCode};
+use futures_util::StreamExt;
+use serde_json::Value;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use todo_api::infrastructure::events::TaskEventBroker;
+use todo_api::infrastructure::http::generated_routes::{create_generated_router, create_generated_router_with_events};
+use todo_api::infrastructure::server::serve;
+use todo_api::interface::gateway::inmemory::InMemoryTaskRepository;
+use tower::ServiceExt;
+
+fn request(method: Method, uri: &str, body: Option<&str>) -> Request<Body> {
+    let builder = Request::builder().method(method).uri(uri).header("host", "localhost");
+    match body {
+        Some(body) => builder.header(CONTENT_TYPE, "application/json").body(Body::from(body.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    }
+}
+
+async fn send(app: &axum::Router, method: Method, uri: &str, body: Option<&str>) -> StatusCode {
+    app.clone().oneshot(request(method, uri, body)).await.unwrap().status()
+}
+
+/// 受信したSSEのイベント（id, event, data）
+#[derive(Debug)]
+struct SseEvent {
+    id: u64,
+    event: String,
+    data: Value,
+}
+
+/// SSEの本文を読み、イベントを1つずつ取り出す
+struct SseReader {
+    stream: BodyDataStream,
+    buffer: String,
+}
+
+impl SseReader {
+    async fn open(app: &axum::Router, last_event_id: Option<u64>) -> Self {
+        let mut builder = Request::get("/tasks/events").header("host", "localhost");
+        if let Some(id) = last_event_id {
+            builder = builder.header("last-event-id", id.to_string());
+        }
+        let response = app.clone().oneshot(builder.body(Body::empty()).unwrap()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_TYPE], "text/event-stream");
+        Self { stream: response.into_body().into_data_stream(), buffer: String::new() }
+    }
+
+    async fn next(&mut self) -> SseEvent {
+        loop {
+            if let Some(end) = self.buffer.find("\n\n") {
+                let block: String = self.buffer.drain(..end + 2).collect();
+                let field = |name: &str| {
+                    block
+                        .lines()
+                        .find_map(|line| line.strip_prefix(name))
+                        .map(|value| value.trim_start().to_string())
+                };
+                // keep-alive のコメント行は読み飛ばす
+                let Some(id) = field("id:") else { continue };
+                return SseEvent {
+                    id: id.parse().unwrap(),
+                    event: field("event:").unwrap(),
+                    data: serde_json::from_str(&field("data:").unwrap()).unwrap(),
+                };
+            }
+            let chunk = tokio::time::timeout(Duration::from_secs(5), self.stream.next())
+                .await
+                .expect("timed out waiting for an event")
+                .expect("stream ended")
+                .unwrap();
+            self.buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_task_changes_are_streamed() {
+    let app = create_generated_router();
+    let mut events = SseReader::open(&app, None).await;
+
+    assert_eq!(send(&app, Method::POST, "/tasks", Some(r#"{"description":"Write report"}"#)).await, StatusCode::CREATED);
+    assert_eq!(send(&app, Method::PUT, "/tasks/1", Some(r#"{"description":"Write the report"}"#)).await, StatusCode::OK);
+    assert_eq!(send(&app, Method::PUT, "/tasks/1/complete", None).await, StatusCode::OK);
+    // 内容が変わらない操作は通知されない
+    assert_eq!(send(&app, Method::DELETE, "/tasks/1/tags/missing", None).await, StatusCode::OK);
+    assert_eq!(send(&app, Method::PUT, "/tasks/1", Some(r#"{"completed":false}"#)).await, StatusCode::OK);
+    assert_eq!(send(&app, Method::DELETE, "/tasks/1", None).await, StatusCode::NO_CONTENT);
+    // 失敗した操作も通知されない
+    assert_eq!(send(&app, Method::DELETE, "/tasks/1", None).await, StatusCode::NOT_FOUND);
+    assert_eq!(send(&app, Method::POST, "/tasks/1/restore", None).await, StatusCode::OK);
+
+    let expected = [
+        (1, "created", false, 1),
+        (2, "updated", false, 2),
+        (3, "completed", true, 3),
+        (4, "uncompleted", false, 4),
+        (5, "deleted", false, 4),
+        (6, "restored", false, 6),
+    ];
+    for (id, kind, completed, version) in expected {
+        let event = events.next().await;
+        assert_eq!((event.id, event.event.as_str()), (id, kind));
+        assert_eq!(event.data["id"], 1);
+        assert_eq!(event.data["completed"], completed);
+        assert_eq!(event.data["version"], version);
+    }
+
+    // 通知されなかった操作の分は欠番にならない
+    send(&app, Method::POST, "/tasks", Some(r#"{"description":"Next"}"#)).await;
+    let event = events.next().await;
+    assert_eq!((event.id, event.event.as_str(), event.data["description"].as_str()), (7, "created", Some("Next")));
+}
+
+#[tokio::test]
+async fn test_stream_resumes_from_last_event_id() {
+    let app = create_generated_router();
+    for description in ["a", "b", "c"] {
+        let body = format!(r#"{{"description":"{}"}}"#, description);
+        send(&app, Method::POST, "/tasks", Some(&body)).await;
+    }
+
+    let mut events = SseReader::open(&app, Some(1)).await;
+    let replayed = [events.next().await, events.next().await];
+    assert_eq!(replayed.iter().map(|e| e.id).collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(replayed[1].data["description"], "c");
+
+    // 再送の後は新しいイベントが続く
+    send(&app, Method::PUT, "/tasks/2/complete", None).await;
+    let event = events.next().await;
+    assert_eq!((event.id, event.event.as_str(), event.data["id"].as_u64()), (4, "completed", Some(2)));
+}
+
+#[tokio::test]
+async fn test_open_streams_end_on_shutdown() {
+    let events = Arc::new(TaskEventBroker::new());
+    let router = create_generated_router_with_events(InMemoryTaskRepository::new(), events.clone());
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let repository = InMemoryTaskRepository::new();
+    let server = tokio::spawn({
+        let events = events.clone();
+        async move {
+            let shutdown = async move {
+                let _ = shutdown_rx.await;
+                events.close();
+            };
+            serve(listener, router, &repository, shutdown, Duration::from_secs(10)).await
+        }
+    });
+
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    stream
+        .write_all(b"GET /tasks/events HTTP/1.1\r\nHost: localhost\r\nAccept: text/event-stream\r\n\r\n")
+        .await
+        .unwrap();
+    let mut head = [0u8; 64];
+    let read = stream.read(&mut head).await.unwrap();
+    assert!(String::from_utf8_lossy(&head[..read]).starts_with("HTTP/1.1 200 OK"));
+
+    shutdown_tx.send(()).unwrap();
+    let summary = tokio::time::timeout(Duration::from_secs(5), server).await.unwrap().unwrap().unwrap();
+    assert_eq!(summary.abandoned_requests, 0);
+    assert!(summary.drain_time < Duration::from_secs(5));
+}
@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use axum::body::{to_bytes, Body};
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use serde_json::Value;
+use todo_api::domain::model::task::{CreateTask, Task, UpdateTask};
+use todo_api::domain::repository::api_key::ApiKeyStorage;
+use todo_api::domain::repository::history::HistoryStorage;
+use todo_api::domain::repository::project::ProjectStorage;
+use todo_api::domain::repository::task::{TaskError, TaskRepository};
+use todo_api::domain::repository::user::UserStorage;
+use todo_api::infrastructure::http::generated_routes::{create_generated_router, create_generated_router_with};
+use todo_api::interface::gateway::inmemory::{InMemoryApiKeyRepository, InMemoryProjectRepository, InMemoryTaskHistoryRepository, InMemoryTaskRepository, InMemoryUserRepository};
+use todo_api::interface::gateway::sqlite::SqliteTaskRepository;
+use tower::ServiceExt;
+
+/// ストレージに到達できない状態を模したリポジトリ
+#[derive(Clone)]
+struct UnavailableRepository;
+
+fn unavailable() -> TaskError {
+    TaskError::RepositoryError(Box::new(std::io::Error::other("database is locked")))
+}
+
+#[async_trait]
+impl TaskRepository for UnavailableRepository {
+    async fn get_all(&self) -> Result<Vec<Task>, TaskError> {
+        Err(unavailable())
+    }
+    async fn get_by_id(&self, _id: u64) -> Result<Task, TaskError> {
+        Err(unavailable())
+    }
+    async fn create(&self, _task: CreateTask) -> Result<Task, TaskError> {
+        Err(unavailable())
+    }
+    async fn update(&self, _id: u64, _update_task: UpdateTask) -> Result<Task, TaskError> {
+        Err(unavailable())
+    }
+    async fn delete(&self, _id: u64) -> Result<(), TaskError> {
+        Err(unavailable())
+    }
+    async fn complete(&self, _id: u64) -> Result<Task, TaskError> {
+        Err(unavailable())
+    }
+    async fn uncomplete(&self, _id: u64) -> Result<Task, TaskError> {
+        Err(unavailable())
+    }
+}
+
+impl ProjectStorage for UnavailableRepository {
+    type Projects = InMemoryProjectRepository;
+
+    fn projects(&self) -> Self::Projects {
+        InMemoryTaskRepository::new().projects()
+    }
+}
+
+impl UserStorage for UnavailableRepository {
+    type Users = InMemoryUserRepository;
+
+    fn users(&self) -> Self::Users {
+        InMemoryTaskRepository::new().users()
+    }
+}
+
+impl HistoryStorage for UnavailableRepository {
+    type History = InMemoryTaskHistoryRepository;
+
+    fn history(&self) -> Self::History {
+        InMemoryTaskRepository::new().history()
+    }
+}
+
+impl ApiKeyStorage for UnavailableRepository {
+    type ApiKeys = InMemoryApiKeyRepository;
+
+    fn api_keys(&self) -> Self::ApiKeys {
+        InMemoryTaskRepository::new().api_keys()
+    }
+}
+
+async fn get(app: axum::Router, path: &str) -> (StatusCode, Option<String>, Value) {
+    let request = Request::get(path).header("host", "localhost").body(Body::empty()).unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let content_type = response.headers().get(CONTENT_TYPE).map(|v| v.to_str().unwrap().to_string());
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, content_type, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_healthz_and_readyz() {
+    let (status, _, body) = get(create_generated_router(), "/healthz").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "ok");
+
+    let (status, _, body) = get(create_generated_router(), "/readyz").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["status"], "ready");
+
+    let app = create_generated_router_with(SqliteTaskRepository::open_in_memory().unwrap());
+    let (status, _, _) = get(app, "/readyz").await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_readyz_reports_unavailable_repository() {
+    let app = create_generated_router_with(UnavailableRepository);
+
+    // 生存確認はストレージの状態に左右されない
+    let (status, _, _) = get(app.clone(), "/healthz").await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, content_type, body) = get(app, "/readyz").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(content_type.as_deref(), Some("application/problem+json"));
+    assert_eq!(body["code"], "NOT_READY");
+    assert!(!body["detail"].as_str().unwrap().contains("database is locked"));
+}
+
+#[tokio::test]
+async fn test_version_reports_build_and_storage() {
+    let (status, _, body) = get(create_generated_router(), "/version").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+    assert!(!body["git_commit"].as_str().unwrap().is_empty());
+    assert!(!body["build_time"].as_str().unwrap().is_empty());
+    assert_eq!(body["storage"], "memory");
+
+    let app = create_generated_router_with(SqliteTaskRepository::open_in_memory().unwrap());
+    let (_, _, body) = get(app, "/version").await;
+    assert_eq!(body["storage"], "sqlite");
+}
@@ -0,0 +1,110 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str) -> Request<Body> {
+    Request::builder().method(method).uri(uri).header("host", "localhost").body(Body::empty()).unwrap()
+}
+
+fn with_json(method: &str, uri: &str, body: &serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+/// 1: Release ─┬─ 2: Write changelog ── 4: Collect merged PRs
+///             └─ 3: Tag release
+async fn create_release(app: &Router) {
+    send(app, with_json("POST", "/tasks", &json!({"description": "Release"}))).await;
+    send(app, with_json("POST", "/tasks", &json!({"description": "Write changelog", "parent_id": 1}))).await;
+    send(app, with_json("POST", "/tasks", &json!({"description": "Tag release", "parent_id": 1}))).await;
+    send(app, with_json("POST", "/tasks", &json!({"description": "Collect merged PRs", "parent_id": 2}))).await;
+}
+
+#[tokio::test]
+async fn test_children_and_tree_show_completion_percentage() {
+    let app = create_generated_router();
+    create_release(&app).await;
+    send(&app, request("PUT", "/tasks/3/complete")).await;
+
+    let (status, body) = send(&app, request("GET", "/tasks/1")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["parent_id"], serde_json::Value::Null);
+    assert_eq!(body["completion_percentage"], 50);
+
+    let (status, body) = send(&app, request("GET", "/tasks/1/children")).await;
+    assert_eq!(status, StatusCode::OK);
+    let children = body.as_array().unwrap();
+    assert_eq!(children.len(), 2);
+    assert_eq!((children[0]["id"].as_i64(), children[0]["parent_id"].as_i64()), (Some(2), Some(1)));
+    assert_eq!(children[0]["completion_percentage"], 0);
+    assert!(children[1].get("completion_percentage").is_none());
+
+    let (status, body) = send(&app, request("GET", "/tasks/tree")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["task"]["id"], 1);
+    assert_eq!(body[0]["children"][0]["task"]["id"], 2);
+    assert_eq!(body[0]["children"][0]["children"][0]["task"]["id"], 4);
+    assert_eq!(body[0]["children"][1]["children"], json!([]));
+
+    let (status, _) = send(&app, request("GET", "/tasks/99/children")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_invalid_parents_are_rejected() {
+    let app = create_generated_router();
+    create_release(&app).await;
+
+    let (status, body) = send(&app, with_json("POST", "/tasks", &json!({"description": "Lost", "parent_id": 99}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "PARENT_NOT_FOUND");
+    assert_eq!(body["field"], "parent_id");
+
+    let (status, body) = send(&app, with_json("PUT", "/tasks/1", &json!({"parent_id": 4}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "PARENT_CYCLE");
+
+    // null で親を外す
+    let (status, body) = send(&app, with_json("PUT", "/tasks/4", &json!({"parent_id": null}))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body.get("parent_id").is_none());
+}
+
+#[tokio::test]
+async fn test_delete_with_children_policies() {
+    let app = create_generated_router();
+    create_release(&app).await;
+
+    let (status, body) = send(&app, request("DELETE", "/tasks/1")).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "TASK_HAS_CHILDREN");
+
+    let (status, _) = send(&app, request("DELETE", "/tasks/2?children=orphan")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, body) = send(&app, request("GET", "/tasks/4")).await;
+    assert!(body.get("parent_id").is_none());
+
+    let (status, _) = send(&app, request("DELETE", "/tasks/1?children=cascade")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, body) = send(&app, request("GET", "/tasks")).await;
+    let remaining: Vec<i64> = body.as_array().unwrap().iter().map(|task| task["id"].as_i64().unwrap()).collect();
+    assert_eq!(remaining, vec![4]);
+    let (_, body) = send(&app, request("GET", "/tasks/trash")).await;
+    assert_eq!(body.as_array().unwrap().len(), 3);
+}
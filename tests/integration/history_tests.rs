@@ -0,0 +1,74 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str) -> Request<Body> {
+    Request::builder().method(method).uri(uri).header("host", "localhost").body(Body::empty()).unwrap()
+}
+
+fn with_json(method: &str, uri: &str, body: &serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn operations(body: &serde_json::Value) -> Vec<&str> {
+    body.as_array().unwrap().iter().map(|entry| entry["operation"].as_str().unwrap()).collect()
+}
+
+#[tokio::test]
+async fn test_task_history_lists_every_change_with_field_diffs() {
+    let app = create_generated_router();
+    send(&app, with_json("POST", "/tasks", &json!({"description": "Write report"}))).await;
+    send(&app, with_json("PUT", "/tasks/1", &json!({"description": "Write final report"}))).await;
+    send(&app, request("PUT", "/tasks/1/complete")).await;
+
+    let (status, body) = send(&app, request("GET", "/tasks/1/history")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(operations(&body), vec!["created", "updated", "completed"]);
+    assert_eq!(body[0]["task_id"], 1);
+    assert!(body[0]["recorded_at"].is_string());
+    assert_eq!(
+        body[1]["changes"],
+        json!([{"field": "description", "before": "Write report", "after": "Write final report"}])
+    );
+    assert_eq!(body[2]["changes"], json!([{"field": "completed", "before": false, "after": true}]));
+}
+
+#[tokio::test]
+async fn test_task_history_is_kept_after_task_is_purged() {
+    let app = create_generated_router();
+    send(&app, with_json("POST", "/tasks", &json!({"description": "Write report"}))).await;
+    send(&app, request("DELETE", "/tasks/1")).await;
+    send(&app, request("DELETE", "/tasks/trash")).await;
+
+    let (status, _) = send(&app, request("GET", "/tasks/1")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, body) = send(&app, request("GET", "/tasks/1/history")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(operations(&body), vec!["created", "deleted", "purged"]);
+}
+
+#[tokio::test]
+async fn test_task_history_returns_not_found_for_unknown_task() {
+    let app = create_generated_router();
+
+    let (status, body) = send(&app, request("GET", "/tasks/999/history")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["status"], 404);
+}
@@ -0,0 +1,76 @@
+use axum::body::Body;
+use axum::http::{header::{ACCESS_CONTROL_ALLOW_ORIGIN, CONTENT_TYPE, ORIGIN}, Request, StatusCode};
+use todo_api::infrastructure::config::Config;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use todo_api::infrastructure::server::{apply_http_config, build_router};
+use tower::ServiceExt;
+
+fn post_task(description: &str) -> Request<Body> {
+    Request::post("/tasks")
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(format!(r#"{{"description":"{}"}}"#, description)))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_body_limit_returns_problem() {
+    let mut config = Config::default();
+    config.limits.max_body_bytes = 64;
+    let app = apply_http_config(create_generated_router(), &config);
+
+    let response = app.clone().oneshot(post_task("short")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = app.oneshot(post_task(&"a".repeat(100))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/problem+json");
+}
+
+#[tokio::test]
+async fn test_cors_allows_configured_origins_only() {
+    let mut config = Config::default();
+    config.cors.allowed_origins = vec!["https://app.example".to_string()];
+    let app = apply_http_config(create_generated_router(), &config);
+
+    let request = |origin: &str| {
+        Request::get("/tasks")
+            .header("host", "localhost")
+            .header(ORIGIN, origin)
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(request("https://app.example")).await.unwrap();
+    assert_eq!(response.headers()[ACCESS_CONTROL_ALLOW_ORIGIN], "https://app.example");
+
+    let response = app.oneshot(request("https://evil.example")).await.unwrap();
+    assert!(response.headers().get(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn test_cors_disabled_by_default() {
+    let app = build_router(&Config::default()).unwrap();
+    let request = Request::get("/tasks")
+        .header("host", "localhost")
+        .header(ORIGIN, "https://app.example")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn test_sqlite_backend_from_config() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut config = Config::default();
+    config.storage.backend = todo_api::infrastructure::config::StorageBackend::Sqlite;
+    config.storage.path = dir.path().join("todo.db");
+
+    let app = build_router(&config).unwrap();
+    let response = app.oneshot(post_task("persisted")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert!(config.storage.path.exists());
+}
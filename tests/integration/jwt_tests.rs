@@ -0,0 +1,176 @@
+use std::io::Write;
+use std::sync::Arc;
+use axum::body::Body;
+use axum::http::{header::{CONTENT_TYPE, WWW_AUTHENTICATE}, Request, StatusCode};
+use axum::routing::get;
+use axum::Router;
+use chrono::Utc;
+use jsonwebtoken::{encode, EncodingKey, Header};
+use serde_json::json;
+use todo_api::domain::model::user::CreateUser;
+use todo_api::domain::repository::api_key::ApiKeyStorage;
+use todo_api::domain::repository::user::{UserRepository, UserStorage};
+use todo_api::infrastructure::config::{Config, JwtAlgorithm};
+use todo_api::infrastructure::events::TaskEventBroker;
+use todo_api::infrastructure::http::auth::{authenticate, Authenticator, Credential};
+use todo_api::infrastructure::http::generated_routes::create_generated_router_with_config;
+use todo_api::infrastructure::http::identity::{current_principal, identify};
+use todo_api::infrastructure::http::jwt::JwtVerifier;
+use todo_api::interface::gateway::inmemory::InMemoryTaskRepository;
+use todo_api::usecase::api_key::ApiKeyUsecaseImpl;
+use todo_api::usecase::user::UserUsecaseImpl;
+use tower::ServiceExt;
+
+const SECRET: &[u8] = b"shared-secret-for-tests-0123456789";
+const ADMIN_KEY: &str = "bootstrap-admin-key-0123";
+
+/// HS256 の共有鍵で JWT を受け付ける設定（鍵ファイルは返り値が生きている間だけ残る）
+fn jwt_config() -> (Config, Arc<JwtVerifier>, tempfile::NamedTempFile) {
+    let mut file = tempfile::NamedTempFile::new().unwrap();
+    file.write_all(SECRET).unwrap();
+    let mut config = Config::default();
+    config.auth.jwt.key_file = Some(file.path().to_path_buf());
+    config.auth.jwt.algorithm = Some(JwtAlgorithm::HS256);
+    config.auth.jwt.issuer = Some("https://auth.internal".to_string());
+    config.auth.jwt.audience = Some("todo-api".to_string());
+    config.auth.admin_key = Some(ADMIN_KEY.to_string());
+    config.validate().unwrap();
+    let verifier = Arc::new(JwtVerifier::from_config(&config.auth.jwt).unwrap().unwrap());
+    (config, verifier, file)
+}
+
+fn token(sub: &str, scope: &str, expires_in: i64) -> String {
+    let now = Utc::now().timestamp();
+    let claims = json!({"sub": sub, "scope": scope, "iss": "https://auth.internal", "aud": "todo-api", "exp": now + expires_in});
+    encode(&Header::default(), &claims, &EncodingKey::from_secret(SECRET)).unwrap()
+}
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, Option<String>, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let challenge = response.headers().get(WWW_AUTHENTICATE).map(|value| value.to_str().unwrap().to_string());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, challenge, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str, token: &str, body: Option<serde_json::Value>) -> Request<Body> {
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("host", "localhost")
+        .header("authorization", format!("Bearer {}", token));
+    match body {
+        Some(body) => builder.header(CONTENT_TYPE, "application/json").body(Body::from(body.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    }
+}
+
+/// 利用者 alice (ID 2) を持つリポジトリで、JWT を受け付けるルーターを作る
+async fn app_with_jwt() -> (Router, tempfile::NamedTempFile) {
+    let (config, verifier, file) = jwt_config();
+    let repository = InMemoryTaskRepository::new();
+    repository.users().create(CreateUser { name: "alice".to_string() }).await.unwrap();
+    let app = create_generated_router_with_config(repository, Arc::new(TaskEventBroker::new()), &config, Some(verifier));
+    (app, file)
+}
+
+#[tokio::test]
+async fn test_sub_claim_selects_the_task_owner() {
+    let (app, _file) = app_with_jwt().await;
+
+    // sub は利用者のIDでも利用者名でもよい
+    let (status, _, task) = send(&app, request("POST", "/tasks", &token("2", "write", 300), Some(json!({"description": "Alice's"})))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(task["owner_id"], 2);
+    let (_, _, body) = send(&app, request("GET", "/tasks", &token("ALICE", "read", 300), None)).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    let (_, _, me) = send(&app, request("GET", "/users/me", &token("alice", "read", 300), None)).await;
+    assert_eq!(me["id"], 2);
+
+    // 他の利用者のタスクは見えない
+    let (_, _, body) = send(&app, request("GET", "/tasks", &token("1", "read", 300), None)).await;
+    assert!(body.as_array().unwrap().is_empty());
+    let (status, _, _) = send(&app, request("GET", "/tasks/1", &token("default", "read", 300), None)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _, body) = send(&app, request("GET", "/tasks", &token("mallory", "read", 300), None)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "UNKNOWN_USER");
+}
+
+#[tokio::test]
+async fn test_invalid_tokens_are_rejected() {
+    let (app, _file) = app_with_jwt().await;
+
+    let (status, challenge, body) = send(&app, request("GET", "/tasks", &token("2", "read", -120), None)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "INVALID_TOKEN");
+    assert_eq!(body["detail"], "The token has expired");
+    assert!(challenge.unwrap().starts_with("Bearer error=\"invalid_token\""));
+
+    let forged = encode(&Header::default(), &json!({"sub": "2", "exp": Utc::now().timestamp() + 300}), &EncodingKey::from_secret(b"guess")).unwrap();
+    let (status, _, body) = send(&app, request("GET", "/tasks", &forged, None)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "INVALID_TOKEN");
+
+    // 範囲が足りなければ 403
+    let (status, _, body) = send(&app, request("DELETE", "/tasks/trash", &token("2", "read", 300), None)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["code"], "INSUFFICIENT_SCOPE");
+}
+
+#[tokio::test]
+async fn test_api_keys_are_accepted_alongside_tokens() {
+    let (app, _file) = app_with_jwt().await;
+
+    let (status, _, created) = send(&app, request("POST", "/api-keys", ADMIN_KEY, Some(json!({"name": "ci", "scope": "read"})))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let key = created["key"].as_str().unwrap();
+    let (status, _, _) = send(&app, request("GET", "/tasks", key, None)).await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_token_claiming_admin_cannot_manage_api_keys() {
+    let (app, _file) = app_with_jwt().await;
+    let admin = token("1", "read write admin", 300);
+
+    let (status, challenge, body) = send(&app, request("POST", "/api-keys", &admin, Some(json!({"name": "escalated", "scope": "admin"})))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["code"], "INSUFFICIENT_SCOPE");
+    assert!(challenge.unwrap().contains("scope=\"admin\""));
+    let (status, _, _) = send(&app, request("GET", "/api-keys", &admin, None)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    // write までは使える
+    let (status, _, _) = send(&app, request("POST", "/tasks", &admin, Some(json!({"description": "Still writable"})))).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_verified_principal_is_available_to_handlers() {
+    let (config, verifier, _file) = jwt_config();
+    let repository = InMemoryTaskRepository::new();
+    let users = UserUsecaseImpl::new(repository.users());
+    let auth = Authenticator::new(ApiKeyUsecaseImpl::new(repository.api_keys()), users.clone(), &config.auth, Some(verifier));
+    let app = Router::new()
+        .route(
+            "/whoami",
+            get(|| async {
+                let principal = current_principal().unwrap();
+                match principal.credential {
+                    Credential::Jwt { subject } => format!("{} {} {}", principal.user_id, principal.scope, subject),
+                    other => format!("{:?}", other),
+                }
+            }),
+        )
+        .layer(axum::middleware::from_fn_with_state(users, identify::<UserUsecaseImpl<_>>))
+        .layer(axum::middleware::from_fn_with_state(
+            auth,
+            authenticate::<ApiKeyUsecaseImpl<_>, UserUsecaseImpl<_>>,
+        ));
+
+    let response = app.oneshot(request("GET", "/whoami", &token("default", "write", 300), None)).await.unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert_eq!(body, "1 write default");
+    assert!(current_principal().is_none());
+}
@@ -1,16 +1,17 @@
 use todo_api::domain::model::task::{CreateTask, UpdateTask};
 use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
-use todo_api::usecase::task::TaskUsecase;
+use todo_api::usecase::task::TaskUsecaseImpl;
 
 #[tokio::test]
 async fn test_full_task_lifecycle() {
     // リポジトリとユースケースを作成
     let repository = InMemoryTaskRepository::new();
-    let usecase = TaskUsecase::new(repository);
+    let usecase = TaskUsecaseImpl::new(repository);
     
     // 1. タスクを作成
     let create_task = CreateTask {
         description: "Test task".to_string(),
+        ..Default::default()
     };
     let created_task = usecase.create_task(create_task).await.unwrap();
     assert_eq!(created_task.description, "Test task");
@@ -25,6 +26,7 @@ async fn test_full_task_lifecycle() {
     let update_task = UpdateTask {
         description: Some("Updated task".to_string()),
         completed: Some(true),
+        ..Default::default()
     };
     let updated_task = usecase.update_task(created_task.id, update_task).await.unwrap();
     assert_eq!(updated_task.description, "Updated task");
@@ -0,0 +1,86 @@
+use axum::body::{to_bytes, Body};
+use axum::http::{header::CONTENT_TYPE, Method, Request, StatusCode};
+use todo_api::infrastructure::http::generated_routes::{create_generated_router, create_generated_router_with};
+use todo_api::infrastructure::metrics::http::operation_name;
+use todo_api::interface::gateway::sqlite::SqliteTaskRepository;
+use tower::ServiceExt;
+
+fn request(method: Method, uri: &str, body: Option<&str>) -> Request<Body> {
+    let builder = Request::builder().method(method).uri(uri).header("host", "localhost");
+    match body {
+        Some(body) => builder.header(CONTENT_TYPE, "application/json").body(Body::from(body.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    }
+}
+
+async fn scrape(app: &axum::Router) -> String {
+    let response = app.clone().oneshot(request(Method::GET, "/metrics", None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()[CONTENT_TYPE].to_str().unwrap().starts_with("text/plain; version=0.0.4"));
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+fn assert_line(metrics: &str, line: &str) {
+    assert!(metrics.lines().any(|l| l == line), "missing `{}` in:\n{}", line, metrics);
+}
+
+#[test]
+fn test_operation_name_matches_generated_names() {
+    assert_eq!(operation_name(&Method::PUT, "/tasks/{id}"), "tasks_id_put");
+    assert_eq!(operation_name(&Method::PUT, "/tasks/{id}/complete"), "tasks_id_complete_put");
+    assert_eq!(operation_name(&Method::GET, "/tasks/due-soon"), "tasks_due_soon_get");
+    assert_eq!(operation_name(&Method::DELETE, "/tasks/{id}/tags/{tag}"), "tasks_id_tags_tag_delete");
+}
+
+#[tokio::test]
+async fn test_http_requests_are_counted_by_operation() {
+    let app = create_generated_router();
+    for description in ["first", "second"] {
+        let body = format!(r#"{{"description":"{}"}}"#, description);
+        let response = app.clone().oneshot(request(Method::POST, "/tasks", Some(&body))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+    let response = app.clone().oneshot(request(Method::PUT, "/tasks/1", Some(r#"{"completed":true}"#))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = app.clone().oneshot(request(Method::GET, "/tasks/99", None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = app.clone().oneshot(request(Method::GET, "/no/such/route", None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = app.clone().oneshot(request(Method::PATCH, "/tasks/1", None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+    let metrics = scrape(&app).await;
+    assert_line(&metrics, r#"todo_http_requests_total{operation="tasks_post",status="201"} 2"#);
+    assert_line(&metrics, r#"todo_http_requests_total{operation="tasks_id_put",status="200"} 1"#);
+    assert_line(&metrics, r#"todo_http_requests_total{operation="tasks_id_get",status="404"} 1"#);
+    assert_line(&metrics, r#"todo_http_requests_total{operation="unmatched",status="404"} 1"#);
+    assert_line(&metrics, r#"todo_http_requests_total{operation="unmatched",status="405"} 1"#);
+    assert_line(&metrics, r#"todo_http_request_duration_seconds_count{operation="tasks_post"} 2"#);
+
+    // リポジトリの呼び出しは失敗も含めて記録される
+    assert_line(&metrics, r#"todo_repository_operation_duration_seconds_count{operation="create"} 2"#);
+    assert_line(&metrics, r#"todo_repository_operation_errors_total{operation="get_by_id"} 1"#);
+
+    assert_line(&metrics, r#"todo_tasks{state="total"} 2"#);
+    assert_line(&metrics, r#"todo_tasks{state="completed"} 1"#);
+    assert_line(&metrics, r#"todo_tasks{state="pending"} 1"#);
+}
+
+#[tokio::test]
+async fn test_task_gauges_with_sqlite() {
+    let app = create_generated_router_with(SqliteTaskRepository::open_in_memory().unwrap());
+    let metrics = scrape(&app).await;
+    assert_line(&metrics, r#"todo_tasks{state="total"} 0"#);
+
+    let response = app.clone().oneshot(request(Method::POST, "/tasks", Some(r#"{"description":"task"}"#))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let response = app.clone().oneshot(request(Method::PUT, "/tasks/1/complete", None)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let metrics = scrape(&app).await;
+    assert_line(&metrics, r#"todo_tasks{state="total"} 1"#);
+    assert_line(&metrics, r#"todo_tasks{state="completed"} 1"#);
+    assert_line(&metrics, r#"todo_tasks{state="pending"} 0"#);
+    assert_line(&metrics, r#"todo_http_requests_total{operation="metrics_get",status="200"} 1"#);
+}
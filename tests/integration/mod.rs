@@ -1,3 +1,26 @@
 pub mod lifecycle_tests;
 pub mod multiple_tasks_tests;
-pub mod error_handling_tests; 
\ No newline at end of file
+pub mod error_handling_tests;
+pub mod problem_tests;
+pub mod pagination_tests;
+pub mod due_date_tests;
+pub mod tags_tests;
+pub mod etag_tests;
+pub mod http_config_tests;
+pub mod shutdown_tests;
+pub mod health_tests;
+pub mod metrics_tests;
+pub mod request_id_tests;
+pub mod events_tests;
+pub mod bulk_tests;
+pub mod trash_tests;
+pub mod history_tests;
+pub mod hierarchy_tests;
+pub mod dependency_tests;
+pub mod recurrence_tests;
+pub mod project_tests;
+pub mod ownership_tests;
+pub mod api_key_tests;
+pub mod jwt_tests;
+pub mod session_tests;
+pub mod rate_limit_tests;
\ No newline at end of file
@@ -1,21 +1,24 @@
 use todo_api::domain::model::task::CreateTask;
 use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
-use todo_api::usecase::task::TaskUsecase;
+use todo_api::usecase::task::TaskUsecaseImpl;
 
 #[tokio::test]
 async fn test_multiple_tasks() {
     let repository = InMemoryTaskRepository::new();
-    let usecase = TaskUsecase::new(repository);
+    let usecase = TaskUsecaseImpl::new(repository);
     
     // 複数のタスクを作成
     let task1 = CreateTask {
         description: "Task 1".to_string(),
+        ..Default::default()
     };
     let task2 = CreateTask {
         description: "Task 2".to_string(),
+        ..Default::default()
     };
     let task3 = CreateTask {
         description: "Task 3".to_string(),
+        ..Default::default()
     };
     
     let created_task1 = usecase.create_task(task1).await.unwrap();
@@ -0,0 +1,226 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::http::generated_routes::{create_generated_router, create_generated_router_with};
+use todo_api::interface::gateway::sqlite::SqliteTaskRepository;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str, user: Option<&str>) -> Request<Body> {
+    let builder = Request::builder().method(method).uri(uri).header("host", "localhost");
+    let builder = match user {
+        Some(user) => builder.header("x-user-id", user),
+        None => builder,
+    };
+    builder.body(Body::empty()).unwrap()
+}
+
+fn with_json(method: &str, uri: &str, user: Option<&str>, body: &serde_json::Value) -> Request<Body> {
+    let builder = Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json");
+    let builder = match user {
+        Some(user) => builder.header("x-user-id", user),
+        None => builder,
+    };
+    builder.body(Body::from(body.to_string())).unwrap()
+}
+
+fn ids(body: &serde_json::Value) -> Vec<u64> {
+    body.as_array().unwrap().iter().map(|task| task["id"].as_u64().unwrap()).collect()
+}
+
+/// 利用者 alice (ID 2) を作り、既定の利用者と alice がそれぞれタスクを1件ずつ持つ状態にする
+async fn two_users_with_tasks(app: &Router) {
+    let (status, body) = send(app, with_json("POST", "/users", None, &json!({"name": "alice"}))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["id"], 2);
+    let (_, body) = send(app, with_json("POST", "/tasks", None, &json!({"description": "Mine"}))).await;
+    assert_eq!(body["owner_id"], 1);
+    let (_, body) = send(app, with_json("POST", "/tasks", Some("2"), &json!({"description": "Alice's"}))).await;
+    assert_eq!(body["owner_id"], 2);
+}
+
+async fn assert_tasks_are_isolated_per_user(app: Router) {
+    two_users_with_tasks(&app).await;
+
+    let (_, body) = send(&app, request("GET", "/tasks", None)).await;
+    assert_eq!(ids(&body), vec![1]);
+    let (_, body) = send(&app, request("GET", "/tasks", Some("2"))).await;
+    assert_eq!(ids(&body), vec![2]);
+    let (_, body) = send(&app, request("GET", "/tasks/pending", Some("2"))).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    let (_, body) = send(&app, request("GET", "/projects", Some("2"))).await;
+    assert_eq!(body[0]["pending_count"], 1);
+
+    // 他の利用者のタスクは 403、存在しないタスクは 404
+    let (status, body) = send(&app, request("GET", "/tasks/1", Some("2"))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["code"], "TASK_FORBIDDEN");
+    let (status, _) = send(&app, with_json("PUT", "/tasks/1", Some("2"), &json!({"description": "Stolen"}))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, request("DELETE", "/tasks/1", Some("2"))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, request("GET", "/tasks/9", Some("2"))).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (_, body) = send(&app, request("GET", "/tasks/1", None)).await;
+    assert_eq!(body["description"], "Mine");
+}
+
+#[tokio::test]
+async fn test_tasks_are_isolated_per_user_in_memory() {
+    assert_tasks_are_isolated_per_user(create_generated_router()).await;
+}
+
+#[tokio::test]
+async fn test_tasks_are_isolated_per_user_sqlite() {
+    let repository = SqliteTaskRepository::open_in_memory().unwrap();
+    assert_tasks_are_isolated_per_user(create_generated_router_with(repository)).await;
+}
+
+async fn assert_projects_are_isolated_per_user(app: Router) {
+    two_users_with_tasks(&app).await;
+    let (status, body) = send(&app, with_json("POST", "/projects", None, &json!({"name": "Work"}))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["owner_id"], 1);
+    let mine = body["id"].as_u64().unwrap();
+    // 名前は持ち主ごとに一意なので、alice も同じ名前のプロジェクトを作れる
+    let (status, body) = send(&app, with_json("POST", "/projects", Some("2"), &json!({"name": "Work"}))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["owner_id"], 2);
+    let alices = body["id"].as_u64().unwrap();
+    send(&app, with_json("POST", "/tasks", None, &json!({"description": "Report", "project_id": mine}))).await;
+
+    // 受信箱は共有され、数えるのは自分のタスクだけ
+    let (_, body) = send(&app, request("GET", "/projects", Some("2"))).await;
+    assert_eq!(ids(&body), vec![1, alices]);
+    assert_eq!(body[0]["pending_count"], 1);
+    assert_eq!(body[1]["pending_count"], 0);
+    let (_, body) = send(&app, request("GET", "/projects", None)).await;
+    assert_eq!(ids(&body), vec![1, mine]);
+    assert_eq!(body[1]["pending_count"], 1);
+
+    // 他の利用者のプロジェクトは見ることも変えることもできない
+    let uri = format!("/projects/{}", mine);
+    let (status, body) = send(&app, request("GET", &uri, Some("2"))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["code"], "PROJECT_FORBIDDEN");
+    let (status, _) = send(&app, request("GET", &format!("{}/tasks", uri), Some("2"))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, with_json("PUT", &uri, Some("2"), &json!({"name": "Stolen"}))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(&app, request("DELETE", &uri, Some("2"))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, body) = send(&app, with_json("POST", "/tasks", Some("2"), &json!({"description": "Sneaky", "project_id": mine}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "PROJECT_NOT_FOUND");
+    let (status, _) = send(&app, with_json("PUT", "/tasks/2", Some("2"), &json!({"project_id": mine}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // 受信箱のタスクは自分の分だけが見え、受信箱の名前を変えられるのは持ち主（既定の利用者）だけ
+    let (_, body) = send(&app, request("GET", "/projects/1/tasks", Some("2"))).await;
+    assert_eq!(ids(&body), vec![2]);
+    let (status, _) = send(&app, with_json("PUT", "/projects/1", Some("2"), &json!({"name": "Mine"}))).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (_, body) = send(&app, request("GET", &uri, None)).await;
+    assert_eq!(body["name"], "Work");
+}
+
+#[tokio::test]
+async fn test_projects_are_isolated_per_user_in_memory() {
+    assert_projects_are_isolated_per_user(create_generated_router()).await;
+}
+
+#[tokio::test]
+async fn test_projects_are_isolated_per_user_sqlite() {
+    let repository = SqliteTaskRepository::open_in_memory().unwrap();
+    assert_projects_are_isolated_per_user(create_generated_router_with(repository)).await;
+}
+
+#[tokio::test]
+async fn test_unknown_or_malformed_user_is_rejected() {
+    let app = create_generated_router();
+
+    for user in ["9", "alice", ""] {
+        let (status, body) = send(&app, request("GET", "/tasks", Some(user))).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "X-User-Id: {:?}", user);
+        assert_eq!(body["code"], "UNKNOWN_USER");
+    }
+}
+
+#[tokio::test]
+async fn test_user_endpoints() {
+    let app = create_generated_router();
+
+    let (status, body) = send(&app, request("GET", "/users/me", None)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["id"], 1);
+    assert_eq!(body["name"], "default");
+
+    send(&app, with_json("POST", "/users", None, &json!({"name": "alice"}))).await;
+    let (status, body) = send(&app, with_json("POST", "/users", None, &json!({"name": "Alice"}))).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "USER_NAME_TAKEN");
+    assert_eq!(body["field"], "name");
+    let (status, body) = send(&app, with_json("POST", "/users", None, &json!({"name": "alice smith"}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "USER_NAME_INVALID");
+
+    let (_, body) = send(&app, request("GET", "/users", None)).await;
+    let names: Vec<&str> = body.as_array().unwrap().iter().map(|user| user["name"].as_str().unwrap()).collect();
+    assert_eq!(names, vec!["default", "alice"]);
+    let (_, body) = send(&app, request("GET", "/users/me", Some("2"))).await;
+    assert_eq!(body["name"], "alice");
+}
+
+#[tokio::test]
+async fn test_foreign_tasks_cannot_be_referenced_or_changed_in_bulk() {
+    let app = create_generated_router();
+    two_users_with_tasks(&app).await;
+
+    // 他の利用者のタスクは親や依存先に選べない
+    let (status, body) = send(&app, with_json("POST", "/tasks", Some("2"), &json!({"description": "Child", "parent_id": 1}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["field"], "parent_id");
+    let (status, _) = send(&app, with_json("POST", "/tasks/2/dependencies", Some("2"), &json!({"depends_on": [1]}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // まとめて実行する操作に他の利用者のタスクが含まれていれば、何も実行しない
+    let batch = json!({"operations": [
+        {"op": "complete", "id": 2},
+        {"op": "delete", "id": 1}
+    ]});
+    let (status, body) = send(&app, with_json("POST", "/tasks/bulk", Some("2"), &batch)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["code"], "TASK_FORBIDDEN");
+    let (_, body) = send(&app, request("GET", "/tasks/2", Some("2"))).await;
+    assert_eq!(body["completed"], false);
+
+    // ゴミ箱を空にしても、他の利用者のタスクは残る
+    send(&app, request("DELETE", "/tasks/1", None)).await;
+    send(&app, request("DELETE", "/tasks/2", Some("2"))).await;
+    let (_, body) = send(&app, request("DELETE", "/tasks/trash", Some("2"))).await;
+    assert_eq!(body["purged"], 1);
+    let (_, body) = send(&app, request("GET", "/tasks/trash", None)).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_history_records_the_acting_user() {
+    let app = create_generated_router();
+    two_users_with_tasks(&app).await;
+
+    let (_, body) = send(&app, request("GET", "/tasks/2/history", Some("2"))).await;
+    assert_eq!(body[0]["actor"], "alice");
+    let (status, _) = send(&app, request("GET", "/tasks/2/history", None)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,119 @@
+use axum::body::Body;
+use axum::http::{header::{CONTENT_TYPE, LINK}, Request, StatusCode};
+use axum::Router;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+struct ListResponse {
+    status: StatusCode,
+    body: serde_json::Value,
+    next_cursor: Option<String>,
+    link: Option<String>,
+}
+
+async fn get(app: &Router, uri: &str) -> ListResponse {
+    let request = Request::get(uri).header("host", "localhost").body(Body::empty()).unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .map(|v| v.to_str().unwrap().to_string())
+    };
+    let next_cursor = header("x-next-cursor");
+    let link = header(LINK.as_str());
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    ListResponse {
+        status,
+        body: serde_json::from_slice(&bytes).unwrap_or(serde_json::Value::Null),
+        next_cursor,
+        link,
+    }
+}
+
+/// 5件のタスクを作成し、偶数番目を完了にする
+async fn seed(app: &Router) {
+    for i in 1..=5 {
+        let request = Request::post("/tasks")
+            .header("host", "localhost")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(format!(r#"{{"description":"Task {}"}}"#, i)))
+            .unwrap();
+        app.clone().oneshot(request).await.unwrap();
+        if i % 2 == 0 {
+            let request = Request::put(format!("/tasks/{}/complete", i))
+                .header("host", "localhost")
+                .body(Body::empty())
+                .unwrap();
+            app.clone().oneshot(request).await.unwrap();
+        }
+    }
+}
+
+fn ids(body: &serde_json::Value) -> Vec<i64> {
+    body.as_array().unwrap().iter().map(|t| t["id"].as_i64().unwrap()).collect()
+}
+
+#[tokio::test]
+async fn test_list_follows_link_header() {
+    let app = create_generated_router();
+    seed(&app).await;
+
+    let mut uri = "/tasks?limit=2".to_string();
+    let mut pages = Vec::new();
+    loop {
+        let response = get(&app, &uri).await;
+        assert_eq!(response.status, StatusCode::OK);
+        pages.push(ids(&response.body));
+        let Some(link) = response.link else {
+            assert!(response.next_cursor.is_none());
+            break;
+        };
+
+        // Linkヘッダーは次ページのURLと同じカーソルを指す
+        let cursor = response.next_cursor.unwrap();
+        assert!(link.ends_with(r#">; rel="next""#));
+        assert!(link.contains(&format!("cursor={}", cursor)));
+        uri = link.trim_start_matches('<').split('>').next().unwrap().to_string();
+        assert!(uri.contains("limit=2"));
+    }
+    assert_eq!(pages, vec![vec![1, 2], vec![3, 4], vec![5]]);
+}
+
+#[tokio::test]
+async fn test_list_sort_and_filter() {
+    let app = create_generated_router();
+    seed(&app).await;
+
+    let response = get(&app, "/tasks?sort=id&order=desc").await;
+    assert_eq!(ids(&response.body), vec![5, 4, 3, 2, 1]);
+    assert!(response.link.is_none());
+
+    let response = get(&app, "/tasks?completed=true").await;
+    assert_eq!(ids(&response.body), vec![2, 4]);
+
+    let response = get(&app, "/tasks?completed=false&sort=created_at&order=desc&limit=2").await;
+    assert_eq!(ids(&response.body), vec![5, 3]);
+    let link = response.link.unwrap();
+    assert!(link.contains("completed=false"));
+    assert!(link.contains("sort=created_at"));
+    assert!(link.contains("order=desc"));
+}
+
+#[tokio::test]
+async fn test_list_invalid_parameters() {
+    let app = create_generated_router();
+
+    let response = get(&app, "/tasks?cursor=not-a-cursor").await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+    assert_eq!(response.body["code"], "INVALID_CURSOR");
+    assert_eq!(response.body["field"], "cursor");
+
+    let response = get(&app, "/tasks?limit=0").await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+    assert_eq!(response.body["field"], "limit");
+
+    let response = get(&app, "/tasks?sort=description").await;
+    assert_eq!(response.status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,78 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, String, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .map(|v| v.to_str().unwrap().to_string())
+        .unwrap_or_default();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, content_type, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn post_task(body: &str) -> Request<Body> {
+    Request::post("/tasks")
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_empty_description_returns_problem() {
+    let app = create_generated_router();
+
+    let (status, content_type, problem) = send(&app, post_task(r#"{"description":"   "}"#)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(content_type, "application/problem+json");
+    assert_eq!(problem["status"], 400);
+    assert_eq!(problem["code"], "DESCRIPTION_EMPTY");
+    assert_eq!(problem["field"], "description");
+    assert_eq!(problem["detail"], "Description cannot be empty");
+    assert!(problem["request_id"].is_string());
+}
+
+#[tokio::test]
+async fn test_too_long_description_returns_problem() {
+    let app = create_generated_router();
+    let body = format!(r#"{{"description":"{}"}}"#, "a".repeat(1001));
+
+    let (status, content_type, problem) = send(&app, post_task(&body)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(content_type, "application/problem+json");
+    assert_eq!(problem["code"], "DESCRIPTION_TOO_LONG");
+    assert_eq!(problem["field"], "description");
+    assert_eq!(problem["detail"], "Description cannot exceed 1000 characters");
+}
+
+#[tokio::test]
+async fn test_malformed_body_returns_problem() {
+    let app = create_generated_router();
+
+    let (status, content_type, problem) = send(&app, post_task(r#"{"unexpected":1}"#)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(content_type, "application/problem+json");
+    assert_eq!(problem["code"], "UNPROCESSABLE_ENTITY");
+    assert!(problem["detail"].as_str().unwrap().contains("description"));
+}
+
+#[tokio::test]
+async fn test_unknown_route_returns_problem() {
+    let app = create_generated_router();
+    let request = Request::get("/no-such-route")
+        .header("host", "localhost")
+        .body(Body::empty())
+        .unwrap();
+
+    let (status, content_type, problem) = send(&app, request).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(content_type, "application/problem+json");
+    assert_eq!(problem["code"], "NOT_FOUND");
+    assert_eq!(problem["type"], "about:blank");
+}
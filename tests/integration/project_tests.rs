@@ -0,0 +1,147 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, axum::http::HeaderMap, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, headers, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str) -> Request<Body> {
+    Request::builder().method(method).uri(uri).header("host", "localhost").body(Body::empty()).unwrap()
+}
+
+fn with_json(method: &str, uri: &str, body: &serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_project_crud() {
+    let app = create_generated_router();
+
+    let (status, _, body) = send(&app, request("GET", "/projects")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!([{
+        "id": 1,
+        "name": "Inbox",
+        "owner_id": 1,
+        "inbox": true,
+        "pending_count": 0,
+        "completed_count": 0,
+        "created_at": body[0]["created_at"],
+        "updated_at": body[0]["updated_at"]
+    }]));
+
+    let (status, _, body) = send(&app, with_json("POST", "/projects", &json!({"name": "Work"}))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["id"], 2);
+    assert_eq!(body["inbox"], false);
+
+    let (status, _, body) = send(&app, with_json("POST", "/projects", &json!({"name": "WORK"}))).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "PROJECT_NAME_TAKEN");
+    assert_eq!(body["field"], "name");
+    let (status, _, body) = send(&app, with_json("POST", "/projects", &json!({"name": "  "}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "PROJECT_NAME_EMPTY");
+
+    let (status, _, body) = send(&app, with_json("PUT", "/projects/2", &json!({"name": "Office"}))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["name"], "Office");
+    let (_, _, body) = send(&app, request("GET", "/projects/2")).await;
+    assert_eq!(body["name"], "Office");
+
+    let (status, _, body) = send(&app, request("GET", "/projects/9")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "PROJECT_NOT_FOUND");
+
+    let (status, _, body) = send(&app, request("DELETE", "/projects/1")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "INVALID_OPERATION");
+    let (status, _, _) = send(&app, request("DELETE", "/projects/2")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _, _) = send(&app, request("GET", "/projects/2")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_tasks_belong_to_projects() {
+    let app = create_generated_router();
+    send(&app, with_json("POST", "/projects", &json!({"name": "Work"}))).await;
+
+    let (status, _, body) = send(&app, with_json("POST", "/tasks", &json!({"description": "Inbox task"}))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["project_id"], 1);
+    let (_, _, body) = send(&app, with_json("POST", "/tasks", &json!({"description": "Work task", "project_id": 2}))).await;
+    assert_eq!(body["project_id"], 2);
+
+    let (status, _, body) = send(&app, with_json("POST", "/tasks", &json!({"description": "Lost", "project_id": 9}))).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "PROJECT_NOT_FOUND");
+    assert_eq!(body["field"], "project_id");
+
+    let (status, _, body) = send(&app, with_json("PUT", "/tasks/1", &json!({"project_id": 2}))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["project_id"], 2);
+    send(&app, request("PUT", "/tasks/2/complete")).await;
+
+    let (_, _, body) = send(&app, request("GET", "/projects/2")).await;
+    assert_eq!(body["pending_count"], 1);
+    assert_eq!(body["completed_count"], 1);
+
+    // タスクが残っている間は削除できない
+    let (status, _, body) = send(&app, request("DELETE", "/projects/2")).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["code"], "PROJECT_NOT_EMPTY");
+}
+
+#[tokio::test]
+async fn test_list_project_tasks_with_filters_and_pagination() {
+    let app = create_generated_router();
+    send(&app, with_json("POST", "/projects", &json!({"name": "Work"}))).await;
+    for i in 1..=5 {
+        let project_id = if i % 2 == 0 { 1 } else { 2 };
+        send(&app, with_json("POST", "/tasks", &json!({"description": format!("Task {}", i), "project_id": project_id}))).await;
+    }
+    send(&app, request("PUT", "/tasks/3/complete")).await;
+    send(&app, with_json("POST", "/tasks/5/tags", &json!({"tags": ["urgent"]}))).await;
+
+    let (status, _, body) = send(&app, request("GET", "/projects/2/tasks")).await;
+    assert_eq!(status, StatusCode::OK);
+    let ids: Vec<u64> = body.as_array().unwrap().iter().map(|task| task["id"].as_u64().unwrap()).collect();
+    assert_eq!(ids, vec![1, 3, 5]);
+
+    let (_, _, body) = send(&app, request("GET", "/projects/2/tasks?completed=false&order=desc")).await;
+    let ids: Vec<u64> = body.as_array().unwrap().iter().map(|task| task["id"].as_u64().unwrap()).collect();
+    assert_eq!(ids, vec![5, 1]);
+    let (_, _, body) = send(&app, request("GET", "/projects/2/tasks?tag=urgent")).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    let (_, headers, body) = send(&app, request("GET", "/projects/2/tasks?limit=2")).await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+    let cursor = headers["x-next-cursor"].to_str().unwrap().to_string();
+    let link = headers["link"].to_str().unwrap();
+    assert_eq!(link, format!("</projects/2/tasks?limit=2&cursor={}>; rel=\"next\"", cursor));
+    let (_, headers, body) = send(&app, request("GET", &format!("/projects/2/tasks?limit=2&cursor={}", cursor))).await;
+    assert_eq!(body[0]["id"], 5);
+    assert!(headers.get("x-next-cursor").is_none());
+
+    let (status, _, body) = send(&app, request("GET", "/projects/9/tasks")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["code"], "PROJECT_NOT_FOUND");
+    let (status, _, body) = send(&app, request("GET", "/projects/2/tasks?cursor=bogus")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "INVALID_CURSOR");
+}
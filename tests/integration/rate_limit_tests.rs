@@ -0,0 +1,184 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use axum::body::Body;
+use axum::extract::ConnectInfo;
+use axum::http::{header::{CONTENT_TYPE, RETRY_AFTER}, HeaderMap, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::config::{Config, RateLimitBudget};
+use todo_api::infrastructure::events::TaskEventBroker;
+use todo_api::infrastructure::http::generated_routes::{create_generated_router, create_generated_router_with_config};
+use todo_api::interface::gateway::inmemory::InMemoryTaskRepository;
+use tower::ServiceExt;
+
+const ADMIN_KEY: &str = "bootstrap-admin-key-0123";
+
+fn app_with(configure: impl FnOnce(&mut Config)) -> Router {
+    let mut config = Config::default();
+    config.rate_limit.enabled = true;
+    config.rate_limit.read = RateLimitBudget { requests_per_minute: 60, burst: 3 };
+    config.rate_limit.write = RateLimitBudget { requests_per_minute: 6, burst: 2 };
+    configure(&mut config);
+    create_generated_router_with_config(InMemoryTaskRepository::new(), Arc::new(TaskEventBroker::new()), &config, None)
+}
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, HeaderMap, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, headers, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str, headers: &[(&str, &str)]) -> Request<Body> {
+    let mut builder = Request::builder().method(method).uri(uri).header("host", "localhost");
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    if method == "POST" {
+        let body = json!({"description": "Hammered"});
+        builder.header(CONTENT_TYPE, "application/json").body(Body::from(body.to_string())).unwrap()
+    } else {
+        builder.body(Body::empty()).unwrap()
+    }
+}
+
+/// 接続元のアドレスを付けたリクエスト（`into_make_service_with_connect_info` で動かしたときと同じ）
+fn from_peer(method: &str, uri: &str, peer: &str, headers: &[(&str, &str)]) -> Request<Body> {
+    let mut request = request(method, uri, headers);
+    request.extensions_mut().insert(ConnectInfo(peer.parse::<SocketAddr>().unwrap()));
+    request
+}
+
+fn header(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers.get(name).map(|value| value.to_str().unwrap().to_string())
+}
+
+#[tokio::test]
+async fn test_write_budget_is_enforced_with_retry_after() {
+    let app = app_with(|_| {});
+
+    for remaining in ["1", "0"] {
+        let (status, headers, _) = send(&app, from_peer("POST", "/tasks", "192.0.2.1:5000", &[])).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(header(&headers, "ratelimit-limit").as_deref(), Some("2"));
+        assert_eq!(header(&headers, "ratelimit-remaining").as_deref(), Some(remaining));
+    }
+
+    let (status, headers, body) = send(&app, from_peer("POST", "/tasks", "192.0.2.1:5000", &[])).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(body["code"], "RATE_LIMITED");
+    assert_eq!(body["status"], 429);
+    // 1分に6件なら、次の1件まで10秒、上限まで戻るのに20秒
+    assert_eq!(header(&headers, RETRY_AFTER.as_str()).as_deref(), Some("10"));
+    assert_eq!(header(&headers, "ratelimit-remaining").as_deref(), Some("0"));
+    assert_eq!(header(&headers, "ratelimit-reset").as_deref(), Some("20"));
+
+    // 断ったリクエストは処理されない
+    let (_, _, body) = send(&app, from_peer("GET", "/tasks", "192.0.2.1:5000", &[])).await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+    // 参照の予算と他の接続元の予算は別
+    let (status, headers, _) = send(&app, from_peer("GET", "/tasks", "192.0.2.1:5000", &[])).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(header(&headers, "ratelimit-limit").as_deref(), Some("3"));
+    let (status, _, _) = send(&app, from_peer("POST", "/tasks", "192.0.2.2:5000", &[])).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_authenticated_clients_are_limited_per_key_or_user() {
+    let app = app_with(|config| {
+        config.auth.require_api_key = true;
+        config.auth.admin_key = Some(ADMIN_KEY.to_string());
+        config.rate_limit.write.burst = 3;
+    });
+    let body = json!({"name": "ci", "scope": "write"});
+    let issue = Request::builder()
+        .method("POST")
+        .uri("/api-keys")
+        .header("host", "localhost")
+        .header("x-api-key", ADMIN_KEY)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let (status, _, body) = send(&app, issue).await;
+    assert_eq!(status, StatusCode::CREATED);
+    let key = body["key"].as_str().unwrap().to_string();
+
+    // 同じ接続元からでも、鍵ごとに予算がある
+    for _ in 0..3 {
+        let (status, _, _) = send(&app, from_peer("POST", "/tasks", "192.0.2.1:5000", &[("x-api-key", &key)])).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+    let (status, _, _) = send(&app, from_peer("POST", "/tasks", "192.0.2.1:5000", &[("x-api-key", &key)])).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    let (status, _, _) = send(&app, from_peer("POST", "/tasks", "192.0.2.1:5000", &[("x-api-key", ADMIN_KEY)])).await;
+    assert_eq!(status, StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn test_rejected_credentials_are_counted_per_address() {
+    let app = app_with(|config| {
+        config.auth.require_api_key = true;
+        config.auth.admin_key = Some(ADMIN_KEY.to_string());
+        config.rate_limit.per_address = RateLimitBudget { requests_per_minute: 6, burst: 4 };
+    });
+    let guess = [("x-api-key", "todo_guessed-key")];
+
+    for remaining in ["3", "2", "1", "0"] {
+        let (status, headers, body) = send(&app, from_peer("GET", "/tasks", "192.0.2.1:5000", &guess)).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["code"], "INVALID_API_KEY");
+        assert_eq!(header(&headers, "ratelimit-remaining").as_deref(), Some(remaining));
+    }
+    // 認証に失敗し続けた接続元は、鍵を確かめる前に断る
+    let (status, headers, body) = send(&app, from_peer("GET", "/tasks", "192.0.2.1:5000", &guess)).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(body["code"], "RATE_LIMITED");
+    assert_eq!(header(&headers, RETRY_AFTER.as_str()).as_deref(), Some("10"));
+    let (status, _, _) = send(&app, from_peer("GET", "/tasks", "192.0.2.1:5000", &[("x-api-key", ADMIN_KEY)])).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+
+    // 他の接続元は数えられていない
+    let (status, headers, _) = send(&app, from_peer("GET", "/tasks", "192.0.2.2:5000", &[("x-api-key", ADMIN_KEY)])).await;
+    assert_eq!(status, StatusCode::OK);
+    // 認証した依頼元には、内側の参照の予算を示す
+    assert_eq!(header(&headers, "ratelimit-limit").as_deref(), Some("3"));
+}
+
+#[tokio::test]
+async fn test_forwarded_for_is_used_only_when_trusted() {
+    let proxy = "10.0.0.1:443";
+    let app = app_with(|config| config.rate_limit.trust_forwarded_for = true);
+    for client in ["198.51.100.1", "198.51.100.2, 10.0.0.1", "198.51.100.3"] {
+        for _ in 0..2 {
+            let (status, _, _) = send(&app, from_peer("POST", "/tasks", proxy, &[("x-forwarded-for", client)])).await;
+            assert_eq!(status, StatusCode::CREATED, "{}", client);
+        }
+    }
+
+    let app = app_with(|_| {});
+    let mut statuses = Vec::new();
+    for client in ["198.51.100.1", "198.51.100.2", "198.51.100.3"] {
+        let (status, _, _) = send(&app, from_peer("POST", "/tasks", proxy, &[("x-forwarded-for", client)])).await;
+        statuses.push(status);
+    }
+    assert_eq!(statuses, vec![StatusCode::CREATED, StatusCode::CREATED, StatusCode::TOO_MANY_REQUESTS]);
+}
+
+#[tokio::test]
+async fn test_probes_are_not_limited_and_limits_are_off_by_default() {
+    let app = app_with(|config| config.rate_limit.read.burst = 1);
+    for _ in 0..3 {
+        let (status, headers, _) = send(&app, request("GET", "/healthz", &[])).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(headers.get("ratelimit-limit").is_none());
+    }
+
+    let app = create_generated_router();
+    for _ in 0..30 {
+        let (status, headers, _) = send(&app, request("POST", "/tasks", &[])).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(headers.get("ratelimit-limit").is_none());
+    }
+}
@@ -0,0 +1,129 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use chrono::{DateTime, Duration, Utc};
+use serde_json::json;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str) -> Request<Body> {
+    Request::builder().method(method).uri(uri).header("host", "localhost").body(Body::empty()).unwrap()
+}
+
+fn with_json(method: &str, uri: &str, body: &serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn date_time(value: &serde_json::Value) -> DateTime<Utc> {
+    value.as_str().unwrap().parse().unwrap()
+}
+
+/// 1日後が期限で、毎日繰り返すタスクを作る
+async fn create_daily(app: &Router) -> DateTime<Utc> {
+    let due_at = Utc::now() + Duration::days(1);
+    let (status, body) = send(app, with_json("POST", "/tasks", &json!({
+        "description": "Water the plants",
+        "due_at": due_at,
+        "recurrence": {"frequency": "daily"}
+    }))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["recurrence"]["rrule"], "FREQ=DAILY");
+    assert_eq!(body["recurrence"]["interval"], 1);
+    send(app, with_json("POST", "/tasks/1/tags", &json!({"tags": ["home"]}))).await;
+    date_time(&body["due_at"])
+}
+
+#[tokio::test]
+async fn test_completing_a_recurring_task_creates_the_next_occurrence() {
+    let app = create_generated_router();
+    let due_at = create_daily(&app).await;
+
+    let (status, body) = send(&app, request("PUT", "/tasks/1/complete")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["completed"], true);
+    assert!(body["recurrence"].is_null());
+
+    let (status, body) = send(&app, request("GET", "/tasks/2")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["description"], "Water the plants");
+    assert_eq!(body["completed"], false);
+    assert_eq!(body["tags"], json!(["home"]));
+    assert_eq!(body["recurrence"]["rrule"], "FREQ=DAILY");
+    assert_eq!(date_time(&body["due_at"]), due_at + Duration::days(1));
+
+    // 完了を取り消しても、もう一度完了にしても次の回は増えない
+    send(&app, request("PUT", "/tasks/1/uncomplete")).await;
+    send(&app, request("PUT", "/tasks/1/complete")).await;
+    let (_, body) = send(&app, request("GET", "/tasks")).await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_recurrence_accepts_rrule_and_rejects_invalid_rules() {
+    let app = create_generated_router();
+    let (status, body) = send(&app, with_json("POST", "/tasks", &json!({
+        "description": "Team sync",
+        "recurrence": {"rrule": "RRULE:FREQ=WEEKLY;BYDAY=TU,MO"}
+    }))).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(body["recurrence"]["frequency"], "weekly");
+    assert_eq!(body["recurrence"]["weekdays"], json!(["monday", "tuesday"]));
+    assert_eq!(body["recurrence"]["rrule"], "FREQ=WEEKLY;BYDAY=MO,TU");
+
+    for recurrence in [
+        json!({"rrule": "FREQ=YEARLY"}),
+        json!({"frequency": "daily", "weekdays": ["monday"]}),
+        json!({"frequency": "daily", "rrule": "FREQ=DAILY"}),
+        json!({}),
+    ] {
+        let (status, body) = send(&app, with_json("PUT", "/tasks/1", &json!({"recurrence": recurrence}))).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{recurrence}");
+        assert_eq!(body["code"], "INVALID_RECURRENCE");
+        assert_eq!(body["field"], "recurrence");
+    }
+}
+
+#[tokio::test]
+async fn test_preview_and_stop_recurrence() {
+    let app = create_generated_router();
+    let due_at = create_daily(&app).await;
+
+    let (status, body) = send(&app, request("GET", "/tasks/1/occurrences")).await;
+    assert_eq!(status, StatusCode::OK);
+    let occurrences: Vec<DateTime<Utc>> = body.as_array().unwrap().iter().map(date_time).collect();
+    assert_eq!(occurrences, (1..=5).map(|days| due_at + Duration::days(days)).collect::<Vec<_>>());
+
+    let (_, body) = send(&app, request("GET", "/tasks/1/occurrences?count=2")).await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+    let (status, _) = send(&app, request("GET", "/tasks/1/occurrences?count=101")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = send(&app, request("DELETE", "/tasks/1/recurrence")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["recurrence"].is_null());
+
+    let (status, body) = send(&app, request("GET", "/tasks/1/occurrences")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "INVALID_OPERATION");
+
+    // 繰り返しをやめたタスクを完了にしても次の回はできない
+    send(&app, request("PUT", "/tasks/1/complete")).await;
+    let (_, body) = send(&app, request("GET", "/tasks")).await;
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    let (status, _) = send(&app, request("DELETE", "/tasks/9/recurrence")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,100 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use serde_json::Value;
+use todo_api::infrastructure::config::{LogConfig, LogFormat};
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use todo_api::infrastructure::logging;
+use tower::ServiceExt;
+
+fn get(uri: &str, request_id: Option<&str>) -> Request<Body> {
+    let mut builder = Request::get(uri).header("host", "localhost");
+    if let Some(request_id) = request_id {
+        builder = builder.header("x-request-id", request_id);
+    }
+    builder.body(Body::empty()).unwrap()
+}
+
+async fn send(request: Request<Body>) -> (StatusCode, String, Value) {
+    let response = create_generated_router().oneshot(request).await.unwrap();
+    let status = response.status();
+    let request_id = response.headers()["x-request-id"].to_str().unwrap().to_string();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, request_id, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+#[tokio::test]
+async fn test_request_id_is_generated_and_echoed() {
+    let (status, request_id, _) = send(get("/tasks", None)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(request_id.len(), 16);
+
+    // エラー本文のIDはレスポンスヘッダーのIDと同じ
+    let (status, request_id, body) = send(get("/tasks/42", None)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["request_id"], request_id.as_str());
+
+    // ルートに一致しないリクエストでも同じ
+    let (status, request_id, body) = send(get("/nowhere", None)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(body["request_id"], request_id.as_str());
+}
+
+#[tokio::test]
+async fn test_client_request_id_is_propagated() {
+    let (_, request_id, body) = send(get("/tasks/42", Some("trace-abc.123:x_y"))).await;
+    assert_eq!(request_id, "trace-abc.123:x_y");
+    assert_eq!(body["request_id"], "trace-abc.123:x_y");
+
+    // ログを汚す値や長すぎる値は使わずに生成し直す
+    for invalid in ["has space", "quote\"", &"a".repeat(129)] {
+        let (_, request_id, _) = send(get("/tasks", Some(invalid))).await;
+        assert_ne!(request_id, invalid);
+        assert_eq!(request_id.len(), 16);
+    }
+}
+
+/// テスト内で書き出されたログを溜めておく出力先
+#[derive(Clone, Default)]
+struct Captured(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Captured {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_json_logs_carry_request_fields() {
+    let captured = Captured::default();
+    let config = LogConfig { format: LogFormat::Json, ..LogConfig::default() };
+    let writer = captured.clone();
+    let _guard = tracing::subscriber::set_default(logging::subscriber(&config, move || writer.clone()));
+
+    let (status, request_id, _) = send(get("/tasks/7", Some("log-test-1"))).await;
+    assert_eq!((status, request_id.as_str()), (StatusCode::NOT_FOUND, "log-test-1"));
+
+    let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+    let completed: Value = output
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .find(|event| event["message"] == "request completed")
+        .unwrap_or_else(|| panic!("no completion event in:\n{}", output));
+    let span = &completed["span"];
+    assert_eq!(span["request_id"], "log-test-1");
+    assert_eq!(span["method"], "GET");
+    assert_eq!(span["path"], "/tasks/7");
+    assert_eq!(span["operation"], "tasks_id_get");
+    assert_eq!(span["task_id"], 7);
+    assert_eq!(span["status"], 404);
+    assert!(span["latency_ms"].as_f64().is_some());
+
+    // ハンドラー内のエラーログにも同じリクエストのスパンが付く
+    assert!(output.lines().any(|line| line.contains("API Error") && line.contains("log-test-1")), "{}", output);
+}
@@ -0,0 +1,252 @@
+use std::sync::Arc;
+use std::time::Duration;
+use axum::body::Body;
+use axum::http::{header::{CONTENT_TYPE, SET_COOKIE}, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::config::Config;
+use todo_api::infrastructure::events::TaskEventBroker;
+use todo_api::infrastructure::http::generated_routes::{create_generated_router, create_generated_router_with_config};
+use todo_api::interface::gateway::inmemory::InMemoryTaskRepository;
+use tower::ServiceExt;
+
+const ADMIN_KEY: &str = "bootstrap-admin-key-0123";
+
+struct Reply {
+    status: StatusCode,
+    set_cookie: Option<String>,
+    body: serde_json::Value,
+}
+
+impl Reply {
+    /// `Set-Cookie` の `name=value` の部分（次のリクエストの `Cookie` に使う）
+    fn cookie(&self) -> String {
+        self.set_cookie.as_deref().unwrap().split(';').next().unwrap().to_string()
+    }
+}
+
+/// 管理用キーで認証するアプリ（ログインには資格情報がいる）
+fn app_with(configure: impl FnOnce(&mut Config)) -> Router {
+    let mut config = Config::default();
+    config.auth.require_api_key = true;
+    config.auth.admin_key = Some(ADMIN_KEY.to_string());
+    configure(&mut config);
+    create_generated_router_with_config(InMemoryTaskRepository::new(), Arc::new(TaskEventBroker::new()), &config, None)
+}
+
+async fn send(app: &Router, request: Request<Body>) -> Reply {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let set_cookie = response.headers().get(SET_COOKIE).map(|value| value.to_str().unwrap().to_string());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    Reply { status, set_cookie, body: serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null) }
+}
+
+fn app() -> Router {
+    app_with(|_| {})
+}
+
+fn login_request(headers: &[(&str, &str)]) -> Request<Body> {
+    let mut headers = headers.to_vec();
+    headers.push(("x-api-key", ADMIN_KEY));
+    request("POST", "/auth/login", &headers, None)
+}
+
+fn request(method: &str, uri: &str, headers: &[(&str, &str)], body: Option<serde_json::Value>) -> Request<Body> {
+    let mut builder = Request::builder().method(method).uri(uri).header("host", "localhost");
+    for (name, value) in headers {
+        builder = builder.header(*name, *value);
+    }
+    match body {
+        Some(body) => builder.header(CONTENT_TYPE, "application/json").body(Body::from(body.to_string())).unwrap(),
+        None => builder.body(Body::empty()).unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn test_login_sets_a_hardened_session_cookie() {
+    let app = app();
+
+    let login = send(&app, login_request(&[])).await;
+    assert_eq!(login.status, StatusCode::OK);
+    let set_cookie = login.set_cookie.clone().unwrap();
+    assert!(set_cookie.starts_with("todo_session="));
+    for attribute in ["HttpOnly", "SameSite=Strict", "Secure", "Path=/", "Max-Age=28800"] {
+        assert!(set_cookie.contains(attribute), "{} in {}", attribute, set_cookie);
+    }
+    assert_eq!(login.body["user_id"], 1);
+    assert_eq!(login.body["scope"], "admin");
+    let csrf = login.body["csrf_token"].as_str().unwrap();
+    assert_eq!(csrf.len(), 32);
+    // クッキーは暗号化され、CSRFトークンもセッションIDも読み取れない
+    assert!(!set_cookie.contains(csrf));
+
+    let session = send(&app, request("GET", "/auth/session", &[("cookie", &login.cookie())], None)).await;
+    assert_eq!(session.status, StatusCode::OK);
+    assert_eq!(session.body, login.body);
+
+    let reply = send(&app, request("GET", "/auth/session", &[("x-api-key", ADMIN_KEY)], None)).await;
+    assert_eq!(reply.status, StatusCode::NOT_FOUND);
+    assert_eq!(reply.body["code"], "NO_SESSION");
+}
+
+#[tokio::test]
+async fn test_mutating_requests_need_the_csrf_token() {
+    let app = app();
+    let login = send(&app, login_request(&[])).await;
+    let cookie = login.cookie();
+    let csrf = login.body["csrf_token"].as_str().unwrap();
+    let task = json!({"description": "From the browser"});
+
+    let reply = send(&app, request("POST", "/tasks", &[("cookie", &cookie)], Some(task.clone()))).await;
+    assert_eq!(reply.status, StatusCode::FORBIDDEN);
+    assert_eq!(reply.body["code"], "CSRF_TOKEN_INVALID");
+    let wrong = [("cookie", cookie.as_str()), ("x-csrf-token", "not-the-token")];
+    let reply = send(&app, request("POST", "/tasks", &wrong, Some(task.clone()))).await;
+    assert_eq!(reply.status, StatusCode::FORBIDDEN);
+
+    let headers = [("cookie", cookie.as_str()), ("x-csrf-token", csrf)];
+    let reply = send(&app, request("POST", "/tasks", &headers, Some(task))).await;
+    assert_eq!(reply.status, StatusCode::CREATED);
+    // 参照だけのリクエストにトークンはいらない
+    let reply = send(&app, request("GET", "/tasks", &[("cookie", &cookie)], None)).await;
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(reply.body.as_array().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_logout_ends_the_session() {
+    let app = app();
+    let login = send(&app, login_request(&[])).await;
+    let cookie = login.cookie();
+    let csrf = login.body["csrf_token"].as_str().unwrap();
+
+    let logout = send(&app, request("POST", "/auth/logout", &[("cookie", &cookie), ("x-csrf-token", csrf)], None)).await;
+    assert_eq!(logout.status, StatusCode::NO_CONTENT);
+    let removal = logout.set_cookie.unwrap();
+    assert!(removal.starts_with("todo_session=;"), "{}", removal);
+    assert!(removal.contains("Max-Age=0"));
+
+    // 終えたセッションのクッキーは 401 にして消させる
+    let reply = send(&app, request("GET", "/tasks", &[("cookie", &cookie)], None)).await;
+    assert_eq!(reply.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(reply.body["code"], "SESSION_EXPIRED");
+    assert!(reply.set_cookie.unwrap().contains("Max-Age=0"));
+    let reply = send(&app, request("GET", "/tasks", &[("cookie", "todo_session=forged")], None)).await;
+    assert_eq!(reply.status, StatusCode::UNAUTHORIZED);
+
+    // 古いクッキーが残っていてもログインし直せる
+    let login = send(&app, login_request(&[("cookie", &cookie)])).await;
+    assert_eq!(login.status, StatusCode::OK);
+    assert_ne!(login.cookie(), cookie);
+}
+
+#[tokio::test]
+async fn test_session_acts_as_the_authenticated_principal() {
+    let app = app();
+    let reply = send(&app, request("POST", "/users", &[("x-api-key", ADMIN_KEY)], Some(json!({"name": "alice"})))).await;
+    assert_eq!(reply.status, StatusCode::CREATED);
+    let key = json!({"name": "browser", "scope": "read", "user_id": 2});
+    let reply = send(&app, request("POST", "/api-keys", &[("x-api-key", ADMIN_KEY)], Some(key))).await;
+    let reader = reply.body["key"].as_str().unwrap().to_string();
+
+    // ログインには資格情報がいる
+    let reply = send(&app, request("POST", "/auth/login", &[], None)).await;
+    assert_eq!(reply.status, StatusCode::UNAUTHORIZED);
+    let login = send(&app, request("POST", "/auth/login", &[("x-api-key", &reader)], None)).await;
+    assert_eq!(login.status, StatusCode::OK);
+    assert_eq!(login.body["user_id"], 2);
+    assert_eq!(login.body["scope"], "read");
+    let cookie = login.cookie();
+    let csrf = login.body["csrf_token"].as_str().unwrap();
+
+    let reply = send(&app, request("GET", "/users/me", &[("cookie", &cookie)], None)).await;
+    assert_eq!(reply.status, StatusCode::OK);
+    assert_eq!(reply.body["name"], "alice");
+    // セッションの範囲はログインに使った資格情報と同じ
+    let headers = [("cookie", cookie.as_str()), ("x-csrf-token", csrf)];
+    let reply = send(&app, request("POST", "/tasks", &headers, Some(json!({"description": "Nope"})))).await;
+    assert_eq!(reply.status, StatusCode::FORBIDDEN);
+    assert_eq!(reply.body["code"], "INSUFFICIENT_SCOPE");
+    // 資格情報を送ったリクエストではクッキーを見ない
+    let headers = [("cookie", cookie.as_str()), ("x-api-key", ADMIN_KEY)];
+    let reply = send(&app, request("GET", "/users/me", &headers, None)).await;
+    assert_eq!(reply.body["name"], "default");
+
+    let logout = send(&app, request("POST", "/auth/logout", &[("cookie", &cookie), ("x-csrf-token", csrf)], None)).await;
+    assert_eq!(logout.status, StatusCode::NO_CONTENT);
+    let reply = send(&app, request("GET", "/users/me", &[("cookie", &cookie)], None)).await;
+    assert_eq!(reply.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_session_id_is_rotated_but_keeps_its_csrf_token_and_expiry() {
+    let app = app_with(|config| config.auth.session.rotate_after_secs = 1);
+    let login = send(&app, login_request(&[])).await;
+    let cookie = login.cookie();
+
+    let reply = send(&app, request("GET", "/tasks", &[("cookie", &cookie)], None)).await;
+    assert!(reply.set_cookie.is_none());
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    let reply = send(&app, request("GET", "/tasks", &[("cookie", &cookie)], None)).await;
+    assert_eq!(reply.status, StatusCode::OK);
+    let rotated = reply.cookie();
+    assert_ne!(rotated, cookie);
+
+    let session = send(&app, request("GET", "/auth/session", &[("cookie", &rotated)], None)).await;
+    assert_eq!(session.body["csrf_token"], login.body["csrf_token"]);
+    assert_eq!(session.body["expires_at"], login.body["expires_at"]);
+    // 並行して送られたリクエストのため、古いIDもしばらくは受け付け、もう一度は新しくしない
+    let reply = send(&app, request("GET", "/tasks", &[("cookie", &cookie)], None)).await;
+    assert_eq!(reply.status, StatusCode::OK);
+    assert!(reply.set_cookie.is_none());
+}
+
+#[tokio::test]
+async fn test_insecure_cookie_can_be_allowed_for_local_development() {
+    let app = app_with(|config| config.auth.session.secure_cookie = false);
+    let login = send(&app, login_request(&[])).await;
+    assert!(!login.set_cookie.unwrap().contains("Secure"));
+}
+
+
+#[tokio::test]
+async fn test_login_needs_a_credential_other_than_the_session() {
+    // 認証しない設定でも、自己申告の X-User-Id ではログインできない
+    let open = create_generated_router();
+    let reply = send(&open, request("POST", "/auth/login", &[("x-user-id", "1")], None)).await;
+    assert_eq!(reply.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(reply.body["code"], "CREDENTIALS_REQUIRED");
+    assert!(reply.set_cookie.is_none());
+
+    // セッションのクッキーだけではログインし直して期限を延ばせない
+    let app = app();
+    let login = send(&app, login_request(&[])).await;
+    let cookie = login.cookie();
+    let headers = [("cookie", cookie.as_str()), ("x-csrf-token", login.body["csrf_token"].as_str().unwrap())];
+    let reply = send(&app, request("POST", "/auth/login", &headers, None)).await;
+    assert_eq!(reply.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(reply.body["code"], "CREDENTIALS_REQUIRED");
+}
+
+#[tokio::test]
+async fn test_session_scope_never_exceeds_the_credential() {
+    let app = app();
+    let key = json!({"name": "writer", "scope": "write"});
+    let reply = send(&app, request("POST", "/api-keys", &[("x-api-key", ADMIN_KEY)], Some(key))).await;
+    let writer = reply.body["key"].as_str().unwrap().to_string();
+
+    let login = send(&app, request("POST", "/auth/login", &[("x-api-key", &writer)], None)).await;
+    assert_eq!(login.status, StatusCode::OK);
+    assert_eq!(login.body["scope"], "write");
+    let cookie = login.cookie();
+    let csrf = login.body["csrf_token"].as_str().unwrap();
+
+    let headers = [("cookie", cookie.as_str()), ("x-csrf-token", csrf)];
+    let reply = send(&app, request("POST", "/tasks", &headers, Some(json!({"description": "Allowed"})))).await;
+    assert_eq!(reply.status, StatusCode::CREATED);
+    let reply = send(&app, request("POST", "/api-keys", &headers, Some(json!({"name": "escalated"})))).await;
+    assert_eq!(reply.status, StatusCode::FORBIDDEN);
+    assert_eq!(reply.body["code"], "INSUFFICIENT_SCOPE");
+}
\ No newline at end of file
@@ -0,0 +1,165 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use async_trait::async_trait;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use todo_api::domain::model::task::{CreateTask, Task, UpdateTask};
+use todo_api::domain::repository::api_key::ApiKeyStorage;
+use todo_api::domain::repository::history::HistoryStorage;
+use todo_api::domain::repository::project::ProjectStorage;
+use todo_api::domain::repository::task::{TaskError, TaskRepository};
+use todo_api::domain::repository::user::UserStorage;
+use todo_api::infrastructure::http::generated_routes::create_generated_router_with;
+use todo_api::infrastructure::server::serve;
+use todo_api::interface::gateway::inmemory::{InMemoryApiKeyRepository, InMemoryProjectRepository, InMemoryTaskHistoryRepository, InMemoryTaskRepository, InMemoryUserRepository};
+
+/// 一覧取得に時間がかかり、停止フックが呼ばれたかを記録するリポジトリ
+#[derive(Clone)]
+struct SlowRepository {
+    inner: InMemoryTaskRepository,
+    delay: Duration,
+    shut_down: Arc<AtomicBool>,
+}
+
+impl SlowRepository {
+    fn new(delay: Duration) -> Self {
+        Self { inner: InMemoryTaskRepository::new(), delay, shut_down: Arc::new(AtomicBool::new(false)) }
+    }
+}
+
+#[async_trait]
+impl TaskRepository for SlowRepository {
+    async fn get_all(&self) -> Result<Vec<Task>, TaskError> {
+        tokio::time::sleep(self.delay).await;
+        self.inner.get_all().await
+    }
+    async fn get_by_id(&self, id: u64) -> Result<Task, TaskError> {
+        self.inner.get_by_id(id).await
+    }
+    async fn create(&self, task: CreateTask) -> Result<Task, TaskError> {
+        self.inner.create(task).await
+    }
+    async fn update(&self, id: u64, update_task: UpdateTask) -> Result<Task, TaskError> {
+        self.inner.update(id, update_task).await
+    }
+    async fn delete(&self, id: u64) -> Result<(), TaskError> {
+        self.inner.delete(id).await
+    }
+    async fn complete(&self, id: u64) -> Result<Task, TaskError> {
+        self.inner.complete(id).await
+    }
+    async fn uncomplete(&self, id: u64) -> Result<Task, TaskError> {
+        self.inner.uncomplete(id).await
+    }
+    async fn shutdown(&self) -> Result<(), TaskError> {
+        self.shut_down.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+impl ProjectStorage for SlowRepository {
+    type Projects = InMemoryProjectRepository;
+
+    fn projects(&self) -> Self::Projects {
+        self.inner.projects()
+    }
+}
+
+impl UserStorage for SlowRepository {
+    type Users = InMemoryUserRepository;
+
+    fn users(&self) -> Self::Users {
+        self.inner.users()
+    }
+}
+
+impl HistoryStorage for SlowRepository {
+    type History = InMemoryTaskHistoryRepository;
+
+    fn history(&self) -> Self::History {
+        self.inner.history()
+    }
+}
+
+impl ApiKeyStorage for SlowRepository {
+    type ApiKeys = InMemoryApiKeyRepository;
+
+    fn api_keys(&self) -> Self::ApiKeys {
+        self.inner.api_keys()
+    }
+}
+
+/// HTTP/1.1 で GET を送り、レスポンス全体を文字列で返す
+async fn http_get(addr: std::net::SocketAddr, path: &str) -> std::io::Result<String> {
+    let mut stream = TcpStream::connect(addr).await?;
+    let request = format!("GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n", path);
+    stream.write_all(request.as_bytes()).await?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await?;
+    Ok(response)
+}
+
+#[tokio::test]
+async fn test_slow_request_completes_during_shutdown() {
+    let repository = SlowRepository::new(Duration::from_millis(500));
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+
+    let server = tokio::spawn({
+        let repository = repository.clone();
+        async move {
+            let router = create_generated_router_with(repository.clone());
+            let shutdown = async move {
+                let _ = shutdown_rx.await;
+            };
+            serve(listener, router, &repository, shutdown, Duration::from_secs(5)).await
+        }
+    });
+
+    // 遅いリクエストの処理中に停止を指示する
+    let slow_request = tokio::spawn(http_get(addr, "/tasks"));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    shutdown_tx.send(()).unwrap();
+
+    let response = slow_request.await.unwrap().unwrap();
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    assert!(response.ends_with("[]"), "{}", response);
+
+    let summary = server.await.unwrap().unwrap();
+    assert_eq!(summary.requests_served, 1);
+    assert_eq!(summary.abandoned_requests, 0);
+    assert!(repository.shut_down.load(Ordering::SeqCst));
+
+    // 停止後は新しい接続を受け付けない
+    assert!(http_get(addr, "/tasks").await.is_err());
+}
+
+#[tokio::test]
+async fn test_shutdown_deadline_abandons_stuck_requests() {
+    let repository = SlowRepository::new(Duration::from_secs(10));
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (shutdown_tx, shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+
+    let server = tokio::spawn({
+        let repository = repository.clone();
+        async move {
+            let router = create_generated_router_with(repository.clone());
+            let shutdown = async move {
+                let _ = shutdown_rx.await;
+            };
+            serve(listener, router, &repository, shutdown, Duration::from_millis(200)).await
+        }
+    });
+
+    let _stuck_request = tokio::spawn(http_get(addr, "/tasks"));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    shutdown_tx.send(()).unwrap();
+
+    let summary = tokio::time::timeout(Duration::from_secs(5), server).await.unwrap().unwrap().unwrap();
+    assert_eq!(summary.abandoned_requests, 1);
+    assert!(summary.drain_time >= Duration::from_millis(200));
+    assert!(repository.shut_down.load(Ordering::SeqCst));
+}
@@ -0,0 +1,124 @@
+use axum::body::Body;
+use axum::http::{header::{CONTENT_TYPE, LINK}, Request, StatusCode};
+use axum::Router;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn get(uri: &str) -> Request<Body> {
+    Request::get(uri).header("host", "localhost").body(Body::empty()).unwrap()
+}
+
+fn post_json(uri: &str, body: &str) -> Request<Body> {
+    Request::post(uri)
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn ids(body: &serde_json::Value) -> Vec<i64> {
+    body.as_array().unwrap().iter().map(|t| t["id"].as_i64().unwrap()).collect()
+}
+
+/// 3件のタスクを作成してタグを付ける（1: work,urgent / 2: work / 3: home,urgent）
+async fn seed(app: &Router) {
+    for (i, tags) in [r#"["Work","urgent"]"#, r#"["work"]"#, r#"["home","urgent"]"#].iter().enumerate() {
+        send(app, post_json("/tasks", &format!(r#"{{"description":"Task {}"}}"#, i + 1))).await;
+        let (status, _) = send(app, post_json(&format!("/tasks/{}/tags", i + 1), &format!(r#"{{"tags":{}}}"#, tags))).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn test_add_and_remove_tags() {
+    let app = create_generated_router();
+    send(&app, post_json("/tasks", r#"{"description":"Task 1"}"#)).await;
+
+    let (status, task) = send(&app, get("/tasks/1")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["tags"], serde_json::json!([]));
+
+    let (status, task) = send(&app, post_json("/tasks/1/tags", r#"{"tags":["Work"," home "]}"#)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["tags"], serde_json::json!(["home", "work"]));
+
+    let request = Request::delete("/tasks/1/tags/work").header("host", "localhost").body(Body::empty()).unwrap();
+    let (status, task) = send(&app, request).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(task["tags"], serde_json::json!(["home"]));
+}
+
+#[tokio::test]
+async fn test_filter_tasks_by_tag() {
+    let app = create_generated_router();
+    seed(&app).await;
+
+    let (_, body) = send(&app, get("/tasks?tag=work")).await;
+    assert_eq!(ids(&body), vec![1, 2]);
+
+    let (_, body) = send(&app, get("/tasks?tag=work&tag=urgent")).await;
+    assert_eq!(ids(&body), vec![1]);
+
+    let (_, body) = send(&app, get("/tasks?tag=work&tag=home&tag_mode=any")).await;
+    assert_eq!(ids(&body), vec![1, 2, 3]);
+
+    let (_, body) = send(&app, get("/tasks?tag=work&tag=WORK&tag_mode=all")).await;
+    assert_eq!(ids(&body), vec![1, 2]);
+}
+
+#[tokio::test]
+async fn test_tag_filter_is_kept_in_next_link() {
+    let app = create_generated_router();
+    seed(&app).await;
+
+    let response = app.clone().oneshot(get("/tasks?tag=URGENT&limit=1")).await.unwrap();
+    let link = response.headers().get(LINK).unwrap().to_str().unwrap().to_string();
+    assert!(link.contains("tag=urgent&"), "{}", link);
+
+    let next = link.trim_start_matches('<').split('>').next().unwrap();
+    let (_, body) = send(&app, get(next)).await;
+    assert_eq!(ids(&body), vec![3]);
+}
+
+#[tokio::test]
+async fn test_list_tags_with_counts() {
+    let app = create_generated_router();
+    seed(&app).await;
+
+    let (status, body) = send(&app, get("/tags")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        body,
+        serde_json::json!([
+            {"tag": "home", "count": 1},
+            {"tag": "urgent", "count": 2},
+            {"tag": "work", "count": 2},
+        ])
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_tag_returns_problem() {
+    let app = create_generated_router();
+    send(&app, post_json("/tasks", r#"{"description":"Task 1"}"#)).await;
+
+    let (status, problem) = send(&app, post_json("/tasks/1/tags", r#"{"tags":["not valid"]}"#)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(problem["code"], "INVALID_TAG");
+    assert_eq!(problem["field"], "tags");
+
+    let (status, problem) = send(&app, get("/tasks?tag=a,b")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(problem["code"], "INVALID_TAG");
+
+    let (status, problem) = send(&app, post_json("/tasks/99/tags", r#"{"tags":["work"]}"#)).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(problem["code"], "TASK_NOT_FOUND");
+}
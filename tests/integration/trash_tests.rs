@@ -0,0 +1,91 @@
+use axum::body::Body;
+use axum::http::{header::CONTENT_TYPE, Request, StatusCode};
+use axum::Router;
+use serde_json::json;
+use todo_api::infrastructure::http::generated_routes::create_generated_router;
+use tower::ServiceExt;
+
+async fn send(app: &Router, request: Request<Body>) -> (StatusCode, serde_json::Value) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+fn request(method: &str, uri: &str) -> Request<Body> {
+    Request::builder().method(method).uri(uri).header("host", "localhost").body(Body::empty()).unwrap()
+}
+
+fn post_json(uri: &str, body: &serde_json::Value) -> Request<Body> {
+    Request::post(uri)
+        .header("host", "localhost")
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn ids(body: &serde_json::Value) -> Vec<i64> {
+    body.as_array().unwrap().iter().map(|t| t["id"].as_i64().unwrap()).collect()
+}
+
+#[tokio::test]
+async fn test_deleted_task_moves_to_trash_and_can_be_restored() {
+    let app = create_generated_router();
+    send(&app, post_json("/tasks", &json!({"description": "Write report"}))).await;
+    send(&app, post_json("/tasks", &json!({"description": "Write tests"}))).await;
+
+    let (status, _) = send(&app, request("DELETE", "/tasks/1")).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // 削除したタスクは一覧・検索・個別取得に現れない
+    let (status, _) = send(&app, request("GET", "/tasks/1")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (_, tasks) = send(&app, request("GET", "/tasks")).await;
+    assert_eq!(ids(&tasks), vec![2]);
+    assert!(tasks[0].get("deleted_at").is_none());
+    let (_, found) = send(&app, request("GET", "/tasks/search?q=Write")).await;
+    assert_eq!(ids(&found), vec![2]);
+
+    let (status, trash) = send(&app, request("GET", "/tasks/trash")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(ids(&trash), vec![1]);
+    assert!(trash[0]["deleted_at"].is_string());
+
+    let response = app.clone().oneshot(request("POST", "/tasks/1/restore")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["etag"], "\"3\"");
+    let (_, tasks) = send(&app, request("GET", "/tasks")).await;
+    assert_eq!(ids(&tasks), vec![1, 2]);
+    let (_, trash) = send(&app, request("GET", "/tasks/trash")).await;
+    assert_eq!(trash, json!([]));
+
+    // ゴミ箱にないタスクは戻せない
+    let (status, problem) = send(&app, request("POST", "/tasks/2/restore")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(problem["code"], json!("TASK_NOT_FOUND"));
+}
+
+#[tokio::test]
+async fn test_purge_trash() {
+    let app = create_generated_router();
+    for description in ["First", "Second"] {
+        send(&app, post_json("/tasks", &json!({"description": description}))).await;
+    }
+    send(&app, request("DELETE", "/tasks/1")).await;
+    send(&app, request("DELETE", "/tasks/2")).await;
+
+    // 削除してから1日経っていないタスクは残る
+    let (status, body) = send(&app, request("DELETE", "/tasks/trash?older_than_days=1")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!({"purged": 0}));
+
+    let (status, _) = send(&app, request("DELETE", "/tasks/trash?older_than_days=-1")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (_, body) = send(&app, request("DELETE", "/tasks/trash")).await;
+    assert_eq!(body, json!({"purged": 2}));
+    let (_, trash) = send(&app, request("GET", "/tasks/trash")).await;
+    assert_eq!(trash, json!([]));
+    let (status, _) = send(&app, request("POST", "/tasks/1/restore")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -1,4 +1,5 @@
 pub mod domain;
 pub mod repository;
 pub mod usecase;
+pub mod infrastructure;
 pub mod integration; 
\ No newline at end of file
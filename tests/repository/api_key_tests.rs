@@ -0,0 +1,80 @@
+use todo_api::domain::model::api_key::{hash_secret, ApiKeyScope, ApiKeyValidationError};
+use todo_api::domain::model::user::{CreateUser, DEFAULT_USER_ID};
+use todo_api::domain::repository::api_key::{ApiKeyError, ApiKeyRepository, ApiKeyStorage, NewApiKey};
+use todo_api::domain::repository::user::{UserRepository, UserStorage};
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+
+fn new_key(name: &str, scope: ApiKeyScope, user_id: u64, secret: &str) -> NewApiKey {
+    NewApiKey {
+        name: name.to_string(),
+        scope,
+        user_id,
+        prefix: secret[..8].to_string(),
+        secret_hash: hash_secret(secret),
+    }
+}
+
+async fn assert_api_key_contract<R: UserStorage + ApiKeyStorage>(repo: R) {
+    let keys = repo.api_keys();
+    assert!(keys.get_all().await.unwrap().is_empty());
+
+    let alice = repo.users().create(CreateUser { name: "alice".to_string() }).await.unwrap();
+    let ci = keys.create(new_key("ci", ApiKeyScope::Write, alice.id, "todo_ci_secret")).await.unwrap();
+    assert_eq!(ci.name, "ci");
+    assert_eq!(ci.scope, ApiKeyScope::Write);
+    assert_eq!(ci.user_id, alice.id);
+    assert_eq!(ci.prefix, "todo_ci_");
+    assert!(ci.is_active());
+    let viewer = keys.create(new_key("viewer", ApiKeyScope::Read, DEFAULT_USER_ID, "todo_viewer_secret")).await.unwrap();
+    assert_ne!(viewer.id, ci.id);
+
+    // ハッシュ値で探せる
+    let found = keys.find_by_hash(&hash_secret("todo_ci_secret")).await.unwrap().unwrap();
+    assert_eq!(found.id, ci.id);
+    assert_eq!(found.secret_hash, hash_secret("todo_ci_secret"));
+    assert!(keys.find_by_hash(&hash_secret("todo_other")).await.unwrap().is_none());
+
+    // 存在しない利用者の鍵は作らない
+    assert!(matches!(
+        keys.create(new_key("ghost", ApiKeyScope::Read, 999, "todo_ghost_secret")).await,
+        Err(ApiKeyError::ValidationError(ApiKeyValidationError::UserNotFound(999)))
+    ));
+
+    // 失効は何度呼んでも最初の日時のまま
+    let revoked = keys.revoke(ci.id).await.unwrap();
+    assert!(!revoked.is_active());
+    assert_eq!(keys.revoke(ci.id).await.unwrap().revoked_at, revoked.revoked_at);
+    let found = keys.find_by_hash(&hash_secret("todo_ci_secret")).await.unwrap().unwrap();
+    assert!(!found.is_active());
+    assert!(matches!(keys.revoke(999).await, Err(ApiKeyError::NotFound(999))));
+
+    let all: Vec<(u64, bool)> = keys.get_all().await.unwrap().iter().map(|key| (key.id, key.is_active())).collect();
+    assert_eq!(all, vec![(ci.id, false), (viewer.id, true)]);
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_api_keys() {
+    assert_api_key_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_api_keys() {
+    assert_api_key_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_api_keys_persist_across_reopen() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tasks.db");
+
+    let repo = SqliteTaskRepository::open(&path).unwrap();
+    let key = repo.api_keys().create(new_key("ci", ApiKeyScope::Admin, DEFAULT_USER_ID, "todo_persisted")).await.unwrap();
+    drop(repo);
+
+    let repo = SqliteTaskRepository::open(&path).unwrap();
+    let found = repo.api_keys().find_by_hash(&hash_secret("todo_persisted")).await.unwrap().unwrap();
+    assert_eq!(found.id, key.id);
+    assert_eq!(found.scope, ApiKeyScope::Admin);
+    assert_eq!(found.created_at.timestamp(), key.created_at.timestamp());
+}
@@ -0,0 +1,156 @@
+use std::sync::{Arc, Mutex};
+use todo_api::domain::model::event::{TaskChange, TaskChangeKind};
+use todo_api::domain::model::task::{CreateTask, UpdateTask};
+use todo_api::domain::repository::event::TaskChangePublisher;
+use todo_api::domain::repository::task::{BatchMode, TaskError, TaskOperation, TaskRepository};
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::notifying::task::NotifyingTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+
+fn create(description: &str) -> TaskOperation {
+    TaskOperation::Create(CreateTask { description: description.to_string(), ..Default::default() })
+}
+
+fn describe(id: u64, description: &str) -> TaskOperation {
+    TaskOperation::Update {
+        id,
+        update: UpdateTask {
+            description: Some(description.to_string()),
+            add_tags: vec!["batch".to_string()],
+            ..Default::default()
+        },
+    }
+}
+
+async fn assert_atomic_batch_contract<R: TaskRepository>(repo: R) {
+    let existing = repo.create(CreateTask { description: "Existing".to_string(), ..Default::default() }).await.unwrap();
+
+    let outcome = repo
+        .apply_batch(
+            vec![create("First"), describe(existing.id, "Changed"), TaskOperation::Complete(existing.id)],
+            BatchMode::Atomic,
+        )
+        .await
+        .unwrap();
+    assert!(outcome.committed);
+    assert_eq!(outcome.results.len(), 3);
+    let created = outcome.results[0].as_ref().unwrap();
+    assert!(created.previous.is_none());
+    let created = created.current.clone().unwrap();
+    let completed = outcome.results[2].as_ref().unwrap();
+    assert_eq!(completed.previous.as_ref().unwrap().description, "Changed");
+    assert!(completed.current.as_ref().unwrap().completed);
+
+    // 途中で失敗すると、それまでの作成・更新・削除もすべて取り消される
+    let before = repo.get_all().await.unwrap();
+    let outcome = repo
+        .apply_batch(
+            vec![
+                create("Rolled back"),
+                describe(existing.id, "Rolled back"),
+                TaskOperation::Delete(created.id),
+                TaskOperation::Uncomplete(999),
+                TaskOperation::Delete(existing.id),
+            ],
+            BatchMode::Atomic,
+        )
+        .await
+        .unwrap();
+    assert!(!outcome.committed);
+    assert_eq!(outcome.results.len(), 4);
+    assert!(outcome.results[..3].iter().all(Result::is_ok));
+    assert!(matches!(outcome.results[3], Err(TaskError::NotFound(999))));
+
+    let mut after = repo.get_all().await.unwrap();
+    let mut before = before;
+    before.sort_by_key(|task| task.id);
+    after.sort_by_key(|task| task.id);
+    assert_eq!(after, before);
+    let tags = repo.get_tag_counts().await.unwrap();
+    assert_eq!(tags.len(), 1);
+    assert_eq!((tags[0].tag.as_str(), tags[0].count), ("batch", 1));
+
+    // 取り消された作成で採番したIDは次の作成で使われる
+    let next = repo.create(CreateTask { description: "Next".to_string(), ..Default::default() }).await.unwrap();
+    assert_eq!(next.id, created.id + 1);
+}
+
+async fn assert_best_effort_batch_contract<R: TaskRepository>(repo: R) {
+    let existing = repo.create(CreateTask { description: "Existing".to_string(), ..Default::default() }).await.unwrap();
+
+    let outcome = repo
+        .apply_batch(
+            vec![
+                create(""),
+                TaskOperation::Complete(existing.id),
+                TaskOperation::Delete(999),
+                TaskOperation::Update { id: existing.id, update: UpdateTask::default() },
+                TaskOperation::Delete(existing.id),
+            ],
+            BatchMode::BestEffort,
+        )
+        .await
+        .unwrap();
+    assert!(outcome.committed);
+    assert_eq!(outcome.results.len(), 5);
+    assert!(matches!(outcome.results[0], Err(TaskError::ValidationError(_))));
+    assert!(outcome.results[1].is_ok());
+    assert!(matches!(outcome.results[2], Err(TaskError::NotFound(999))));
+    assert!(matches!(outcome.results[3], Err(TaskError::InvalidOperation(_))));
+    let deleted = outcome.results[4].as_ref().unwrap();
+    assert!(deleted.previous.as_ref().unwrap().completed);
+    assert!(deleted.current.is_none());
+
+    assert!(repo.get_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_atomic_batch() {
+    assert_atomic_batch_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_atomic_batch() {
+    assert_atomic_batch_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_best_effort_batch() {
+    assert_best_effort_batch_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_best_effort_batch() {
+    assert_best_effort_batch_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
+
+#[derive(Default)]
+struct RecordingPublisher {
+    changes: Mutex<Vec<TaskChange>>,
+}
+
+impl TaskChangePublisher for RecordingPublisher {
+    fn publish(&self, change: TaskChange) {
+        self.changes.lock().unwrap().push(change);
+    }
+}
+
+#[tokio::test]
+async fn test_batch_notifies_only_committed_changes() {
+    let publisher = Arc::new(RecordingPublisher::default());
+    let repo = NotifyingTaskRepository::new(InMemoryTaskRepository::new(), publisher.clone());
+
+    repo.apply_batch(vec![create("Rolled back"), TaskOperation::Delete(999)], BatchMode::Atomic)
+        .await
+        .unwrap();
+    assert!(publisher.changes.lock().unwrap().is_empty());
+
+    repo.apply_batch(
+        vec![create("Task"), TaskOperation::Complete(1), TaskOperation::Delete(999), TaskOperation::Delete(1)],
+        BatchMode::BestEffort,
+    )
+    .await
+    .unwrap();
+    let kinds: Vec<TaskChangeKind> = publisher.changes.lock().unwrap().iter().map(|change| change.kind).collect();
+    assert_eq!(kinds, vec![TaskChangeKind::Created, TaskChangeKind::Completed, TaskChangeKind::Deleted]);
+}
@@ -1,4 +1,5 @@
 use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
 use todo_api::domain::model::task::CreateTask;
 use todo_api::domain::repository::task::TaskRepository;
 
@@ -9,6 +10,7 @@ async fn test_in_memory_repository_create_and_get() {
     // タスクを作成
     let create_task = CreateTask {
         description: "Test task".to_string(),
+        ..Default::default()
     };
     let created_task = repo.create(create_task).await.unwrap();
     
@@ -20,6 +22,22 @@ async fn test_in_memory_repository_create_and_get() {
     assert_eq!(created_task.completed, retrieved_task.completed);
 }
 
+#[tokio::test]
+async fn test_sqlite_repository_create_and_get() {
+    let repo = SqliteTaskRepository::open_in_memory().unwrap();
+
+    // タスクを作成
+    let create_task = CreateTask {
+        description: "Test task".to_string(),
+        ..Default::default()
+    };
+    let created_task = repo.create(create_task).await.unwrap();
+
+    // 作成されたタスクを取得
+    let retrieved_task = repo.get_by_id(created_task.id).await.unwrap();
+    assert_eq!(created_task, retrieved_task);
+}
+
 #[tokio::test]
 async fn test_in_memory_repository_get_all() {
     let repo = InMemoryTaskRepository::new();
@@ -27,14 +45,27 @@ async fn test_in_memory_repository_get_all() {
     // 複数のタスクを作成
     let task1 = CreateTask {
         description: "Task 1".to_string(),
+        ..Default::default()
     };
     let task2 = CreateTask {
         description: "Task 2".to_string(),
+        ..Default::default()
     };
     
     repo.create(task1).await.unwrap();
     repo.create(task2).await.unwrap();
     
+    let all_tasks = repo.get_all().await.unwrap();
+    assert_eq!(all_tasks.len(), 2);
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_get_all() {
+    let repo = SqliteTaskRepository::open_in_memory().unwrap();
+
+    repo.create(CreateTask { description: "Task 1".to_string(), ..Default::default() }).await.unwrap();
+    repo.create(CreateTask { description: "Task 2".to_string(), ..Default::default() }).await.unwrap();
+
     let all_tasks = repo.get_all().await.unwrap();
     assert_eq!(all_tasks.len(), 2);
 } 
\ No newline at end of file
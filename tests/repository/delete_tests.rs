@@ -1,4 +1,5 @@
 use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
 use todo_api::domain::model::task::CreateTask;
 use todo_api::domain::repository::task::{TaskRepository, TaskError};
 
@@ -9,6 +10,7 @@ async fn test_in_memory_repository_delete() {
     // タスクを作成
     let create_task = CreateTask {
         description: "Task to delete".to_string(),
+        ..Default::default()
     };
     let created_task = repo.create(create_task).await.unwrap();
     
@@ -19,4 +21,16 @@ async fn test_in_memory_repository_delete() {
     // 削除されたタスクを取得しようとするとエラー
     let get_result = repo.get_by_id(created_task.id).await;
     assert!(matches!(get_result, Err(TaskError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_delete() {
+    let repo = SqliteTaskRepository::open_in_memory().unwrap();
+    let created_task = repo.create(CreateTask { description: "Task to delete".to_string(), ..Default::default() }).await.unwrap();
+
+    assert!(repo.delete(created_task.id).await.is_ok());
+
+    // 削除されたタスクを取得・削除しようとするとエラー
+    assert!(matches!(repo.get_by_id(created_task.id).await, Err(TaskError::NotFound(_))));
+    assert!(matches!(repo.delete(created_task.id).await, Err(TaskError::NotFound(_))));
 } 
\ No newline at end of file
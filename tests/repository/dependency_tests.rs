@@ -0,0 +1,103 @@
+use todo_api::domain::model::task::{CreateTask, TaskValidationError, UpdateTask};
+use todo_api::domain::repository::task::{TaskError, TaskRepository};
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+
+async fn create<R: TaskRepository>(repo: &R, description: &str) -> u64 {
+    let create_task = CreateTask { description: description.to_string(), ..Default::default() };
+    repo.create(create_task).await.unwrap().id
+}
+
+fn depend_on(ids: &[u64]) -> UpdateTask {
+    UpdateTask { add_dependencies: ids.to_vec(), ..Default::default() }
+}
+
+fn depends_on(task: &todo_api::domain::model::task::Task) -> Vec<u64> {
+    task.depends_on.iter().copied().collect()
+}
+
+async fn assert_dependency_contract<R: TaskRepository>(repo: R) {
+    let design = create(&repo, "Design").await;
+    let build = create(&repo, "Build").await;
+    let ship = create(&repo, "Ship").await;
+
+    let task = repo.update(ship, depend_on(&[build])).await.unwrap();
+    assert_eq!(depends_on(&task), vec![build]);
+    repo.update(build, depend_on(&[design])).await.unwrap();
+    assert_eq!(depends_on(&repo.get_by_id(ship).await.unwrap()), vec![build]);
+
+    // 存在しないタスクや循環する依存は指定できない
+    assert!(matches!(
+        repo.update(ship, depend_on(&[999])).await,
+        Err(TaskError::ValidationError(TaskValidationError::DependencyNotFound(999)))
+    ));
+    assert!(matches!(
+        repo.update(design, depend_on(&[ship])).await,
+        Err(TaskError::ValidationError(TaskValidationError::DependencyCycle(id))) if id == ship
+    ));
+    assert!(repo.get_by_id(design).await.unwrap().depends_on.is_empty());
+
+    // 未完了の依存先が残っていれば完了にできない
+    assert!(matches!(
+        repo.complete(build).await,
+        Err(TaskError::Blocked { id, ref blockers }) if id == build && blockers == &vec![design]
+    ));
+    let complete = || UpdateTask { completed: Some(true), ..Default::default() };
+    assert!(matches!(repo.update(build, complete()).await, Err(TaskError::Blocked { .. })));
+    assert!(!repo.get_by_id(build).await.unwrap().completed);
+
+    repo.complete(design).await.unwrap();
+    repo.update(build, complete()).await.unwrap();
+
+    // 指定すれば未完了の依存先が残っていても完了にできる
+    let cleanup = create(&repo, "Clean up").await;
+    repo.update(cleanup, depend_on(&[ship])).await.unwrap();
+    let version = repo.get_by_id(cleanup).await.unwrap().version;
+    assert!(matches!(repo.force_complete(cleanup, Some(version + 1)).await, Err(TaskError::VersionMismatch { .. })));
+    let forced = repo.force_complete(cleanup, Some(version)).await.unwrap();
+    assert!(forced.completed);
+    assert_eq!(forced.version, version + 1);
+
+    let task = repo.update(cleanup, UpdateTask { remove_dependencies: vec![ship], ..Default::default() }).await.unwrap();
+    assert!(task.depends_on.is_empty());
+}
+
+async fn assert_trashed_dependency_contract<R: TaskRepository>(repo: R) {
+    let first = create(&repo, "First").await;
+    let second = create(&repo, "Second").await;
+    let third = create(&repo, "Third").await;
+    repo.update(first, depend_on(&[second])).await.unwrap();
+    repo.update(second, depend_on(&[third])).await.unwrap();
+
+    // ゴミ箱にあるタスクは待たない
+    repo.delete(second).await.unwrap();
+    repo.complete(first).await.unwrap();
+    repo.uncomplete(first).await.unwrap();
+
+    // ゴミ箱にある間にできた循環は、戻すときにその依存を外して解消する
+    repo.update(third, depend_on(&[first])).await.unwrap();
+    let restored = repo.restore(second).await.unwrap();
+    assert!(restored.depends_on.is_empty());
+    assert_eq!(depends_on(&repo.get_by_id(first).await.unwrap()), vec![second]);
+    assert!(matches!(repo.complete(first).await, Err(TaskError::Blocked { .. })));
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_dependencies() {
+    assert_dependency_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_dependencies() {
+    assert_dependency_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_trashed_dependencies() {
+    assert_trashed_dependency_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_trashed_dependencies() {
+    assert_trashed_dependency_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
@@ -0,0 +1,112 @@
+use todo_api::domain::model::task::{CreateTask, Task, TaskValidationError, UpdateTask};
+use todo_api::domain::repository::task::{ChildPolicy, TaskError, TaskQuery, TaskRepository};
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+
+async fn create_child<R: TaskRepository>(repo: &R, description: &str, parent_id: Option<u64>) -> u64 {
+    let create_task = CreateTask { description: description.to_string(), parent_id, ..Default::default() };
+    repo.create(create_task).await.unwrap().id
+}
+
+fn reparent(parent_id: Option<u64>) -> UpdateTask {
+    UpdateTask { parent_id: Some(parent_id), ..Default::default() }
+}
+
+fn ids<'a>(tasks: impl IntoIterator<Item = &'a Task>) -> Vec<u64> {
+    let mut ids: Vec<u64> = tasks.into_iter().map(|task| task.id).collect();
+    ids.sort_unstable();
+    ids
+}
+
+async fn assert_hierarchy_contract<R: TaskRepository>(repo: R) {
+    let root = create_child(&repo, "Release", None).await;
+    let child = create_child(&repo, "Write changelog", Some(root)).await;
+    let grandchild = create_child(&repo, "Collect merged PRs", Some(child)).await;
+    assert_eq!(repo.get_by_id(grandchild).await.unwrap().parent_id, Some(child));
+
+    // 存在しない親や循環する親は指定できない
+    let dangling = CreateTask { description: "Orphan".to_string(), parent_id: Some(999), ..Default::default() };
+    assert!(matches!(
+        repo.create(dangling).await,
+        Err(TaskError::ValidationError(TaskValidationError::ParentNotFound(999)))
+    ));
+    assert!(matches!(
+        repo.update(root, reparent(Some(grandchild))).await,
+        Err(TaskError::ValidationError(TaskValidationError::ParentCycle(_)))
+    ));
+    assert!(matches!(
+        repo.update(root, reparent(Some(root))).await,
+        Err(TaskError::ValidationError(TaskValidationError::ParentCycle(_)))
+    ));
+    assert_eq!(repo.get_by_id(root).await.unwrap().parent_id, None);
+
+    let query = TaskQuery { parent_id: Some(root), ..TaskQuery::default() };
+    assert_eq!(ids(&repo.query(query).await.unwrap().tasks), vec![child]);
+
+    // 子タスクを持つタスクは、扱いを指定しなければ削除できない
+    assert!(matches!(repo.delete(child).await, Err(TaskError::HasChildren(id)) if id == child));
+    assert!(matches!(
+        repo.delete_with_children(child, ChildPolicy::Reject, None).await,
+        Err(TaskError::HasChildren(_))
+    ));
+
+    // 親を外すと最上位のタスクになる
+    let moved = repo.update(grandchild, reparent(None)).await.unwrap();
+    assert_eq!(moved.parent_id, None);
+    repo.update(grandchild, reparent(Some(child))).await.unwrap();
+
+    let outcomes = repo.delete_with_children(root, ChildPolicy::Cascade, None).await.unwrap();
+    assert_eq!(outcomes[0].previous.as_ref().unwrap().id, root);
+    assert_eq!(ids(outcomes.iter().filter_map(|outcome| outcome.previous.as_ref())), vec![root, child, grandchild]);
+    assert!(outcomes.iter().all(|outcome| outcome.current.is_none()));
+    assert!(repo.get_all().await.unwrap().is_empty());
+    assert_eq!(ids(&repo.get_trash().await.unwrap()), vec![root, child, grandchild]);
+
+    // 親がゴミ箱にある子タスクは最上位のタスクとして戻る
+    let restored = repo.restore(child).await.unwrap();
+    assert_eq!(restored.parent_id, None);
+    assert_eq!(repo.restore(grandchild).await.unwrap().parent_id, Some(child));
+}
+
+async fn assert_orphan_contract<R: TaskRepository>(repo: R) {
+    let parent = create_child(&repo, "Release", None).await;
+    let first = create_child(&repo, "Write changelog", Some(parent)).await;
+    let second = create_child(&repo, "Tag release", Some(parent)).await;
+    let before = repo.get_by_id(first).await.unwrap();
+
+    let outcomes = repo.delete_with_children(parent, ChildPolicy::Orphan, Some(1)).await.unwrap();
+    assert_eq!(outcomes.len(), 3);
+    let orphaned: Vec<_> = outcomes.iter().filter_map(|outcome| outcome.current.as_ref()).collect();
+    assert_eq!(ids(orphaned.iter().copied()), vec![first, second]);
+    assert!(orphaned.iter().all(|task| task.parent_id.is_none()));
+    assert_eq!(repo.get_by_id(first).await.unwrap().version, before.version + 1);
+    assert_eq!(ids(&repo.get_all().await.unwrap()), vec![first, second]);
+
+    // 版数が一致しなければ何も変えない
+    let leaf = create_child(&repo, "Announce", Some(first)).await;
+    assert!(matches!(
+        repo.delete_with_children(first, ChildPolicy::Cascade, Some(99)).await,
+        Err(TaskError::VersionMismatch { .. })
+    ));
+    assert_eq!(repo.get_by_id(leaf).await.unwrap().parent_id, Some(first));
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_hierarchy() {
+    assert_hierarchy_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_hierarchy() {
+    assert_hierarchy_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_orphans_children() {
+    assert_orphan_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_orphans_children() {
+    assert_orphan_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
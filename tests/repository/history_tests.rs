@@ -0,0 +1,121 @@
+use std::sync::Arc;
+use todo_api::domain::model::history::{TaskHistoryEntry, TaskHistoryOperation};
+use todo_api::domain::model::task::{CreateTask, UpdateTask};
+use todo_api::domain::repository::history::HistoryStorage;
+use todo_api::domain::repository::task::{BatchMode, TaskOperation, TaskRepository};
+use todo_api::interface::gateway::audited::AuditedTaskRepository;
+use todo_api::interface::gateway::inmemory::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+
+fn audited<R: TaskRepository + HistoryStorage>(inner: R) -> AuditedTaskRepository<R> {
+    let history = Arc::new(inner.history());
+    AuditedTaskRepository::new(inner, history)
+}
+
+fn operations(history: &[TaskHistoryEntry]) -> Vec<TaskHistoryOperation> {
+    history.iter().map(|entry| entry.operation).collect()
+}
+
+async fn assert_history_contract<R: TaskRepository>(repo: AuditedTaskRepository<R>) {
+    let task = repo.create(CreateTask { description: "Task".to_string(), ..Default::default() }).await.unwrap();
+    let rename = UpdateTask { description: Some("Renamed".to_string()), ..Default::default() };
+    repo.update(task.id, rename).await.unwrap();
+    // 内容が変わらない更新と失敗した操作は記録しない
+    let no_op = UpdateTask { remove_tags: vec!["missing".to_string()], ..Default::default() };
+    repo.update(task.id, no_op).await.unwrap();
+    repo.update_if_version(task.id, UpdateTask { completed: Some(true), ..Default::default() }, 99).await.unwrap_err();
+    repo.complete(task.id).await.unwrap();
+    repo.delete(task.id).await.unwrap();
+    repo.restore(task.id).await.unwrap();
+    repo.delete(task.id).await.unwrap();
+    repo.purge_trash(None, None).await.unwrap();
+
+    // 完全に削除したタスクの履歴も残る
+    let history = repo.get_history(task.id).await.unwrap();
+    assert_eq!(
+        operations(&history),
+        vec![
+            TaskHistoryOperation::Created,
+            TaskHistoryOperation::Updated,
+            TaskHistoryOperation::Completed,
+            TaskHistoryOperation::Deleted,
+            TaskHistoryOperation::Restored,
+            TaskHistoryOperation::Deleted,
+            TaskHistoryOperation::Purged,
+        ]
+    );
+    assert!(history.windows(2).all(|pair| pair[0].id < pair[1].id));
+    assert!(history.iter().all(|entry| entry.task_id == task.id && entry.actor.is_none()));
+
+    let renamed = &history[1].changes;
+    assert_eq!(renamed.len(), 1);
+    assert_eq!(renamed[0].field, "description");
+    assert_eq!(renamed[0].before, Some(serde_json::json!("Task")));
+    assert_eq!(renamed[0].after, Some(serde_json::json!("Renamed")));
+
+    // 削除と復元はゴミ箱に移した日時の変化として残る
+    let deleted = &history[3].changes;
+    assert_eq!(deleted.len(), 1);
+    assert_eq!((deleted[0].field.as_str(), deleted[0].before.is_none(), deleted[0].after.is_some()), ("deleted_at", true, true));
+    assert_eq!(history[4].changes[0].before, deleted[0].after);
+
+    assert!(repo.get_history(999).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_history() {
+    assert_history_contract(audited(InMemoryTaskRepository::new())).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_history() {
+    assert_history_contract(audited(SqliteTaskRepository::open_in_memory().unwrap())).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_history_persists_across_reopen() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tasks.db");
+
+    let recorded = {
+        let repo = audited(SqliteTaskRepository::open(&path).unwrap()).with_actor(Arc::new(|| Some("alice".to_string())));
+        let task = repo.create(CreateTask { description: "Durable task".to_string(), ..Default::default() }).await.unwrap();
+        repo.update(task.id, UpdateTask { description: Some("Renamed".to_string()), ..Default::default() }).await.unwrap();
+        repo.get_history(task.id).await.unwrap()
+    };
+    assert_eq!(recorded.len(), 2);
+
+    // 開き直しても同じ履歴が取得でき、連番は続きから振られる
+    let repo = audited(SqliteTaskRepository::open(&path).unwrap());
+    assert_eq!(repo.get_history(recorded[0].task_id).await.unwrap(), recorded);
+    repo.complete(recorded[0].task_id).await.unwrap();
+    let history = repo.get_history(recorded[0].task_id).await.unwrap();
+    assert_eq!(operations(&history)[2], TaskHistoryOperation::Completed);
+    assert!(history[2].id > recorded[1].id);
+}
+
+#[tokio::test]
+async fn test_history_records_actor_and_committed_batches_only() {
+    let repo = audited(InMemoryTaskRepository::new()).with_actor(Arc::new(|| Some("alice".to_string())));
+
+    let rolled_back = vec![
+        TaskOperation::Create(CreateTask { description: "Rolled back".to_string(), ..Default::default() }),
+        TaskOperation::Delete(999),
+    ];
+    repo.apply_batch(rolled_back, BatchMode::Atomic).await.unwrap();
+    assert!(repo.get_history(1).await.unwrap().is_empty());
+
+    let committed = vec![
+        TaskOperation::Create(CreateTask { description: "Task".to_string(), ..Default::default() }),
+        TaskOperation::Complete(1),
+        TaskOperation::Delete(1),
+    ];
+    repo.apply_batch(committed, BatchMode::Atomic).await.unwrap();
+    let history = repo.get_history(1).await.unwrap();
+    assert_eq!(
+        operations(&history),
+        vec![TaskHistoryOperation::Created, TaskHistoryOperation::Completed, TaskHistoryOperation::Deleted]
+    );
+    assert!(history.iter().all(|entry| entry.actor.as_deref() == Some("alice")));
+    assert_eq!(history[2].changes[0].field, "deleted_at");
+}
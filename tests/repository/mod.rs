@@ -2,4 +2,16 @@ pub mod create_tests;
 pub mod read_tests;
 pub mod update_tests;
 pub mod delete_tests;
-pub mod validation_tests; 
\ No newline at end of file
+pub mod validation_tests;
+pub mod sqlite_tests;
+pub mod query_tests;
+pub mod version_tests;
+pub mod batch_tests;
+pub mod trash_tests;
+pub mod history_tests;
+pub mod hierarchy_tests;
+pub mod dependency_tests;
+pub mod recurrence_tests;
+pub mod project_tests;
+pub mod user_tests;
+pub mod api_key_tests;
\ No newline at end of file
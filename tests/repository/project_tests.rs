@@ -0,0 +1,107 @@
+use todo_api::domain::model::project::{CreateProject, ProjectValidationError, UpdateProject, INBOX_PROJECT_ID};
+use todo_api::domain::model::task::{CreateTask, TaskValidationError, UpdateTask};
+use todo_api::domain::model::user::DEFAULT_USER_ID;
+use todo_api::domain::repository::project::{ProjectError, ProjectRepository, ProjectStorage};
+use todo_api::domain::repository::task::{TaskError, TaskQuery, TaskRepository};
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+
+fn task_in(description: &str, project_id: Option<u64>) -> CreateTask {
+    CreateTask { description: description.to_string(), project_id, ..Default::default() }
+}
+
+async fn assert_project_contract<R: TaskRepository + ProjectStorage>(repo: R) {
+    let projects = repo.projects();
+
+    // 受信箱は最初からある
+    let all = projects.get_all().await.unwrap();
+    assert_eq!(all.len(), 1);
+    assert!(all[0].is_inbox());
+
+    let work = projects.create(CreateProject { name: " Work ".to_string(), owner_id: None }).await.unwrap();
+    assert_eq!(work.name, "Work");
+    assert_ne!(work.id, INBOX_PROJECT_ID);
+    assert!(matches!(
+        projects.create(CreateProject { name: "work".to_string(), owner_id: None }).await,
+        Err(ProjectError::ValidationError(ProjectValidationError::DuplicateName(_)))
+    ));
+    let home = projects.create(CreateProject { name: "Home".to_string(), owner_id: None }).await.unwrap();
+    assert!(matches!(
+        projects.update(home.id, UpdateProject { name: Some("WORK".to_string()) }).await,
+        Err(ProjectError::ValidationError(ProjectValidationError::DuplicateName(_)))
+    ));
+    let renamed = projects.update(home.id, UpdateProject { name: Some("Household".to_string()) }).await.unwrap();
+    assert_eq!(projects.get_by_id(home.id).await.unwrap().name, renamed.name);
+    assert!(matches!(projects.get_by_id(999).await, Err(ProjectError::NotFound(999))));
+
+    // 所属先を省略したタスクは受信箱に入り、存在しないプロジェクトには入れられない
+    let inbox_task = repo.create(task_in("Inbox task", None)).await.unwrap();
+    assert_eq!(inbox_task.project_id, INBOX_PROJECT_ID);
+    let work_task = repo.create(task_in("Work task", Some(work.id))).await.unwrap();
+    assert_eq!(work_task.project_id, work.id);
+    assert!(matches!(
+        repo.create(task_in("Lost task", Some(999))).await,
+        Err(TaskError::ValidationError(TaskValidationError::ProjectNotFound(999)))
+    ));
+    let move_to_missing = UpdateTask { project_id: Some(999), ..Default::default() };
+    assert!(matches!(
+        repo.update(inbox_task.id, move_to_missing).await,
+        Err(TaskError::ValidationError(TaskValidationError::ProjectNotFound(999)))
+    ));
+
+    // タスクを移すと版数が進み、プロジェクトで絞り込める
+    let move_to_work = UpdateTask { project_id: Some(work.id), ..Default::default() };
+    let moved = repo.update(inbox_task.id, move_to_work).await.unwrap();
+    assert_eq!(moved.project_id, work.id);
+    assert!(moved.version > inbox_task.version);
+    repo.complete(work_task.id).await.unwrap();
+    let query = TaskQuery { project_id: Some(work.id), ..Default::default() };
+    assert_eq!(repo.query(query).await.unwrap().tasks.len(), 2);
+    let query = TaskQuery { project_id: Some(INBOX_PROJECT_ID), ..Default::default() };
+    assert!(repo.query(query).await.unwrap().tasks.is_empty());
+
+    let counts = projects.count_tasks(None).await.unwrap();
+    assert_eq!(counts[&work.id].total, 2);
+    assert_eq!(counts[&work.id].completed, 1);
+    assert!(!counts.contains_key(&home.id));
+
+    // 名前は持ち主ごとに一意で、他の利用者のプロジェクトにはタスクを入れられない
+    let other_work = projects.create(CreateProject { name: "Work".to_string(), owner_id: Some(2) }).await.unwrap();
+    assert_eq!(other_work.owner_id, 2);
+    assert_eq!(work.owner_id, DEFAULT_USER_ID);
+    assert!(matches!(
+        projects.create(CreateProject { name: "Inbox".to_string(), owner_id: Some(2) }).await,
+        Err(ProjectError::ValidationError(ProjectValidationError::DuplicateName(_)))
+    ));
+    let foreign_task = CreateTask { owner_id: Some(2), ..task_in("Foreign task", Some(work.id)) };
+    assert!(matches!(
+        repo.create(foreign_task).await,
+        Err(TaskError::ValidationError(TaskValidationError::ProjectNotFound(_)))
+    ));
+    projects.delete(other_work.id).await.unwrap();
+
+    // 受信箱とタスクの残るプロジェクトは削除できない
+    assert!(matches!(projects.delete(INBOX_PROJECT_ID).await, Err(ProjectError::InvalidOperation(_))));
+    assert!(matches!(projects.delete(work.id).await, Err(ProjectError::NotEmpty(_))));
+    assert!(matches!(projects.delete(999).await, Err(ProjectError::NotFound(999))));
+    projects.delete(home.id).await.unwrap();
+
+    // ゴミ箱のタスクは受信箱に移ってから削除される
+    repo.delete(inbox_task.id).await.unwrap();
+    repo.delete(work_task.id).await.unwrap();
+    projects.delete(work.id).await.unwrap();
+    assert_eq!(projects.get_all().await.unwrap().len(), 1);
+    let restored = repo.restore(work_task.id).await.unwrap();
+    assert_eq!(restored.project_id, INBOX_PROJECT_ID);
+    assert_eq!(projects.count_tasks(None).await.unwrap()[&INBOX_PROJECT_ID].total, 1);
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_projects() {
+    assert_project_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_projects() {
+    assert_project_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
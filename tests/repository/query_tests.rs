@@ -0,0 +1,176 @@
+use chrono::{Duration, Utc};
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+use todo_api::domain::model::task::{CreateTask, UpdateTask};
+use todo_api::domain::repository::task::{SortDirection, TagCount, TagMatch, TaskQuery, TaskRepository, TaskSortField};
+
+/// 説明文 "Task 1" 〜 "Task n" のタスクを作成し、偶数番目を完了にする
+async fn seed<R: TaskRepository>(repo: &R, n: usize) {
+    for i in 1..=n {
+        let task = repo.create(CreateTask { description: format!("Task {}", i), ..Default::default() }).await.unwrap();
+        if i % 2 == 0 {
+            repo.complete(task.id).await.unwrap();
+        }
+    }
+}
+
+async fn ids<R: TaskRepository>(repo: &R, query: TaskQuery) -> Vec<u64> {
+    repo.query(query).await.unwrap().tasks.iter().map(|t| t.id).collect()
+}
+
+/// カーソルをたどって全ページのIDを集める
+async fn collect_pages<R: TaskRepository>(repo: &R, mut query: TaskQuery) -> Vec<Vec<u64>> {
+    let mut pages = Vec::new();
+    loop {
+        let page = repo.query(query.clone()).await.unwrap();
+        pages.push(page.tasks.iter().map(|t| t.id).collect());
+        match page.next {
+            Some(next) => query.after = Some(next),
+            None => return pages,
+        }
+    }
+}
+
+async fn assert_query_contract<R: TaskRepository>(repo: R) {
+    seed(&repo, 5).await;
+
+    // 既定ではID昇順で全件
+    assert_eq!(ids(&repo, TaskQuery::default()).await, vec![1, 2, 3, 4, 5]);
+
+    // 完了状態での絞り込み
+    let completed = TaskQuery { completed: Some(true), ..TaskQuery::default() };
+    assert_eq!(ids(&repo, completed).await, vec![2, 4]);
+    let pending = TaskQuery { completed: Some(false), ..TaskQuery::default() };
+    assert_eq!(ids(&repo, pending).await, vec![1, 3, 5]);
+
+    // 降順
+    let desc = TaskQuery { direction: SortDirection::Desc, ..TaskQuery::default() };
+    assert_eq!(ids(&repo, desc).await, vec![5, 4, 3, 2, 1]);
+
+    // 更新日時順では最後に更新したタスクが末尾に来る
+    let update_task = UpdateTask { description: Some("Task 1 updated".to_string()), completed: None, ..Default::default() };
+    repo.update(1, update_task).await.unwrap();
+    let by_updated = TaskQuery { sort: TaskSortField::UpdatedAt, ..TaskQuery::default() };
+    assert_eq!(ids(&repo, by_updated).await.last(), Some(&1));
+
+    // カーソルで重複・欠落なくページをたどれる
+    for sort in [TaskSortField::Id, TaskSortField::CreatedAt, TaskSortField::UpdatedAt] {
+        for direction in [SortDirection::Asc, SortDirection::Desc] {
+            let query = TaskQuery { sort, direction, limit: Some(2), ..TaskQuery::default() };
+            let all = ids(&repo, TaskQuery { limit: None, ..query.clone() }).await;
+            let pages = collect_pages(&repo, query).await;
+            assert_eq!(pages.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 2, 1]);
+            assert_eq!(pages.concat(), all, "{:?} {:?}", sort, direction);
+        }
+    }
+
+    // 絞り込みとページングの組み合わせ
+    let query = TaskQuery { completed: Some(false), limit: Some(2), ..TaskQuery::default() };
+    assert_eq!(collect_pages(&repo, query).await, vec![vec![1, 3], vec![5]]);
+
+    // ちょうど件数分しかない場合は次ページなし
+    let query = TaskQuery { limit: Some(5), ..TaskQuery::default() };
+    assert!(repo.query(query).await.unwrap().next.is_none());
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_query() {
+    assert_query_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_query() {
+    assert_query_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
+
+async fn assert_due_date_filter<R: TaskRepository>(repo: R) {
+    let now = Utc::now();
+    for (i, hours) in [Some(1), Some(5), None, Some(48)].into_iter().enumerate() {
+        let create_task = CreateTask {
+            description: format!("Task {}", i + 1),
+            due_at: hours.map(|h| now + Duration::hours(h)),
+            ..Default::default()
+        };
+        repo.create(create_task).await.unwrap();
+    }
+
+    // 期限のないタスクは期限による絞り込みに含まれない
+    let within_day = TaskQuery {
+        due_from: Some(now),
+        due_before: Some(now + Duration::hours(24)),
+        ..TaskQuery::default()
+    };
+    assert_eq!(ids(&repo, within_day).await, vec![1, 2]);
+
+    let before = TaskQuery { due_before: Some(now + Duration::hours(3)), ..TaskQuery::default() };
+    assert_eq!(ids(&repo, before).await, vec![1]);
+
+    let from = TaskQuery { due_from: Some(now + Duration::hours(3)), ..TaskQuery::default() };
+    assert_eq!(ids(&repo, from).await, vec![2, 4]);
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_due_date_filter() {
+    assert_due_date_filter(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_due_date_filter() {
+    assert_due_date_filter(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
+
+async fn tag<R: TaskRepository>(repo: &R, id: u64, tags: &[&str]) {
+    let update_task = UpdateTask { add_tags: tags.iter().map(|t| t.to_string()).collect(), ..Default::default() };
+    repo.update(id, update_task).await.unwrap();
+}
+
+async fn assert_tag_filter<R: TaskRepository>(repo: R) {
+    seed(&repo, 4).await;
+    tag(&repo, 1, &["work", "urgent"]).await;
+    tag(&repo, 2, &["Work"]).await;
+    tag(&repo, 3, &["home", "urgent"]).await;
+
+    // タグは正規化して保存される
+    assert_eq!(repo.get_by_id(2).await.unwrap().tags.into_iter().collect::<Vec<_>>(), vec!["work"]);
+
+    let tags = |tags: &[&str], tag_match| TaskQuery {
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        tag_match,
+        ..TaskQuery::default()
+    };
+    assert_eq!(ids(&repo, tags(&["work"], TagMatch::All)).await, vec![1, 2]);
+    assert_eq!(ids(&repo, tags(&["work", "urgent"], TagMatch::All)).await, vec![1]);
+    // 同じタグを重ねて指定しても結果は変わらない
+    assert_eq!(ids(&repo, tags(&["work", "work"], TagMatch::All)).await, vec![1, 2]);
+    assert_eq!(ids(&repo, tags(&["urgent", "work", "urgent"], TagMatch::All)).await, vec![1]);
+    assert_eq!(ids(&repo, tags(&["work", "work"], TagMatch::Any)).await, vec![1, 2]);
+    assert_eq!(ids(&repo, tags(&["work", "home"], TagMatch::Any)).await, vec![1, 2, 3]);
+    assert_eq!(ids(&repo, tags(&["missing"], TagMatch::Any)).await, Vec::<u64>::new());
+
+    // 他の条件と組み合わせられる
+    let pending_urgent = TaskQuery { completed: Some(false), ..tags(&["urgent"], TagMatch::All) };
+    assert_eq!(ids(&repo, pending_urgent).await, vec![1, 3]);
+
+    let count = |tag: &str, count| TagCount { tag: tag.to_string(), count };
+    assert_eq!(
+        repo.get_tag_counts().await.unwrap(),
+        vec![count("home", 1), count("urgent", 2), count("work", 2)]
+    );
+
+    // タグを外したタスクや削除したタスクは集計・絞り込みから消える
+    let update_task = UpdateTask { remove_tags: vec!["urgent".to_string()], ..Default::default() };
+    repo.update(1, update_task).await.unwrap();
+    repo.delete(3).await.unwrap();
+    assert_eq!(ids(&repo, tags(&["urgent"], TagMatch::Any)).await, Vec::<u64>::new());
+    assert_eq!(repo.get_tag_counts().await.unwrap(), vec![count("work", 2)]);
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_tag_filter() {
+    assert_tag_filter(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_tag_filter() {
+    assert_tag_filter(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
@@ -1,5 +1,7 @@
 use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
-use todo_api::domain::repository::task::{TaskRepository, TaskError};
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+use todo_api::domain::model::task::CreateTask;
+use todo_api::domain::repository::task::{TaskCounts, TaskRepository, TaskError};
 
 #[tokio::test]
 async fn test_in_memory_repository_not_found() {
@@ -8,4 +10,36 @@ async fn test_in_memory_repository_not_found() {
     // 存在しないタスクを取得しようとするとエラー
     let result = repo.get_by_id(999).await;
     assert!(matches!(result, Err(TaskError::NotFound(999))));
-} 
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_not_found() {
+    let repo = SqliteTaskRepository::open_in_memory().unwrap();
+
+    assert!(matches!(repo.get_by_id(999).await, Err(TaskError::NotFound(999))));
+    assert!(matches!(repo.complete(999).await, Err(TaskError::NotFound(999))));
+    assert!(matches!(repo.uncomplete(999).await, Err(TaskError::NotFound(999))));
+}
+
+async fn assert_count_contract<R: TaskRepository>(repo: R) {
+    assert_eq!(repo.count_tasks().await.unwrap(), TaskCounts::default());
+
+    for description in ["a", "b", "c"] {
+        repo.create(CreateTask { description: description.to_string(), ..Default::default() }).await.unwrap();
+    }
+    repo.complete(2).await.unwrap();
+
+    let counts = repo.count_tasks().await.unwrap();
+    assert_eq!(counts, TaskCounts { total: 3, completed: 1 });
+    assert_eq!(counts.pending(), 2);
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_count_tasks() {
+    assert_count_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_count_tasks() {
+    assert_count_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
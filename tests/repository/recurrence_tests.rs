@@ -0,0 +1,42 @@
+use todo_api::domain::model::recurrence::Recurrence;
+use todo_api::domain::model::task::{CreateTask, UpdateTask};
+use todo_api::domain::repository::task::{TaskQuery, TaskRepository};
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+
+async fn assert_recurrence_contract<R: TaskRepository>(repo: R) {
+    let weekly: Recurrence = "FREQ=WEEKLY;BYDAY=MO,FR".parse().unwrap();
+    let create_task = CreateTask {
+        description: "Standup notes".to_string(),
+        recurrence: Some(weekly.clone()),
+        tags: vec!["team".to_string()],
+        ..Default::default()
+    };
+    let id = repo.create(create_task).await.unwrap().id;
+
+    let stored = repo.get_by_id(id).await.unwrap();
+    assert_eq!(stored.recurrence, Some(weekly));
+    assert_eq!(stored.tags.iter().cloned().collect::<Vec<_>>(), vec!["team".to_string()]);
+    let query = TaskQuery { tags: vec!["team".to_string()], ..Default::default() };
+    assert_eq!(repo.query(query).await.unwrap().tasks.len(), 1);
+
+    let monthly: Recurrence = "FREQ=MONTHLY;BYMONTHDAY=1".parse().unwrap();
+    let update_task = UpdateTask { recurrence: Some(Some(monthly.clone())), ..Default::default() };
+    repo.update(id, update_task).await.unwrap();
+    assert_eq!(repo.get_by_id(id).await.unwrap().recurrence, Some(monthly));
+
+    let stop = UpdateTask { recurrence: Some(None), ..Default::default() };
+    let task = repo.update(id, stop).await.unwrap();
+    assert_eq!(task.recurrence, None);
+    assert_eq!(repo.get_by_id(id).await.unwrap().recurrence, None);
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_recurrence() {
+    assert_recurrence_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_recurrence() {
+    assert_recurrence_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
@@ -0,0 +1,113 @@
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+use chrono::{Duration, Utc};
+use todo_api::domain::model::task::{CreateTask, UpdateTask, TaskPriority};
+use todo_api::domain::repository::task::TaskRepository;
+
+#[tokio::test]
+async fn test_sqlite_repository_persists_across_reopen() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tasks.db");
+
+    let created_task = {
+        let repo = SqliteTaskRepository::open(&path).unwrap();
+        let task = repo.create(CreateTask { description: "Durable task".to_string(), ..Default::default() }).await.unwrap();
+        repo.complete(task.id).await.unwrap()
+    };
+
+    // 開き直しても同じタスクが取得できること
+    let repo = SqliteTaskRepository::open(&path).unwrap();
+    let stored = repo.get_by_id(created_task.id).await.unwrap();
+    assert_eq!(stored, created_task);
+
+    // 削除済みのIDは再利用されないこと
+    repo.delete(created_task.id).await.unwrap();
+    let next_task = repo.create(CreateTask { description: "Next task".to_string(), ..Default::default() }).await.unwrap();
+    assert!(next_task.id > created_task.id);
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_priority_and_due_at() {
+    let repo = SqliteTaskRepository::open_in_memory().unwrap();
+    let due_at = Utc::now() + Duration::days(3);
+    let created_task = repo.create(CreateTask {
+        description: "Planned task".to_string(),
+        priority: Some(TaskPriority::Urgent),
+        due_at: Some(due_at),
+        parent_id: None,
+        recurrence: None,
+        tags: Vec::new(),
+        project_id: None,
+        owner_id: None,
+    }).await.unwrap();
+
+    let stored = repo.get_by_id(created_task.id).await.unwrap();
+    assert_eq!(stored.priority, Some(TaskPriority::Urgent));
+    assert_eq!(stored.due_at, Some(due_at));
+
+    // 優先度の変更と期限の解除
+    let update_task = UpdateTask {
+        priority: Some(TaskPriority::Low),
+        due_at: Some(None),
+        ..Default::default()
+    };
+    repo.update(created_task.id, update_task).await.unwrap();
+    let stored = repo.get_by_id(created_task.id).await.unwrap();
+    assert_eq!(stored.priority, Some(TaskPriority::Low));
+    assert_eq!(stored.due_at, None);
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_migrates_old_schema() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tasks.db");
+
+    // 優先度と期限の列がない古いスキーマのデータベース
+    {
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE tasks (
+                id          INTEGER PRIMARY KEY AUTOINCREMENT,
+                description TEXT    NOT NULL,
+                completed   INTEGER NOT NULL DEFAULT 0,
+                created_at  TEXT    NOT NULL,
+                updated_at  TEXT    NOT NULL
+            );
+            INSERT INTO tasks (description, completed, created_at, updated_at)
+            VALUES ('Old task', 0, '2024-01-01 00:00:00+00:00', '2024-01-01 00:00:00+00:00');",
+        ).unwrap();
+    }
+
+    let repo = SqliteTaskRepository::open(&path).unwrap();
+    let old_task = repo.get_by_id(1).await.unwrap();
+    assert_eq!(old_task.description, "Old task");
+    assert_eq!(old_task.priority, None);
+    assert_eq!(old_task.due_at, None);
+    assert_eq!(old_task.version, 1);
+    assert_eq!(old_task.deleted_at, None);
+    assert_eq!(old_task.parent_id, None);
+
+    let update_task = UpdateTask { priority: Some(TaskPriority::High), ..Default::default() };
+    let updated = repo.update(1, update_task).await.unwrap();
+    assert_eq!(updated.priority, Some(TaskPriority::High));
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_shutdown_checkpoints_wal() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tasks.db");
+    let wal = dir.path().join("tasks.db-wal");
+
+    let repo = SqliteTaskRepository::open(&path).unwrap();
+    let task = repo.create(CreateTask { description: "Logged task".to_string(), ..Default::default() }).await.unwrap();
+
+    // 書き込みはまずWALに追記されること
+    assert!(std::fs::metadata(&wal).unwrap().len() > 0);
+
+    // 停止時にデータベースファイルへ反映され、WALが空になること
+    repo.shutdown().await.unwrap();
+    assert_eq!(std::fs::metadata(&wal).map(|m| m.len()).unwrap_or(0), 0);
+    drop(repo);
+
+    let repo = SqliteTaskRepository::open(&path).unwrap();
+    assert_eq!(repo.get_by_id(task.id).await.unwrap(), task);
+}
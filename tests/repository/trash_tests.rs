@@ -0,0 +1,82 @@
+use chrono::{Duration, Utc};
+use todo_api::domain::model::task::{CreateTask, UpdateTask};
+use todo_api::domain::repository::task::{TaskError, TaskQuery, TaskRepository};
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+
+async fn create_tagged<R: TaskRepository>(repo: &R, description: &str) -> u64 {
+    let task = repo.create(CreateTask { description: description.to_string(), ..Default::default() }).await.unwrap();
+    let tags = UpdateTask { add_tags: vec!["work".to_string()], ..Default::default() };
+    repo.update(task.id, tags).await.unwrap().id
+}
+
+async fn assert_trash_contract<R: TaskRepository>(repo: R) {
+    let kept = create_tagged(&repo, "Kept").await;
+    let deleted = create_tagged(&repo, "Deleted").await;
+    let before_delete = repo.get_by_id(deleted).await.unwrap();
+
+    repo.delete(deleted).await.unwrap();
+
+    // ゴミ箱のタスクは取得・一覧・集計のいずれにも現れない
+    assert!(matches!(repo.get_by_id(deleted).await, Err(TaskError::NotFound(_))));
+    assert!(matches!(repo.delete(deleted).await, Err(TaskError::NotFound(_))));
+    let ids: Vec<u64> = repo.get_all().await.unwrap().iter().map(|task| task.id).collect();
+    assert_eq!(ids, vec![kept]);
+    let query = TaskQuery { tags: vec!["work".to_string()], ..TaskQuery::default() };
+    assert_eq!(repo.query(query).await.unwrap().tasks.len(), 1);
+    assert_eq!(repo.count_tasks().await.unwrap().total, 1);
+    assert_eq!(repo.get_tag_counts().await.unwrap()[0].count, 1);
+
+    let trash = repo.get_trash().await.unwrap();
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].id, deleted);
+    assert!(trash[0].deleted_at.is_some());
+    assert_eq!(trash[0].version, before_delete.version + 1);
+
+    // 元に戻すとタグも含めて元どおりになる
+    assert!(matches!(repo.restore(kept).await, Err(TaskError::NotFound(_))));
+    let restored = repo.restore(deleted).await.unwrap();
+    assert_eq!(restored.deleted_at, None);
+    assert_eq!(restored.version, before_delete.version + 2);
+    assert_eq!(restored.tags, before_delete.tags);
+    assert_eq!(repo.get_by_id(deleted).await.unwrap(), restored);
+    assert_eq!(repo.get_tag_counts().await.unwrap()[0].count, 2);
+    assert!(repo.get_trash().await.unwrap().is_empty());
+}
+
+async fn assert_purge_contract<R: TaskRepository>(repo: R) {
+    let first = create_tagged(&repo, "First").await;
+    let second = create_tagged(&repo, "Second").await;
+    repo.delete(first).await.unwrap();
+    repo.delete(second).await.unwrap();
+
+    // 指定した日時より前に削除したタスクだけを消す
+    assert_eq!(repo.purge_trash(Some(Utc::now() - Duration::days(1)), None).await.unwrap(), 0);
+    assert_eq!(repo.get_trash().await.unwrap().len(), 2);
+
+    assert_eq!(repo.purge_trash(None, None).await.unwrap(), 2);
+    assert!(repo.get_trash().await.unwrap().is_empty());
+    assert!(matches!(repo.restore(first).await, Err(TaskError::NotFound(_))));
+    assert!(repo.get_tag_counts().await.unwrap().is_empty());
+    assert_eq!(repo.purge_trash(Some(Utc::now() + Duration::days(1)), None).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_trash() {
+    assert_trash_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_trash() {
+    assert_trash_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_purge_trash() {
+    assert_purge_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_purge_trash() {
+    assert_purge_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
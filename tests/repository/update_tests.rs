@@ -1,4 +1,5 @@
 use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
 use todo_api::domain::model::task::{CreateTask, UpdateTask};
 use todo_api::domain::repository::task::{TaskRepository, TaskError};
 
@@ -9,6 +10,7 @@ async fn test_in_memory_repository_update() {
     // タスクを作成
     let create_task = CreateTask {
         description: "Original task".to_string(),
+        ..Default::default()
     };
     let created_task = repo.create(create_task).await.unwrap();
     
@@ -16,11 +18,31 @@ async fn test_in_memory_repository_update() {
     let update_task = UpdateTask {
         description: Some("Updated task".to_string()),
         completed: Some(true),
+        ..Default::default()
     };
     let result = repo.update(created_task.id, update_task).await.unwrap();
     
     assert_eq!(result.description, "Updated task");
-    assert_eq!(result.completed, true);
+    assert!(result.completed);
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_update() {
+    let repo = SqliteTaskRepository::open_in_memory().unwrap();
+    let created_task = repo.create(CreateTask { description: "Original task".to_string(), ..Default::default() }).await.unwrap();
+
+    let update_task = UpdateTask {
+        description: Some("Updated task".to_string()),
+        completed: Some(true),
+        ..Default::default()
+    };
+    let result = repo.update(created_task.id, update_task).await.unwrap();
+    assert_eq!(result.description, "Updated task");
+    assert!(result.completed);
+
+    // 更新内容が保存されていること
+    let stored = repo.get_by_id(created_task.id).await.unwrap();
+    assert_eq!(stored, result);
 }
 
 #[tokio::test]
@@ -30,6 +52,7 @@ async fn test_in_memory_repository_empty_update() {
     // タスクを作成
     let create_task = CreateTask {
         description: "Original task".to_string(),
+        ..Default::default()
     };
     let created_task = repo.create(create_task).await.unwrap();
     
@@ -37,6 +60,21 @@ async fn test_in_memory_repository_empty_update() {
     let update_task = UpdateTask {
         description: None,
         completed: None,
+        ..Default::default()
+    };
+    let result = repo.update(created_task.id, update_task).await;
+    assert!(matches!(result, Err(TaskError::InvalidOperation(_))));
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_empty_update() {
+    let repo = SqliteTaskRepository::open_in_memory().unwrap();
+    let created_task = repo.create(CreateTask { description: "Original task".to_string(), ..Default::default() }).await.unwrap();
+
+    let update_task = UpdateTask {
+        description: None,
+        completed: None,
+        ..Default::default()
     };
     let result = repo.update(created_task.id, update_task).await;
     assert!(matches!(result, Err(TaskError::InvalidOperation(_))));
@@ -49,6 +87,7 @@ async fn test_in_memory_repository_complete() {
     // タスクを作成
     let create_task = CreateTask {
         description: "Task to complete".to_string(),
+        ..Default::default()
     };
     let created_task = repo.create(create_task).await.unwrap();
     
@@ -64,6 +103,7 @@ async fn test_in_memory_repository_uncomplete() {
     // タスクを作成
     let create_task = CreateTask {
         description: "Task to uncomplete".to_string(),
+        ..Default::default()
     };
     let created_task = repo.create(create_task).await.unwrap();
     
@@ -72,6 +112,18 @@ async fn test_in_memory_repository_uncomplete() {
     assert!(completed_task.completed);
     
     // タスクを未完了に戻す
+    let uncompleted_task = repo.uncomplete(created_task.id).await.unwrap();
+    assert!(!uncompleted_task.completed);
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_complete_and_uncomplete() {
+    let repo = SqliteTaskRepository::open_in_memory().unwrap();
+    let created_task = repo.create(CreateTask { description: "Task to complete".to_string(), ..Default::default() }).await.unwrap();
+
+    let completed_task = repo.complete(created_task.id).await.unwrap();
+    assert!(completed_task.completed);
+
     let uncompleted_task = repo.uncomplete(created_task.id).await.unwrap();
     assert!(!uncompleted_task.completed);
 } 
\ No newline at end of file
@@ -0,0 +1,86 @@
+use todo_api::domain::model::task::CreateTask;
+use todo_api::domain::model::user::{CreateUser, UserValidationError, DEFAULT_USER_ID, DEFAULT_USER_NAME};
+use todo_api::domain::repository::project::{ProjectRepository, ProjectStorage};
+use todo_api::domain::repository::task::{TaskQuery, TaskRepository};
+use todo_api::domain::repository::user::{UserError, UserRepository, UserStorage};
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+
+fn task_of(description: &str, owner_id: Option<u64>) -> CreateTask {
+    CreateTask { description: description.to_string(), owner_id, ..Default::default() }
+}
+
+async fn assert_user_contract<R: TaskRepository + ProjectStorage + UserStorage>(repo: R) {
+    let users = repo.users();
+
+    // 既定の利用者は最初からいる
+    let all = users.get_all().await.unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].id, DEFAULT_USER_ID);
+    assert_eq!(all[0].name, DEFAULT_USER_NAME);
+
+    let alice = users.create(CreateUser { name: " alice ".to_string() }).await.unwrap();
+    assert_eq!(alice.name, "alice");
+    assert_ne!(alice.id, DEFAULT_USER_ID);
+    assert!(matches!(
+        users.create(CreateUser { name: "ALICE".to_string() }).await,
+        Err(UserError::ValidationError(UserValidationError::DuplicateName(_)))
+    ));
+    assert!(matches!(
+        users.create(CreateUser { name: "bad name".to_string() }).await,
+        Err(UserError::ValidationError(UserValidationError::InvalidName(_)))
+    ));
+    assert_eq!(users.get_by_id(alice.id).await.unwrap().name, "alice");
+    assert!(matches!(users.get_by_id(999).await, Err(UserError::NotFound(999))));
+    assert_eq!(users.get_all().await.unwrap().len(), 2);
+
+    // 持ち主を省略したタスクは既定の利用者のものになり、持ち主で絞り込める
+    let mine = repo.create(task_of("Mine", None)).await.unwrap();
+    assert_eq!(mine.owner_id, DEFAULT_USER_ID);
+    let theirs = repo.create(task_of("Theirs", Some(alice.id))).await.unwrap();
+    assert_eq!(theirs.owner_id, alice.id);
+    assert_eq!(repo.get_by_id(theirs.id).await.unwrap().owner_id, alice.id);
+    let query = TaskQuery { owner_id: Some(alice.id), ..Default::default() };
+    let ids: Vec<u64> = repo.query(query).await.unwrap().tasks.iter().map(|task| task.id).collect();
+    assert_eq!(ids, vec![theirs.id]);
+    assert_eq!(repo.query(TaskQuery::default()).await.unwrap().tasks.len(), 2);
+
+    // プロジェクトのタスク数は利用者ごとに数えられる
+    let projects = repo.projects();
+    assert_eq!(projects.count_tasks(None).await.unwrap().values().map(|counts| counts.total).sum::<usize>(), 2);
+    assert_eq!(projects.count_tasks(Some(alice.id)).await.unwrap().values().map(|counts| counts.total).sum::<usize>(), 1);
+
+    // ゴミ箱は持ち主を指定すると、その利用者のタスクだけを完全に削除する
+    repo.delete(mine.id).await.unwrap();
+    repo.delete(theirs.id).await.unwrap();
+    assert_eq!(repo.purge_trash(None, Some(alice.id)).await.unwrap(), 1);
+    let trash: Vec<u64> = repo.get_trash().await.unwrap().iter().map(|task| task.id).collect();
+    assert_eq!(trash, vec![mine.id]);
+    assert_eq!(repo.purge_trash(None, None).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_users() {
+    assert_user_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_users() {
+    assert_user_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_users_persist_across_reopen() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("tasks.db");
+
+    let repo = SqliteTaskRepository::open(&path).unwrap();
+    let bob = repo.users().create(CreateUser { name: "bob".to_string() }).await.unwrap();
+    repo.create(task_of("Bob's task", Some(bob.id))).await.unwrap();
+    drop(repo);
+
+    let repo = SqliteTaskRepository::open(&path).unwrap();
+    let users = repo.users().get_all().await.unwrap();
+    assert_eq!(users.iter().map(|user| user.name.as_str()).collect::<Vec<_>>(), vec![DEFAULT_USER_NAME, "bob"]);
+    assert_eq!(repo.get_all().await.unwrap()[0].owner_id, bob.id);
+}
@@ -1,4 +1,5 @@
 use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
 use todo_api::domain::model::task::CreateTask;
 use todo_api::domain::repository::task::{TaskRepository, TaskError};
 
@@ -9,7 +10,16 @@ async fn test_in_memory_repository_validation() {
     // 空の説明でタスクを作成しようとするとエラー
     let create_task = CreateTask {
         description: "".to_string(),
+        ..Default::default()
     };
     let result = repo.create(create_task).await;
     assert!(matches!(result, Err(TaskError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_validation() {
+    let repo = SqliteTaskRepository::open_in_memory().unwrap();
+
+    let result = repo.create(CreateTask { description: "".to_string(), ..Default::default() }).await;
+    assert!(matches!(result, Err(TaskError::ValidationError(_))));
 } 
\ No newline at end of file
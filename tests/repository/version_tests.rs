@@ -0,0 +1,47 @@
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::interface::gateway::sqlite::task::SqliteTaskRepository;
+use todo_api::domain::model::task::{CreateTask, UpdateTask};
+use todo_api::domain::repository::task::{TaskError, TaskRepository};
+
+fn describe(description: &str) -> UpdateTask {
+    UpdateTask { description: Some(description.to_string()), ..Default::default() }
+}
+
+async fn assert_version_contract<R: TaskRepository>(repo: R) {
+    let task = repo.create(CreateTask { description: "Task".to_string(), ..Default::default() }).await.unwrap();
+    assert_eq!(task.version, 1);
+
+    // 書き込みごとに1つずつ増える
+    assert_eq!(repo.update(task.id, describe("Updated")).await.unwrap().version, 2);
+    assert_eq!(repo.complete(task.id).await.unwrap().version, 3);
+    assert_eq!(repo.uncomplete(task.id).await.unwrap().version, 4);
+    assert_eq!(repo.get_by_id(task.id).await.unwrap().version, 4);
+
+    // 内容が変わらない更新では増えない
+    let no_op = UpdateTask { remove_tags: vec!["missing".to_string()], ..Default::default() };
+    assert_eq!(repo.update(task.id, no_op).await.unwrap().version, 4);
+
+    // 版数が一致しない条件付き更新は拒否され、内容も変わらない
+    let result = repo.update_if_version(task.id, describe("Stale"), 3).await;
+    assert!(matches!(result, Err(TaskError::VersionMismatch { expected: 3, actual: 4, .. })));
+    assert_eq!(repo.get_by_id(task.id).await.unwrap().description, "Updated");
+
+    let updated = repo.update_if_version(task.id, describe("Fresh"), 4).await.unwrap();
+    assert_eq!((updated.description.as_str(), updated.version), ("Fresh", 5));
+
+    // 条件付き削除
+    let result = repo.delete_if_version(task.id, 4).await;
+    assert!(matches!(result, Err(TaskError::VersionMismatch { expected: 4, actual: 5, .. })));
+    repo.delete_if_version(task.id, 5).await.unwrap();
+    assert!(matches!(repo.delete_if_version(task.id, 5).await, Err(TaskError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_in_memory_repository_version() {
+    assert_version_contract(InMemoryTaskRepository::new()).await;
+}
+
+#[tokio::test]
+async fn test_sqlite_repository_version() {
+    assert_version_contract(SqliteTaskRepository::open_in_memory().unwrap()).await;
+}
@@ -17,12 +17,13 @@ mock! {
     }
 }
 
-use todo_api::usecase::task::TaskUsecase;
+use todo_api::usecase::task::TaskUsecaseImpl;
 
 #[tokio::test]
 async fn test_create_task_with_validation() {
     let create_task = CreateTask {
         description: "Valid task".to_string(),
+        ..Default::default()
     };
     let created_task = Task::new(1, "Valid task".to_string()).unwrap();
     
@@ -30,7 +31,7 @@ async fn test_create_task_with_validation() {
     mock_repo.expect_create()
         .times(1)
         .returning(move |_| Ok(created_task.clone()));
-    let usecase = TaskUsecase::new(mock_repo);
+    let usecase = TaskUsecaseImpl::new(mock_repo);
     
     let result = usecase.create_task(create_task).await;
     assert!(result.is_ok());
@@ -17,7 +17,7 @@ mock! {
     }
 }
 
-use todo_api::usecase::task::{TaskUsecase, TaskError};
+use todo_api::usecase::task::{TaskUsecaseImpl, TaskError};
 
 #[tokio::test]
 async fn test_delete_task_with_existence_check() {
@@ -26,8 +26,8 @@ async fn test_delete_task_with_existence_check() {
         .with(mockall::predicate::eq(999))
         .times(1)
         .returning(|_| Err(todo_api::domain::repository::task::TaskError::NotFound(999)));
-    let usecase = TaskUsecase::new(mock_repo);
+    let usecase = TaskUsecaseImpl::new(mock_repo);
     
     let result = usecase.delete_task(999).await;
-    assert!(matches!(result, Err(TaskError::Repository(_))));
+    assert!(matches!(result, Err(TaskError::NotFound(999))));
 } 
\ No newline at end of file
@@ -1,2 +1,36 @@
-// ユースケースの読み取り機能に関するテスト
-// 現在は空ですが、将来の拡張のために作成 
\ No newline at end of file
+use todo_api::domain::model::task::CreateTask;
+use todo_api::domain::repository::task::TaskQuery;
+use todo_api::interface::gateway::inmemory::task::InMemoryTaskRepository;
+use todo_api::usecase::task::{TaskUsecaseImpl, DEFAULT_PAGE_LIMIT};
+
+#[tokio::test]
+async fn test_list_tasks_applies_default_limit() {
+    let usecase = TaskUsecaseImpl::new(InMemoryTaskRepository::new());
+    for i in 0..=DEFAULT_PAGE_LIMIT {
+        usecase.create_task(CreateTask { description: format!("Task {}", i), ..Default::default() }).await.unwrap();
+    }
+
+    // 件数の指定がなければ既定の件数で区切られる
+    let page = usecase.list_tasks(TaskQuery::default()).await.unwrap();
+    assert_eq!(page.tasks.len(), DEFAULT_PAGE_LIMIT);
+    assert!(page.next.is_some());
+
+    // 続きのページに残りの1件が含まれる
+    let next = TaskQuery { after: page.next, ..TaskQuery::default() };
+    let page = usecase.list_tasks(next).await.unwrap();
+    assert_eq!(page.tasks.len(), 1);
+    assert!(page.next.is_none());
+}
+
+#[tokio::test]
+async fn test_list_tasks_with_limit() {
+    let usecase = TaskUsecaseImpl::new(InMemoryTaskRepository::new());
+    for i in 0..3 {
+        usecase.create_task(CreateTask { description: format!("Task {}", i), ..Default::default() }).await.unwrap();
+    }
+
+    let query = TaskQuery { limit: Some(2), ..TaskQuery::default() };
+    let page = usecase.list_tasks(query).await.unwrap();
+    assert_eq!(page.tasks.len(), 2);
+    assert_eq!(page.next.map(|cursor| cursor.id), Some(page.tasks[1].id));
+}
@@ -17,7 +17,7 @@ mock! {
     }
 }
 
-use todo_api::usecase::task::TaskUsecase;
+use todo_api::usecase::task::TaskUsecaseImpl;
 use std::sync::Arc;
 
 #[tokio::test]
@@ -34,7 +34,7 @@ async fn test_get_completed_tasks() {
     mock_repo.expect_get_all()
         .times(1)
         .returning(move || Ok((*all_tasks_clone).clone()));
-    let usecase = TaskUsecase::new(mock_repo);
+    let usecase = TaskUsecaseImpl::new(mock_repo);
     
     let completed_tasks = usecase.get_completed_tasks().await.unwrap();
     assert_eq!(completed_tasks.len(), 1);
@@ -54,7 +54,7 @@ async fn test_get_pending_tasks() {
     mock_repo.expect_get_all()
         .times(1)
         .returning(move || Ok((*all_tasks_clone).clone()));
-    let usecase = TaskUsecase::new(mock_repo);
+    let usecase = TaskUsecaseImpl::new(mock_repo);
     
     let pending_tasks = usecase.get_pending_tasks().await.unwrap();
     assert_eq!(pending_tasks.len(), 1);
@@ -73,7 +73,7 @@ async fn test_search_tasks() {
     mock_repo.expect_get_all()
         .times(1)
         .returning(move || Ok((*all_tasks_clone).clone()));
-    let usecase = TaskUsecase::new(mock_repo);
+    let usecase = TaskUsecaseImpl::new(mock_repo);
     
     let search_results = usecase.search_tasks("buy").await.unwrap();
     assert_eq!(search_results.len(), 2);
@@ -93,7 +93,7 @@ async fn test_get_tasks_by_status() {
     mock_repo.expect_get_all()
         .times(1)
         .returning(move || Ok((*all_tasks_clone).clone()));
-    let usecase = TaskUsecase::new(mock_repo);
+    let usecase = TaskUsecaseImpl::new(mock_repo);
     
     let completed_tasks = usecase.get_tasks_by_status(true).await.unwrap();
     assert_eq!(completed_tasks.len(), 1);
@@ -103,8 +103,60 @@ async fn test_get_tasks_by_status() {
     mock_repo2.expect_get_all()
         .times(1)
         .returning(move || Ok((*all_tasks_clone2).clone()));
-    let usecase2 = TaskUsecase::new(mock_repo2);
+    let usecase2 = TaskUsecaseImpl::new(mock_repo2);
     
     let pending_tasks = usecase2.get_tasks_by_status(false).await.unwrap();
     assert_eq!(pending_tasks.len(), 1);
-} 
\ No newline at end of file
+}
+
+/// 期限を指定したタスクを作る（検証を通さずに過去の期限も設定する）
+fn task_due_in(id: u64, hours: Option<i64>) -> Task {
+    let mut task = Task::new(id, format!("Task {}", id)).unwrap();
+    task.due_at = hours.map(|h| chrono::Utc::now() + chrono::Duration::hours(h));
+    task
+}
+
+#[tokio::test]
+async fn test_get_overdue_tasks() {
+    let mut completed_overdue = task_due_in(4, Some(-1));
+    completed_overdue.complete();
+    let tasks = vec![
+        task_due_in(1, Some(-1)),
+        task_due_in(2, Some(-3)),
+        task_due_in(3, Some(2)),
+        completed_overdue,
+        task_due_in(5, None),
+    ];
+
+    let mut mock_repo = MockTaskRepository::default();
+    mock_repo.expect_get_all()
+        .times(1)
+        .returning(move || Ok(tasks.clone()));
+    let usecase = TaskUsecaseImpl::new(mock_repo);
+
+    // 未完了で期限切れのタスクだけが期限の早い順に返る
+    let overdue_tasks = usecase.get_overdue_tasks().await.unwrap();
+    let ids: Vec<u64> = overdue_tasks.iter().map(|t| t.id).collect();
+    assert_eq!(ids, vec![2, 1]);
+}
+
+#[tokio::test]
+async fn test_get_tasks_due_within() {
+    let tasks = vec![
+        task_due_in(1, Some(30)),
+        task_due_in(2, Some(10)),
+        task_due_in(3, Some(-1)),
+        task_due_in(4, Some(2)),
+        task_due_in(5, None),
+    ];
+
+    let mut mock_repo = MockTaskRepository::default();
+    mock_repo.expect_get_all()
+        .times(1)
+        .returning(move || Ok(tasks.clone()));
+    let usecase = TaskUsecaseImpl::new(mock_repo);
+
+    let due_tasks = usecase.get_tasks_due_within(chrono::Duration::hours(24)).await.unwrap();
+    let ids: Vec<u64> = due_tasks.iter().map(|t| t.id).collect();
+    assert_eq!(ids, vec![4, 2]);
+}
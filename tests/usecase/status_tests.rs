@@ -17,7 +17,7 @@ mock! {
     }
 }
 
-use todo_api::usecase::task::TaskUsecase;
+use todo_api::usecase::task::TaskUsecaseImpl;
 
 #[tokio::test]
 async fn test_complete_task() {
@@ -28,7 +28,7 @@ async fn test_complete_task() {
         .with(mockall::predicate::eq(1))
         .times(1)
         .returning(move |_| Ok(completed_task.clone()));
-    let usecase = TaskUsecase::new(mock_repo);
+    let usecase = TaskUsecaseImpl::new(mock_repo);
     
     let result = usecase.complete_task(1).await;
     assert!(result.is_ok());
@@ -43,7 +43,7 @@ async fn test_uncomplete_task() {
         .with(mockall::predicate::eq(1))
         .times(1)
         .returning(move |_| Ok(uncompleted_task.clone()));
-    let usecase = TaskUsecase::new(mock_repo);
+    let usecase = TaskUsecaseImpl::new(mock_repo);
     
     let result = usecase.uncomplete_task(1).await;
     assert!(result.is_ok());
@@ -17,13 +17,14 @@ mock! {
     }
 }
 
-use todo_api::usecase::task::TaskUsecase;
+use todo_api::usecase::task::TaskUsecaseImpl;
 
 #[tokio::test]
 async fn test_update_task_with_validation() {
     let update_task = UpdateTask {
         description: None,
         completed: None,
+        ..Default::default()
     };
     let updated_task = Task::new(1, "Updated task".to_string()).unwrap();
     
@@ -31,7 +32,7 @@ async fn test_update_task_with_validation() {
     mock_repo.expect_update()
         .times(1)
         .returning(move |_, _| Ok(updated_task.clone()));
-    let usecase = TaskUsecase::new(mock_repo);
+    let usecase = TaskUsecaseImpl::new(mock_repo);
     
     let result = usecase.update_task(1, update_task).await;
     assert!(result.is_ok());